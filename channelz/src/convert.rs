@@ -0,0 +1,439 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Converts the channelz data of `grpcio::channelz` into messages.
+//!
+//! Messages of all codecs have the same field names except `ref`, so the
+//! conversion is shared. Nested types, enum values and oneof variants are
+//! imported from [`names`] under the names used by prost.
+
+// Conversions and default fields are only needed by some codecs.
+#![allow(clippy::needless_update, clippy::useless_conversion)]
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use grpcio::channelz::{
+    CallCounters, ChannelInfo, ChannelTrace as TraceInfo, EntityRef, SecurityInfo, ServerInfo,
+    SocketAddress, SocketInfo, TraceSeverity,
+};
+use grpcio::ConnectivityState;
+
+use self::names::*;
+use crate::proto::{
+    Address, Channel, ChannelConnectivityState, ChannelData, ChannelRef, ChannelTrace,
+    ChannelTraceEvent, Security, Server, ServerData, ServerRef, Socket, SocketData, SocketRef,
+    Subchannel, SubchannelRef,
+};
+
+#[cfg(feature = "protobuf-codec")]
+#[macro_use]
+mod names {
+    pub use crate::proto::{
+        Address_OtherAddress as OtherAddress, Address_TcpIpAddress as TcpIpAddress,
+        Address_UdsAddress as UdsAddress, Security_OtherSecurity as OtherSecurity,
+        Security_Tls as Tls,
+    };
+    pub use protobuf::well_known_types::{Int64Value, Timestamp};
+
+    pub mod state {
+        pub use crate::proto::ChannelConnectivityState_State::{
+            CONNECTING as Connecting, IDLE as Idle, READY as Ready, SHUTDOWN as Shutdown,
+            TRANSIENT_FAILURE as TransientFailure, UNKNOWN as Unknown,
+        };
+    }
+    pub mod severity {
+        pub use crate::proto::ChannelTraceEvent_Severity::{
+            CT_ERROR as CtError, CT_INFO as CtInfo, CT_UNKNOWN as CtUnknown,
+            CT_WARNING as CtWarning,
+        };
+    }
+    pub mod child_ref {
+        pub use crate::proto::ChannelTraceEvent_oneof_child_ref::{
+            channel_ref as ChannelRef, subchannel_ref as SubchannelRef,
+        };
+    }
+    pub mod address {
+        pub use crate::proto::Address_oneof_address::{
+            other_address as OtherAddress, tcpip_address as TcpipAddress, uds_address as UdsAddress,
+        };
+    }
+    pub mod model {
+        pub use crate::proto::Security_oneof_model::{other as Other, tls as Tls};
+    }
+    pub mod cipher_suite {
+        pub use crate::proto::Security_Tls_oneof_cipher_suite::standard_name as StandardName;
+    }
+
+    pub fn int64_value(value: i64) -> Option<Int64Value> {
+        Some(Int64Value {
+            value,
+            ..Default::default()
+        })
+    }
+
+    macro_rules! ref_field {
+        ($m:expr) => {
+            $m.field_ref
+        };
+    }
+}
+
+#[cfg(feature = "protobufv3-codec")]
+#[macro_use]
+mod names {
+    pub use crate::proto::address::{OtherAddress, TcpIpAddress, UdsAddress};
+    pub use crate::proto::security::{OtherSecurity, Tls};
+    pub use protobufv3::well_known_types::{timestamp::Timestamp, wrappers::Int64Value};
+
+    pub mod state {
+        pub use crate::proto::channel_connectivity_state::State::{
+            CONNECTING as Connecting, IDLE as Idle, READY as Ready, SHUTDOWN as Shutdown,
+            TRANSIENT_FAILURE as TransientFailure, UNKNOWN as Unknown,
+        };
+    }
+    pub mod severity {
+        pub use crate::proto::channel_trace_event::Severity::{
+            CT_ERROR as CtError, CT_INFO as CtInfo, CT_UNKNOWN as CtUnknown,
+            CT_WARNING as CtWarning,
+        };
+    }
+    pub mod child_ref {
+        pub use crate::proto::channel_trace_event::Child_ref::*;
+    }
+    pub mod address {
+        pub use crate::proto::address::Address::*;
+    }
+    pub mod model {
+        pub use crate::proto::security::Model::*;
+    }
+    pub mod cipher_suite {
+        pub use crate::proto::security::tls::Cipher_suite::*;
+    }
+
+    pub fn int64_value(value: i64) -> Option<Int64Value> {
+        Some(Int64Value {
+            value,
+            ..Default::default()
+        })
+    }
+
+    macro_rules! ref_field {
+        ($m:expr) => {
+            $m.ref_
+        };
+    }
+}
+
+#[cfg(feature = "prost-codec")]
+#[macro_use]
+mod names {
+    pub use crate::proto::address::{OtherAddress, TcpIpAddress, UdsAddress};
+    pub use crate::proto::security::{OtherSecurity, Tls};
+    pub use prost_types::Timestamp;
+
+    pub mod state {
+        pub use crate::proto::channel_connectivity_state::State::*;
+    }
+    pub mod severity {
+        pub use crate::proto::channel_trace_event::Severity::*;
+    }
+    pub mod child_ref {
+        pub use crate::proto::channel_trace_event::ChildRef::*;
+    }
+    pub mod address {
+        pub use crate::proto::address::Address::*;
+    }
+    pub mod model {
+        pub use crate::proto::security::Model::*;
+    }
+    pub mod cipher_suite {
+        pub use crate::proto::security::tls::CipherSuite::*;
+    }
+
+    /// prost maps `google.protobuf.Int64Value` to `Option<i64>`.
+    pub fn int64_value(value: i64) -> Option<i64> {
+        Some(value)
+    }
+
+    macro_rules! ref_field {
+        ($m:expr) => {
+            $m.r#ref
+        };
+    }
+}
+
+fn timestamp(t: SystemTime) -> Option<Timestamp> {
+    // C core never reports time before the epoch.
+    let d = t.duration_since(UNIX_EPOCH).ok()?;
+    Some(Timestamp {
+        seconds: d.as_secs() as i64,
+        nanos: d.subsec_nanos() as i32,
+        ..Default::default()
+    })
+}
+
+fn channel_ref(r: EntityRef) -> ChannelRef {
+    ChannelRef {
+        channel_id: r.id as i64,
+        name: r.name,
+        ..Default::default()
+    }
+}
+
+fn subchannel_ref(r: EntityRef) -> SubchannelRef {
+    SubchannelRef {
+        subchannel_id: r.id as i64,
+        name: r.name,
+        ..Default::default()
+    }
+}
+
+pub fn socket_ref(r: EntityRef) -> SocketRef {
+    SocketRef {
+        socket_id: r.id as i64,
+        name: r.name,
+        ..Default::default()
+    }
+}
+
+fn trace(t: TraceInfo) -> ChannelTrace {
+    let events: Vec<_> = t
+        .events
+        .into_iter()
+        .map(|e| {
+            let severity = match e.severity {
+                TraceSeverity::Unknown => severity::CtUnknown,
+                TraceSeverity::Info => severity::CtInfo,
+                TraceSeverity::Warning => severity::CtWarning,
+                TraceSeverity::Error => severity::CtError,
+            };
+            let child_ref = match (e.channel_ref, e.subchannel_ref) {
+                (Some(r), _) => Some(child_ref::ChannelRef(channel_ref(r))),
+                (None, Some(r)) => Some(child_ref::SubchannelRef(subchannel_ref(r))),
+                (None, None) => None,
+            };
+            ChannelTraceEvent {
+                description: e.description,
+                severity: severity.into(),
+                timestamp: e.timestamp.and_then(timestamp).into(),
+                child_ref,
+                ..Default::default()
+            }
+        })
+        .collect();
+    ChannelTrace {
+        num_events_logged: t.num_events_logged,
+        creation_timestamp: t.creation_timestamp.and_then(timestamp).into(),
+        events: events.into(),
+        ..Default::default()
+    }
+}
+
+fn channel_data(c: &mut ChannelInfo) -> ChannelData {
+    let state = match c.state {
+        Some(ConnectivityState::GRPC_CHANNEL_IDLE) => state::Idle,
+        Some(ConnectivityState::GRPC_CHANNEL_CONNECTING) => state::Connecting,
+        Some(ConnectivityState::GRPC_CHANNEL_READY) => state::Ready,
+        Some(ConnectivityState::GRPC_CHANNEL_TRANSIENT_FAILURE) => state::TransientFailure,
+        Some(ConnectivityState::GRPC_CHANNEL_SHUTDOWN) => state::Shutdown,
+        None => state::Unknown,
+    };
+    let CallCounters {
+        calls_started,
+        calls_succeeded,
+        calls_failed,
+        last_call_started,
+    } = std::mem::take(&mut c.calls);
+    ChannelData {
+        state: Some(ChannelConnectivityState {
+            state: state.into(),
+            ..Default::default()
+        })
+        .into(),
+        target: std::mem::take(&mut c.target),
+        trace: c.trace.take().map(trace).into(),
+        calls_started,
+        calls_succeeded,
+        calls_failed,
+        last_call_started_timestamp: last_call_started.and_then(timestamp).into(),
+        ..Default::default()
+    }
+}
+
+pub fn channel(mut c: ChannelInfo) -> Channel {
+    let mut channel = Channel {
+        data: Some(channel_data(&mut c)).into(),
+        channel_ref: c
+            .channel_refs
+            .into_iter()
+            .map(channel_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        subchannel_ref: c
+            .subchannel_refs
+            .into_iter()
+            .map(subchannel_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        socket_ref: c
+            .socket_refs
+            .into_iter()
+            .map(socket_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        ..Default::default()
+    };
+    ref_field!(channel) = Some(ChannelRef {
+        channel_id: c.id as i64,
+        name: c.name,
+        ..Default::default()
+    })
+    .into();
+    channel
+}
+
+pub fn subchannel(mut c: ChannelInfo) -> Subchannel {
+    let mut subchannel = Subchannel {
+        data: Some(channel_data(&mut c)).into(),
+        channel_ref: c
+            .channel_refs
+            .into_iter()
+            .map(channel_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        subchannel_ref: c
+            .subchannel_refs
+            .into_iter()
+            .map(subchannel_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        socket_ref: c
+            .socket_refs
+            .into_iter()
+            .map(socket_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        ..Default::default()
+    };
+    ref_field!(subchannel) = Some(SubchannelRef {
+        subchannel_id: c.id as i64,
+        name: c.name,
+        ..Default::default()
+    })
+    .into();
+    subchannel
+}
+
+pub fn server(s: ServerInfo) -> Server {
+    let data = ServerData {
+        trace: s.trace.map(trace).into(),
+        calls_started: s.calls.calls_started,
+        calls_succeeded: s.calls.calls_succeeded,
+        calls_failed: s.calls.calls_failed,
+        last_call_started_timestamp: s.calls.last_call_started.and_then(timestamp).into(),
+        ..Default::default()
+    };
+    let mut server = Server {
+        data: Some(data).into(),
+        listen_socket: s
+            .listen_sockets
+            .into_iter()
+            .map(socket_ref)
+            .collect::<Vec<_>>()
+            .into(),
+        ..Default::default()
+    };
+    ref_field!(server) = Some(ServerRef {
+        server_id: s.id as i64,
+        name: s.name,
+        ..Default::default()
+    })
+    .into();
+    server
+}
+
+fn address(a: SocketAddress) -> Address {
+    let address = match a {
+        SocketAddress::Tcp(addr) => {
+            let ip_address = match addr.ip() {
+                std::net::IpAddr::V4(ip) => ip.octets().to_vec(),
+                std::net::IpAddr::V6(ip) => ip.octets().to_vec(),
+            };
+            address::TcpipAddress(TcpIpAddress {
+                ip_address,
+                port: i32::from(addr.port()),
+                ..Default::default()
+            })
+        }
+        SocketAddress::Uds(filename) => address::UdsAddress(UdsAddress {
+            filename,
+            ..Default::default()
+        }),
+        SocketAddress::Other(name) => address::OtherAddress(OtherAddress {
+            name,
+            ..Default::default()
+        }),
+    };
+    Address {
+        address: Some(address),
+        ..Default::default()
+    }
+}
+
+fn security(s: SecurityInfo) -> Security {
+    let model = match s {
+        SecurityInfo::Tls {
+            cipher_suite,
+            local_certificate,
+            remote_certificate,
+        } => model::Tls(Tls {
+            cipher_suite: Some(cipher_suite::StandardName(cipher_suite)),
+            local_certificate,
+            remote_certificate,
+            ..Default::default()
+        }),
+        SecurityInfo::Other(name) => model::Other(OtherSecurity {
+            name,
+            ..Default::default()
+        }),
+    };
+    Security {
+        model: Some(model),
+        ..Default::default()
+    }
+}
+
+pub fn socket(s: SocketInfo) -> Socket {
+    let data = SocketData {
+        streams_started: s.streams_started,
+        streams_succeeded: s.streams_succeeded,
+        streams_failed: s.streams_failed,
+        messages_sent: s.messages_sent,
+        messages_received: s.messages_received,
+        keep_alives_sent: s.keep_alives_sent,
+        last_local_stream_created_timestamp: s.last_local_stream_created.and_then(timestamp).into(),
+        last_remote_stream_created_timestamp: s
+            .last_remote_stream_created
+            .and_then(timestamp)
+            .into(),
+        last_message_sent_timestamp: s.last_message_sent.and_then(timestamp).into(),
+        last_message_received_timestamp: s.last_message_received.and_then(timestamp).into(),
+        local_flow_control_window: s.local_flow_control_window.and_then(int64_value).into(),
+        remote_flow_control_window: s.remote_flow_control_window.and_then(int64_value).into(),
+        ..Default::default()
+    };
+    let mut socket = Socket {
+        data: Some(data).into(),
+        local: s.local.map(address).into(),
+        remote: s.remote.map(address).into(),
+        security: s.security.map(security).into(),
+        remote_name: s.remote_name,
+        ..Default::default()
+    };
+    ref_field!(socket) = Some(SocketRef {
+        socket_id: s.id as i64,
+        name: s.name,
+        ..Default::default()
+    })
+    .into();
+    socket
+}
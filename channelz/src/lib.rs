@@ -0,0 +1,26 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! grpcio-channelz provides an implementation of `grpc.channelz.v1.Channelz`
+//! service, so that channelz tools can inspect a grpcio process remotely. For
+//! the detail design of channelz, see
+//! https://github.com/grpc/proposal/blob/master/A14-channelz.md.
+//!
+//! ### Usage
+//!
+//! ```ignore
+//! use grpcio_channelz::{create_channelz, ChannelzService};
+//!
+//! let builder = builder.register_service(create_channelz(ChannelzService::new()));
+//! ```
+//!
+//! The service answers queries with the data collected by gRPC C core, which
+//! is exposed by `grpcio::channelz`. Exactly one of the codec features
+//! `protobuf-codec`, `protobufv3-codec` and `prost-codec` should be enabled,
+//! which decides the types of the messages in [`proto`].
+
+mod convert;
+pub mod proto;
+mod service;
+
+pub use self::proto::{create_channelz, Channelz, ChannelzClient};
+pub use self::service::ChannelzService;
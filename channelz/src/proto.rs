@@ -0,0 +1,31 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+#[cfg(feature = "prost-codec")]
+mod reexports {
+    include!("proto/prost/grpc.channelz.v1.rs");
+}
+
+#[cfg(feature = "protobuf-codec")]
+mod protobuf {
+    #[allow(deprecated)]
+    pub mod channelz;
+    pub mod channelz_grpc;
+}
+#[cfg(feature = "protobufv3-codec")]
+mod protobuf_v3 {
+    #[allow(deprecated)]
+    pub mod channelz;
+    pub mod channelz_grpc;
+}
+#[cfg(feature = "protobuf-codec")]
+mod reexports {
+    pub use super::protobuf::channelz::*;
+    // Following is included in channelz import above.
+    // pub use super::protobuf::channelz_grpc::*;
+}
+#[cfg(feature = "protobufv3-codec")]
+mod reexports {
+    pub use super::protobuf_v3::channelz::*;
+}
+
+pub use self::reexports::*;
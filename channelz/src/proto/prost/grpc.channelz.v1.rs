@@ -0,0 +1,1134 @@
+// This file is @generated by prost-build.
+/// Channel is a logical grouping of channels, subchannels, and sockets.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Channel {
+    /// The identifier for this channel. This should be set.
+    #[prost(message, optional, tag = "1")]
+    pub r#ref: ::core::option::Option<ChannelRef>,
+    /// Data specific to this channel.
+    ///
+    /// At most one of 'channel_ref+subchannel_ref' and 'socket' is set.
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<ChannelData>,
+    /// There are no ordering guarantees on the order of channel refs.
+    /// There may not be cycles in the ref graph.
+    /// A channel ref may be present in more than one channel or subchannel.
+    #[prost(message, repeated, tag = "3")]
+    pub channel_ref: ::prost::alloc::vec::Vec<ChannelRef>,
+    /// At most one of 'channel_ref+subchannel_ref' and 'socket' is set.
+    /// There are no ordering guarantees on the order of subchannel refs.
+    /// There may not be cycles in the ref graph.
+    /// A sub channel ref may be present in more than one channel or subchannel.
+    #[prost(message, repeated, tag = "4")]
+    pub subchannel_ref: ::prost::alloc::vec::Vec<SubchannelRef>,
+    /// There are no ordering guarantees on the order of sockets.
+    #[prost(message, repeated, tag = "5")]
+    pub socket_ref: ::prost::alloc::vec::Vec<SocketRef>,
+}
+/// Subchannel is a logical grouping of channels, subchannels, and sockets.
+/// A subchannel is load balanced over by it's ancestor
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Subchannel {
+    /// The identifier for this channel.
+    #[prost(message, optional, tag = "1")]
+    pub r#ref: ::core::option::Option<SubchannelRef>,
+    /// Data specific to this channel.
+    ///
+    /// At most one of 'channel_ref+subchannel_ref' and 'socket' is set.
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<ChannelData>,
+    /// There are no ordering guarantees on the order of channel refs.
+    /// There may not be cycles in the ref graph.
+    /// A channel ref may be present in more than one channel or subchannel.
+    #[prost(message, repeated, tag = "3")]
+    pub channel_ref: ::prost::alloc::vec::Vec<ChannelRef>,
+    /// At most one of 'channel_ref+subchannel_ref' and 'socket' is set.
+    /// There are no ordering guarantees on the order of subchannel refs.
+    /// There may not be cycles in the ref graph.
+    /// A sub channel ref may be present in more than one channel or subchannel.
+    #[prost(message, repeated, tag = "4")]
+    pub subchannel_ref: ::prost::alloc::vec::Vec<SubchannelRef>,
+    /// There are no ordering guarantees on the order of sockets.
+    #[prost(message, repeated, tag = "5")]
+    pub socket_ref: ::prost::alloc::vec::Vec<SocketRef>,
+}
+/// These come from the specified states in this document:
+/// <https://github.com/grpc/grpc/blob/master/doc/connectivity-semantics-and-api.md>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ChannelConnectivityState {
+    #[prost(enumeration = "channel_connectivity_state::State", tag = "1")]
+    pub state: i32,
+}
+/// Nested message and enum types in `ChannelConnectivityState`.
+pub mod channel_connectivity_state {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        Unknown = 0,
+        Idle = 1,
+        Connecting = 2,
+        Ready = 3,
+        TransientFailure = 4,
+        Shutdown = 5,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unknown => "UNKNOWN",
+                Self::Idle => "IDLE",
+                Self::Connecting => "CONNECTING",
+                Self::Ready => "READY",
+                Self::TransientFailure => "TRANSIENT_FAILURE",
+                Self::Shutdown => "SHUTDOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "UNKNOWN" => Some(Self::Unknown),
+                "IDLE" => Some(Self::Idle),
+                "CONNECTING" => Some(Self::Connecting),
+                "READY" => Some(Self::Ready),
+                "TRANSIENT_FAILURE" => Some(Self::TransientFailure),
+                "SHUTDOWN" => Some(Self::Shutdown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Channel data is data related to a specific Channel or Subchannel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelData {
+    /// The connectivity state of the channel or subchannel.  Implementations
+    /// should always set this.
+    #[prost(message, optional, tag = "1")]
+    pub state: ::core::option::Option<ChannelConnectivityState>,
+    /// The target this channel originally tried to connect to.  May be absent
+    #[prost(string, tag = "2")]
+    pub target: ::prost::alloc::string::String,
+    /// A trace of recent events on the channel.  May be absent.
+    #[prost(message, optional, tag = "3")]
+    pub trace: ::core::option::Option<ChannelTrace>,
+    /// The number of calls started on the channel
+    #[prost(int64, tag = "4")]
+    pub calls_started: i64,
+    /// The number of calls that have completed with an OK status
+    #[prost(int64, tag = "5")]
+    pub calls_succeeded: i64,
+    /// The number of calls that have completed with a non-OK status
+    #[prost(int64, tag = "6")]
+    pub calls_failed: i64,
+    /// The last time a call was started on the channel.
+    #[prost(message, optional, tag = "7")]
+    pub last_call_started_timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// A trace event is an interesting thing that happened to a channel or
+/// subchannel, such as creation, address resolution, subchannel creation, etc.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelTraceEvent {
+    /// High level description of the event.
+    #[prost(string, tag = "1")]
+    pub description: ::prost::alloc::string::String,
+    /// the severity of the trace event
+    #[prost(enumeration = "channel_trace_event::Severity", tag = "2")]
+    pub severity: i32,
+    /// When this event occurred.
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// ref of referenced channel or subchannel.
+    /// Optional, only present if this event refers to a child object. For example,
+    /// this field would be filled if this trace event was for a subchannel being
+    /// created.
+    #[prost(oneof = "channel_trace_event::ChildRef", tags = "4, 5")]
+    pub child_ref: ::core::option::Option<channel_trace_event::ChildRef>,
+}
+/// Nested message and enum types in `ChannelTraceEvent`.
+pub mod channel_trace_event {
+    /// The supported severity levels of trace events.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Severity {
+        CtUnknown = 0,
+        CtInfo = 1,
+        CtWarning = 2,
+        CtError = 3,
+    }
+    impl Severity {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::CtUnknown => "CT_UNKNOWN",
+                Self::CtInfo => "CT_INFO",
+                Self::CtWarning => "CT_WARNING",
+                Self::CtError => "CT_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "CT_UNKNOWN" => Some(Self::CtUnknown),
+                "CT_INFO" => Some(Self::CtInfo),
+                "CT_WARNING" => Some(Self::CtWarning),
+                "CT_ERROR" => Some(Self::CtError),
+                _ => None,
+            }
+        }
+    }
+    /// ref of referenced channel or subchannel.
+    /// Optional, only present if this event refers to a child object. For example,
+    /// this field would be filled if this trace event was for a subchannel being
+    /// created.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ChildRef {
+        #[prost(message, tag = "4")]
+        ChannelRef(super::ChannelRef),
+        #[prost(message, tag = "5")]
+        SubchannelRef(super::SubchannelRef),
+    }
+}
+/// ChannelTrace represents the recent events that have occurred on the channel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelTrace {
+    /// Number of events ever logged in this tracing object. This can differ from
+    /// events.size() because events can be overwritten or garbage collected by
+    /// implementations.
+    #[prost(int64, tag = "1")]
+    pub num_events_logged: i64,
+    /// Time that this channel was created.
+    #[prost(message, optional, tag = "2")]
+    pub creation_timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// List of events that have occurred on this channel.
+    #[prost(message, repeated, tag = "3")]
+    pub events: ::prost::alloc::vec::Vec<ChannelTraceEvent>,
+}
+/// ChannelRef is a reference to a Channel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelRef {
+    /// The globally unique id for this channel.  Must be a positive number.
+    #[prost(int64, tag = "1")]
+    pub channel_id: i64,
+    /// An optional name associated with the channel.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
+/// SubchannelRef is a reference to a Subchannel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubchannelRef {
+    /// The globally unique id for this subchannel.  Must be a positive number.
+    #[prost(int64, tag = "7")]
+    pub subchannel_id: i64,
+    /// An optional name associated with the subchannel.
+    #[prost(string, tag = "8")]
+    pub name: ::prost::alloc::string::String,
+}
+/// SocketRef is a reference to a Socket.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SocketRef {
+    /// The globally unique id for this socket.  Must be a positive number.
+    #[prost(int64, tag = "3")]
+    pub socket_id: i64,
+    /// An optional name associated with the socket.
+    #[prost(string, tag = "4")]
+    pub name: ::prost::alloc::string::String,
+}
+/// ServerRef is a reference to a Server.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerRef {
+    /// A globally unique identifier for this server.  Must be a positive number.
+    #[prost(int64, tag = "5")]
+    pub server_id: i64,
+    /// An optional name associated with the server.
+    #[prost(string, tag = "6")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Server represents a single server.  There may be multiple servers in a single
+/// program.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Server {
+    /// The identifier for a Server.  This should be set.
+    #[prost(message, optional, tag = "1")]
+    pub r#ref: ::core::option::Option<ServerRef>,
+    /// The associated data of the Server.
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<ServerData>,
+    /// The sockets that the server is listening on.  There are no ordering
+    /// guarantees.  This may be absent.
+    #[prost(message, repeated, tag = "3")]
+    pub listen_socket: ::prost::alloc::vec::Vec<SocketRef>,
+}
+/// ServerData is data for a specific Server.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerData {
+    /// A trace of recent events on the server.  May be absent.
+    #[prost(message, optional, tag = "1")]
+    pub trace: ::core::option::Option<ChannelTrace>,
+    /// The number of incoming calls started on the server
+    #[prost(int64, tag = "2")]
+    pub calls_started: i64,
+    /// The number of incoming calls that have completed with an OK status
+    #[prost(int64, tag = "3")]
+    pub calls_succeeded: i64,
+    /// The number of incoming calls that have a completed with a non-OK status
+    #[prost(int64, tag = "4")]
+    pub calls_failed: i64,
+    /// The last time a call was started on the server.
+    #[prost(message, optional, tag = "5")]
+    pub last_call_started_timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Information about an actual connection.  Pronounced "sock-ay".
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Socket {
+    /// The identifier for the Socket.
+    #[prost(message, optional, tag = "1")]
+    pub r#ref: ::core::option::Option<SocketRef>,
+    /// Data specific to this Socket.
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<SocketData>,
+    /// The locally bound address.
+    #[prost(message, optional, tag = "3")]
+    pub local: ::core::option::Option<Address>,
+    /// The remote bound address.  May be absent.
+    #[prost(message, optional, tag = "4")]
+    pub remote: ::core::option::Option<Address>,
+    /// Security details for this socket.  May be absent if not available, or
+    /// there is no security on the socket.
+    #[prost(message, optional, tag = "5")]
+    pub security: ::core::option::Option<Security>,
+    /// Optional, represents the name of the remote endpoint, if different than
+    /// the original target name.
+    #[prost(string, tag = "6")]
+    pub remote_name: ::prost::alloc::string::String,
+}
+/// SocketData is data associated for a specific Socket.  The fields present
+/// are specific to the implementation, so there may be minor differences in
+/// the semantics.  (e.g. flow control windows)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SocketData {
+    /// The number of streams that have been started.
+    #[prost(int64, tag = "1")]
+    pub streams_started: i64,
+    /// The number of streams that have ended successfully:
+    /// On client side, received frame with eos bit set;
+    /// On server side, sent frame with eos bit set.
+    #[prost(int64, tag = "2")]
+    pub streams_succeeded: i64,
+    /// The number of streams that have ended unsuccessfully:
+    /// On client side, ended without receiving frame with eos bit set;
+    /// On server side, ended without sending frame with eos bit set.
+    #[prost(int64, tag = "3")]
+    pub streams_failed: i64,
+    /// The number of grpc messages successfully sent on this socket.
+    #[prost(int64, tag = "4")]
+    pub messages_sent: i64,
+    /// The number of grpc messages received on this socket.
+    #[prost(int64, tag = "5")]
+    pub messages_received: i64,
+    /// The number of keep alives sent.  This is typically implemented with HTTP/2
+    /// ping messages.
+    #[prost(int64, tag = "6")]
+    pub keep_alives_sent: i64,
+    /// The last time a stream was created by this endpoint.  Usually unset for
+    /// servers.
+    #[prost(message, optional, tag = "7")]
+    pub last_local_stream_created_timestamp: ::core::option::Option<
+        ::prost_types::Timestamp,
+    >,
+    /// The last time a stream was created by the remote endpoint.  Usually unset
+    /// for clients.
+    #[prost(message, optional, tag = "8")]
+    pub last_remote_stream_created_timestamp: ::core::option::Option<
+        ::prost_types::Timestamp,
+    >,
+    /// The last time a message was sent by this endpoint.
+    #[prost(message, optional, tag = "9")]
+    pub last_message_sent_timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// The last time a message was received by this endpoint.
+    #[prost(message, optional, tag = "10")]
+    pub last_message_received_timestamp: ::core::option::Option<
+        ::prost_types::Timestamp,
+    >,
+    /// The amount of window, granted to the local endpoint by the remote endpoint.
+    /// This may be slightly out of date due to network latency.  This does NOT
+    /// include stream level or TCP level flow control info.
+    #[prost(message, optional, tag = "11")]
+    pub local_flow_control_window: ::core::option::Option<i64>,
+    /// The amount of window, granted to the remote endpoint by the local endpoint.
+    /// This may be slightly out of date due to network latency.  This does NOT
+    /// include stream level or TCP level flow control info.
+    #[prost(message, optional, tag = "12")]
+    pub remote_flow_control_window: ::core::option::Option<i64>,
+    /// Socket options set on this socket.  May be absent if 'summary' is set
+    /// on GetSocketRequest.
+    #[prost(message, repeated, tag = "13")]
+    pub option: ::prost::alloc::vec::Vec<SocketOption>,
+}
+/// Address represents the address used to create the socket.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Address {
+    #[prost(oneof = "address::Address", tags = "1, 2, 3")]
+    pub address: ::core::option::Option<address::Address>,
+}
+/// Nested message and enum types in `Address`.
+pub mod address {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TcpIpAddress {
+        /// Either the IPv4 or IPv6 address in bytes.  Will be either 4 bytes or 16
+        /// bytes in length.
+        #[prost(bytes = "vec", tag = "1")]
+        pub ip_address: ::prost::alloc::vec::Vec<u8>,
+        /// 0-64k, or -1 if not appropriate.
+        #[prost(int32, tag = "2")]
+        pub port: i32,
+    }
+    /// A Unix Domain Socket address.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UdsAddress {
+        #[prost(string, tag = "1")]
+        pub filename: ::prost::alloc::string::String,
+    }
+    /// An address type not included above.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct OtherAddress {
+        /// The human readable version of the value.  This value should be set.
+        #[prost(string, tag = "1")]
+        pub name: ::prost::alloc::string::String,
+        /// The actual address message.
+        #[prost(message, optional, tag = "2")]
+        pub value: ::core::option::Option<::prost_types::Any>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Address {
+        #[prost(message, tag = "1")]
+        TcpipAddress(TcpIpAddress),
+        #[prost(message, tag = "2")]
+        UdsAddress(UdsAddress),
+        #[prost(message, tag = "3")]
+        OtherAddress(OtherAddress),
+    }
+}
+/// Security represents details about how secure the socket is.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Security {
+    #[prost(oneof = "security::Model", tags = "1, 2")]
+    pub model: ::core::option::Option<security::Model>,
+}
+/// Nested message and enum types in `Security`.
+pub mod security {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Tls {
+        /// the certificate used by this endpoint.
+        #[prost(bytes = "vec", tag = "3")]
+        pub local_certificate: ::prost::alloc::vec::Vec<u8>,
+        /// the certificate used by the remote endpoint.
+        #[prost(bytes = "vec", tag = "4")]
+        pub remote_certificate: ::prost::alloc::vec::Vec<u8>,
+        #[prost(oneof = "tls::CipherSuite", tags = "1, 2")]
+        pub cipher_suite: ::core::option::Option<tls::CipherSuite>,
+    }
+    /// Nested message and enum types in `Tls`.
+    pub mod tls {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum CipherSuite {
+            /// The cipher suite name in the RFC 4346 format:
+            /// <https://tools.ietf.org/html/rfc4346#appendix-C>
+            #[prost(string, tag = "1")]
+            StandardName(::prost::alloc::string::String),
+            /// Some other way to describe the cipher suite if
+            /// the RFC 4346 name is not available.
+            #[prost(string, tag = "2")]
+            OtherName(::prost::alloc::string::String),
+        }
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct OtherSecurity {
+        /// The human readable version of the value.
+        #[prost(string, tag = "1")]
+        pub name: ::prost::alloc::string::String,
+        /// The actual security details message.
+        #[prost(message, optional, tag = "2")]
+        pub value: ::core::option::Option<::prost_types::Any>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Model {
+        #[prost(message, tag = "1")]
+        Tls(Tls),
+        #[prost(message, tag = "2")]
+        Other(OtherSecurity),
+    }
+}
+/// SocketOption represents socket options for a socket.  Specifically, these
+/// are the options returned by getsockopt().
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SocketOption {
+    /// The full name of the socket option.  Typically this will be the upper case
+    /// name, such as "SO_REUSEPORT".
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The human readable value of this socket option.  At least one of value or
+    /// additional will be set.
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+    /// Additional data associated with the socket option.  At least one of value
+    /// or additional will be set.
+    #[prost(message, optional, tag = "3")]
+    pub additional: ::core::option::Option<::prost_types::Any>,
+}
+/// For use with SocketOption's additional field.  This is primarily used for
+/// SO_RCVTIMEO and SO_SNDTIMEO
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SocketOptionTimeout {
+    #[prost(message, optional, tag = "1")]
+    pub duration: ::core::option::Option<::prost_types::Duration>,
+}
+/// For use with SocketOption's additional field.  This is primarily used for
+/// SO_LINGER.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SocketOptionLinger {
+    /// active maps to `struct linger.l_onoff`
+    #[prost(bool, tag = "1")]
+    pub active: bool,
+    /// duration maps to `struct linger.l_linger`
+    #[prost(message, optional, tag = "2")]
+    pub duration: ::core::option::Option<::prost_types::Duration>,
+}
+/// For use with SocketOption's additional field.  Tcp info for
+/// SOL_TCP and TCP_INFO.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SocketOptionTcpInfo {
+    #[prost(uint32, tag = "1")]
+    pub tcpi_state: u32,
+    #[prost(uint32, tag = "2")]
+    pub tcpi_ca_state: u32,
+    #[prost(uint32, tag = "3")]
+    pub tcpi_retransmits: u32,
+    #[prost(uint32, tag = "4")]
+    pub tcpi_probes: u32,
+    #[prost(uint32, tag = "5")]
+    pub tcpi_backoff: u32,
+    #[prost(uint32, tag = "6")]
+    pub tcpi_options: u32,
+    #[prost(uint32, tag = "7")]
+    pub tcpi_snd_wscale: u32,
+    #[prost(uint32, tag = "8")]
+    pub tcpi_rcv_wscale: u32,
+    #[prost(uint32, tag = "9")]
+    pub tcpi_rto: u32,
+    #[prost(uint32, tag = "10")]
+    pub tcpi_ato: u32,
+    #[prost(uint32, tag = "11")]
+    pub tcpi_snd_mss: u32,
+    #[prost(uint32, tag = "12")]
+    pub tcpi_rcv_mss: u32,
+    #[prost(uint32, tag = "13")]
+    pub tcpi_unacked: u32,
+    #[prost(uint32, tag = "14")]
+    pub tcpi_sacked: u32,
+    #[prost(uint32, tag = "15")]
+    pub tcpi_lost: u32,
+    #[prost(uint32, tag = "16")]
+    pub tcpi_retrans: u32,
+    #[prost(uint32, tag = "17")]
+    pub tcpi_fackets: u32,
+    #[prost(uint32, tag = "18")]
+    pub tcpi_last_data_sent: u32,
+    #[prost(uint32, tag = "19")]
+    pub tcpi_last_ack_sent: u32,
+    #[prost(uint32, tag = "20")]
+    pub tcpi_last_data_recv: u32,
+    #[prost(uint32, tag = "21")]
+    pub tcpi_last_ack_recv: u32,
+    #[prost(uint32, tag = "22")]
+    pub tcpi_pmtu: u32,
+    #[prost(uint32, tag = "23")]
+    pub tcpi_rcv_ssthresh: u32,
+    #[prost(uint32, tag = "24")]
+    pub tcpi_rtt: u32,
+    #[prost(uint32, tag = "25")]
+    pub tcpi_rttvar: u32,
+    #[prost(uint32, tag = "26")]
+    pub tcpi_snd_ssthresh: u32,
+    #[prost(uint32, tag = "27")]
+    pub tcpi_snd_cwnd: u32,
+    #[prost(uint32, tag = "28")]
+    pub tcpi_advmss: u32,
+    #[prost(uint32, tag = "29")]
+    pub tcpi_reordering: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetTopChannelsRequest {
+    /// start_channel_id indicates that only channels at or above this id should be
+    /// included in the results.
+    /// To request the first page, this should be set to 0. To request
+    /// subsequent pages, the client generates this value by adding 1 to
+    /// the highest seen result ID.
+    #[prost(int64, tag = "1")]
+    pub start_channel_id: i64,
+    /// If non-zero, the server will return a page of results containing
+    /// at most this many items. If zero, the server will choose a
+    /// reasonable page size.  Must never be negative.
+    #[prost(int64, tag = "2")]
+    pub max_results: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTopChannelsResponse {
+    /// list of channels that the connection detail service knows about.  Sorted in
+    /// ascending channel_id order.
+    /// Must contain at least 1 result, otherwise 'end' must be true.
+    #[prost(message, repeated, tag = "1")]
+    pub channel: ::prost::alloc::vec::Vec<Channel>,
+    /// If set, indicates that the list of channels is the final list.  Requesting
+    /// more channels can only return more if they are created after this RPC
+    /// completes.
+    #[prost(bool, tag = "2")]
+    pub end: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetServersRequest {
+    /// start_server_id indicates that only servers at or above this id should be
+    /// included in the results.
+    /// To request the first page, this must be set to 0. To request
+    /// subsequent pages, the client generates this value by adding 1 to
+    /// the highest seen result ID.
+    #[prost(int64, tag = "1")]
+    pub start_server_id: i64,
+    /// If non-zero, the server will return a page of results containing
+    /// at most this many items. If zero, the server will choose a
+    /// reasonable page size.  Must never be negative.
+    #[prost(int64, tag = "2")]
+    pub max_results: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetServersResponse {
+    /// list of servers that the connection detail service knows about.  Sorted in
+    /// ascending server_id order.
+    /// Must contain at least 1 result, otherwise 'end' must be true.
+    #[prost(message, repeated, tag = "1")]
+    pub server: ::prost::alloc::vec::Vec<Server>,
+    /// If set, indicates that the list of servers is the final list.  Requesting
+    /// more servers will only return more if they are created after this RPC
+    /// completes.
+    #[prost(bool, tag = "2")]
+    pub end: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetServerRequest {
+    /// server_id is the identifier of the specific server to get.
+    #[prost(int64, tag = "1")]
+    pub server_id: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetServerResponse {
+    /// The Server that corresponds to the requested server_id.  This field
+    /// should be set.
+    #[prost(message, optional, tag = "1")]
+    pub server: ::core::option::Option<Server>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetServerSocketsRequest {
+    #[prost(int64, tag = "1")]
+    pub server_id: i64,
+    /// start_socket_id indicates that only sockets at or above this id should be
+    /// included in the results.
+    /// To request the first page, this must be set to 0. To request
+    /// subsequent pages, the client generates this value by adding 1 to
+    /// the highest seen result ID.
+    #[prost(int64, tag = "2")]
+    pub start_socket_id: i64,
+    /// If non-zero, the server will return a page of results containing
+    /// at most this many items. If zero, the server will choose a
+    /// reasonable page size.  Must never be negative.
+    #[prost(int64, tag = "3")]
+    pub max_results: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetServerSocketsResponse {
+    /// list of socket refs that the connection detail service knows about.  Sorted in
+    /// ascending socket_id order.
+    /// Must contain at least 1 result, otherwise 'end' must be true.
+    #[prost(message, repeated, tag = "1")]
+    pub socket_ref: ::prost::alloc::vec::Vec<SocketRef>,
+    /// If set, indicates that the list of sockets is the final list.  Requesting
+    /// more sockets will only return more if they are created after this RPC
+    /// completes.
+    #[prost(bool, tag = "2")]
+    pub end: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetChannelRequest {
+    /// channel_id is the identifier of the specific channel to get.
+    #[prost(int64, tag = "1")]
+    pub channel_id: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetChannelResponse {
+    /// The Channel that corresponds to the requested channel_id.  This field
+    /// should be set.
+    #[prost(message, optional, tag = "1")]
+    pub channel: ::core::option::Option<Channel>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetSubchannelRequest {
+    /// subchannel_id is the identifier of the specific subchannel to get.
+    #[prost(int64, tag = "1")]
+    pub subchannel_id: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSubchannelResponse {
+    /// The Subchannel that corresponds to the requested subchannel_id.  This
+    /// field should be set.
+    #[prost(message, optional, tag = "1")]
+    pub subchannel: ::core::option::Option<Subchannel>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetSocketRequest {
+    /// socket_id is the identifier of the specific socket to get.
+    #[prost(int64, tag = "1")]
+    pub socket_id: i64,
+    /// If true, the response will contain only high level information
+    /// that is inexpensive to obtain. Fields thay may be omitted are
+    /// documented.
+    #[prost(bool, tag = "2")]
+    pub summary: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSocketResponse {
+    /// The Socket that corresponds to the requested socket_id.  This field
+    /// should be set.
+    #[prost(message, optional, tag = "1")]
+    pub socket: ::core::option::Option<Socket>,
+}
+const METHOD_CHANNELZ_GET_TOP_CHANNELS: ::grpcio::Method<
+    GetTopChannelsRequest,
+    GetTopChannelsResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetTopChannels",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+const METHOD_CHANNELZ_GET_SERVERS: ::grpcio::Method<
+    GetServersRequest,
+    GetServersResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetServers",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+const METHOD_CHANNELZ_GET_SERVER: ::grpcio::Method<
+    GetServerRequest,
+    GetServerResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetServer",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+const METHOD_CHANNELZ_GET_SERVER_SOCKETS: ::grpcio::Method<
+    GetServerSocketsRequest,
+    GetServerSocketsResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetServerSockets",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+const METHOD_CHANNELZ_GET_CHANNEL: ::grpcio::Method<
+    GetChannelRequest,
+    GetChannelResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetChannel",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+const METHOD_CHANNELZ_GET_SUBCHANNEL: ::grpcio::Method<
+    GetSubchannelRequest,
+    GetSubchannelResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetSubchannel",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+const METHOD_CHANNELZ_GET_SOCKET: ::grpcio::Method<
+    GetSocketRequest,
+    GetSocketResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetSocket",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pr_ser,
+        de: ::grpcio::pr_de,
+    },
+};
+#[derive(Clone)]
+pub struct ChannelzClient {
+    pub client: ::grpcio::Client,
+}
+impl ChannelzClient {
+    pub fn new(channel: ::grpcio::Channel) -> Self {
+        ChannelzClient {
+            client: ::grpcio::Client::new(channel),
+        }
+    }
+    pub fn get_top_channels_opt(
+        &self,
+        req: &GetTopChannelsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetTopChannelsResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_TOP_CHANNELS, req, opt)
+    }
+    pub fn get_top_channels(
+        &self,
+        req: &GetTopChannelsRequest,
+    ) -> ::grpcio::Result<GetTopChannelsResponse> {
+        self.get_top_channels_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_top_channels_async_opt(
+        &self,
+        req: &GetTopChannelsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetTopChannelsResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_TOP_CHANNELS, req, opt)
+    }
+    pub fn get_top_channels_async(
+        &self,
+        req: &GetTopChannelsRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetTopChannelsResponse>> {
+        self.get_top_channels_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_servers_opt(
+        &self,
+        req: &GetServersRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetServersResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_SERVERS, req, opt)
+    }
+    pub fn get_servers(
+        &self,
+        req: &GetServersRequest,
+    ) -> ::grpcio::Result<GetServersResponse> {
+        self.get_servers_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_servers_async_opt(
+        &self,
+        req: &GetServersRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetServersResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_SERVERS, req, opt)
+    }
+    pub fn get_servers_async(
+        &self,
+        req: &GetServersRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetServersResponse>> {
+        self.get_servers_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_server_opt(
+        &self,
+        req: &GetServerRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetServerResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_SERVER, req, opt)
+    }
+    pub fn get_server(
+        &self,
+        req: &GetServerRequest,
+    ) -> ::grpcio::Result<GetServerResponse> {
+        self.get_server_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_server_async_opt(
+        &self,
+        req: &GetServerRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetServerResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_SERVER, req, opt)
+    }
+    pub fn get_server_async(
+        &self,
+        req: &GetServerRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetServerResponse>> {
+        self.get_server_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_server_sockets_opt(
+        &self,
+        req: &GetServerSocketsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetServerSocketsResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_SERVER_SOCKETS, req, opt)
+    }
+    pub fn get_server_sockets(
+        &self,
+        req: &GetServerSocketsRequest,
+    ) -> ::grpcio::Result<GetServerSocketsResponse> {
+        self.get_server_sockets_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_server_sockets_async_opt(
+        &self,
+        req: &GetServerSocketsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetServerSocketsResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_SERVER_SOCKETS, req, opt)
+    }
+    pub fn get_server_sockets_async(
+        &self,
+        req: &GetServerSocketsRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetServerSocketsResponse>> {
+        self.get_server_sockets_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_channel_opt(
+        &self,
+        req: &GetChannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetChannelResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_CHANNEL, req, opt)
+    }
+    pub fn get_channel(
+        &self,
+        req: &GetChannelRequest,
+    ) -> ::grpcio::Result<GetChannelResponse> {
+        self.get_channel_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_channel_async_opt(
+        &self,
+        req: &GetChannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetChannelResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_CHANNEL, req, opt)
+    }
+    pub fn get_channel_async(
+        &self,
+        req: &GetChannelRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetChannelResponse>> {
+        self.get_channel_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_subchannel_opt(
+        &self,
+        req: &GetSubchannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetSubchannelResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_SUBCHANNEL, req, opt)
+    }
+    pub fn get_subchannel(
+        &self,
+        req: &GetSubchannelRequest,
+    ) -> ::grpcio::Result<GetSubchannelResponse> {
+        self.get_subchannel_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_subchannel_async_opt(
+        &self,
+        req: &GetSubchannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetSubchannelResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_SUBCHANNEL, req, opt)
+    }
+    pub fn get_subchannel_async(
+        &self,
+        req: &GetSubchannelRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetSubchannelResponse>> {
+        self.get_subchannel_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_socket_opt(
+        &self,
+        req: &GetSocketRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<GetSocketResponse> {
+        self.client.unary_call(&METHOD_CHANNELZ_GET_SOCKET, req, opt)
+    }
+    pub fn get_socket(
+        &self,
+        req: &GetSocketRequest,
+    ) -> ::grpcio::Result<GetSocketResponse> {
+        self.get_socket_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn get_socket_async_opt(
+        &self,
+        req: &GetSocketRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetSocketResponse>> {
+        self.client.unary_call_async(&METHOD_CHANNELZ_GET_SOCKET, req, opt)
+    }
+    pub fn get_socket_async(
+        &self,
+        req: &GetSocketRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<GetSocketResponse>> {
+        self.get_socket_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: ::std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.client.spawn(f)
+    }
+}
+pub trait Channelz {
+    fn get_top_channels(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetTopChannelsRequest,
+        sink: ::grpcio::UnarySink<GetTopChannelsResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_servers(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetServersRequest,
+        sink: ::grpcio::UnarySink<GetServersResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_server(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetServerRequest,
+        sink: ::grpcio::UnarySink<GetServerResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_server_sockets(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetServerSocketsRequest,
+        sink: ::grpcio::UnarySink<GetServerSocketsResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_channel(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetChannelRequest,
+        sink: ::grpcio::UnarySink<GetChannelResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_subchannel(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetSubchannelRequest,
+        sink: ::grpcio::UnarySink<GetSubchannelResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_socket(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: GetSocketRequest,
+        sink: ::grpcio::UnarySink<GetSocketResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+}
+pub fn create_channelz<S: Channelz + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
+    let mut builder = ::grpcio::ServiceBuilder::new();
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_TOP_CHANNELS,
+            move |ctx, req, resp| instance.get_top_channels(ctx, req, resp),
+        );
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_SERVERS,
+            move |ctx, req, resp| instance.get_servers(ctx, req, resp),
+        );
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_SERVER,
+            move |ctx, req, resp| instance.get_server(ctx, req, resp),
+        );
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_SERVER_SOCKETS,
+            move |ctx, req, resp| instance.get_server_sockets(ctx, req, resp),
+        );
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_CHANNEL,
+            move |ctx, req, resp| instance.get_channel(ctx, req, resp),
+        );
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_SUBCHANNEL,
+            move |ctx, req, resp| instance.get_subchannel(ctx, req, resp),
+        );
+    let mut instance = s;
+    builder = builder
+        .add_unary_handler(
+            &METHOD_CHANNELZ_GET_SOCKET,
+            move |ctx, req, resp| instance.get_socket(ctx, req, resp),
+        );
+    builder.build()
+}
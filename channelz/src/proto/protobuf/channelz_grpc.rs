@@ -0,0 +1,471 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+const METHOD_CHANNELZ_GET_TOP_CHANNELS: ::grpcio::Method<
+    super::channelz::GetTopChannelsRequest,
+    super::channelz::GetTopChannelsResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetTopChannels",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+const METHOD_CHANNELZ_GET_SERVERS: ::grpcio::Method<
+    super::channelz::GetServersRequest,
+    super::channelz::GetServersResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetServers",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+const METHOD_CHANNELZ_GET_SERVER: ::grpcio::Method<
+    super::channelz::GetServerRequest,
+    super::channelz::GetServerResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetServer",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+const METHOD_CHANNELZ_GET_SERVER_SOCKETS: ::grpcio::Method<
+    super::channelz::GetServerSocketsRequest,
+    super::channelz::GetServerSocketsResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetServerSockets",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+const METHOD_CHANNELZ_GET_CHANNEL: ::grpcio::Method<
+    super::channelz::GetChannelRequest,
+    super::channelz::GetChannelResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetChannel",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+const METHOD_CHANNELZ_GET_SUBCHANNEL: ::grpcio::Method<
+    super::channelz::GetSubchannelRequest,
+    super::channelz::GetSubchannelResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetSubchannel",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+const METHOD_CHANNELZ_GET_SOCKET: ::grpcio::Method<
+    super::channelz::GetSocketRequest,
+    super::channelz::GetSocketResponse,
+> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.channelz.v1.Channelz/GetSocket",
+    req_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+    resp_mar: ::grpcio::Marshaller {
+        ser: ::grpcio::pb_ser,
+        de: ::grpcio::pb_de,
+    },
+};
+
+#[derive(Clone)]
+pub struct ChannelzClient {
+    pub client: ::grpcio::Client,
+}
+
+impl ChannelzClient {
+    pub fn new(channel: ::grpcio::Channel) -> Self {
+        ChannelzClient {
+            client: ::grpcio::Client::new(channel),
+        }
+    }
+
+    pub fn get_top_channels_opt(
+        &self,
+        req: &super::channelz::GetTopChannelsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetTopChannelsResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_TOP_CHANNELS, req, opt)
+    }
+
+    pub fn get_top_channels(
+        &self,
+        req: &super::channelz::GetTopChannelsRequest,
+    ) -> ::grpcio::Result<super::channelz::GetTopChannelsResponse> {
+        self.get_top_channels_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_top_channels_async_opt(
+        &self,
+        req: &super::channelz::GetTopChannelsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetTopChannelsResponse>>
+    {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_TOP_CHANNELS, req, opt)
+    }
+
+    pub fn get_top_channels_async(
+        &self,
+        req: &super::channelz::GetTopChannelsRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetTopChannelsResponse>>
+    {
+        self.get_top_channels_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_servers_opt(
+        &self,
+        req: &super::channelz::GetServersRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetServersResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_SERVERS, req, opt)
+    }
+
+    pub fn get_servers(
+        &self,
+        req: &super::channelz::GetServersRequest,
+    ) -> ::grpcio::Result<super::channelz::GetServersResponse> {
+        self.get_servers_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_servers_async_opt(
+        &self,
+        req: &super::channelz::GetServersRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetServersResponse>> {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_SERVERS, req, opt)
+    }
+
+    pub fn get_servers_async(
+        &self,
+        req: &super::channelz::GetServersRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetServersResponse>> {
+        self.get_servers_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_server_opt(
+        &self,
+        req: &super::channelz::GetServerRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetServerResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_SERVER, req, opt)
+    }
+
+    pub fn get_server(
+        &self,
+        req: &super::channelz::GetServerRequest,
+    ) -> ::grpcio::Result<super::channelz::GetServerResponse> {
+        self.get_server_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_server_async_opt(
+        &self,
+        req: &super::channelz::GetServerRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetServerResponse>> {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_SERVER, req, opt)
+    }
+
+    pub fn get_server_async(
+        &self,
+        req: &super::channelz::GetServerRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetServerResponse>> {
+        self.get_server_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_server_sockets_opt(
+        &self,
+        req: &super::channelz::GetServerSocketsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetServerSocketsResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_SERVER_SOCKETS, req, opt)
+    }
+
+    pub fn get_server_sockets(
+        &self,
+        req: &super::channelz::GetServerSocketsRequest,
+    ) -> ::grpcio::Result<super::channelz::GetServerSocketsResponse> {
+        self.get_server_sockets_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_server_sockets_async_opt(
+        &self,
+        req: &super::channelz::GetServerSocketsRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetServerSocketsResponse>>
+    {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_SERVER_SOCKETS, req, opt)
+    }
+
+    pub fn get_server_sockets_async(
+        &self,
+        req: &super::channelz::GetServerSocketsRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetServerSocketsResponse>>
+    {
+        self.get_server_sockets_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_channel_opt(
+        &self,
+        req: &super::channelz::GetChannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetChannelResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_CHANNEL, req, opt)
+    }
+
+    pub fn get_channel(
+        &self,
+        req: &super::channelz::GetChannelRequest,
+    ) -> ::grpcio::Result<super::channelz::GetChannelResponse> {
+        self.get_channel_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_channel_async_opt(
+        &self,
+        req: &super::channelz::GetChannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetChannelResponse>> {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_CHANNEL, req, opt)
+    }
+
+    pub fn get_channel_async(
+        &self,
+        req: &super::channelz::GetChannelRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetChannelResponse>> {
+        self.get_channel_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_subchannel_opt(
+        &self,
+        req: &super::channelz::GetSubchannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetSubchannelResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_SUBCHANNEL, req, opt)
+    }
+
+    pub fn get_subchannel(
+        &self,
+        req: &super::channelz::GetSubchannelRequest,
+    ) -> ::grpcio::Result<super::channelz::GetSubchannelResponse> {
+        self.get_subchannel_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_subchannel_async_opt(
+        &self,
+        req: &super::channelz::GetSubchannelRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetSubchannelResponse>>
+    {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_SUBCHANNEL, req, opt)
+    }
+
+    pub fn get_subchannel_async(
+        &self,
+        req: &super::channelz::GetSubchannelRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetSubchannelResponse>>
+    {
+        self.get_subchannel_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_socket_opt(
+        &self,
+        req: &super::channelz::GetSocketRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<super::channelz::GetSocketResponse> {
+        self.client
+            .unary_call(&METHOD_CHANNELZ_GET_SOCKET, req, opt)
+    }
+
+    pub fn get_socket(
+        &self,
+        req: &super::channelz::GetSocketRequest,
+    ) -> ::grpcio::Result<super::channelz::GetSocketResponse> {
+        self.get_socket_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_socket_async_opt(
+        &self,
+        req: &super::channelz::GetSocketRequest,
+        opt: ::grpcio::CallOption,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetSocketResponse>> {
+        self.client
+            .unary_call_async(&METHOD_CHANNELZ_GET_SOCKET, req, opt)
+    }
+
+    pub fn get_socket_async(
+        &self,
+        req: &super::channelz::GetSocketRequest,
+    ) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::channelz::GetSocketResponse>> {
+        self.get_socket_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: ::std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.client.spawn(f)
+    }
+}
+
+pub trait Channelz {
+    fn get_top_channels(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetTopChannelsRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetTopChannelsResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_servers(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetServersRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetServersResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_server(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetServerRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetServerResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_server_sockets(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetServerSocketsRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetServerSocketsResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_channel(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetChannelRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetChannelResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_subchannel(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetSubchannelRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetSubchannelResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+    fn get_socket(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: super::channelz::GetSocketRequest,
+        sink: ::grpcio::UnarySink<super::channelz::GetSocketResponse>,
+    ) {
+        grpcio::unimplemented_call!(ctx, sink)
+    }
+}
+
+pub fn create_channelz<S: Channelz + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
+    let mut builder = ::grpcio::ServiceBuilder::new();
+    let mut instance = s.clone();
+    builder = builder
+        .add_unary_handler(&METHOD_CHANNELZ_GET_TOP_CHANNELS, move |ctx, req, resp| {
+            instance.get_top_channels(ctx, req, resp)
+        });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_CHANNELZ_GET_SERVERS, move |ctx, req, resp| {
+        instance.get_servers(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_CHANNELZ_GET_SERVER, move |ctx, req, resp| {
+        instance.get_server(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(
+        &METHOD_CHANNELZ_GET_SERVER_SOCKETS,
+        move |ctx, req, resp| instance.get_server_sockets(ctx, req, resp),
+    );
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_CHANNELZ_GET_CHANNEL, move |ctx, req, resp| {
+        instance.get_channel(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_CHANNELZ_GET_SUBCHANNEL, move |ctx, req, resp| {
+        instance.get_subchannel(ctx, req, resp)
+    });
+    let mut instance = s;
+    builder = builder.add_unary_handler(&METHOD_CHANNELZ_GET_SOCKET, move |ctx, req, resp| {
+        instance.get_socket(ctx, req, resp)
+    });
+    builder.build()
+}
@@ -0,0 +1,7550 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `grpc/channelz/channelz.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobufv3::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.Channel)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Channel {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Channel.ref)
+    pub ref_: ::protobufv3::MessageField<ChannelRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Channel.data)
+    pub data: ::protobufv3::MessageField<ChannelData>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Channel.channel_ref)
+    pub channel_ref: ::std::vec::Vec<ChannelRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Channel.subchannel_ref)
+    pub subchannel_ref: ::std::vec::Vec<SubchannelRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Channel.socket_ref)
+    pub socket_ref: ::std::vec::Vec<SocketRef>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.Channel.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Channel {
+    fn default() -> &'a Channel {
+        <Channel as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Channel {
+    pub fn new() -> Channel {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ChannelRef>(
+            "ref",
+            |m: &Channel| { &m.ref_ },
+            |m: &mut Channel| { &mut m.ref_ },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ChannelData>(
+            "data",
+            |m: &Channel| { &m.data },
+            |m: &mut Channel| { &mut m.data },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "channel_ref",
+            |m: &Channel| { &m.channel_ref },
+            |m: &mut Channel| { &mut m.channel_ref },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "subchannel_ref",
+            |m: &Channel| { &m.subchannel_ref },
+            |m: &mut Channel| { &mut m.subchannel_ref },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "socket_ref",
+            |m: &Channel| { &m.socket_ref },
+            |m: &mut Channel| { &mut m.socket_ref },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Channel>(
+            "Channel",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Channel {
+    const NAME: &'static str = "Channel";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.ref_)?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.data)?;
+                },
+                26 => {
+                    self.channel_ref.push(is.read_message()?);
+                },
+                34 => {
+                    self.subchannel_ref.push(is.read_message()?);
+                },
+                42 => {
+                    self.socket_ref.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.ref_.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.data.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.channel_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.subchannel_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.socket_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.ref_.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.channel_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.subchannel_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.socket_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Channel {
+        Channel::new()
+    }
+
+    fn clear(&mut self) {
+        self.ref_.clear();
+        self.data.clear();
+        self.channel_ref.clear();
+        self.subchannel_ref.clear();
+        self.socket_ref.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Channel {
+        static instance: Channel = Channel {
+            ref_: ::protobufv3::MessageField::none(),
+            data: ::protobufv3::MessageField::none(),
+            channel_ref: ::std::vec::Vec::new(),
+            subchannel_ref: ::std::vec::Vec::new(),
+            socket_ref: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Channel {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Channel").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Channel {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.Subchannel)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Subchannel {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Subchannel.ref)
+    pub ref_: ::protobufv3::MessageField<SubchannelRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Subchannel.data)
+    pub data: ::protobufv3::MessageField<ChannelData>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Subchannel.channel_ref)
+    pub channel_ref: ::std::vec::Vec<ChannelRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Subchannel.subchannel_ref)
+    pub subchannel_ref: ::std::vec::Vec<SubchannelRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Subchannel.socket_ref)
+    pub socket_ref: ::std::vec::Vec<SocketRef>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.Subchannel.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Subchannel {
+    fn default() -> &'a Subchannel {
+        <Subchannel as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Subchannel {
+    pub fn new() -> Subchannel {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, SubchannelRef>(
+            "ref",
+            |m: &Subchannel| { &m.ref_ },
+            |m: &mut Subchannel| { &mut m.ref_ },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ChannelData>(
+            "data",
+            |m: &Subchannel| { &m.data },
+            |m: &mut Subchannel| { &mut m.data },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "channel_ref",
+            |m: &Subchannel| { &m.channel_ref },
+            |m: &mut Subchannel| { &mut m.channel_ref },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "subchannel_ref",
+            |m: &Subchannel| { &m.subchannel_ref },
+            |m: &mut Subchannel| { &mut m.subchannel_ref },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "socket_ref",
+            |m: &Subchannel| { &m.socket_ref },
+            |m: &mut Subchannel| { &mut m.socket_ref },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Subchannel>(
+            "Subchannel",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Subchannel {
+    const NAME: &'static str = "Subchannel";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.ref_)?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.data)?;
+                },
+                26 => {
+                    self.channel_ref.push(is.read_message()?);
+                },
+                34 => {
+                    self.subchannel_ref.push(is.read_message()?);
+                },
+                42 => {
+                    self.socket_ref.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.ref_.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.data.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.channel_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.subchannel_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.socket_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.ref_.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.channel_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.subchannel_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.socket_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Subchannel {
+        Subchannel::new()
+    }
+
+    fn clear(&mut self) {
+        self.ref_.clear();
+        self.data.clear();
+        self.channel_ref.clear();
+        self.subchannel_ref.clear();
+        self.socket_ref.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Subchannel {
+        static instance: Subchannel = Subchannel {
+            ref_: ::protobufv3::MessageField::none(),
+            data: ::protobufv3::MessageField::none(),
+            channel_ref: ::std::vec::Vec::new(),
+            subchannel_ref: ::std::vec::Vec::new(),
+            socket_ref: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Subchannel {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Subchannel").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Subchannel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Subchannel {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ChannelConnectivityState)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChannelConnectivityState {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelConnectivityState.state)
+    pub state: ::protobufv3::EnumOrUnknown<channel_connectivity_state::State>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ChannelConnectivityState.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChannelConnectivityState {
+    fn default() -> &'a ChannelConnectivityState {
+        <ChannelConnectivityState as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ChannelConnectivityState {
+    pub fn new() -> ChannelConnectivityState {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &ChannelConnectivityState| { &m.state },
+            |m: &mut ChannelConnectivityState| { &mut m.state },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ChannelConnectivityState>(
+            "ChannelConnectivityState",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ChannelConnectivityState {
+    const NAME: &'static str = "ChannelConnectivityState";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.state != ::protobufv3::EnumOrUnknown::new(channel_connectivity_state::State::UNKNOWN) {
+            my_size += ::protobufv3::rt::int32_size(1, self.state.value());
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.state != ::protobufv3::EnumOrUnknown::new(channel_connectivity_state::State::UNKNOWN) {
+            os.write_enum(1, ::protobufv3::EnumOrUnknown::value(&self.state))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChannelConnectivityState {
+        ChannelConnectivityState::new()
+    }
+
+    fn clear(&mut self) {
+        self.state = ::protobufv3::EnumOrUnknown::new(channel_connectivity_state::State::UNKNOWN);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChannelConnectivityState {
+        static instance: ChannelConnectivityState = ChannelConnectivityState {
+            state: ::protobufv3::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ChannelConnectivityState {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChannelConnectivityState").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChannelConnectivityState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ChannelConnectivityState {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ChannelConnectivityState`
+pub mod channel_connectivity_state {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:grpc.channelz.v1.ChannelConnectivityState.State)
+    pub enum State {
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelConnectivityState.State.UNKNOWN)
+        UNKNOWN = 0,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelConnectivityState.State.IDLE)
+        IDLE = 1,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelConnectivityState.State.CONNECTING)
+        CONNECTING = 2,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelConnectivityState.State.READY)
+        READY = 3,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelConnectivityState.State.TRANSIENT_FAILURE)
+        TRANSIENT_FAILURE = 4,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelConnectivityState.State.SHUTDOWN)
+        SHUTDOWN = 5,
+    }
+
+    impl ::protobufv3::Enum for State {
+        const NAME: &'static str = "State";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<State> {
+            match value {
+                0 => ::std::option::Option::Some(State::UNKNOWN),
+                1 => ::std::option::Option::Some(State::IDLE),
+                2 => ::std::option::Option::Some(State::CONNECTING),
+                3 => ::std::option::Option::Some(State::READY),
+                4 => ::std::option::Option::Some(State::TRANSIENT_FAILURE),
+                5 => ::std::option::Option::Some(State::SHUTDOWN),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn from_str(str: &str) -> ::std::option::Option<State> {
+            match str {
+                "UNKNOWN" => ::std::option::Option::Some(State::UNKNOWN),
+                "IDLE" => ::std::option::Option::Some(State::IDLE),
+                "CONNECTING" => ::std::option::Option::Some(State::CONNECTING),
+                "READY" => ::std::option::Option::Some(State::READY),
+                "TRANSIENT_FAILURE" => ::std::option::Option::Some(State::TRANSIENT_FAILURE),
+                "SHUTDOWN" => ::std::option::Option::Some(State::SHUTDOWN),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [State] = &[
+            State::UNKNOWN,
+            State::IDLE,
+            State::CONNECTING,
+            State::READY,
+            State::TRANSIENT_FAILURE,
+            State::SHUTDOWN,
+        ];
+    }
+
+    impl ::protobufv3::EnumFull for State {
+        fn enum_descriptor() -> ::protobufv3::reflect::EnumDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::EnumDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ChannelConnectivityState.State").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobufv3::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for State {
+        fn default() -> Self {
+            State::UNKNOWN
+        }
+    }
+
+    impl State {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobufv3::reflect::GeneratedEnumDescriptorData {
+            ::protobufv3::reflect::GeneratedEnumDescriptorData::new::<State>("ChannelConnectivityState.State")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ChannelData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChannelData {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.state)
+    pub state: ::protobufv3::MessageField<ChannelConnectivityState>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.target)
+    pub target: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.trace)
+    pub trace: ::protobufv3::MessageField<ChannelTrace>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.calls_started)
+    pub calls_started: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.calls_succeeded)
+    pub calls_succeeded: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.calls_failed)
+    pub calls_failed: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelData.last_call_started_timestamp)
+    pub last_call_started_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ChannelData.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChannelData {
+    fn default() -> &'a ChannelData {
+        <ChannelData as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ChannelData {
+    pub fn new() -> ChannelData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ChannelConnectivityState>(
+            "state",
+            |m: &ChannelData| { &m.state },
+            |m: &mut ChannelData| { &mut m.state },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target",
+            |m: &ChannelData| { &m.target },
+            |m: &mut ChannelData| { &mut m.target },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ChannelTrace>(
+            "trace",
+            |m: &ChannelData| { &m.trace },
+            |m: &mut ChannelData| { &mut m.trace },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls_started",
+            |m: &ChannelData| { &m.calls_started },
+            |m: &mut ChannelData| { &mut m.calls_started },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls_succeeded",
+            |m: &ChannelData| { &m.calls_succeeded },
+            |m: &mut ChannelData| { &mut m.calls_succeeded },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls_failed",
+            |m: &ChannelData| { &m.calls_failed },
+            |m: &mut ChannelData| { &mut m.calls_failed },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "last_call_started_timestamp",
+            |m: &ChannelData| { &m.last_call_started_timestamp },
+            |m: &mut ChannelData| { &mut m.last_call_started_timestamp },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ChannelData>(
+            "ChannelData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ChannelData {
+    const NAME: &'static str = "ChannelData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.state)?;
+                },
+                18 => {
+                    self.target = is.read_string()?;
+                },
+                26 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.trace)?;
+                },
+                32 => {
+                    self.calls_started = is.read_int64()?;
+                },
+                40 => {
+                    self.calls_succeeded = is.read_int64()?;
+                },
+                48 => {
+                    self.calls_failed = is.read_int64()?;
+                },
+                58 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.last_call_started_timestamp)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.state.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.target.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.target);
+        }
+        if let Some(v) = self.trace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.calls_started != 0 {
+            my_size += ::protobufv3::rt::int64_size(4, self.calls_started);
+        }
+        if self.calls_succeeded != 0 {
+            my_size += ::protobufv3::rt::int64_size(5, self.calls_succeeded);
+        }
+        if self.calls_failed != 0 {
+            my_size += ::protobufv3::rt::int64_size(6, self.calls_failed);
+        }
+        if let Some(v) = self.last_call_started_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.state.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.target.is_empty() {
+            os.write_string(2, &self.target)?;
+        }
+        if let Some(v) = self.trace.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.calls_started != 0 {
+            os.write_int64(4, self.calls_started)?;
+        }
+        if self.calls_succeeded != 0 {
+            os.write_int64(5, self.calls_succeeded)?;
+        }
+        if self.calls_failed != 0 {
+            os.write_int64(6, self.calls_failed)?;
+        }
+        if let Some(v) = self.last_call_started_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChannelData {
+        ChannelData::new()
+    }
+
+    fn clear(&mut self) {
+        self.state.clear();
+        self.target.clear();
+        self.trace.clear();
+        self.calls_started = 0;
+        self.calls_succeeded = 0;
+        self.calls_failed = 0;
+        self.last_call_started_timestamp.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChannelData {
+        static instance: ChannelData = ChannelData {
+            state: ::protobufv3::MessageField::none(),
+            target: ::std::string::String::new(),
+            trace: ::protobufv3::MessageField::none(),
+            calls_started: 0,
+            calls_succeeded: 0,
+            calls_failed: 0,
+            last_call_started_timestamp: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ChannelData {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChannelData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChannelData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ChannelData {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ChannelTraceEvent)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChannelTraceEvent {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelTraceEvent.description)
+    pub description: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelTraceEvent.severity)
+    pub severity: ::protobufv3::EnumOrUnknown<channel_trace_event::Severity>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelTraceEvent.timestamp)
+    pub timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // message oneof groups
+    pub child_ref: ::std::option::Option<channel_trace_event::Child_ref>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ChannelTraceEvent.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChannelTraceEvent {
+    fn default() -> &'a ChannelTraceEvent {
+        <ChannelTraceEvent as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ChannelTraceEvent {
+    pub fn new() -> ChannelTraceEvent {
+        ::std::default::Default::default()
+    }
+
+    // .grpc.channelz.v1.ChannelRef channel_ref = 4;
+
+    pub fn channel_ref(&self) -> &ChannelRef {
+        match self.child_ref {
+            ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(ref v)) => v,
+            _ => <ChannelRef as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_channel_ref(&mut self) {
+        self.child_ref = ::std::option::Option::None;
+    }
+
+    pub fn has_channel_ref(&self) -> bool {
+        match self.child_ref {
+            ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel_ref(&mut self, v: ChannelRef) {
+        self.child_ref = ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_channel_ref(&mut self) -> &mut ChannelRef {
+        if let ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(_)) = self.child_ref {
+        } else {
+            self.child_ref = ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(ChannelRef::new()));
+        }
+        match self.child_ref {
+            ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_channel_ref(&mut self) -> ChannelRef {
+        if self.has_channel_ref() {
+            match self.child_ref.take() {
+                ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ChannelRef::new()
+        }
+    }
+
+    // .grpc.channelz.v1.SubchannelRef subchannel_ref = 5;
+
+    pub fn subchannel_ref(&self) -> &SubchannelRef {
+        match self.child_ref {
+            ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(ref v)) => v,
+            _ => <SubchannelRef as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_subchannel_ref(&mut self) {
+        self.child_ref = ::std::option::Option::None;
+    }
+
+    pub fn has_subchannel_ref(&self) -> bool {
+        match self.child_ref {
+            ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subchannel_ref(&mut self, v: SubchannelRef) {
+        self.child_ref = ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_subchannel_ref(&mut self) -> &mut SubchannelRef {
+        if let ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(_)) = self.child_ref {
+        } else {
+            self.child_ref = ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(SubchannelRef::new()));
+        }
+        match self.child_ref {
+            ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_subchannel_ref(&mut self) -> SubchannelRef {
+        if self.has_subchannel_ref() {
+            match self.child_ref.take() {
+                ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SubchannelRef::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "description",
+            |m: &ChannelTraceEvent| { &m.description },
+            |m: &mut ChannelTraceEvent| { &mut m.description },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "severity",
+            |m: &ChannelTraceEvent| { &m.severity },
+            |m: &mut ChannelTraceEvent| { &mut m.severity },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "timestamp",
+            |m: &ChannelTraceEvent| { &m.timestamp },
+            |m: &mut ChannelTraceEvent| { &mut m.timestamp },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ChannelRef>(
+            "channel_ref",
+            ChannelTraceEvent::has_channel_ref,
+            ChannelTraceEvent::channel_ref,
+            ChannelTraceEvent::mut_channel_ref,
+            ChannelTraceEvent::set_channel_ref,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SubchannelRef>(
+            "subchannel_ref",
+            ChannelTraceEvent::has_subchannel_ref,
+            ChannelTraceEvent::subchannel_ref,
+            ChannelTraceEvent::mut_subchannel_ref,
+            ChannelTraceEvent::set_subchannel_ref,
+        ));
+        oneofs.push(channel_trace_event::Child_ref::generated_oneof_descriptor_data());
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ChannelTraceEvent>(
+            "ChannelTraceEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ChannelTraceEvent {
+    const NAME: &'static str = "ChannelTraceEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.description = is.read_string()?;
+                },
+                16 => {
+                    self.severity = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
+                },
+                34 => {
+                    self.child_ref = ::std::option::Option::Some(channel_trace_event::Child_ref::ChannelRef(is.read_message()?));
+                },
+                42 => {
+                    self.child_ref = ::std::option::Option::Some(channel_trace_event::Child_ref::SubchannelRef(is.read_message()?));
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.description.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.description);
+        }
+        if self.severity != ::protobufv3::EnumOrUnknown::new(channel_trace_event::Severity::CT_UNKNOWN) {
+            my_size += ::protobufv3::rt::int32_size(2, self.severity.value());
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.child_ref {
+            match v {
+                &channel_trace_event::Child_ref::ChannelRef(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &channel_trace_event::Child_ref::SubchannelRef(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.description.is_empty() {
+            os.write_string(1, &self.description)?;
+        }
+        if self.severity != ::protobufv3::EnumOrUnknown::new(channel_trace_event::Severity::CT_UNKNOWN) {
+            os.write_enum(2, ::protobufv3::EnumOrUnknown::value(&self.severity))?;
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.child_ref {
+            match v {
+                &channel_trace_event::Child_ref::ChannelRef(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &channel_trace_event::Child_ref::SubchannelRef(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChannelTraceEvent {
+        ChannelTraceEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.description.clear();
+        self.severity = ::protobufv3::EnumOrUnknown::new(channel_trace_event::Severity::CT_UNKNOWN);
+        self.timestamp.clear();
+        self.child_ref = ::std::option::Option::None;
+        self.child_ref = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChannelTraceEvent {
+        static instance: ChannelTraceEvent = ChannelTraceEvent {
+            description: ::std::string::String::new(),
+            severity: ::protobufv3::EnumOrUnknown::from_i32(0),
+            timestamp: ::protobufv3::MessageField::none(),
+            child_ref: ::std::option::Option::None,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ChannelTraceEvent {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChannelTraceEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChannelTraceEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ChannelTraceEvent {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ChannelTraceEvent`
+pub mod channel_trace_event {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:grpc.channelz.v1.ChannelTraceEvent.child_ref)
+    pub enum Child_ref {
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.ChannelTraceEvent.channel_ref)
+        ChannelRef(super::ChannelRef),
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.ChannelTraceEvent.subchannel_ref)
+        SubchannelRef(super::SubchannelRef),
+    }
+
+    impl ::protobufv3::Oneof for Child_ref {
+    }
+
+    impl ::protobufv3::OneofFull for Child_ref {
+        fn descriptor() -> ::protobufv3::reflect::OneofDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::OneofDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| <super::ChannelTraceEvent as ::protobufv3::MessageFull>::descriptor().oneof_by_name("child_ref").unwrap()).clone()
+        }
+    }
+
+    impl Child_ref {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobufv3::reflect::GeneratedOneofDescriptorData {
+            ::protobufv3::reflect::GeneratedOneofDescriptorData::new::<Child_ref>("child_ref")
+        }
+    }
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:grpc.channelz.v1.ChannelTraceEvent.Severity)
+    pub enum Severity {
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelTraceEvent.Severity.CT_UNKNOWN)
+        CT_UNKNOWN = 0,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelTraceEvent.Severity.CT_INFO)
+        CT_INFO = 1,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelTraceEvent.Severity.CT_WARNING)
+        CT_WARNING = 2,
+        // @@protoc_insertion_point(enum_value:grpc.channelz.v1.ChannelTraceEvent.Severity.CT_ERROR)
+        CT_ERROR = 3,
+    }
+
+    impl ::protobufv3::Enum for Severity {
+        const NAME: &'static str = "Severity";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Severity> {
+            match value {
+                0 => ::std::option::Option::Some(Severity::CT_UNKNOWN),
+                1 => ::std::option::Option::Some(Severity::CT_INFO),
+                2 => ::std::option::Option::Some(Severity::CT_WARNING),
+                3 => ::std::option::Option::Some(Severity::CT_ERROR),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn from_str(str: &str) -> ::std::option::Option<Severity> {
+            match str {
+                "CT_UNKNOWN" => ::std::option::Option::Some(Severity::CT_UNKNOWN),
+                "CT_INFO" => ::std::option::Option::Some(Severity::CT_INFO),
+                "CT_WARNING" => ::std::option::Option::Some(Severity::CT_WARNING),
+                "CT_ERROR" => ::std::option::Option::Some(Severity::CT_ERROR),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Severity] = &[
+            Severity::CT_UNKNOWN,
+            Severity::CT_INFO,
+            Severity::CT_WARNING,
+            Severity::CT_ERROR,
+        ];
+    }
+
+    impl ::protobufv3::EnumFull for Severity {
+        fn enum_descriptor() -> ::protobufv3::reflect::EnumDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::EnumDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ChannelTraceEvent.Severity").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobufv3::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Severity {
+        fn default() -> Self {
+            Severity::CT_UNKNOWN
+        }
+    }
+
+    impl Severity {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobufv3::reflect::GeneratedEnumDescriptorData {
+            ::protobufv3::reflect::GeneratedEnumDescriptorData::new::<Severity>("ChannelTraceEvent.Severity")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ChannelTrace)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChannelTrace {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelTrace.num_events_logged)
+    pub num_events_logged: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelTrace.creation_timestamp)
+    pub creation_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelTrace.events)
+    pub events: ::std::vec::Vec<ChannelTraceEvent>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ChannelTrace.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChannelTrace {
+    fn default() -> &'a ChannelTrace {
+        <ChannelTrace as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ChannelTrace {
+    pub fn new() -> ChannelTrace {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "num_events_logged",
+            |m: &ChannelTrace| { &m.num_events_logged },
+            |m: &mut ChannelTrace| { &mut m.num_events_logged },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "creation_timestamp",
+            |m: &ChannelTrace| { &m.creation_timestamp },
+            |m: &mut ChannelTrace| { &mut m.creation_timestamp },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &ChannelTrace| { &m.events },
+            |m: &mut ChannelTrace| { &mut m.events },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ChannelTrace>(
+            "ChannelTrace",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ChannelTrace {
+    const NAME: &'static str = "ChannelTrace";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.num_events_logged = is.read_int64()?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.creation_timestamp)?;
+                },
+                26 => {
+                    self.events.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.num_events_logged != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.num_events_logged);
+        }
+        if let Some(v) = self.creation_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.num_events_logged != 0 {
+            os.write_int64(1, self.num_events_logged)?;
+        }
+        if let Some(v) = self.creation_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.events {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChannelTrace {
+        ChannelTrace::new()
+    }
+
+    fn clear(&mut self) {
+        self.num_events_logged = 0;
+        self.creation_timestamp.clear();
+        self.events.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChannelTrace {
+        static instance: ChannelTrace = ChannelTrace {
+            num_events_logged: 0,
+            creation_timestamp: ::protobufv3::MessageField::none(),
+            events: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ChannelTrace {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChannelTrace").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChannelTrace {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ChannelTrace {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ChannelRef)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChannelRef {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelRef.channel_id)
+    pub channel_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ChannelRef.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ChannelRef.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChannelRef {
+    fn default() -> &'a ChannelRef {
+        <ChannelRef as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ChannelRef {
+    pub fn new() -> ChannelRef {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "channel_id",
+            |m: &ChannelRef| { &m.channel_id },
+            |m: &mut ChannelRef| { &mut m.channel_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ChannelRef| { &m.name },
+            |m: &mut ChannelRef| { &mut m.name },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ChannelRef>(
+            "ChannelRef",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ChannelRef {
+    const NAME: &'static str = "ChannelRef";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.channel_id = is.read_int64()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.channel_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.channel_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.name);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.channel_id != 0 {
+            os.write_int64(1, self.channel_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChannelRef {
+        ChannelRef::new()
+    }
+
+    fn clear(&mut self) {
+        self.channel_id = 0;
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChannelRef {
+        static instance: ChannelRef = ChannelRef {
+            channel_id: 0,
+            name: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ChannelRef {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChannelRef").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChannelRef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ChannelRef {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SubchannelRef)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SubchannelRef {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SubchannelRef.subchannel_id)
+    pub subchannel_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SubchannelRef.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SubchannelRef.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SubchannelRef {
+    fn default() -> &'a SubchannelRef {
+        <SubchannelRef as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SubchannelRef {
+    pub fn new() -> SubchannelRef {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "subchannel_id",
+            |m: &SubchannelRef| { &m.subchannel_id },
+            |m: &mut SubchannelRef| { &mut m.subchannel_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &SubchannelRef| { &m.name },
+            |m: &mut SubchannelRef| { &mut m.name },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SubchannelRef>(
+            "SubchannelRef",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SubchannelRef {
+    const NAME: &'static str = "SubchannelRef";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                56 => {
+                    self.subchannel_id = is.read_int64()?;
+                },
+                66 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.subchannel_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(7, self.subchannel_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(8, &self.name);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.subchannel_id != 0 {
+            os.write_int64(7, self.subchannel_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(8, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SubchannelRef {
+        SubchannelRef::new()
+    }
+
+    fn clear(&mut self) {
+        self.subchannel_id = 0;
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SubchannelRef {
+        static instance: SubchannelRef = SubchannelRef {
+            subchannel_id: 0,
+            name: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SubchannelRef {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SubchannelRef").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SubchannelRef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SubchannelRef {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SocketRef)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SocketRef {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketRef.socket_id)
+    pub socket_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketRef.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SocketRef.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SocketRef {
+    fn default() -> &'a SocketRef {
+        <SocketRef as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SocketRef {
+    pub fn new() -> SocketRef {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "socket_id",
+            |m: &SocketRef| { &m.socket_id },
+            |m: &mut SocketRef| { &mut m.socket_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &SocketRef| { &m.name },
+            |m: &mut SocketRef| { &mut m.name },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SocketRef>(
+            "SocketRef",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SocketRef {
+    const NAME: &'static str = "SocketRef";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                24 => {
+                    self.socket_id = is.read_int64()?;
+                },
+                34 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.socket_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(3, self.socket_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(4, &self.name);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.socket_id != 0 {
+            os.write_int64(3, self.socket_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(4, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SocketRef {
+        SocketRef::new()
+    }
+
+    fn clear(&mut self) {
+        self.socket_id = 0;
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SocketRef {
+        static instance: SocketRef = SocketRef {
+            socket_id: 0,
+            name: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SocketRef {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SocketRef").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SocketRef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SocketRef {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ServerRef)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ServerRef {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerRef.server_id)
+    pub server_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerRef.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ServerRef.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ServerRef {
+    fn default() -> &'a ServerRef {
+        <ServerRef as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ServerRef {
+    pub fn new() -> ServerRef {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "server_id",
+            |m: &ServerRef| { &m.server_id },
+            |m: &mut ServerRef| { &mut m.server_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ServerRef| { &m.name },
+            |m: &mut ServerRef| { &mut m.name },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ServerRef>(
+            "ServerRef",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ServerRef {
+    const NAME: &'static str = "ServerRef";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                40 => {
+                    self.server_id = is.read_int64()?;
+                },
+                50 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.server_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(5, self.server_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(6, &self.name);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.server_id != 0 {
+            os.write_int64(5, self.server_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(6, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ServerRef {
+        ServerRef::new()
+    }
+
+    fn clear(&mut self) {
+        self.server_id = 0;
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ServerRef {
+        static instance: ServerRef = ServerRef {
+            server_id: 0,
+            name: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ServerRef {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServerRef").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ServerRef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ServerRef {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.Server)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Server {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Server.ref)
+    pub ref_: ::protobufv3::MessageField<ServerRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Server.data)
+    pub data: ::protobufv3::MessageField<ServerData>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Server.listen_socket)
+    pub listen_socket: ::std::vec::Vec<SocketRef>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.Server.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Server {
+    fn default() -> &'a Server {
+        <Server as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Server {
+    pub fn new() -> Server {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ServerRef>(
+            "ref",
+            |m: &Server| { &m.ref_ },
+            |m: &mut Server| { &mut m.ref_ },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ServerData>(
+            "data",
+            |m: &Server| { &m.data },
+            |m: &mut Server| { &mut m.data },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "listen_socket",
+            |m: &Server| { &m.listen_socket },
+            |m: &mut Server| { &mut m.listen_socket },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Server>(
+            "Server",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Server {
+    const NAME: &'static str = "Server";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.ref_)?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.data)?;
+                },
+                26 => {
+                    self.listen_socket.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.ref_.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.data.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.listen_socket {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.ref_.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.listen_socket {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Server {
+        Server::new()
+    }
+
+    fn clear(&mut self) {
+        self.ref_.clear();
+        self.data.clear();
+        self.listen_socket.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Server {
+        static instance: Server = Server {
+            ref_: ::protobufv3::MessageField::none(),
+            data: ::protobufv3::MessageField::none(),
+            listen_socket: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Server {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Server").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Server {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Server {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.ServerData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ServerData {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerData.trace)
+    pub trace: ::protobufv3::MessageField<ChannelTrace>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerData.calls_started)
+    pub calls_started: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerData.calls_succeeded)
+    pub calls_succeeded: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerData.calls_failed)
+    pub calls_failed: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.ServerData.last_call_started_timestamp)
+    pub last_call_started_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.ServerData.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ServerData {
+    fn default() -> &'a ServerData {
+        <ServerData as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ServerData {
+    pub fn new() -> ServerData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ChannelTrace>(
+            "trace",
+            |m: &ServerData| { &m.trace },
+            |m: &mut ServerData| { &mut m.trace },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls_started",
+            |m: &ServerData| { &m.calls_started },
+            |m: &mut ServerData| { &mut m.calls_started },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls_succeeded",
+            |m: &ServerData| { &m.calls_succeeded },
+            |m: &mut ServerData| { &mut m.calls_succeeded },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls_failed",
+            |m: &ServerData| { &m.calls_failed },
+            |m: &mut ServerData| { &mut m.calls_failed },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "last_call_started_timestamp",
+            |m: &ServerData| { &m.last_call_started_timestamp },
+            |m: &mut ServerData| { &mut m.last_call_started_timestamp },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ServerData>(
+            "ServerData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ServerData {
+    const NAME: &'static str = "ServerData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.trace)?;
+                },
+                16 => {
+                    self.calls_started = is.read_int64()?;
+                },
+                24 => {
+                    self.calls_succeeded = is.read_int64()?;
+                },
+                32 => {
+                    self.calls_failed = is.read_int64()?;
+                },
+                42 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.last_call_started_timestamp)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.trace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.calls_started != 0 {
+            my_size += ::protobufv3::rt::int64_size(2, self.calls_started);
+        }
+        if self.calls_succeeded != 0 {
+            my_size += ::protobufv3::rt::int64_size(3, self.calls_succeeded);
+        }
+        if self.calls_failed != 0 {
+            my_size += ::protobufv3::rt::int64_size(4, self.calls_failed);
+        }
+        if let Some(v) = self.last_call_started_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.trace.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.calls_started != 0 {
+            os.write_int64(2, self.calls_started)?;
+        }
+        if self.calls_succeeded != 0 {
+            os.write_int64(3, self.calls_succeeded)?;
+        }
+        if self.calls_failed != 0 {
+            os.write_int64(4, self.calls_failed)?;
+        }
+        if let Some(v) = self.last_call_started_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ServerData {
+        ServerData::new()
+    }
+
+    fn clear(&mut self) {
+        self.trace.clear();
+        self.calls_started = 0;
+        self.calls_succeeded = 0;
+        self.calls_failed = 0;
+        self.last_call_started_timestamp.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ServerData {
+        static instance: ServerData = ServerData {
+            trace: ::protobufv3::MessageField::none(),
+            calls_started: 0,
+            calls_succeeded: 0,
+            calls_failed: 0,
+            last_call_started_timestamp: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ServerData {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServerData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ServerData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ServerData {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.Socket)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Socket {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Socket.ref)
+    pub ref_: ::protobufv3::MessageField<SocketRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Socket.data)
+    pub data: ::protobufv3::MessageField<SocketData>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Socket.local)
+    pub local: ::protobufv3::MessageField<Address>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Socket.remote)
+    pub remote: ::protobufv3::MessageField<Address>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Socket.security)
+    pub security: ::protobufv3::MessageField<Security>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.Socket.remote_name)
+    pub remote_name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.Socket.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Socket {
+    fn default() -> &'a Socket {
+        <Socket as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Socket {
+    pub fn new() -> Socket {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, SocketRef>(
+            "ref",
+            |m: &Socket| { &m.ref_ },
+            |m: &mut Socket| { &mut m.ref_ },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, SocketData>(
+            "data",
+            |m: &Socket| { &m.data },
+            |m: &mut Socket| { &mut m.data },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Address>(
+            "local",
+            |m: &Socket| { &m.local },
+            |m: &mut Socket| { &mut m.local },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Address>(
+            "remote",
+            |m: &Socket| { &m.remote },
+            |m: &mut Socket| { &mut m.remote },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Security>(
+            "security",
+            |m: &Socket| { &m.security },
+            |m: &mut Socket| { &mut m.security },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "remote_name",
+            |m: &Socket| { &m.remote_name },
+            |m: &mut Socket| { &mut m.remote_name },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Socket>(
+            "Socket",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Socket {
+    const NAME: &'static str = "Socket";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.ref_)?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.data)?;
+                },
+                26 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.local)?;
+                },
+                34 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.remote)?;
+                },
+                42 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.security)?;
+                },
+                50 => {
+                    self.remote_name = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.ref_.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.data.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.local.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.remote.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.security.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.remote_name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(6, &self.remote_name);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.ref_.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.local.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.remote.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.security.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        if !self.remote_name.is_empty() {
+            os.write_string(6, &self.remote_name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Socket {
+        Socket::new()
+    }
+
+    fn clear(&mut self) {
+        self.ref_.clear();
+        self.data.clear();
+        self.local.clear();
+        self.remote.clear();
+        self.security.clear();
+        self.remote_name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Socket {
+        static instance: Socket = Socket {
+            ref_: ::protobufv3::MessageField::none(),
+            data: ::protobufv3::MessageField::none(),
+            local: ::protobufv3::MessageField::none(),
+            remote: ::protobufv3::MessageField::none(),
+            security: ::protobufv3::MessageField::none(),
+            remote_name: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Socket {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Socket").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Socket {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Socket {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SocketData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SocketData {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.streams_started)
+    pub streams_started: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.streams_succeeded)
+    pub streams_succeeded: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.streams_failed)
+    pub streams_failed: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.messages_sent)
+    pub messages_sent: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.messages_received)
+    pub messages_received: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.keep_alives_sent)
+    pub keep_alives_sent: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.last_local_stream_created_timestamp)
+    pub last_local_stream_created_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.last_remote_stream_created_timestamp)
+    pub last_remote_stream_created_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.last_message_sent_timestamp)
+    pub last_message_sent_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.last_message_received_timestamp)
+    pub last_message_received_timestamp: ::protobufv3::MessageField<::protobufv3::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.local_flow_control_window)
+    pub local_flow_control_window: ::protobufv3::MessageField<::protobufv3::well_known_types::wrappers::Int64Value>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.remote_flow_control_window)
+    pub remote_flow_control_window: ::protobufv3::MessageField<::protobufv3::well_known_types::wrappers::Int64Value>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketData.option)
+    pub option: ::std::vec::Vec<SocketOption>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SocketData.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SocketData {
+    fn default() -> &'a SocketData {
+        <SocketData as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SocketData {
+    pub fn new() -> SocketData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(13);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "streams_started",
+            |m: &SocketData| { &m.streams_started },
+            |m: &mut SocketData| { &mut m.streams_started },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "streams_succeeded",
+            |m: &SocketData| { &m.streams_succeeded },
+            |m: &mut SocketData| { &mut m.streams_succeeded },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "streams_failed",
+            |m: &SocketData| { &m.streams_failed },
+            |m: &mut SocketData| { &mut m.streams_failed },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "messages_sent",
+            |m: &SocketData| { &m.messages_sent },
+            |m: &mut SocketData| { &mut m.messages_sent },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "messages_received",
+            |m: &SocketData| { &m.messages_received },
+            |m: &mut SocketData| { &mut m.messages_received },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "keep_alives_sent",
+            |m: &SocketData| { &m.keep_alives_sent },
+            |m: &mut SocketData| { &mut m.keep_alives_sent },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "last_local_stream_created_timestamp",
+            |m: &SocketData| { &m.last_local_stream_created_timestamp },
+            |m: &mut SocketData| { &mut m.last_local_stream_created_timestamp },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "last_remote_stream_created_timestamp",
+            |m: &SocketData| { &m.last_remote_stream_created_timestamp },
+            |m: &mut SocketData| { &mut m.last_remote_stream_created_timestamp },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "last_message_sent_timestamp",
+            |m: &SocketData| { &m.last_message_sent_timestamp },
+            |m: &mut SocketData| { &mut m.last_message_sent_timestamp },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::timestamp::Timestamp>(
+            "last_message_received_timestamp",
+            |m: &SocketData| { &m.last_message_received_timestamp },
+            |m: &mut SocketData| { &mut m.last_message_received_timestamp },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::wrappers::Int64Value>(
+            "local_flow_control_window",
+            |m: &SocketData| { &m.local_flow_control_window },
+            |m: &mut SocketData| { &mut m.local_flow_control_window },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::wrappers::Int64Value>(
+            "remote_flow_control_window",
+            |m: &SocketData| { &m.remote_flow_control_window },
+            |m: &mut SocketData| { &mut m.remote_flow_control_window },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "option",
+            |m: &SocketData| { &m.option },
+            |m: &mut SocketData| { &mut m.option },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SocketData>(
+            "SocketData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SocketData {
+    const NAME: &'static str = "SocketData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.streams_started = is.read_int64()?;
+                },
+                16 => {
+                    self.streams_succeeded = is.read_int64()?;
+                },
+                24 => {
+                    self.streams_failed = is.read_int64()?;
+                },
+                32 => {
+                    self.messages_sent = is.read_int64()?;
+                },
+                40 => {
+                    self.messages_received = is.read_int64()?;
+                },
+                48 => {
+                    self.keep_alives_sent = is.read_int64()?;
+                },
+                58 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.last_local_stream_created_timestamp)?;
+                },
+                66 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.last_remote_stream_created_timestamp)?;
+                },
+                74 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.last_message_sent_timestamp)?;
+                },
+                82 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.last_message_received_timestamp)?;
+                },
+                90 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.local_flow_control_window)?;
+                },
+                98 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.remote_flow_control_window)?;
+                },
+                106 => {
+                    self.option.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.streams_started != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.streams_started);
+        }
+        if self.streams_succeeded != 0 {
+            my_size += ::protobufv3::rt::int64_size(2, self.streams_succeeded);
+        }
+        if self.streams_failed != 0 {
+            my_size += ::protobufv3::rt::int64_size(3, self.streams_failed);
+        }
+        if self.messages_sent != 0 {
+            my_size += ::protobufv3::rt::int64_size(4, self.messages_sent);
+        }
+        if self.messages_received != 0 {
+            my_size += ::protobufv3::rt::int64_size(5, self.messages_received);
+        }
+        if self.keep_alives_sent != 0 {
+            my_size += ::protobufv3::rt::int64_size(6, self.keep_alives_sent);
+        }
+        if let Some(v) = self.last_local_stream_created_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.last_remote_stream_created_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.last_message_sent_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.last_message_received_timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.local_flow_control_window.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.remote_flow_control_window.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.option {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.streams_started != 0 {
+            os.write_int64(1, self.streams_started)?;
+        }
+        if self.streams_succeeded != 0 {
+            os.write_int64(2, self.streams_succeeded)?;
+        }
+        if self.streams_failed != 0 {
+            os.write_int64(3, self.streams_failed)?;
+        }
+        if self.messages_sent != 0 {
+            os.write_int64(4, self.messages_sent)?;
+        }
+        if self.messages_received != 0 {
+            os.write_int64(5, self.messages_received)?;
+        }
+        if self.keep_alives_sent != 0 {
+            os.write_int64(6, self.keep_alives_sent)?;
+        }
+        if let Some(v) = self.last_local_stream_created_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        if let Some(v) = self.last_remote_stream_created_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(8, v, os)?;
+        }
+        if let Some(v) = self.last_message_sent_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(9, v, os)?;
+        }
+        if let Some(v) = self.last_message_received_timestamp.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if let Some(v) = self.local_flow_control_window.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(11, v, os)?;
+        }
+        if let Some(v) = self.remote_flow_control_window.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
+        for v in &self.option {
+            ::protobufv3::rt::write_message_field_with_cached_size(13, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SocketData {
+        SocketData::new()
+    }
+
+    fn clear(&mut self) {
+        self.streams_started = 0;
+        self.streams_succeeded = 0;
+        self.streams_failed = 0;
+        self.messages_sent = 0;
+        self.messages_received = 0;
+        self.keep_alives_sent = 0;
+        self.last_local_stream_created_timestamp.clear();
+        self.last_remote_stream_created_timestamp.clear();
+        self.last_message_sent_timestamp.clear();
+        self.last_message_received_timestamp.clear();
+        self.local_flow_control_window.clear();
+        self.remote_flow_control_window.clear();
+        self.option.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SocketData {
+        static instance: SocketData = SocketData {
+            streams_started: 0,
+            streams_succeeded: 0,
+            streams_failed: 0,
+            messages_sent: 0,
+            messages_received: 0,
+            keep_alives_sent: 0,
+            last_local_stream_created_timestamp: ::protobufv3::MessageField::none(),
+            last_remote_stream_created_timestamp: ::protobufv3::MessageField::none(),
+            last_message_sent_timestamp: ::protobufv3::MessageField::none(),
+            last_message_received_timestamp: ::protobufv3::MessageField::none(),
+            local_flow_control_window: ::protobufv3::MessageField::none(),
+            remote_flow_control_window: ::protobufv3::MessageField::none(),
+            option: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SocketData {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SocketData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SocketData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SocketData {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.Address)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Address {
+    // message oneof groups
+    pub address: ::std::option::Option<address::Address>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.Address.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Address {
+    fn default() -> &'a Address {
+        <Address as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Address {
+    pub fn new() -> Address {
+        ::std::default::Default::default()
+    }
+
+    // .grpc.channelz.v1.Address.TcpIpAddress tcpip_address = 1;
+
+    pub fn tcpip_address(&self) -> &address::TcpIpAddress {
+        match self.address {
+            ::std::option::Option::Some(address::Address::TcpipAddress(ref v)) => v,
+            _ => <address::TcpIpAddress as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_tcpip_address(&mut self) {
+        self.address = ::std::option::Option::None;
+    }
+
+    pub fn has_tcpip_address(&self) -> bool {
+        match self.address {
+            ::std::option::Option::Some(address::Address::TcpipAddress(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tcpip_address(&mut self, v: address::TcpIpAddress) {
+        self.address = ::std::option::Option::Some(address::Address::TcpipAddress(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tcpip_address(&mut self) -> &mut address::TcpIpAddress {
+        if let ::std::option::Option::Some(address::Address::TcpipAddress(_)) = self.address {
+        } else {
+            self.address = ::std::option::Option::Some(address::Address::TcpipAddress(address::TcpIpAddress::new()));
+        }
+        match self.address {
+            ::std::option::Option::Some(address::Address::TcpipAddress(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tcpip_address(&mut self) -> address::TcpIpAddress {
+        if self.has_tcpip_address() {
+            match self.address.take() {
+                ::std::option::Option::Some(address::Address::TcpipAddress(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            address::TcpIpAddress::new()
+        }
+    }
+
+    // .grpc.channelz.v1.Address.UdsAddress uds_address = 2;
+
+    pub fn uds_address(&self) -> &address::UdsAddress {
+        match self.address {
+            ::std::option::Option::Some(address::Address::UdsAddress(ref v)) => v,
+            _ => <address::UdsAddress as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_uds_address(&mut self) {
+        self.address = ::std::option::Option::None;
+    }
+
+    pub fn has_uds_address(&self) -> bool {
+        match self.address {
+            ::std::option::Option::Some(address::Address::UdsAddress(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uds_address(&mut self, v: address::UdsAddress) {
+        self.address = ::std::option::Option::Some(address::Address::UdsAddress(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_uds_address(&mut self) -> &mut address::UdsAddress {
+        if let ::std::option::Option::Some(address::Address::UdsAddress(_)) = self.address {
+        } else {
+            self.address = ::std::option::Option::Some(address::Address::UdsAddress(address::UdsAddress::new()));
+        }
+        match self.address {
+            ::std::option::Option::Some(address::Address::UdsAddress(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_uds_address(&mut self) -> address::UdsAddress {
+        if self.has_uds_address() {
+            match self.address.take() {
+                ::std::option::Option::Some(address::Address::UdsAddress(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            address::UdsAddress::new()
+        }
+    }
+
+    // .grpc.channelz.v1.Address.OtherAddress other_address = 3;
+
+    pub fn other_address(&self) -> &address::OtherAddress {
+        match self.address {
+            ::std::option::Option::Some(address::Address::OtherAddress(ref v)) => v,
+            _ => <address::OtherAddress as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_other_address(&mut self) {
+        self.address = ::std::option::Option::None;
+    }
+
+    pub fn has_other_address(&self) -> bool {
+        match self.address {
+            ::std::option::Option::Some(address::Address::OtherAddress(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_other_address(&mut self, v: address::OtherAddress) {
+        self.address = ::std::option::Option::Some(address::Address::OtherAddress(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_other_address(&mut self) -> &mut address::OtherAddress {
+        if let ::std::option::Option::Some(address::Address::OtherAddress(_)) = self.address {
+        } else {
+            self.address = ::std::option::Option::Some(address::Address::OtherAddress(address::OtherAddress::new()));
+        }
+        match self.address {
+            ::std::option::Option::Some(address::Address::OtherAddress(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_other_address(&mut self) -> address::OtherAddress {
+        if self.has_other_address() {
+            match self.address.take() {
+                ::std::option::Option::Some(address::Address::OtherAddress(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            address::OtherAddress::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, address::TcpIpAddress>(
+            "tcpip_address",
+            Address::has_tcpip_address,
+            Address::tcpip_address,
+            Address::mut_tcpip_address,
+            Address::set_tcpip_address,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, address::UdsAddress>(
+            "uds_address",
+            Address::has_uds_address,
+            Address::uds_address,
+            Address::mut_uds_address,
+            Address::set_uds_address,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, address::OtherAddress>(
+            "other_address",
+            Address::has_other_address,
+            Address::other_address,
+            Address::mut_other_address,
+            Address::set_other_address,
+        ));
+        oneofs.push(address::Address::generated_oneof_descriptor_data());
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Address>(
+            "Address",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Address {
+    const NAME: &'static str = "Address";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.address = ::std::option::Option::Some(address::Address::TcpipAddress(is.read_message()?));
+                },
+                18 => {
+                    self.address = ::std::option::Option::Some(address::Address::UdsAddress(is.read_message()?));
+                },
+                26 => {
+                    self.address = ::std::option::Option::Some(address::Address::OtherAddress(is.read_message()?));
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.address {
+            match v {
+                &address::Address::TcpipAddress(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &address::Address::UdsAddress(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &address::Address::OtherAddress(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.address {
+            match v {
+                &address::Address::TcpipAddress(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &address::Address::UdsAddress(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &address::Address::OtherAddress(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Address {
+        Address::new()
+    }
+
+    fn clear(&mut self) {
+        self.address = ::std::option::Option::None;
+        self.address = ::std::option::Option::None;
+        self.address = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Address {
+        static instance: Address = Address {
+            address: ::std::option::Option::None,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Address {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Address").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Address {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Address`
+pub mod address {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:grpc.channelz.v1.Address.address)
+    pub enum Address {
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Address.tcpip_address)
+        TcpipAddress(TcpIpAddress),
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Address.uds_address)
+        UdsAddress(UdsAddress),
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Address.other_address)
+        OtherAddress(OtherAddress),
+    }
+
+    impl ::protobufv3::Oneof for Address {
+    }
+
+    impl ::protobufv3::OneofFull for Address {
+        fn descriptor() -> ::protobufv3::reflect::OneofDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::OneofDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| <super::Address as ::protobufv3::MessageFull>::descriptor().oneof_by_name("address").unwrap()).clone()
+        }
+    }
+
+    impl Address {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobufv3::reflect::GeneratedOneofDescriptorData {
+            ::protobufv3::reflect::GeneratedOneofDescriptorData::new::<Address>("address")
+        }
+    }
+    // @@protoc_insertion_point(message:grpc.channelz.v1.Address.TcpIpAddress)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct TcpIpAddress {
+        // message fields
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Address.TcpIpAddress.ip_address)
+        pub ip_address: ::std::vec::Vec<u8>,
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Address.TcpIpAddress.port)
+        pub port: i32,
+        // special fields
+        // @@protoc_insertion_point(special_field:grpc.channelz.v1.Address.TcpIpAddress.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a TcpIpAddress {
+        fn default() -> &'a TcpIpAddress {
+            <TcpIpAddress as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl TcpIpAddress {
+        pub fn new() -> TcpIpAddress {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "ip_address",
+                |m: &TcpIpAddress| { &m.ip_address },
+                |m: &mut TcpIpAddress| { &mut m.ip_address },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "port",
+                |m: &TcpIpAddress| { &m.port },
+                |m: &mut TcpIpAddress| { &mut m.port },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<TcpIpAddress>(
+                "Address.TcpIpAddress",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for TcpIpAddress {
+        const NAME: &'static str = "TcpIpAddress";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.ip_address = is.read_bytes()?;
+                    },
+                    16 => {
+                        self.port = is.read_int32()?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.ip_address.is_empty() {
+                my_size += ::protobufv3::rt::bytes_size(1, &self.ip_address);
+            }
+            if self.port != 0 {
+                my_size += ::protobufv3::rt::int32_size(2, self.port);
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.ip_address.is_empty() {
+                os.write_bytes(1, &self.ip_address)?;
+            }
+            if self.port != 0 {
+                os.write_int32(2, self.port)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> TcpIpAddress {
+            TcpIpAddress::new()
+        }
+
+        fn clear(&mut self) {
+            self.ip_address.clear();
+            self.port = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static TcpIpAddress {
+            static instance: TcpIpAddress = TcpIpAddress {
+                ip_address: ::std::vec::Vec::new(),
+                port: 0,
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for TcpIpAddress {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Address.TcpIpAddress").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for TcpIpAddress {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for TcpIpAddress {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    // @@protoc_insertion_point(message:grpc.channelz.v1.Address.UdsAddress)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct UdsAddress {
+        // message fields
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Address.UdsAddress.filename)
+        pub filename: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:grpc.channelz.v1.Address.UdsAddress.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a UdsAddress {
+        fn default() -> &'a UdsAddress {
+            <UdsAddress as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl UdsAddress {
+        pub fn new() -> UdsAddress {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(1);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "filename",
+                |m: &UdsAddress| { &m.filename },
+                |m: &mut UdsAddress| { &mut m.filename },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<UdsAddress>(
+                "Address.UdsAddress",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for UdsAddress {
+        const NAME: &'static str = "UdsAddress";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.filename = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.filename.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.filename);
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.filename.is_empty() {
+                os.write_string(1, &self.filename)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> UdsAddress {
+            UdsAddress::new()
+        }
+
+        fn clear(&mut self) {
+            self.filename.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static UdsAddress {
+            static instance: UdsAddress = UdsAddress {
+                filename: ::std::string::String::new(),
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for UdsAddress {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Address.UdsAddress").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for UdsAddress {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for UdsAddress {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    // @@protoc_insertion_point(message:grpc.channelz.v1.Address.OtherAddress)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct OtherAddress {
+        // message fields
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Address.OtherAddress.name)
+        pub name: ::std::string::String,
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Address.OtherAddress.value)
+        pub value: ::protobufv3::MessageField<::protobufv3::well_known_types::any::Any>,
+        // special fields
+        // @@protoc_insertion_point(special_field:grpc.channelz.v1.Address.OtherAddress.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a OtherAddress {
+        fn default() -> &'a OtherAddress {
+            <OtherAddress as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl OtherAddress {
+        pub fn new() -> OtherAddress {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "name",
+                |m: &OtherAddress| { &m.name },
+                |m: &mut OtherAddress| { &mut m.name },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::any::Any>(
+                "value",
+                |m: &OtherAddress| { &m.value },
+                |m: &mut OtherAddress| { &mut m.value },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<OtherAddress>(
+                "Address.OtherAddress",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for OtherAddress {
+        const NAME: &'static str = "OtherAddress";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.name = is.read_string()?;
+                    },
+                    18 => {
+                        ::protobufv3::rt::read_singular_message_into_field(is, &mut self.value)?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.name.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.name);
+            }
+            if let Some(v) = self.value.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.name.is_empty() {
+                os.write_string(1, &self.name)?;
+            }
+            if let Some(v) = self.value.as_ref() {
+                ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> OtherAddress {
+            OtherAddress::new()
+        }
+
+        fn clear(&mut self) {
+            self.name.clear();
+            self.value.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static OtherAddress {
+            static instance: OtherAddress = OtherAddress {
+                name: ::std::string::String::new(),
+                value: ::protobufv3::MessageField::none(),
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for OtherAddress {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Address.OtherAddress").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for OtherAddress {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for OtherAddress {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.Security)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Security {
+    // message oneof groups
+    pub model: ::std::option::Option<security::Model>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.Security.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Security {
+    fn default() -> &'a Security {
+        <Security as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Security {
+    pub fn new() -> Security {
+        ::std::default::Default::default()
+    }
+
+    // .grpc.channelz.v1.Security.Tls tls = 1;
+
+    pub fn tls(&self) -> &security::Tls {
+        match self.model {
+            ::std::option::Option::Some(security::Model::Tls(ref v)) => v,
+            _ => <security::Tls as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_tls(&mut self) {
+        self.model = ::std::option::Option::None;
+    }
+
+    pub fn has_tls(&self) -> bool {
+        match self.model {
+            ::std::option::Option::Some(security::Model::Tls(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tls(&mut self, v: security::Tls) {
+        self.model = ::std::option::Option::Some(security::Model::Tls(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tls(&mut self) -> &mut security::Tls {
+        if let ::std::option::Option::Some(security::Model::Tls(_)) = self.model {
+        } else {
+            self.model = ::std::option::Option::Some(security::Model::Tls(security::Tls::new()));
+        }
+        match self.model {
+            ::std::option::Option::Some(security::Model::Tls(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tls(&mut self) -> security::Tls {
+        if self.has_tls() {
+            match self.model.take() {
+                ::std::option::Option::Some(security::Model::Tls(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            security::Tls::new()
+        }
+    }
+
+    // .grpc.channelz.v1.Security.OtherSecurity other = 2;
+
+    pub fn other(&self) -> &security::OtherSecurity {
+        match self.model {
+            ::std::option::Option::Some(security::Model::Other(ref v)) => v,
+            _ => <security::OtherSecurity as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_other(&mut self) {
+        self.model = ::std::option::Option::None;
+    }
+
+    pub fn has_other(&self) -> bool {
+        match self.model {
+            ::std::option::Option::Some(security::Model::Other(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_other(&mut self, v: security::OtherSecurity) {
+        self.model = ::std::option::Option::Some(security::Model::Other(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_other(&mut self) -> &mut security::OtherSecurity {
+        if let ::std::option::Option::Some(security::Model::Other(_)) = self.model {
+        } else {
+            self.model = ::std::option::Option::Some(security::Model::Other(security::OtherSecurity::new()));
+        }
+        match self.model {
+            ::std::option::Option::Some(security::Model::Other(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_other(&mut self) -> security::OtherSecurity {
+        if self.has_other() {
+            match self.model.take() {
+                ::std::option::Option::Some(security::Model::Other(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            security::OtherSecurity::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, security::Tls>(
+            "tls",
+            Security::has_tls,
+            Security::tls,
+            Security::mut_tls,
+            Security::set_tls,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, security::OtherSecurity>(
+            "other",
+            Security::has_other,
+            Security::other,
+            Security::mut_other,
+            Security::set_other,
+        ));
+        oneofs.push(security::Model::generated_oneof_descriptor_data());
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Security>(
+            "Security",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Security {
+    const NAME: &'static str = "Security";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.model = ::std::option::Option::Some(security::Model::Tls(is.read_message()?));
+                },
+                18 => {
+                    self.model = ::std::option::Option::Some(security::Model::Other(is.read_message()?));
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.model {
+            match v {
+                &security::Model::Tls(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &security::Model::Other(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.model {
+            match v {
+                &security::Model::Tls(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &security::Model::Other(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Security {
+        Security::new()
+    }
+
+    fn clear(&mut self) {
+        self.model = ::std::option::Option::None;
+        self.model = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Security {
+        static instance: Security = Security {
+            model: ::std::option::Option::None,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Security {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Security").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Security {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Security {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Security`
+pub mod security {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:grpc.channelz.v1.Security.model)
+    pub enum Model {
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Security.tls)
+        Tls(Tls),
+        // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Security.other)
+        Other(OtherSecurity),
+    }
+
+    impl ::protobufv3::Oneof for Model {
+    }
+
+    impl ::protobufv3::OneofFull for Model {
+        fn descriptor() -> ::protobufv3::reflect::OneofDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::OneofDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| <super::Security as ::protobufv3::MessageFull>::descriptor().oneof_by_name("model").unwrap()).clone()
+        }
+    }
+
+    impl Model {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobufv3::reflect::GeneratedOneofDescriptorData {
+            ::protobufv3::reflect::GeneratedOneofDescriptorData::new::<Model>("model")
+        }
+    }
+    // @@protoc_insertion_point(message:grpc.channelz.v1.Security.Tls)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct Tls {
+        // message fields
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Security.Tls.local_certificate)
+        pub local_certificate: ::std::vec::Vec<u8>,
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Security.Tls.remote_certificate)
+        pub remote_certificate: ::std::vec::Vec<u8>,
+        // message oneof groups
+        pub cipher_suite: ::std::option::Option<tls::Cipher_suite>,
+        // special fields
+        // @@protoc_insertion_point(special_field:grpc.channelz.v1.Security.Tls.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Tls {
+        fn default() -> &'a Tls {
+            <Tls as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl Tls {
+        pub fn new() -> Tls {
+            ::std::default::Default::default()
+        }
+
+        // string standard_name = 1;
+
+        pub fn standard_name(&self) -> &str {
+            match self.cipher_suite {
+                ::std::option::Option::Some(tls::Cipher_suite::StandardName(ref v)) => v,
+                _ => "",
+            }
+        }
+
+        pub fn clear_standard_name(&mut self) {
+            self.cipher_suite = ::std::option::Option::None;
+        }
+
+        pub fn has_standard_name(&self) -> bool {
+            match self.cipher_suite {
+                ::std::option::Option::Some(tls::Cipher_suite::StandardName(..)) => true,
+                _ => false,
+            }
+        }
+
+        // Param is passed by value, moved
+        pub fn set_standard_name(&mut self, v: ::std::string::String) {
+            self.cipher_suite = ::std::option::Option::Some(tls::Cipher_suite::StandardName(v))
+        }
+
+        // Mutable pointer to the field.
+        pub fn mut_standard_name(&mut self) -> &mut ::std::string::String {
+            if let ::std::option::Option::Some(tls::Cipher_suite::StandardName(_)) = self.cipher_suite {
+            } else {
+                self.cipher_suite = ::std::option::Option::Some(tls::Cipher_suite::StandardName(::std::string::String::new()));
+            }
+            match self.cipher_suite {
+                ::std::option::Option::Some(tls::Cipher_suite::StandardName(ref mut v)) => v,
+                _ => panic!(),
+            }
+        }
+
+        // Take field
+        pub fn take_standard_name(&mut self) -> ::std::string::String {
+            if self.has_standard_name() {
+                match self.cipher_suite.take() {
+                    ::std::option::Option::Some(tls::Cipher_suite::StandardName(v)) => v,
+                    _ => panic!(),
+                }
+            } else {
+                ::std::string::String::new()
+            }
+        }
+
+        // string other_name = 2;
+
+        pub fn other_name(&self) -> &str {
+            match self.cipher_suite {
+                ::std::option::Option::Some(tls::Cipher_suite::OtherName(ref v)) => v,
+                _ => "",
+            }
+        }
+
+        pub fn clear_other_name(&mut self) {
+            self.cipher_suite = ::std::option::Option::None;
+        }
+
+        pub fn has_other_name(&self) -> bool {
+            match self.cipher_suite {
+                ::std::option::Option::Some(tls::Cipher_suite::OtherName(..)) => true,
+                _ => false,
+            }
+        }
+
+        // Param is passed by value, moved
+        pub fn set_other_name(&mut self, v: ::std::string::String) {
+            self.cipher_suite = ::std::option::Option::Some(tls::Cipher_suite::OtherName(v))
+        }
+
+        // Mutable pointer to the field.
+        pub fn mut_other_name(&mut self) -> &mut ::std::string::String {
+            if let ::std::option::Option::Some(tls::Cipher_suite::OtherName(_)) = self.cipher_suite {
+            } else {
+                self.cipher_suite = ::std::option::Option::Some(tls::Cipher_suite::OtherName(::std::string::String::new()));
+            }
+            match self.cipher_suite {
+                ::std::option::Option::Some(tls::Cipher_suite::OtherName(ref mut v)) => v,
+                _ => panic!(),
+            }
+        }
+
+        // Take field
+        pub fn take_other_name(&mut self) -> ::std::string::String {
+            if self.has_other_name() {
+                match self.cipher_suite.take() {
+                    ::std::option::Option::Some(tls::Cipher_suite::OtherName(v)) => v,
+                    _ => panic!(),
+                }
+            } else {
+                ::std::string::String::new()
+            }
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(1);
+            fields.push(::protobufv3::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+                "standard_name",
+                Tls::has_standard_name,
+                Tls::standard_name,
+                Tls::set_standard_name,
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+                "other_name",
+                Tls::has_other_name,
+                Tls::other_name,
+                Tls::set_other_name,
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "local_certificate",
+                |m: &Tls| { &m.local_certificate },
+                |m: &mut Tls| { &mut m.local_certificate },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "remote_certificate",
+                |m: &Tls| { &m.remote_certificate },
+                |m: &mut Tls| { &mut m.remote_certificate },
+            ));
+            oneofs.push(tls::Cipher_suite::generated_oneof_descriptor_data());
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Tls>(
+                "Security.Tls",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for Tls {
+        const NAME: &'static str = "Tls";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.cipher_suite = ::std::option::Option::Some(tls::Cipher_suite::StandardName(is.read_string()?));
+                    },
+                    18 => {
+                        self.cipher_suite = ::std::option::Option::Some(tls::Cipher_suite::OtherName(is.read_string()?));
+                    },
+                    26 => {
+                        self.local_certificate = is.read_bytes()?;
+                    },
+                    34 => {
+                        self.remote_certificate = is.read_bytes()?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.local_certificate.is_empty() {
+                my_size += ::protobufv3::rt::bytes_size(3, &self.local_certificate);
+            }
+            if !self.remote_certificate.is_empty() {
+                my_size += ::protobufv3::rt::bytes_size(4, &self.remote_certificate);
+            }
+            if let ::std::option::Option::Some(ref v) = self.cipher_suite {
+                match v {
+                    &tls::Cipher_suite::StandardName(ref v) => {
+                        my_size += ::protobufv3::rt::string_size(1, &v);
+                    },
+                    &tls::Cipher_suite::OtherName(ref v) => {
+                        my_size += ::protobufv3::rt::string_size(2, &v);
+                    },
+                };
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.local_certificate.is_empty() {
+                os.write_bytes(3, &self.local_certificate)?;
+            }
+            if !self.remote_certificate.is_empty() {
+                os.write_bytes(4, &self.remote_certificate)?;
+            }
+            if let ::std::option::Option::Some(ref v) = self.cipher_suite {
+                match v {
+                    &tls::Cipher_suite::StandardName(ref v) => {
+                        os.write_string(1, v)?;
+                    },
+                    &tls::Cipher_suite::OtherName(ref v) => {
+                        os.write_string(2, v)?;
+                    },
+                };
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Tls {
+            Tls::new()
+        }
+
+        fn clear(&mut self) {
+            self.cipher_suite = ::std::option::Option::None;
+            self.cipher_suite = ::std::option::Option::None;
+            self.local_certificate.clear();
+            self.remote_certificate.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Tls {
+            static instance: Tls = Tls {
+                local_certificate: ::std::vec::Vec::new(),
+                remote_certificate: ::std::vec::Vec::new(),
+                cipher_suite: ::std::option::Option::None,
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for Tls {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Security.Tls").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Tls {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for Tls {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    /// Nested message and enums of message `Tls`
+    pub mod tls {
+
+        #[derive(Clone,PartialEq,Debug)]
+        #[non_exhaustive]
+        // @@protoc_insertion_point(oneof:grpc.channelz.v1.Security.Tls.cipher_suite)
+        pub enum Cipher_suite {
+            // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Security.Tls.standard_name)
+            StandardName(::std::string::String),
+            // @@protoc_insertion_point(oneof_field:grpc.channelz.v1.Security.Tls.other_name)
+            OtherName(::std::string::String),
+        }
+
+        impl ::protobufv3::Oneof for Cipher_suite {
+        }
+
+        impl ::protobufv3::OneofFull for Cipher_suite {
+            fn descriptor() -> ::protobufv3::reflect::OneofDescriptor {
+                static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::OneofDescriptor> = ::protobufv3::rt::Lazy::new();
+                descriptor.get(|| <super::Tls as ::protobufv3::MessageFull>::descriptor().oneof_by_name("cipher_suite").unwrap()).clone()
+            }
+        }
+
+        impl Cipher_suite {
+            pub(in super::super) fn generated_oneof_descriptor_data() -> ::protobufv3::reflect::GeneratedOneofDescriptorData {
+                ::protobufv3::reflect::GeneratedOneofDescriptorData::new::<Cipher_suite>("cipher_suite")
+            }
+        }
+    }
+
+    // @@protoc_insertion_point(message:grpc.channelz.v1.Security.OtherSecurity)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct OtherSecurity {
+        // message fields
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Security.OtherSecurity.name)
+        pub name: ::std::string::String,
+        // @@protoc_insertion_point(field:grpc.channelz.v1.Security.OtherSecurity.value)
+        pub value: ::protobufv3::MessageField<::protobufv3::well_known_types::any::Any>,
+        // special fields
+        // @@protoc_insertion_point(special_field:grpc.channelz.v1.Security.OtherSecurity.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a OtherSecurity {
+        fn default() -> &'a OtherSecurity {
+            <OtherSecurity as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl OtherSecurity {
+        pub fn new() -> OtherSecurity {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "name",
+                |m: &OtherSecurity| { &m.name },
+                |m: &mut OtherSecurity| { &mut m.name },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::any::Any>(
+                "value",
+                |m: &OtherSecurity| { &m.value },
+                |m: &mut OtherSecurity| { &mut m.value },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<OtherSecurity>(
+                "Security.OtherSecurity",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for OtherSecurity {
+        const NAME: &'static str = "OtherSecurity";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.name = is.read_string()?;
+                    },
+                    18 => {
+                        ::protobufv3::rt::read_singular_message_into_field(is, &mut self.value)?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.name.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.name);
+            }
+            if let Some(v) = self.value.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.name.is_empty() {
+                os.write_string(1, &self.name)?;
+            }
+            if let Some(v) = self.value.as_ref() {
+                ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> OtherSecurity {
+            OtherSecurity::new()
+        }
+
+        fn clear(&mut self) {
+            self.name.clear();
+            self.value.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static OtherSecurity {
+            static instance: OtherSecurity = OtherSecurity {
+                name: ::std::string::String::new(),
+                value: ::protobufv3::MessageField::none(),
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for OtherSecurity {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Security.OtherSecurity").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for OtherSecurity {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for OtherSecurity {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SocketOption)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SocketOption {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOption.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOption.value)
+    pub value: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOption.additional)
+    pub additional: ::protobufv3::MessageField<::protobufv3::well_known_types::any::Any>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SocketOption.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SocketOption {
+    fn default() -> &'a SocketOption {
+        <SocketOption as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SocketOption {
+    pub fn new() -> SocketOption {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &SocketOption| { &m.name },
+            |m: &mut SocketOption| { &mut m.name },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "value",
+            |m: &SocketOption| { &m.value },
+            |m: &mut SocketOption| { &mut m.value },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::any::Any>(
+            "additional",
+            |m: &SocketOption| { &m.additional },
+            |m: &mut SocketOption| { &mut m.additional },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SocketOption>(
+            "SocketOption",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SocketOption {
+    const NAME: &'static str = "SocketOption";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    self.value = is.read_string()?;
+                },
+                26 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.additional)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.name);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.value);
+        }
+        if let Some(v) = self.additional.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        if let Some(v) = self.additional.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SocketOption {
+        SocketOption::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.value.clear();
+        self.additional.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SocketOption {
+        static instance: SocketOption = SocketOption {
+            name: ::std::string::String::new(),
+            value: ::std::string::String::new(),
+            additional: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SocketOption {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SocketOption").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SocketOption {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SocketOption {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SocketOptionTimeout)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SocketOptionTimeout {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTimeout.duration)
+    pub duration: ::protobufv3::MessageField<::protobufv3::well_known_types::duration::Duration>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SocketOptionTimeout.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SocketOptionTimeout {
+    fn default() -> &'a SocketOptionTimeout {
+        <SocketOptionTimeout as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SocketOptionTimeout {
+    pub fn new() -> SocketOptionTimeout {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::duration::Duration>(
+            "duration",
+            |m: &SocketOptionTimeout| { &m.duration },
+            |m: &mut SocketOptionTimeout| { &mut m.duration },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SocketOptionTimeout>(
+            "SocketOptionTimeout",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SocketOptionTimeout {
+    const NAME: &'static str = "SocketOptionTimeout";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.duration)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.duration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.duration.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SocketOptionTimeout {
+        SocketOptionTimeout::new()
+    }
+
+    fn clear(&mut self) {
+        self.duration.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SocketOptionTimeout {
+        static instance: SocketOptionTimeout = SocketOptionTimeout {
+            duration: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SocketOptionTimeout {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SocketOptionTimeout").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SocketOptionTimeout {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SocketOptionTimeout {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SocketOptionLinger)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SocketOptionLinger {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionLinger.active)
+    pub active: bool,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionLinger.duration)
+    pub duration: ::protobufv3::MessageField<::protobufv3::well_known_types::duration::Duration>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SocketOptionLinger.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SocketOptionLinger {
+    fn default() -> &'a SocketOptionLinger {
+        <SocketOptionLinger as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SocketOptionLinger {
+    pub fn new() -> SocketOptionLinger {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "active",
+            |m: &SocketOptionLinger| { &m.active },
+            |m: &mut SocketOptionLinger| { &mut m.active },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::duration::Duration>(
+            "duration",
+            |m: &SocketOptionLinger| { &m.duration },
+            |m: &mut SocketOptionLinger| { &mut m.duration },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SocketOptionLinger>(
+            "SocketOptionLinger",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SocketOptionLinger {
+    const NAME: &'static str = "SocketOptionLinger";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.active = is.read_bool()?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.duration)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.active != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.duration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.active != false {
+            os.write_bool(1, self.active)?;
+        }
+        if let Some(v) = self.duration.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SocketOptionLinger {
+        SocketOptionLinger::new()
+    }
+
+    fn clear(&mut self) {
+        self.active = false;
+        self.duration.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SocketOptionLinger {
+        static instance: SocketOptionLinger = SocketOptionLinger {
+            active: false,
+            duration: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SocketOptionLinger {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SocketOptionLinger").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SocketOptionLinger {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SocketOptionLinger {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.SocketOptionTcpInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SocketOptionTcpInfo {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_state)
+    pub tcpi_state: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_ca_state)
+    pub tcpi_ca_state: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_retransmits)
+    pub tcpi_retransmits: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_probes)
+    pub tcpi_probes: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_backoff)
+    pub tcpi_backoff: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_options)
+    pub tcpi_options: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_snd_wscale)
+    pub tcpi_snd_wscale: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_rcv_wscale)
+    pub tcpi_rcv_wscale: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_rto)
+    pub tcpi_rto: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_ato)
+    pub tcpi_ato: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_snd_mss)
+    pub tcpi_snd_mss: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_rcv_mss)
+    pub tcpi_rcv_mss: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_unacked)
+    pub tcpi_unacked: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_sacked)
+    pub tcpi_sacked: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_lost)
+    pub tcpi_lost: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_retrans)
+    pub tcpi_retrans: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_fackets)
+    pub tcpi_fackets: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_last_data_sent)
+    pub tcpi_last_data_sent: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_last_ack_sent)
+    pub tcpi_last_ack_sent: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_last_data_recv)
+    pub tcpi_last_data_recv: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_last_ack_recv)
+    pub tcpi_last_ack_recv: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_pmtu)
+    pub tcpi_pmtu: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_rcv_ssthresh)
+    pub tcpi_rcv_ssthresh: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_rtt)
+    pub tcpi_rtt: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_rttvar)
+    pub tcpi_rttvar: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_snd_ssthresh)
+    pub tcpi_snd_ssthresh: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_snd_cwnd)
+    pub tcpi_snd_cwnd: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_advmss)
+    pub tcpi_advmss: u32,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.SocketOptionTcpInfo.tcpi_reordering)
+    pub tcpi_reordering: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.SocketOptionTcpInfo.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SocketOptionTcpInfo {
+    fn default() -> &'a SocketOptionTcpInfo {
+        <SocketOptionTcpInfo as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl SocketOptionTcpInfo {
+    pub fn new() -> SocketOptionTcpInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(29);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_state",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_state },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_state },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_ca_state",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_ca_state },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_ca_state },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_retransmits",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_retransmits },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_retransmits },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_probes",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_probes },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_probes },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_backoff",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_backoff },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_backoff },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_options",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_options },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_options },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_snd_wscale",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_snd_wscale },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_snd_wscale },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_rcv_wscale",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_rcv_wscale },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_rcv_wscale },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_rto",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_rto },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_rto },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_ato",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_ato },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_ato },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_snd_mss",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_snd_mss },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_snd_mss },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_rcv_mss",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_rcv_mss },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_rcv_mss },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_unacked",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_unacked },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_unacked },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_sacked",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_sacked },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_sacked },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_lost",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_lost },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_lost },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_retrans",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_retrans },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_retrans },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_fackets",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_fackets },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_fackets },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_last_data_sent",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_last_data_sent },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_last_data_sent },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_last_ack_sent",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_last_ack_sent },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_last_ack_sent },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_last_data_recv",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_last_data_recv },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_last_data_recv },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_last_ack_recv",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_last_ack_recv },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_last_ack_recv },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_pmtu",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_pmtu },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_pmtu },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_rcv_ssthresh",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_rcv_ssthresh },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_rcv_ssthresh },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_rtt",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_rtt },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_rtt },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_rttvar",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_rttvar },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_rttvar },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_snd_ssthresh",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_snd_ssthresh },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_snd_ssthresh },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_snd_cwnd",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_snd_cwnd },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_snd_cwnd },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_advmss",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_advmss },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_advmss },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tcpi_reordering",
+            |m: &SocketOptionTcpInfo| { &m.tcpi_reordering },
+            |m: &mut SocketOptionTcpInfo| { &mut m.tcpi_reordering },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<SocketOptionTcpInfo>(
+            "SocketOptionTcpInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for SocketOptionTcpInfo {
+    const NAME: &'static str = "SocketOptionTcpInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.tcpi_state = is.read_uint32()?;
+                },
+                16 => {
+                    self.tcpi_ca_state = is.read_uint32()?;
+                },
+                24 => {
+                    self.tcpi_retransmits = is.read_uint32()?;
+                },
+                32 => {
+                    self.tcpi_probes = is.read_uint32()?;
+                },
+                40 => {
+                    self.tcpi_backoff = is.read_uint32()?;
+                },
+                48 => {
+                    self.tcpi_options = is.read_uint32()?;
+                },
+                56 => {
+                    self.tcpi_snd_wscale = is.read_uint32()?;
+                },
+                64 => {
+                    self.tcpi_rcv_wscale = is.read_uint32()?;
+                },
+                72 => {
+                    self.tcpi_rto = is.read_uint32()?;
+                },
+                80 => {
+                    self.tcpi_ato = is.read_uint32()?;
+                },
+                88 => {
+                    self.tcpi_snd_mss = is.read_uint32()?;
+                },
+                96 => {
+                    self.tcpi_rcv_mss = is.read_uint32()?;
+                },
+                104 => {
+                    self.tcpi_unacked = is.read_uint32()?;
+                },
+                112 => {
+                    self.tcpi_sacked = is.read_uint32()?;
+                },
+                120 => {
+                    self.tcpi_lost = is.read_uint32()?;
+                },
+                128 => {
+                    self.tcpi_retrans = is.read_uint32()?;
+                },
+                136 => {
+                    self.tcpi_fackets = is.read_uint32()?;
+                },
+                144 => {
+                    self.tcpi_last_data_sent = is.read_uint32()?;
+                },
+                152 => {
+                    self.tcpi_last_ack_sent = is.read_uint32()?;
+                },
+                160 => {
+                    self.tcpi_last_data_recv = is.read_uint32()?;
+                },
+                168 => {
+                    self.tcpi_last_ack_recv = is.read_uint32()?;
+                },
+                176 => {
+                    self.tcpi_pmtu = is.read_uint32()?;
+                },
+                184 => {
+                    self.tcpi_rcv_ssthresh = is.read_uint32()?;
+                },
+                192 => {
+                    self.tcpi_rtt = is.read_uint32()?;
+                },
+                200 => {
+                    self.tcpi_rttvar = is.read_uint32()?;
+                },
+                208 => {
+                    self.tcpi_snd_ssthresh = is.read_uint32()?;
+                },
+                216 => {
+                    self.tcpi_snd_cwnd = is.read_uint32()?;
+                },
+                224 => {
+                    self.tcpi_advmss = is.read_uint32()?;
+                },
+                232 => {
+                    self.tcpi_reordering = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.tcpi_state != 0 {
+            my_size += ::protobufv3::rt::uint32_size(1, self.tcpi_state);
+        }
+        if self.tcpi_ca_state != 0 {
+            my_size += ::protobufv3::rt::uint32_size(2, self.tcpi_ca_state);
+        }
+        if self.tcpi_retransmits != 0 {
+            my_size += ::protobufv3::rt::uint32_size(3, self.tcpi_retransmits);
+        }
+        if self.tcpi_probes != 0 {
+            my_size += ::protobufv3::rt::uint32_size(4, self.tcpi_probes);
+        }
+        if self.tcpi_backoff != 0 {
+            my_size += ::protobufv3::rt::uint32_size(5, self.tcpi_backoff);
+        }
+        if self.tcpi_options != 0 {
+            my_size += ::protobufv3::rt::uint32_size(6, self.tcpi_options);
+        }
+        if self.tcpi_snd_wscale != 0 {
+            my_size += ::protobufv3::rt::uint32_size(7, self.tcpi_snd_wscale);
+        }
+        if self.tcpi_rcv_wscale != 0 {
+            my_size += ::protobufv3::rt::uint32_size(8, self.tcpi_rcv_wscale);
+        }
+        if self.tcpi_rto != 0 {
+            my_size += ::protobufv3::rt::uint32_size(9, self.tcpi_rto);
+        }
+        if self.tcpi_ato != 0 {
+            my_size += ::protobufv3::rt::uint32_size(10, self.tcpi_ato);
+        }
+        if self.tcpi_snd_mss != 0 {
+            my_size += ::protobufv3::rt::uint32_size(11, self.tcpi_snd_mss);
+        }
+        if self.tcpi_rcv_mss != 0 {
+            my_size += ::protobufv3::rt::uint32_size(12, self.tcpi_rcv_mss);
+        }
+        if self.tcpi_unacked != 0 {
+            my_size += ::protobufv3::rt::uint32_size(13, self.tcpi_unacked);
+        }
+        if self.tcpi_sacked != 0 {
+            my_size += ::protobufv3::rt::uint32_size(14, self.tcpi_sacked);
+        }
+        if self.tcpi_lost != 0 {
+            my_size += ::protobufv3::rt::uint32_size(15, self.tcpi_lost);
+        }
+        if self.tcpi_retrans != 0 {
+            my_size += ::protobufv3::rt::uint32_size(16, self.tcpi_retrans);
+        }
+        if self.tcpi_fackets != 0 {
+            my_size += ::protobufv3::rt::uint32_size(17, self.tcpi_fackets);
+        }
+        if self.tcpi_last_data_sent != 0 {
+            my_size += ::protobufv3::rt::uint32_size(18, self.tcpi_last_data_sent);
+        }
+        if self.tcpi_last_ack_sent != 0 {
+            my_size += ::protobufv3::rt::uint32_size(19, self.tcpi_last_ack_sent);
+        }
+        if self.tcpi_last_data_recv != 0 {
+            my_size += ::protobufv3::rt::uint32_size(20, self.tcpi_last_data_recv);
+        }
+        if self.tcpi_last_ack_recv != 0 {
+            my_size += ::protobufv3::rt::uint32_size(21, self.tcpi_last_ack_recv);
+        }
+        if self.tcpi_pmtu != 0 {
+            my_size += ::protobufv3::rt::uint32_size(22, self.tcpi_pmtu);
+        }
+        if self.tcpi_rcv_ssthresh != 0 {
+            my_size += ::protobufv3::rt::uint32_size(23, self.tcpi_rcv_ssthresh);
+        }
+        if self.tcpi_rtt != 0 {
+            my_size += ::protobufv3::rt::uint32_size(24, self.tcpi_rtt);
+        }
+        if self.tcpi_rttvar != 0 {
+            my_size += ::protobufv3::rt::uint32_size(25, self.tcpi_rttvar);
+        }
+        if self.tcpi_snd_ssthresh != 0 {
+            my_size += ::protobufv3::rt::uint32_size(26, self.tcpi_snd_ssthresh);
+        }
+        if self.tcpi_snd_cwnd != 0 {
+            my_size += ::protobufv3::rt::uint32_size(27, self.tcpi_snd_cwnd);
+        }
+        if self.tcpi_advmss != 0 {
+            my_size += ::protobufv3::rt::uint32_size(28, self.tcpi_advmss);
+        }
+        if self.tcpi_reordering != 0 {
+            my_size += ::protobufv3::rt::uint32_size(29, self.tcpi_reordering);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.tcpi_state != 0 {
+            os.write_uint32(1, self.tcpi_state)?;
+        }
+        if self.tcpi_ca_state != 0 {
+            os.write_uint32(2, self.tcpi_ca_state)?;
+        }
+        if self.tcpi_retransmits != 0 {
+            os.write_uint32(3, self.tcpi_retransmits)?;
+        }
+        if self.tcpi_probes != 0 {
+            os.write_uint32(4, self.tcpi_probes)?;
+        }
+        if self.tcpi_backoff != 0 {
+            os.write_uint32(5, self.tcpi_backoff)?;
+        }
+        if self.tcpi_options != 0 {
+            os.write_uint32(6, self.tcpi_options)?;
+        }
+        if self.tcpi_snd_wscale != 0 {
+            os.write_uint32(7, self.tcpi_snd_wscale)?;
+        }
+        if self.tcpi_rcv_wscale != 0 {
+            os.write_uint32(8, self.tcpi_rcv_wscale)?;
+        }
+        if self.tcpi_rto != 0 {
+            os.write_uint32(9, self.tcpi_rto)?;
+        }
+        if self.tcpi_ato != 0 {
+            os.write_uint32(10, self.tcpi_ato)?;
+        }
+        if self.tcpi_snd_mss != 0 {
+            os.write_uint32(11, self.tcpi_snd_mss)?;
+        }
+        if self.tcpi_rcv_mss != 0 {
+            os.write_uint32(12, self.tcpi_rcv_mss)?;
+        }
+        if self.tcpi_unacked != 0 {
+            os.write_uint32(13, self.tcpi_unacked)?;
+        }
+        if self.tcpi_sacked != 0 {
+            os.write_uint32(14, self.tcpi_sacked)?;
+        }
+        if self.tcpi_lost != 0 {
+            os.write_uint32(15, self.tcpi_lost)?;
+        }
+        if self.tcpi_retrans != 0 {
+            os.write_uint32(16, self.tcpi_retrans)?;
+        }
+        if self.tcpi_fackets != 0 {
+            os.write_uint32(17, self.tcpi_fackets)?;
+        }
+        if self.tcpi_last_data_sent != 0 {
+            os.write_uint32(18, self.tcpi_last_data_sent)?;
+        }
+        if self.tcpi_last_ack_sent != 0 {
+            os.write_uint32(19, self.tcpi_last_ack_sent)?;
+        }
+        if self.tcpi_last_data_recv != 0 {
+            os.write_uint32(20, self.tcpi_last_data_recv)?;
+        }
+        if self.tcpi_last_ack_recv != 0 {
+            os.write_uint32(21, self.tcpi_last_ack_recv)?;
+        }
+        if self.tcpi_pmtu != 0 {
+            os.write_uint32(22, self.tcpi_pmtu)?;
+        }
+        if self.tcpi_rcv_ssthresh != 0 {
+            os.write_uint32(23, self.tcpi_rcv_ssthresh)?;
+        }
+        if self.tcpi_rtt != 0 {
+            os.write_uint32(24, self.tcpi_rtt)?;
+        }
+        if self.tcpi_rttvar != 0 {
+            os.write_uint32(25, self.tcpi_rttvar)?;
+        }
+        if self.tcpi_snd_ssthresh != 0 {
+            os.write_uint32(26, self.tcpi_snd_ssthresh)?;
+        }
+        if self.tcpi_snd_cwnd != 0 {
+            os.write_uint32(27, self.tcpi_snd_cwnd)?;
+        }
+        if self.tcpi_advmss != 0 {
+            os.write_uint32(28, self.tcpi_advmss)?;
+        }
+        if self.tcpi_reordering != 0 {
+            os.write_uint32(29, self.tcpi_reordering)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SocketOptionTcpInfo {
+        SocketOptionTcpInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.tcpi_state = 0;
+        self.tcpi_ca_state = 0;
+        self.tcpi_retransmits = 0;
+        self.tcpi_probes = 0;
+        self.tcpi_backoff = 0;
+        self.tcpi_options = 0;
+        self.tcpi_snd_wscale = 0;
+        self.tcpi_rcv_wscale = 0;
+        self.tcpi_rto = 0;
+        self.tcpi_ato = 0;
+        self.tcpi_snd_mss = 0;
+        self.tcpi_rcv_mss = 0;
+        self.tcpi_unacked = 0;
+        self.tcpi_sacked = 0;
+        self.tcpi_lost = 0;
+        self.tcpi_retrans = 0;
+        self.tcpi_fackets = 0;
+        self.tcpi_last_data_sent = 0;
+        self.tcpi_last_ack_sent = 0;
+        self.tcpi_last_data_recv = 0;
+        self.tcpi_last_ack_recv = 0;
+        self.tcpi_pmtu = 0;
+        self.tcpi_rcv_ssthresh = 0;
+        self.tcpi_rtt = 0;
+        self.tcpi_rttvar = 0;
+        self.tcpi_snd_ssthresh = 0;
+        self.tcpi_snd_cwnd = 0;
+        self.tcpi_advmss = 0;
+        self.tcpi_reordering = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SocketOptionTcpInfo {
+        static instance: SocketOptionTcpInfo = SocketOptionTcpInfo {
+            tcpi_state: 0,
+            tcpi_ca_state: 0,
+            tcpi_retransmits: 0,
+            tcpi_probes: 0,
+            tcpi_backoff: 0,
+            tcpi_options: 0,
+            tcpi_snd_wscale: 0,
+            tcpi_rcv_wscale: 0,
+            tcpi_rto: 0,
+            tcpi_ato: 0,
+            tcpi_snd_mss: 0,
+            tcpi_rcv_mss: 0,
+            tcpi_unacked: 0,
+            tcpi_sacked: 0,
+            tcpi_lost: 0,
+            tcpi_retrans: 0,
+            tcpi_fackets: 0,
+            tcpi_last_data_sent: 0,
+            tcpi_last_ack_sent: 0,
+            tcpi_last_data_recv: 0,
+            tcpi_last_ack_recv: 0,
+            tcpi_pmtu: 0,
+            tcpi_rcv_ssthresh: 0,
+            tcpi_rtt: 0,
+            tcpi_rttvar: 0,
+            tcpi_snd_ssthresh: 0,
+            tcpi_snd_cwnd: 0,
+            tcpi_advmss: 0,
+            tcpi_reordering: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for SocketOptionTcpInfo {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SocketOptionTcpInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SocketOptionTcpInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for SocketOptionTcpInfo {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetTopChannelsRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetTopChannelsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetTopChannelsRequest.start_channel_id)
+    pub start_channel_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetTopChannelsRequest.max_results)
+    pub max_results: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetTopChannelsRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetTopChannelsRequest {
+    fn default() -> &'a GetTopChannelsRequest {
+        <GetTopChannelsRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetTopChannelsRequest {
+    pub fn new() -> GetTopChannelsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "start_channel_id",
+            |m: &GetTopChannelsRequest| { &m.start_channel_id },
+            |m: &mut GetTopChannelsRequest| { &mut m.start_channel_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_results",
+            |m: &GetTopChannelsRequest| { &m.max_results },
+            |m: &mut GetTopChannelsRequest| { &mut m.max_results },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetTopChannelsRequest>(
+            "GetTopChannelsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetTopChannelsRequest {
+    const NAME: &'static str = "GetTopChannelsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.start_channel_id = is.read_int64()?;
+                },
+                16 => {
+                    self.max_results = is.read_int64()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.start_channel_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.start_channel_id);
+        }
+        if self.max_results != 0 {
+            my_size += ::protobufv3::rt::int64_size(2, self.max_results);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.start_channel_id != 0 {
+            os.write_int64(1, self.start_channel_id)?;
+        }
+        if self.max_results != 0 {
+            os.write_int64(2, self.max_results)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetTopChannelsRequest {
+        GetTopChannelsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.start_channel_id = 0;
+        self.max_results = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetTopChannelsRequest {
+        static instance: GetTopChannelsRequest = GetTopChannelsRequest {
+            start_channel_id: 0,
+            max_results: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetTopChannelsRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetTopChannelsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetTopChannelsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetTopChannelsRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetTopChannelsResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetTopChannelsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetTopChannelsResponse.channel)
+    pub channel: ::std::vec::Vec<Channel>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetTopChannelsResponse.end)
+    pub end: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetTopChannelsResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetTopChannelsResponse {
+    fn default() -> &'a GetTopChannelsResponse {
+        <GetTopChannelsResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetTopChannelsResponse {
+    pub fn new() -> GetTopChannelsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "channel",
+            |m: &GetTopChannelsResponse| { &m.channel },
+            |m: &mut GetTopChannelsResponse| { &mut m.channel },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &GetTopChannelsResponse| { &m.end },
+            |m: &mut GetTopChannelsResponse| { &mut m.end },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetTopChannelsResponse>(
+            "GetTopChannelsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetTopChannelsResponse {
+    const NAME: &'static str = "GetTopChannelsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.channel.push(is.read_message()?);
+                },
+                16 => {
+                    self.end = is.read_bool()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.channel {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.end != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.channel {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.end != false {
+            os.write_bool(2, self.end)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetTopChannelsResponse {
+        GetTopChannelsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.channel.clear();
+        self.end = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetTopChannelsResponse {
+        static instance: GetTopChannelsResponse = GetTopChannelsResponse {
+            channel: ::std::vec::Vec::new(),
+            end: false,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetTopChannelsResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetTopChannelsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetTopChannelsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetTopChannelsResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetServersRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetServersRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServersRequest.start_server_id)
+    pub start_server_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServersRequest.max_results)
+    pub max_results: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetServersRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetServersRequest {
+    fn default() -> &'a GetServersRequest {
+        <GetServersRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetServersRequest {
+    pub fn new() -> GetServersRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "start_server_id",
+            |m: &GetServersRequest| { &m.start_server_id },
+            |m: &mut GetServersRequest| { &mut m.start_server_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_results",
+            |m: &GetServersRequest| { &m.max_results },
+            |m: &mut GetServersRequest| { &mut m.max_results },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetServersRequest>(
+            "GetServersRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetServersRequest {
+    const NAME: &'static str = "GetServersRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.start_server_id = is.read_int64()?;
+                },
+                16 => {
+                    self.max_results = is.read_int64()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.start_server_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.start_server_id);
+        }
+        if self.max_results != 0 {
+            my_size += ::protobufv3::rt::int64_size(2, self.max_results);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.start_server_id != 0 {
+            os.write_int64(1, self.start_server_id)?;
+        }
+        if self.max_results != 0 {
+            os.write_int64(2, self.max_results)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetServersRequest {
+        GetServersRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.start_server_id = 0;
+        self.max_results = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetServersRequest {
+        static instance: GetServersRequest = GetServersRequest {
+            start_server_id: 0,
+            max_results: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetServersRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetServersRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetServersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetServersRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetServersResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetServersResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServersResponse.server)
+    pub server: ::std::vec::Vec<Server>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServersResponse.end)
+    pub end: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetServersResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetServersResponse {
+    fn default() -> &'a GetServersResponse {
+        <GetServersResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetServersResponse {
+    pub fn new() -> GetServersResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "server",
+            |m: &GetServersResponse| { &m.server },
+            |m: &mut GetServersResponse| { &mut m.server },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &GetServersResponse| { &m.end },
+            |m: &mut GetServersResponse| { &mut m.end },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetServersResponse>(
+            "GetServersResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetServersResponse {
+    const NAME: &'static str = "GetServersResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.server.push(is.read_message()?);
+                },
+                16 => {
+                    self.end = is.read_bool()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.server {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.end != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.server {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.end != false {
+            os.write_bool(2, self.end)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetServersResponse {
+        GetServersResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.server.clear();
+        self.end = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetServersResponse {
+        static instance: GetServersResponse = GetServersResponse {
+            server: ::std::vec::Vec::new(),
+            end: false,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetServersResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetServersResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetServersResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetServersResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetServerRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetServerRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerRequest.server_id)
+    pub server_id: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetServerRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetServerRequest {
+    fn default() -> &'a GetServerRequest {
+        <GetServerRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetServerRequest {
+    pub fn new() -> GetServerRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "server_id",
+            |m: &GetServerRequest| { &m.server_id },
+            |m: &mut GetServerRequest| { &mut m.server_id },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetServerRequest>(
+            "GetServerRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetServerRequest {
+    const NAME: &'static str = "GetServerRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.server_id = is.read_int64()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.server_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.server_id);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.server_id != 0 {
+            os.write_int64(1, self.server_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetServerRequest {
+        GetServerRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.server_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetServerRequest {
+        static instance: GetServerRequest = GetServerRequest {
+            server_id: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetServerRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetServerRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetServerRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetServerRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetServerResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetServerResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerResponse.server)
+    pub server: ::protobufv3::MessageField<Server>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetServerResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetServerResponse {
+    fn default() -> &'a GetServerResponse {
+        <GetServerResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetServerResponse {
+    pub fn new() -> GetServerResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Server>(
+            "server",
+            |m: &GetServerResponse| { &m.server },
+            |m: &mut GetServerResponse| { &mut m.server },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetServerResponse>(
+            "GetServerResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetServerResponse {
+    const NAME: &'static str = "GetServerResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.server)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.server.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.server.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetServerResponse {
+        GetServerResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.server.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetServerResponse {
+        static instance: GetServerResponse = GetServerResponse {
+            server: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetServerResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetServerResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetServerResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetServerResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetServerSocketsRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetServerSocketsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerSocketsRequest.server_id)
+    pub server_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerSocketsRequest.start_socket_id)
+    pub start_socket_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerSocketsRequest.max_results)
+    pub max_results: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetServerSocketsRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetServerSocketsRequest {
+    fn default() -> &'a GetServerSocketsRequest {
+        <GetServerSocketsRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetServerSocketsRequest {
+    pub fn new() -> GetServerSocketsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "server_id",
+            |m: &GetServerSocketsRequest| { &m.server_id },
+            |m: &mut GetServerSocketsRequest| { &mut m.server_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "start_socket_id",
+            |m: &GetServerSocketsRequest| { &m.start_socket_id },
+            |m: &mut GetServerSocketsRequest| { &mut m.start_socket_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_results",
+            |m: &GetServerSocketsRequest| { &m.max_results },
+            |m: &mut GetServerSocketsRequest| { &mut m.max_results },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetServerSocketsRequest>(
+            "GetServerSocketsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetServerSocketsRequest {
+    const NAME: &'static str = "GetServerSocketsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.server_id = is.read_int64()?;
+                },
+                16 => {
+                    self.start_socket_id = is.read_int64()?;
+                },
+                24 => {
+                    self.max_results = is.read_int64()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.server_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.server_id);
+        }
+        if self.start_socket_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(2, self.start_socket_id);
+        }
+        if self.max_results != 0 {
+            my_size += ::protobufv3::rt::int64_size(3, self.max_results);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.server_id != 0 {
+            os.write_int64(1, self.server_id)?;
+        }
+        if self.start_socket_id != 0 {
+            os.write_int64(2, self.start_socket_id)?;
+        }
+        if self.max_results != 0 {
+            os.write_int64(3, self.max_results)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetServerSocketsRequest {
+        GetServerSocketsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.server_id = 0;
+        self.start_socket_id = 0;
+        self.max_results = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetServerSocketsRequest {
+        static instance: GetServerSocketsRequest = GetServerSocketsRequest {
+            server_id: 0,
+            start_socket_id: 0,
+            max_results: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetServerSocketsRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetServerSocketsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetServerSocketsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetServerSocketsRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetServerSocketsResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetServerSocketsResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerSocketsResponse.socket_ref)
+    pub socket_ref: ::std::vec::Vec<SocketRef>,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetServerSocketsResponse.end)
+    pub end: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetServerSocketsResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetServerSocketsResponse {
+    fn default() -> &'a GetServerSocketsResponse {
+        <GetServerSocketsResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetServerSocketsResponse {
+    pub fn new() -> GetServerSocketsResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "socket_ref",
+            |m: &GetServerSocketsResponse| { &m.socket_ref },
+            |m: &mut GetServerSocketsResponse| { &mut m.socket_ref },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &GetServerSocketsResponse| { &m.end },
+            |m: &mut GetServerSocketsResponse| { &mut m.end },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetServerSocketsResponse>(
+            "GetServerSocketsResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetServerSocketsResponse {
+    const NAME: &'static str = "GetServerSocketsResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.socket_ref.push(is.read_message()?);
+                },
+                16 => {
+                    self.end = is.read_bool()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.socket_ref {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.end != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.socket_ref {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.end != false {
+            os.write_bool(2, self.end)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetServerSocketsResponse {
+        GetServerSocketsResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.socket_ref.clear();
+        self.end = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetServerSocketsResponse {
+        static instance: GetServerSocketsResponse = GetServerSocketsResponse {
+            socket_ref: ::std::vec::Vec::new(),
+            end: false,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetServerSocketsResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetServerSocketsResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetServerSocketsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetServerSocketsResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetChannelRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetChannelRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetChannelRequest.channel_id)
+    pub channel_id: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetChannelRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetChannelRequest {
+    fn default() -> &'a GetChannelRequest {
+        <GetChannelRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetChannelRequest {
+    pub fn new() -> GetChannelRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "channel_id",
+            |m: &GetChannelRequest| { &m.channel_id },
+            |m: &mut GetChannelRequest| { &mut m.channel_id },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetChannelRequest>(
+            "GetChannelRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetChannelRequest {
+    const NAME: &'static str = "GetChannelRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.channel_id = is.read_int64()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.channel_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.channel_id);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.channel_id != 0 {
+            os.write_int64(1, self.channel_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetChannelRequest {
+        GetChannelRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.channel_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetChannelRequest {
+        static instance: GetChannelRequest = GetChannelRequest {
+            channel_id: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetChannelRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetChannelRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetChannelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetChannelRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetChannelResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetChannelResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetChannelResponse.channel)
+    pub channel: ::protobufv3::MessageField<Channel>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetChannelResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetChannelResponse {
+    fn default() -> &'a GetChannelResponse {
+        <GetChannelResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetChannelResponse {
+    pub fn new() -> GetChannelResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Channel>(
+            "channel",
+            |m: &GetChannelResponse| { &m.channel },
+            |m: &mut GetChannelResponse| { &mut m.channel },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetChannelResponse>(
+            "GetChannelResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetChannelResponse {
+    const NAME: &'static str = "GetChannelResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.channel)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.channel.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.channel.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetChannelResponse {
+        GetChannelResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.channel.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetChannelResponse {
+        static instance: GetChannelResponse = GetChannelResponse {
+            channel: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetChannelResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetChannelResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetChannelResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetChannelResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetSubchannelRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetSubchannelRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetSubchannelRequest.subchannel_id)
+    pub subchannel_id: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetSubchannelRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetSubchannelRequest {
+    fn default() -> &'a GetSubchannelRequest {
+        <GetSubchannelRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetSubchannelRequest {
+    pub fn new() -> GetSubchannelRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "subchannel_id",
+            |m: &GetSubchannelRequest| { &m.subchannel_id },
+            |m: &mut GetSubchannelRequest| { &mut m.subchannel_id },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetSubchannelRequest>(
+            "GetSubchannelRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetSubchannelRequest {
+    const NAME: &'static str = "GetSubchannelRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.subchannel_id = is.read_int64()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.subchannel_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.subchannel_id);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.subchannel_id != 0 {
+            os.write_int64(1, self.subchannel_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetSubchannelRequest {
+        GetSubchannelRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.subchannel_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetSubchannelRequest {
+        static instance: GetSubchannelRequest = GetSubchannelRequest {
+            subchannel_id: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetSubchannelRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetSubchannelRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetSubchannelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetSubchannelRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetSubchannelResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetSubchannelResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetSubchannelResponse.subchannel)
+    pub subchannel: ::protobufv3::MessageField<Subchannel>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetSubchannelResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetSubchannelResponse {
+    fn default() -> &'a GetSubchannelResponse {
+        <GetSubchannelResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetSubchannelResponse {
+    pub fn new() -> GetSubchannelResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Subchannel>(
+            "subchannel",
+            |m: &GetSubchannelResponse| { &m.subchannel },
+            |m: &mut GetSubchannelResponse| { &mut m.subchannel },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetSubchannelResponse>(
+            "GetSubchannelResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetSubchannelResponse {
+    const NAME: &'static str = "GetSubchannelResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.subchannel)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.subchannel.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.subchannel.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetSubchannelResponse {
+        GetSubchannelResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.subchannel.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetSubchannelResponse {
+        static instance: GetSubchannelResponse = GetSubchannelResponse {
+            subchannel: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetSubchannelResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetSubchannelResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetSubchannelResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetSubchannelResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetSocketRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetSocketRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetSocketRequest.socket_id)
+    pub socket_id: i64,
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetSocketRequest.summary)
+    pub summary: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetSocketRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetSocketRequest {
+    fn default() -> &'a GetSocketRequest {
+        <GetSocketRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetSocketRequest {
+    pub fn new() -> GetSocketRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "socket_id",
+            |m: &GetSocketRequest| { &m.socket_id },
+            |m: &mut GetSocketRequest| { &mut m.socket_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "summary",
+            |m: &GetSocketRequest| { &m.summary },
+            |m: &mut GetSocketRequest| { &mut m.summary },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetSocketRequest>(
+            "GetSocketRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetSocketRequest {
+    const NAME: &'static str = "GetSocketRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.socket_id = is.read_int64()?;
+                },
+                16 => {
+                    self.summary = is.read_bool()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.socket_id != 0 {
+            my_size += ::protobufv3::rt::int64_size(1, self.socket_id);
+        }
+        if self.summary != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.socket_id != 0 {
+            os.write_int64(1, self.socket_id)?;
+        }
+        if self.summary != false {
+            os.write_bool(2, self.summary)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetSocketRequest {
+        GetSocketRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.socket_id = 0;
+        self.summary = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetSocketRequest {
+        static instance: GetSocketRequest = GetSocketRequest {
+            socket_id: 0,
+            summary: false,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetSocketRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetSocketRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetSocketRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetSocketRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.channelz.v1.GetSocketResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetSocketResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.channelz.v1.GetSocketResponse.socket)
+    pub socket: ::protobufv3::MessageField<Socket>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.channelz.v1.GetSocketResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetSocketResponse {
+    fn default() -> &'a GetSocketResponse {
+        <GetSocketResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl GetSocketResponse {
+    pub fn new() -> GetSocketResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, Socket>(
+            "socket",
+            |m: &GetSocketResponse| { &m.socket },
+            |m: &mut GetSocketResponse| { &mut m.socket },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<GetSocketResponse>(
+            "GetSocketResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for GetSocketResponse {
+    const NAME: &'static str = "GetSocketResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.socket)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.socket.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.socket.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetSocketResponse {
+        GetSocketResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.socket.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetSocketResponse {
+        static instance: GetSocketResponse = GetSocketResponse {
+            socket: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for GetSocketResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetSocketResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetSocketResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for GetSocketResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1cgrpc/channelz/channelz.proto\x12\x10grpc.channelz.v1\x1a\x19google\
+    /protobuf/any.proto\x1a\x1egoogle/protobuf/duration.proto\x1a\x1fgoogle/\
+    protobuf/timestamp.proto\x1a\x1egoogle/protobuf/wrappers.proto\"\xaf\x02\
+    \n\x07Channel\x12.\n\x03ref\x18\x01\x20\x01(\x0b2\x1c.grpc.channelz.v1.C\
+    hannelRefR\x03ref\x121\n\x04data\x18\x02\x20\x01(\x0b2\x1d.grpc.channelz\
+    .v1.ChannelDataR\x04data\x12=\n\x0bchannel_ref\x18\x03\x20\x03(\x0b2\x1c\
+    .grpc.channelz.v1.ChannelRefR\nchannelRef\x12F\n\x0esubchannel_ref\x18\
+    \x04\x20\x03(\x0b2\x1f.grpc.channelz.v1.SubchannelRefR\rsubchannelRef\
+    \x12:\n\nsocket_ref\x18\x05\x20\x03(\x0b2\x1b.grpc.channelz.v1.SocketRef\
+    R\tsocketRef\"\xb5\x02\n\nSubchannel\x121\n\x03ref\x18\x01\x20\x01(\x0b2\
+    \x1f.grpc.channelz.v1.SubchannelRefR\x03ref\x121\n\x04data\x18\x02\x20\
+    \x01(\x0b2\x1d.grpc.channelz.v1.ChannelDataR\x04data\x12=\n\x0bchannel_r\
+    ef\x18\x03\x20\x03(\x0b2\x1c.grpc.channelz.v1.ChannelRefR\nchannelRef\
+    \x12F\n\x0esubchannel_ref\x18\x04\x20\x03(\x0b2\x1f.grpc.channelz.v1.Sub\
+    channelRefR\rsubchannelRef\x12:\n\nsocket_ref\x18\x05\x20\x03(\x0b2\x1b.\
+    grpc.channelz.v1.SocketRefR\tsocketRef\"\xc2\x01\n\x18ChannelConnectivit\
+    yState\x12F\n\x05state\x18\x01\x20\x01(\x0e20.grpc.channelz.v1.ChannelCo\
+    nnectivityState.StateR\x05state\"^\n\x05State\x12\x0b\n\x07UNKNOWN\x10\0\
+    \x12\x08\n\x04IDLE\x10\x01\x12\x0e\n\nCONNECTING\x10\x02\x12\t\n\x05READ\
+    Y\x10\x03\x12\x15\n\x11TRANSIENT_FAILURE\x10\x04\x12\x0c\n\x08SHUTDOWN\
+    \x10\x05\"\xe9\x02\n\x0bChannelData\x12@\n\x05state\x18\x01\x20\x01(\x0b\
+    2*.grpc.channelz.v1.ChannelConnectivityStateR\x05state\x12\x16\n\x06targ\
+    et\x18\x02\x20\x01(\tR\x06target\x124\n\x05trace\x18\x03\x20\x01(\x0b2\
+    \x1e.grpc.channelz.v1.ChannelTraceR\x05trace\x12#\n\rcalls_started\x18\
+    \x04\x20\x01(\x03R\x0ccallsStarted\x12'\n\x0fcalls_succeeded\x18\x05\x20\
+    \x01(\x03R\x0ecallsSucceeded\x12!\n\x0ccalls_failed\x18\x06\x20\x01(\x03\
+    R\x0bcallsFailed\x12Y\n\x1blast_call_started_timestamp\x18\x07\x20\x01(\
+    \x0b2\x1a.google.protobuf.TimestampR\x18lastCallStartedTimestamp\"\x98\
+    \x03\n\x11ChannelTraceEvent\x12\x20\n\x0bdescription\x18\x01\x20\x01(\tR\
+    \x0bdescription\x12H\n\x08severity\x18\x02\x20\x01(\x0e2,.grpc.channelz.\
+    v1.ChannelTraceEvent.SeverityR\x08severity\x128\n\ttimestamp\x18\x03\x20\
+    \x01(\x0b2\x1a.google.protobuf.TimestampR\ttimestamp\x12?\n\x0bchannel_r\
+    ef\x18\x04\x20\x01(\x0b2\x1c.grpc.channelz.v1.ChannelRefH\0R\nchannelRef\
+    \x12H\n\x0esubchannel_ref\x18\x05\x20\x01(\x0b2\x1f.grpc.channelz.v1.Sub\
+    channelRefH\0R\rsubchannelRef\"E\n\x08Severity\x12\x0e\n\nCT_UNKNOWN\x10\
+    \0\x12\x0b\n\x07CT_INFO\x10\x01\x12\x0e\n\nCT_WARNING\x10\x02\x12\x0c\n\
+    \x08CT_ERROR\x10\x03B\x0b\n\tchild_ref\"\xc2\x01\n\x0cChannelTrace\x12*\
+    \n\x11num_events_logged\x18\x01\x20\x01(\x03R\x0fnumEventsLogged\x12I\n\
+    \x12creation_timestamp\x18\x02\x20\x01(\x0b2\x1a.google.protobuf.Timesta\
+    mpR\x11creationTimestamp\x12;\n\x06events\x18\x03\x20\x03(\x0b2#.grpc.ch\
+    annelz.v1.ChannelTraceEventR\x06events\"c\n\nChannelRef\x12\x1d\n\nchann\
+    el_id\x18\x01\x20\x01(\x03R\tchannelId\x12\x12\n\x04name\x18\x02\x20\x01\
+    (\tR\x04nameJ\x04\x08\x03\x10\x04J\x04\x08\x04\x10\x05J\x04\x08\x05\x10\
+    \x06J\x04\x08\x06\x10\x07J\x04\x08\x07\x10\x08J\x04\x08\x08\x10\t\"l\n\r\
+    SubchannelRef\x12#\n\rsubchannel_id\x18\x07\x20\x01(\x03R\x0csubchannelI\
+    d\x12\x12\n\x04name\x18\x08\x20\x01(\tR\x04nameJ\x04\x08\x01\x10\x02J\
+    \x04\x08\x02\x10\x03J\x04\x08\x03\x10\x04J\x04\x08\x04\x10\x05J\x04\x08\
+    \x05\x10\x06J\x04\x08\x06\x10\x07\"`\n\tSocketRef\x12\x1b\n\tsocket_id\
+    \x18\x03\x20\x01(\x03R\x08socketId\x12\x12\n\x04name\x18\x04\x20\x01(\tR\
+    \x04nameJ\x04\x08\x01\x10\x02J\x04\x08\x02\x10\x03J\x04\x08\x05\x10\x06J\
+    \x04\x08\x06\x10\x07J\x04\x08\x07\x10\x08J\x04\x08\x08\x10\t\"`\n\tServe\
+    rRef\x12\x1b\n\tserver_id\x18\x05\x20\x01(\x03R\x08serverId\x12\x12\n\
+    \x04name\x18\x06\x20\x01(\tR\x04nameJ\x04\x08\x01\x10\x02J\x04\x08\x02\
+    \x10\x03J\x04\x08\x03\x10\x04J\x04\x08\x04\x10\x05J\x04\x08\x07\x10\x08J\
+    \x04\x08\x08\x10\t\"\xab\x01\n\x06Server\x12-\n\x03ref\x18\x01\x20\x01(\
+    \x0b2\x1b.grpc.channelz.v1.ServerRefR\x03ref\x120\n\x04data\x18\x02\x20\
+    \x01(\x0b2\x1c.grpc.channelz.v1.ServerDataR\x04data\x12@\n\rlisten_socke\
+    t\x18\x03\x20\x03(\x0b2\x1b.grpc.channelz.v1.SocketRefR\x0clistenSocket\
+    \"\x8e\x02\n\nServerData\x124\n\x05trace\x18\x01\x20\x01(\x0b2\x1e.grpc.\
+    channelz.v1.ChannelTraceR\x05trace\x12#\n\rcalls_started\x18\x02\x20\x01\
+    (\x03R\x0ccallsStarted\x12'\n\x0fcalls_succeeded\x18\x03\x20\x01(\x03R\
+    \x0ecallsSucceeded\x12!\n\x0ccalls_failed\x18\x04\x20\x01(\x03R\x0bcalls\
+    Failed\x12Y\n\x1blast_call_started_timestamp\x18\x05\x20\x01(\x0b2\x1a.g\
+    oogle.protobuf.TimestampR\x18lastCallStartedTimestamp\"\xa6\x02\n\x06Soc\
+    ket\x12-\n\x03ref\x18\x01\x20\x01(\x0b2\x1b.grpc.channelz.v1.SocketRefR\
+    \x03ref\x120\n\x04data\x18\x02\x20\x01(\x0b2\x1c.grpc.channelz.v1.Socket\
+    DataR\x04data\x12/\n\x05local\x18\x03\x20\x01(\x0b2\x19.grpc.channelz.v1\
+    .AddressR\x05local\x121\n\x06remote\x18\x04\x20\x01(\x0b2\x19.grpc.chann\
+    elz.v1.AddressR\x06remote\x126\n\x08security\x18\x05\x20\x01(\x0b2\x1a.g\
+    rpc.channelz.v1.SecurityR\x08security\x12\x1f\n\x0bremote_name\x18\x06\
+    \x20\x01(\tR\nremoteName\"\x83\x07\n\nSocketData\x12'\n\x0fstreams_start\
+    ed\x18\x01\x20\x01(\x03R\x0estreamsStarted\x12+\n\x11streams_succeeded\
+    \x18\x02\x20\x01(\x03R\x10streamsSucceeded\x12%\n\x0estreams_failed\x18\
+    \x03\x20\x01(\x03R\rstreamsFailed\x12#\n\rmessages_sent\x18\x04\x20\x01(\
+    \x03R\x0cmessagesSent\x12+\n\x11messages_received\x18\x05\x20\x01(\x03R\
+    \x10messagesReceived\x12(\n\x10keep_alives_sent\x18\x06\x20\x01(\x03R\
+    \x0ekeepAlivesSent\x12h\n#last_local_stream_created_timestamp\x18\x07\
+    \x20\x01(\x0b2\x1a.google.protobuf.TimestampR\x1flastLocalStreamCreatedT\
+    imestamp\x12j\n$last_remote_stream_created_timestamp\x18\x08\x20\x01(\
+    \x0b2\x1a.google.protobuf.TimestampR\x20lastRemoteStreamCreatedTimestamp\
+    \x12Y\n\x1blast_message_sent_timestamp\x18\t\x20\x01(\x0b2\x1a.google.pr\
+    otobuf.TimestampR\x18lastMessageSentTimestamp\x12a\n\x1flast_message_rec\
+    eived_timestamp\x18\n\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\x1cla\
+    stMessageReceivedTimestamp\x12V\n\x19local_flow_control_window\x18\x0b\
+    \x20\x01(\x0b2\x1b.google.protobuf.Int64ValueR\x16localFlowControlWindow\
+    \x12X\n\x1aremote_flow_control_window\x18\x0c\x20\x01(\x0b2\x1b.google.p\
+    rotobuf.Int64ValueR\x17remoteFlowControlWindow\x126\n\x06option\x18\r\
+    \x20\x03(\x0b2\x1e.grpc.channelz.v1.SocketOptionR\x06option\"\xb8\x03\n\
+    \x07Address\x12M\n\rtcpip_address\x18\x01\x20\x01(\x0b2&.grpc.channelz.v\
+    1.Address.TcpIpAddressH\0R\x0ctcpipAddress\x12G\n\x0buds_address\x18\x02\
+    \x20\x01(\x0b2$.grpc.channelz.v1.Address.UdsAddressH\0R\nudsAddress\x12M\
+    \n\rother_address\x18\x03\x20\x01(\x0b2&.grpc.channelz.v1.Address.OtherA\
+    ddressH\0R\x0cotherAddress\x1aA\n\x0cTcpIpAddress\x12\x1d\n\nip_address\
+    \x18\x01\x20\x01(\x0cR\tipAddress\x12\x12\n\x04port\x18\x02\x20\x01(\x05\
+    R\x04port\x1a(\n\nUdsAddress\x12\x1a\n\x08filename\x18\x01\x20\x01(\tR\
+    \x08filename\x1aN\n\x0cOtherAddress\x12\x12\n\x04name\x18\x01\x20\x01(\t\
+    R\x04name\x12*\n\x05value\x18\x02\x20\x01(\x0b2\x14.google.protobuf.AnyR\
+    \x05valueB\t\n\x07address\"\x96\x03\n\x08Security\x122\n\x03tls\x18\x01\
+    \x20\x01(\x0b2\x1e.grpc.channelz.v1.Security.TlsH\0R\x03tls\x12@\n\x05ot\
+    her\x18\x02\x20\x01(\x0b2(.grpc.channelz.v1.Security.OtherSecurityH\0R\
+    \x05other\x1a\xb9\x01\n\x03Tls\x12%\n\rstandard_name\x18\x01\x20\x01(\tH\
+    \0R\x0cstandardName\x12\x1f\n\nother_name\x18\x02\x20\x01(\tH\0R\totherN\
+    ame\x12+\n\x11local_certificate\x18\x03\x20\x01(\x0cR\x10localCertificat\
+    e\x12-\n\x12remote_certificate\x18\x04\x20\x01(\x0cR\x11remoteCertificat\
+    eB\x0e\n\x0ccipher_suite\x1aO\n\rOtherSecurity\x12\x12\n\x04name\x18\x01\
+    \x20\x01(\tR\x04name\x12*\n\x05value\x18\x02\x20\x01(\x0b2\x14.google.pr\
+    otobuf.AnyR\x05valueB\x07\n\x05model\"n\n\x0cSocketOption\x12\x12\n\x04n\
+    ame\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05value\x18\x02\x20\x01(\tR\
+    \x05value\x124\n\nadditional\x18\x03\x20\x01(\x0b2\x14.google.protobuf.A\
+    nyR\nadditional\"L\n\x13SocketOptionTimeout\x125\n\x08duration\x18\x01\
+    \x20\x01(\x0b2\x19.google.protobuf.DurationR\x08duration\"c\n\x12SocketO\
+    ptionLinger\x12\x16\n\x06active\x18\x01\x20\x01(\x08R\x06active\x125\n\
+    \x08duration\x18\x02\x20\x01(\x0b2\x19.google.protobuf.DurationR\x08dura\
+    tion\"\xb2\x08\n\x13SocketOptionTcpInfo\x12\x1d\n\ntcpi_state\x18\x01\
+    \x20\x01(\rR\ttcpiState\x12\"\n\rtcpi_ca_state\x18\x02\x20\x01(\rR\x0btc\
+    piCaState\x12)\n\x10tcpi_retransmits\x18\x03\x20\x01(\rR\x0ftcpiRetransm\
+    its\x12\x1f\n\x0btcpi_probes\x18\x04\x20\x01(\rR\ntcpiProbes\x12!\n\x0ct\
+    cpi_backoff\x18\x05\x20\x01(\rR\x0btcpiBackoff\x12!\n\x0ctcpi_options\
+    \x18\x06\x20\x01(\rR\x0btcpiOptions\x12&\n\x0ftcpi_snd_wscale\x18\x07\
+    \x20\x01(\rR\rtcpiSndWscale\x12&\n\x0ftcpi_rcv_wscale\x18\x08\x20\x01(\r\
+    R\rtcpiRcvWscale\x12\x19\n\x08tcpi_rto\x18\t\x20\x01(\rR\x07tcpiRto\x12\
+    \x19\n\x08tcpi_ato\x18\n\x20\x01(\rR\x07tcpiAto\x12\x20\n\x0ctcpi_snd_ms\
+    s\x18\x0b\x20\x01(\rR\ntcpiSndMss\x12\x20\n\x0ctcpi_rcv_mss\x18\x0c\x20\
+    \x01(\rR\ntcpiRcvMss\x12!\n\x0ctcpi_unacked\x18\r\x20\x01(\rR\x0btcpiUna\
+    cked\x12\x1f\n\x0btcpi_sacked\x18\x0e\x20\x01(\rR\ntcpiSacked\x12\x1b\n\
+    \ttcpi_lost\x18\x0f\x20\x01(\rR\x08tcpiLost\x12!\n\x0ctcpi_retrans\x18\
+    \x10\x20\x01(\rR\x0btcpiRetrans\x12!\n\x0ctcpi_fackets\x18\x11\x20\x01(\
+    \rR\x0btcpiFackets\x12-\n\x13tcpi_last_data_sent\x18\x12\x20\x01(\rR\x10\
+    tcpiLastDataSent\x12+\n\x12tcpi_last_ack_sent\x18\x13\x20\x01(\rR\x0ftcp\
+    iLastAckSent\x12-\n\x13tcpi_last_data_recv\x18\x14\x20\x01(\rR\x10tcpiLa\
+    stDataRecv\x12+\n\x12tcpi_last_ack_recv\x18\x15\x20\x01(\rR\x0ftcpiLastA\
+    ckRecv\x12\x1b\n\ttcpi_pmtu\x18\x16\x20\x01(\rR\x08tcpiPmtu\x12*\n\x11tc\
+    pi_rcv_ssthresh\x18\x17\x20\x01(\rR\x0ftcpiRcvSsthresh\x12\x19\n\x08tcpi\
+    _rtt\x18\x18\x20\x01(\rR\x07tcpiRtt\x12\x1f\n\x0btcpi_rttvar\x18\x19\x20\
+    \x01(\rR\ntcpiRttvar\x12*\n\x11tcpi_snd_ssthresh\x18\x1a\x20\x01(\rR\x0f\
+    tcpiSndSsthresh\x12\"\n\rtcpi_snd_cwnd\x18\x1b\x20\x01(\rR\x0btcpiSndCwn\
+    d\x12\x1f\n\x0btcpi_advmss\x18\x1c\x20\x01(\rR\ntcpiAdvmss\x12'\n\x0ftcp\
+    i_reordering\x18\x1d\x20\x01(\rR\x0etcpiReordering\"b\n\x15GetTopChannel\
+    sRequest\x12(\n\x10start_channel_id\x18\x01\x20\x01(\x03R\x0estartChanne\
+    lId\x12\x1f\n\x0bmax_results\x18\x02\x20\x01(\x03R\nmaxResults\"_\n\x16G\
+    etTopChannelsResponse\x123\n\x07channel\x18\x01\x20\x03(\x0b2\x19.grpc.c\
+    hannelz.v1.ChannelR\x07channel\x12\x10\n\x03end\x18\x02\x20\x01(\x08R\
+    \x03end\"\\\n\x11GetServersRequest\x12&\n\x0fstart_server_id\x18\x01\x20\
+    \x01(\x03R\rstartServerId\x12\x1f\n\x0bmax_results\x18\x02\x20\x01(\x03R\
+    \nmaxResults\"X\n\x12GetServersResponse\x120\n\x06server\x18\x01\x20\x03\
+    (\x0b2\x18.grpc.channelz.v1.ServerR\x06server\x12\x10\n\x03end\x18\x02\
+    \x20\x01(\x08R\x03end\"/\n\x10GetServerRequest\x12\x1b\n\tserver_id\x18\
+    \x01\x20\x01(\x03R\x08serverId\"E\n\x11GetServerResponse\x120\n\x06serve\
+    r\x18\x01\x20\x01(\x0b2\x18.grpc.channelz.v1.ServerR\x06server\"\x7f\n\
+    \x17GetServerSocketsRequest\x12\x1b\n\tserver_id\x18\x01\x20\x01(\x03R\
+    \x08serverId\x12&\n\x0fstart_socket_id\x18\x02\x20\x01(\x03R\rstartSocke\
+    tId\x12\x1f\n\x0bmax_results\x18\x03\x20\x01(\x03R\nmaxResults\"h\n\x18G\
+    etServerSocketsResponse\x12:\n\nsocket_ref\x18\x01\x20\x03(\x0b2\x1b.grp\
+    c.channelz.v1.SocketRefR\tsocketRef\x12\x10\n\x03end\x18\x02\x20\x01(\
+    \x08R\x03end\"2\n\x11GetChannelRequest\x12\x1d\n\nchannel_id\x18\x01\x20\
+    \x01(\x03R\tchannelId\"I\n\x12GetChannelResponse\x123\n\x07channel\x18\
+    \x01\x20\x01(\x0b2\x19.grpc.channelz.v1.ChannelR\x07channel\";\n\x14GetS\
+    ubchannelRequest\x12#\n\rsubchannel_id\x18\x01\x20\x01(\x03R\x0csubchann\
+    elId\"U\n\x15GetSubchannelResponse\x12<\n\nsubchannel\x18\x01\x20\x01(\
+    \x0b2\x1c.grpc.channelz.v1.SubchannelR\nsubchannel\"I\n\x10GetSocketRequ\
+    est\x12\x1b\n\tsocket_id\x18\x01\x20\x01(\x03R\x08socketId\x12\x18\n\x07\
+    summary\x18\x02\x20\x01(\x08R\x07summary\"E\n\x11GetSocketResponse\x120\
+    \n\x06socket\x18\x01\x20\x01(\x0b2\x18.grpc.channelz.v1.SocketR\x06socke\
+    t2\x9a\x05\n\x08Channelz\x12c\n\x0eGetTopChannels\x12'.grpc.channelz.v1.\
+    GetTopChannelsRequest\x1a(.grpc.channelz.v1.GetTopChannelsResponse\x12W\
+    \n\nGetServers\x12#.grpc.channelz.v1.GetServersRequest\x1a$.grpc.channel\
+    z.v1.GetServersResponse\x12T\n\tGetServer\x12\".grpc.channelz.v1.GetServ\
+    erRequest\x1a#.grpc.channelz.v1.GetServerResponse\x12i\n\x10GetServerSoc\
+    kets\x12).grpc.channelz.v1.GetServerSocketsRequest\x1a*.grpc.channelz.v1\
+    .GetServerSocketsResponse\x12W\n\nGetChannel\x12#.grpc.channelz.v1.GetCh\
+    annelRequest\x1a$.grpc.channelz.v1.GetChannelResponse\x12`\n\rGetSubchan\
+    nel\x12&.grpc.channelz.v1.GetSubchannelRequest\x1a'.grpc.channelz.v1.Get\
+    SubchannelResponse\x12T\n\tGetSocket\x12\".grpc.channelz.v1.GetSocketReq\
+    uest\x1a#.grpc.channelz.v1.GetSocketResponseBX\n\x13io.grpc.channelz.v1B\
+    \rChannelzProtoP\x01Z0google.golang.org/grpc/channelz/grpc_channelz_v1b\
+    \x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobufv3::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobufv3::rt::Lazy<::protobufv3::descriptor::FileDescriptorProto> = ::protobufv3::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobufv3::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobufv3::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobufv3::rt::Lazy<::protobufv3::reflect::GeneratedFileDescriptor> = ::protobufv3::rt::Lazy::new();
+    static file_descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::FileDescriptor> = ::protobufv3::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(4);
+            deps.push(::protobufv3::well_known_types::any::file_descriptor().clone());
+            deps.push(::protobufv3::well_known_types::duration::file_descriptor().clone());
+            deps.push(::protobufv3::well_known_types::timestamp::file_descriptor().clone());
+            deps.push(::protobufv3::well_known_types::wrappers::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(39);
+            messages.push(Channel::generated_message_descriptor_data());
+            messages.push(Subchannel::generated_message_descriptor_data());
+            messages.push(ChannelConnectivityState::generated_message_descriptor_data());
+            messages.push(ChannelData::generated_message_descriptor_data());
+            messages.push(ChannelTraceEvent::generated_message_descriptor_data());
+            messages.push(ChannelTrace::generated_message_descriptor_data());
+            messages.push(ChannelRef::generated_message_descriptor_data());
+            messages.push(SubchannelRef::generated_message_descriptor_data());
+            messages.push(SocketRef::generated_message_descriptor_data());
+            messages.push(ServerRef::generated_message_descriptor_data());
+            messages.push(Server::generated_message_descriptor_data());
+            messages.push(ServerData::generated_message_descriptor_data());
+            messages.push(Socket::generated_message_descriptor_data());
+            messages.push(SocketData::generated_message_descriptor_data());
+            messages.push(Address::generated_message_descriptor_data());
+            messages.push(Security::generated_message_descriptor_data());
+            messages.push(SocketOption::generated_message_descriptor_data());
+            messages.push(SocketOptionTimeout::generated_message_descriptor_data());
+            messages.push(SocketOptionLinger::generated_message_descriptor_data());
+            messages.push(SocketOptionTcpInfo::generated_message_descriptor_data());
+            messages.push(GetTopChannelsRequest::generated_message_descriptor_data());
+            messages.push(GetTopChannelsResponse::generated_message_descriptor_data());
+            messages.push(GetServersRequest::generated_message_descriptor_data());
+            messages.push(GetServersResponse::generated_message_descriptor_data());
+            messages.push(GetServerRequest::generated_message_descriptor_data());
+            messages.push(GetServerResponse::generated_message_descriptor_data());
+            messages.push(GetServerSocketsRequest::generated_message_descriptor_data());
+            messages.push(GetServerSocketsResponse::generated_message_descriptor_data());
+            messages.push(GetChannelRequest::generated_message_descriptor_data());
+            messages.push(GetChannelResponse::generated_message_descriptor_data());
+            messages.push(GetSubchannelRequest::generated_message_descriptor_data());
+            messages.push(GetSubchannelResponse::generated_message_descriptor_data());
+            messages.push(GetSocketRequest::generated_message_descriptor_data());
+            messages.push(GetSocketResponse::generated_message_descriptor_data());
+            messages.push(address::TcpIpAddress::generated_message_descriptor_data());
+            messages.push(address::UdsAddress::generated_message_descriptor_data());
+            messages.push(address::OtherAddress::generated_message_descriptor_data());
+            messages.push(security::Tls::generated_message_descriptor_data());
+            messages.push(security::OtherSecurity::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(2);
+            enums.push(channel_connectivity_state::State::generated_enum_descriptor_data());
+            enums.push(channel_trace_event::Severity::generated_enum_descriptor_data());
+            ::protobufv3::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobufv3::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
+
+pub use super::channelz_grpc::*;
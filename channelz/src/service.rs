@@ -0,0 +1,190 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::cmp;
+
+use futures_util::FutureExt as _;
+use grpcio::channelz::{self, Page};
+use grpcio::{RpcContext, RpcStatus, RpcStatusCode, UnarySink};
+
+use crate::convert;
+use crate::proto::*;
+
+fn internal_error(e: grpcio::Error) -> RpcStatus {
+    RpcStatus::with_message(
+        RpcStatusCode::INTERNAL,
+        format!("failed to translate channelz data: {e}"),
+    )
+}
+
+/// Converts a single entity, `None` means the entity doesn't exist.
+fn single<T, M>(res: grpcio::Result<Option<T>>, convert: fn(T) -> M) -> Result<M, RpcStatus> {
+    match res.map_err(internal_error)? {
+        Some(t) => Ok(convert(t)),
+        None => Err(RpcStatus::new(RpcStatusCode::NOT_FOUND)),
+    }
+}
+
+/// Converts a page of entities, returns the messages and whether it's the last
+/// page.
+///
+/// C core decides the page size by itself, so the page is truncated to
+/// `max_results` if it's positive.
+fn page<T, M>(
+    res: grpcio::Result<Page<T>>,
+    max_results: i64,
+    convert: fn(T) -> M,
+) -> Result<(Vec<M>, bool), RpcStatus> {
+    let mut page = res.map_err(internal_error)?;
+    if max_results > 0 && page.items.len() as u64 > max_results as u64 {
+        page.items.truncate(max_results as usize);
+        page.end = false;
+    }
+    Ok((page.items.into_iter().map(convert).collect(), page.end))
+}
+
+fn reply<M>(ctx: &RpcContext, sink: UnarySink<M>, resp: Result<M, RpcStatus>) {
+    let f = match resp {
+        Ok(resp) => sink.success(resp),
+        Err(status) => sink.fail(status),
+    };
+    ctx.spawn(f.map(|_| ()))
+}
+
+/// Ids in channelz requests are int64, but ids can't be negative.
+fn to_id(id: i64) -> u64 {
+    cmp::max(id, 0) as u64
+}
+
+/// An implementation of `grpc.channelz.v1.Channelz` that exposes the channelz
+/// data collected by gRPC C core.
+///
+/// Queries are answered with data of the whole process, not only the server
+/// the service is registered to.
+#[derive(Clone, Default)]
+pub struct ChannelzService;
+
+impl ChannelzService {
+    pub fn new() -> ChannelzService {
+        ChannelzService
+    }
+}
+
+// Conversions and default fields are only needed by some codecs.
+#[allow(clippy::needless_update, clippy::useless_conversion)]
+impl Channelz for ChannelzService {
+    fn get_top_channels(
+        &mut self,
+        ctx: RpcContext,
+        req: GetTopChannelsRequest,
+        sink: UnarySink<GetTopChannelsResponse>,
+    ) {
+        let channels = channelz::top_channels(to_id(req.start_channel_id));
+        let resp = page(channels, req.max_results, convert::channel).map(|(channel, end)| {
+            GetTopChannelsResponse {
+                channel: channel.into(),
+                end,
+                ..Default::default()
+            }
+        });
+        reply(&ctx, sink, resp)
+    }
+
+    fn get_servers(
+        &mut self,
+        ctx: RpcContext,
+        req: GetServersRequest,
+        sink: UnarySink<GetServersResponse>,
+    ) {
+        let servers = channelz::servers(to_id(req.start_server_id));
+        let resp = page(servers, req.max_results, convert::server).map(|(server, end)| {
+            GetServersResponse {
+                server: server.into(),
+                end,
+                ..Default::default()
+            }
+        });
+        reply(&ctx, sink, resp)
+    }
+
+    fn get_server(
+        &mut self,
+        ctx: RpcContext,
+        req: GetServerRequest,
+        sink: UnarySink<GetServerResponse>,
+    ) {
+        let server = channelz::server(to_id(req.server_id));
+        let resp = single(server, convert::server).map(|server| GetServerResponse {
+            server: Some(server).into(),
+            ..Default::default()
+        });
+        reply(&ctx, sink, resp)
+    }
+
+    fn get_server_sockets(
+        &mut self,
+        ctx: RpcContext,
+        req: GetServerSocketsRequest,
+        sink: UnarySink<GetServerSocketsResponse>,
+    ) {
+        // C core chooses the page size when it is 0.
+        let max_results = cmp::max(req.max_results, 0) as usize;
+        let sockets = channelz::server_sockets(
+            to_id(req.server_id),
+            to_id(req.start_socket_id),
+            max_results,
+        );
+        let resp = page(sockets, req.max_results, convert::socket_ref).map(|(socket_ref, end)| {
+            GetServerSocketsResponse {
+                socket_ref: socket_ref.into(),
+                end,
+                ..Default::default()
+            }
+        });
+        reply(&ctx, sink, resp)
+    }
+
+    fn get_channel(
+        &mut self,
+        ctx: RpcContext,
+        req: GetChannelRequest,
+        sink: UnarySink<GetChannelResponse>,
+    ) {
+        let channel = channelz::channel(to_id(req.channel_id));
+        let resp = single(channel, convert::channel).map(|channel| GetChannelResponse {
+            channel: Some(channel).into(),
+            ..Default::default()
+        });
+        reply(&ctx, sink, resp)
+    }
+
+    fn get_subchannel(
+        &mut self,
+        ctx: RpcContext,
+        req: GetSubchannelRequest,
+        sink: UnarySink<GetSubchannelResponse>,
+    ) {
+        let subchannel = channelz::subchannel(to_id(req.subchannel_id));
+        let resp =
+            single(subchannel, convert::subchannel).map(|subchannel| GetSubchannelResponse {
+                subchannel: Some(subchannel).into(),
+                ..Default::default()
+            });
+        reply(&ctx, sink, resp)
+    }
+
+    fn get_socket(
+        &mut self,
+        ctx: RpcContext,
+        req: GetSocketRequest,
+        sink: UnarySink<GetSocketResponse>,
+    ) {
+        // Socket options, the only data documented as optional for `summary`,
+        // are never reported, so the full socket is always returned.
+        let socket = channelz::socket(to_id(req.socket_id));
+        let resp = single(socket, convert::socket).map(|socket| GetSocketResponse {
+            socket: Some(socket).into(),
+            ..Default::default()
+        });
+        reply(&ctx, sink, resp)
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+#![cfg(feature = "protobuf-codec")]
+
+use futures_executor::block_on;
+use grpcio::*;
+use grpcio_channelz::proto::*;
+use grpcio_channelz::*;
+use std::sync::*;
+use std::time::Duration;
+
+#[test]
+fn test_channelz() {
+    let env = Arc::new(Environment::new(1));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_channelz(ChannelzService::new()))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let target = format!("127.0.0.1:{port}");
+    let ch = ChannelBuilder::new(env).connect(&target);
+    assert!(block_on(ch.wait_for_connected(Duration::from_secs(3))));
+    let client = ChannelzClient::new(ch);
+
+    let resp = client
+        .get_top_channels(&GetTopChannelsRequest::default())
+        .unwrap();
+    let channel = resp
+        .get_channel()
+        .iter()
+        .find(|c| c.get_data().get_target().ends_with(&target))
+        .unwrap();
+    let data = channel.get_data();
+    assert_eq!(
+        data.get_state().get_state(),
+        ChannelConnectivityState_State::READY
+    );
+    // The call that is being served is counted.
+    assert!(data.calls_started >= 1, "{:?}", data);
+    assert!(data.has_last_call_started_timestamp(), "{:?}", data);
+    let channel_id = channel.get_field_ref().channel_id;
+    let req = GetChannelRequest {
+        channel_id,
+        ..Default::default()
+    };
+    let resp = client.get_channel(&req).unwrap();
+    assert_eq!(resp.get_channel().get_field_ref().channel_id, channel_id);
+    if let Some(r) = channel.get_subchannel_ref().first() {
+        let req = GetSubchannelRequest {
+            subchannel_id: r.subchannel_id,
+            ..Default::default()
+        };
+        let resp = client.get_subchannel(&req).unwrap();
+        assert_eq!(resp.get_subchannel().get_field_ref(), r);
+    }
+
+    let resp = client.get_servers(&GetServersRequest::default()).unwrap();
+    assert!(resp.end);
+    // There may be servers created by other tests, find the one by calls.
+    let server = resp
+        .get_server()
+        .iter()
+        .find(|s| s.get_data().calls_started > 0)
+        .unwrap();
+    let server_id = server.get_field_ref().server_id;
+    let req = GetServerRequest {
+        server_id,
+        ..Default::default()
+    };
+    let resp = client.get_server(&req).unwrap();
+    assert_eq!(resp.get_server().get_field_ref().server_id, server_id);
+    let req = GetServerSocketsRequest {
+        server_id,
+        ..Default::default()
+    };
+    let resp = client.get_server_sockets(&req).unwrap();
+    assert!(resp.end);
+    assert_eq!(resp.socket_ref.len(), 1, "{:?}", resp);
+    let req = GetSocketRequest {
+        socket_id: resp.socket_ref[0].socket_id,
+        ..Default::default()
+    };
+    let socket = client.get_socket(&req).unwrap().take_socket();
+    assert!(socket.get_data().streams_started >= 1);
+    let local = socket.get_local().get_tcpip_address();
+    assert_eq!(local.ip_address, vec![127, 0, 0, 1]);
+    assert_eq!(local.port, i32::from(port));
+
+    let req = GetChannelRequest::default();
+    match client.get_channel(&req) {
+        Err(Error::RpcFailure(s)) if s.code() == RpcStatusCode::NOT_FOUND => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
+#[test]
+fn test_max_results() {
+    let env = Arc::new(Environment::new(1));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_channelz(ChannelzService::new()))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let target = format!("127.0.0.1:{port}");
+    // Two channels, so there are at least two top channels.
+    let _idle = ChannelBuilder::new(env.clone()).connect(&target);
+    let client = ChannelzClient::new(ChannelBuilder::new(env).connect(&target));
+
+    let req = GetTopChannelsRequest {
+        max_results: 1,
+        ..Default::default()
+    };
+    let resp = client.get_top_channels(&req).unwrap();
+    assert_eq!(resp.channel.len(), 1, "{:?}", resp);
+    assert!(!resp.end);
+    // The next page starts after the returned channel.
+    let req = GetTopChannelsRequest {
+        start_channel_id: resp.channel[0].get_field_ref().channel_id + 1,
+        ..Default::default()
+    };
+    let resp = client.get_top_channels(&req).unwrap();
+    assert!(!resp.channel.is_empty());
+
+    let req = GetServersRequest {
+        max_results: 1,
+        ..Default::default()
+    };
+    let resp = client.get_servers(&req).unwrap();
+    assert_eq!(resp.server.len(), 1, "{:?}", resp);
+}
@@ -3176,3 +3176,10 @@ extern "C" {
         tag: *mut ::std::os::raw::c_void,
     ) -> grpc_call_error;
 }
+extern "C" {
+    #[doc = " Creates a channel that calls the started server in the same process\nwithout going through sockets. Returns NULL if gRPC is not built from\nsource."]
+    pub fn grpcwrap_inproc_channel_create(
+        server: *mut grpc_server,
+        args: *const grpc_channel_args,
+    ) -> *mut grpc_channel;
+}
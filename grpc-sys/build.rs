@@ -461,6 +461,8 @@ fn bindgen_grpc(file_path: &Path) {
         .blocklist_function(r"\bgpr_mu_.*")
         .blocklist_function(r"\bgpr_cv_.*")
         .blocklist_function(r"\bgpr_once_.*")
+        // Declared by grpc_wrap.cc for its own use, not part of the public API.
+        .blocklist_function(r"\bgrpc_inproc_channel_create")
         .blocklist_type(r"gpr_mu")
         .blocklist_type(r"gpr_cv")
         .blocklist_type(r"gpr_once")
@@ -552,6 +554,8 @@ fn main() {
         for inc_path in lib_core.include_paths {
             cc.include(inc_path);
         }
+        // The in-process transport is not exported by shared builds of gRPC.
+        cc.define("GRPC_SYS_NO_INPROC", None);
     } else {
         build_grpc(&mut cc, library);
     }
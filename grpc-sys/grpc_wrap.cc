@@ -824,3 +824,25 @@ grpcwrap_server_request_call(grpc_server* server, grpc_completion_queue* cq,
   return grpc_server_request_call(server, &(ctx->call), &(ctx->call_details),
                                   &(ctx->request_metadata), cq, cq, tag);
 }
+
+#ifndef GRPC_SYS_NO_INPROC
+/* Declared in src/core/ext/transport/inproc/inproc_transport.h, which is not
+   a public header. The function is not exported by shared builds of gRPC, so
+   it's only used when gRPC is built from source, see GRPC_SYS_NO_INPROC in
+   build.rs. It's excluded from the generated bindings. */
+grpc_channel* grpc_inproc_channel_create(grpc_server* server,
+                                         const grpc_channel_args* args,
+                                         void* reserved);
+#endif
+
+/** Creates a channel that calls the started server in the same process
+    without going through sockets. Returns NULL if gRPC is not built from
+    source. */
+GPR_EXPORT grpc_channel* GPR_CALLTYPE grpcwrap_inproc_channel_create(
+    grpc_server* server, const grpc_channel_args* args) {
+#ifdef GRPC_SYS_NO_INPROC
+  return nullptr;
+#else
+  return grpc_inproc_channel_create(server, args, nullptr);
+#endif
+}
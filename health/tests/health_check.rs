@@ -186,3 +186,37 @@ fn test_health_watch_multiple() {
         assert_next(ServingStatus::NotServing, s);
     }
 }
+
+#[test]
+fn test_graceful_shutdown() {
+    let env = Arc::new(Environment::new(1));
+    let service = HealthService::default();
+    service.set_serving_status("", ServingStatus::Serving);
+    let s = service.clone();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_health(service))
+        .before_shutdown(move || s.shutdown())
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = HealthClient::new(ch);
+
+    let mut statuses = watch(&client, "");
+    assert_next(ServingStatus::Serving, &mut statuses);
+    // The watcher is notified before in-flight calls are drained.
+    let shutdown = server.graceful_shutdown(Duration::from_millis(200));
+    assert_next(ServingStatus::NotServing, &mut statuses);
+    // The watch is still open when the timeout expires.
+    let stats = block_on(shutdown).unwrap();
+    assert_eq!(
+        stats,
+        ShutdownStats {
+            drained: 0,
+            cancelled: 1
+        }
+    );
+}
@@ -0,0 +1,300 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Standard error detail messages defined in `google/rpc/error_details.proto`.
+//!
+//! These messages are carried by the `details` field of [`Status`], which is
+//! sent by gRPC in the `grpc-status-details-bin` trailer. The messages are
+//! generated by the enabled codec, so nested messages follow its naming, for
+//! example `bad_request::FieldViolation` with prost and protobuf v3 and
+//! `BadRequest_FieldViolation` with protobuf v2.
+//!
+//! On the server side, add details to a [`Status`] and convert it into an
+//! [`RpcStatus`]:
+//!
+//! ```ignore
+//! let mut status = Status {
+//!     code: RpcStatusCode::INVALID_ARGUMENT.into(),
+//!     message: "invalid name".to_owned(),
+//!     ..Default::default()
+//! };
+//! status.add_detail(&BadRequest {
+//!     field_violations: vec![bad_request::FieldViolation {
+//!         field: "name".to_owned(),
+//!         description: "name can't be empty".to_owned(),
+//!         ..Default::default()
+//!     }],
+//!     ..Default::default()
+//! });
+//! sink.fail(status.try_into()?);
+//! ```
+//!
+//! On the client side, extract them from the returned error:
+//!
+//! ```ignore
+//! if let Some(status) = Status::from_error(&err) {
+//!     let bad_request: Option<BadRequest> = status.get_detail()?;
+//! }
+//! ```
+
+#![allow(clippy::needless_update, clippy::useless_conversion)]
+
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use grpcio::{Error, Result, RpcStatus};
+
+use crate::google::rpc::Status;
+
+#[cfg(any(feature = "protobuf-codec", feature = "protobufv3-codec"))]
+pub use crate::google::rpc::error_details::*;
+#[cfg(feature = "prost-codec")]
+pub use crate::google::rpc::{
+    bad_request, help, precondition_failure, quota_failure, BadRequest, DebugInfo, ErrorInfo, Help,
+    LocalizedMessage, PreconditionFailure, QuotaFailure, RequestInfo, ResourceInfo, RetryInfo,
+};
+
+#[cfg(feature = "prost-codec")]
+use prost_types::Duration as ProtoDuration;
+#[cfg(feature = "protobuf-codec")]
+use protobuf::well_known_types::Duration as ProtoDuration;
+#[cfg(feature = "protobufv3-codec")]
+use protobufv3::well_known_types::duration::Duration as ProtoDuration;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
+/// The largest value of `google.protobuf.Duration`, which is about 10,000
+/// years.
+const MAX_DURATION_SECS: u64 = 315_576_000_000;
+
+/// Messages that can be put into the details of [`Status`].
+pub trait ErrorDetail: Sized {
+    /// The fully qualified name of the message, for example
+    /// `google.rpc.BadRequest`.
+    const TYPE_NAME: &'static str;
+
+    /// Encodes the message into protobuf wire format.
+    fn encode(&self) -> Result<Vec<u8>>;
+
+    /// Decodes a message from protobuf wire format.
+    fn decode(buf: &[u8]) -> Result<Self>;
+
+    /// The type url used by `google.protobuf.Any`.
+    fn type_url() -> String {
+        format!("{}{}", TYPE_URL_PREFIX, Self::TYPE_NAME)
+    }
+}
+
+macro_rules! impl_error_detail {
+    ($($name:ident),*) => {
+        $(
+            impl ErrorDetail for $name {
+                const TYPE_NAME: &'static str = concat!("google.rpc.", stringify!($name));
+
+                #[cfg(feature = "protobuf-codec")]
+                fn encode(&self) -> Result<Vec<u8>> {
+                    Ok(protobuf::Message::write_to_bytes(self)?)
+                }
+
+                #[cfg(feature = "protobufv3-codec")]
+                fn encode(&self) -> Result<Vec<u8>> {
+                    Ok(protobufv3::Message::write_to_bytes(self)?)
+                }
+
+                #[cfg(feature = "prost-codec")]
+                fn encode(&self) -> Result<Vec<u8>> {
+                    Ok(prost::Message::encode_to_vec(self))
+                }
+
+                #[cfg(feature = "protobuf-codec")]
+                fn decode(buf: &[u8]) -> Result<Self> {
+                    Ok(protobuf::Message::parse_from_bytes(buf)?)
+                }
+
+                #[cfg(feature = "protobufv3-codec")]
+                fn decode(buf: &[u8]) -> Result<Self> {
+                    Ok(protobufv3::Message::parse_from_bytes(buf)?)
+                }
+
+                #[cfg(feature = "prost-codec")]
+                fn decode(buf: &[u8]) -> Result<Self> {
+                    Ok(prost::Message::decode(buf)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_error_detail!(
+    ErrorInfo,
+    RetryInfo,
+    DebugInfo,
+    QuotaFailure,
+    PreconditionFailure,
+    BadRequest,
+    RequestInfo,
+    ResourceInfo,
+    Help,
+    LocalizedMessage
+);
+
+impl RetryInfo {
+    /// Creates a `RetryInfo` that asks clients to wait at least `delay`
+    /// before retrying.
+    ///
+    /// Delays longer than `google.protobuf.Duration` can hold are clamped.
+    pub fn with_delay(delay: Duration) -> RetryInfo {
+        let (seconds, nanos) = if delay.as_secs() < MAX_DURATION_SECS {
+            (delay.as_secs() as i64, delay.subsec_nanos() as i32)
+        } else {
+            (MAX_DURATION_SECS as i64, 0)
+        };
+        let delay = ProtoDuration {
+            seconds,
+            nanos,
+            ..Default::default()
+        };
+        RetryInfo {
+            retry_delay: Some(delay).into(),
+            ..Default::default()
+        }
+    }
+
+    /// Gets the delay clients should wait before retrying.
+    ///
+    /// Negative or malformed delays are rejected.
+    pub fn delay(&self) -> Result<Option<Duration>> {
+        let d = match self.retry_delay.as_ref() {
+            Some(d) => d,
+            None => return Ok(None),
+        };
+        match (u64::try_from(d.seconds), u32::try_from(d.nanos)) {
+            (Ok(secs), Ok(nanos)) if nanos < 1_000_000_000 => Ok(Some(Duration::new(secs, nanos))),
+            _ => Err(Error::Codec(
+                format!("invalid duration {}s {}ns", d.seconds, d.nanos).into(),
+            )),
+        }
+    }
+}
+
+impl Status {
+    /// Appends `detail` to the details of the status.
+    pub fn add_detail<D: ErrorDetail>(&mut self, detail: &D) -> Result<()> {
+        let value = detail.encode()?;
+        self.details.push(Default::default());
+        let any = self.details.last_mut().unwrap();
+        any.type_url = D::type_url();
+        any.value = value;
+        Ok(())
+    }
+
+    /// Gets the first detail of type `D`.
+    ///
+    /// `None` is returned if there is no such detail.
+    pub fn get_detail<D: ErrorDetail>(&self) -> Result<Option<D>> {
+        self.get_details_of().into_iter().next().transpose()
+    }
+
+    /// Gets all details of type `D`.
+    pub fn get_details_of<D: ErrorDetail>(&self) -> Vec<Result<D>> {
+        self.details
+            .iter()
+            .filter(|any| type_name(&any.type_url) == D::TYPE_NAME)
+            .map(|any| D::decode(&any.value))
+            .collect()
+    }
+
+    /// Decodes the status from the details of `status`.
+    ///
+    /// Unlike `TryFrom<RpcStatus>`, a status without details is accepted, in
+    /// which case only code and message are filled.
+    pub fn from_rpc_status(status: &RpcStatus) -> Result<Status> {
+        if status.details().is_empty() {
+            return Ok(Status {
+                code: status.code().into(),
+                message: status.message().to_owned(),
+                ..Default::default()
+            });
+        }
+        Status::try_from(status.clone())
+    }
+
+    /// Extracts the status returned by server from `err`.
+    ///
+    /// `None` is returned if `err` is not a `RpcFailure` or the details can't
+    /// be decoded.
+    pub fn from_error(err: &Error) -> Option<Status> {
+        match err {
+            Error::RpcFailure(s) => Status::from_rpc_status(s).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Strips the prefix of a type url, any prefix is accepted.
+fn type_name(type_url: &str) -> &str {
+    match type_url.rfind('/') {
+        Some(pos) => &type_url[pos + 1..],
+        None => type_url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_details() {
+        let mut status = Status::default();
+        let bad_request = BadRequest {
+            field_violations: vec![Default::default(), Default::default()].into(),
+            ..Default::default()
+        };
+        status.add_detail(&bad_request).unwrap();
+        let retry_info = RetryInfo::with_delay(Duration::new(3, 5));
+        status.add_detail(&retry_info).unwrap();
+        let mut error_info = ErrorInfo {
+            reason: "STOCKOUT".to_owned(),
+            domain: "example.com".to_owned(),
+            ..Default::default()
+        };
+        error_info
+            .metadata
+            .insert("zone".to_owned(), "z1".to_owned());
+        status.add_detail(&error_info).unwrap();
+        status.add_detail(&DebugInfo::default()).unwrap();
+        status.add_detail(&DebugInfo::default()).unwrap();
+
+        assert_eq!(
+            status.details[0].type_url,
+            "type.googleapis.com/google.rpc.BadRequest"
+        );
+        assert_eq!(status.get_detail().unwrap(), Some(bad_request));
+        let r: RetryInfo = status.get_detail().unwrap().unwrap();
+        assert_eq!(r.delay().unwrap(), Some(Duration::new(3, 5)));
+        assert_eq!(status.get_detail().unwrap(), Some(error_info));
+        assert_eq!(status.get_details_of::<DebugInfo>().len(), 2);
+        assert_eq!(status.get_detail::<QuotaFailure>().unwrap(), None);
+
+        status.details[1].value = vec![10, 2, 8, 0xff];
+        assert!(status.get_detail::<RetryInfo>().is_err());
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(RetryInfo::default().delay().unwrap(), None);
+        let r = RetryInfo::with_delay(Duration::from_secs(u64::MAX));
+        assert_eq!(
+            r.delay().unwrap(),
+            Some(Duration::from_secs(MAX_DURATION_SECS))
+        );
+        let r = RetryInfo {
+            retry_delay: Some(ProtoDuration {
+                seconds: -1,
+                ..Default::default()
+            })
+            .into(),
+            ..Default::default()
+        };
+        assert!(r.delay().is_err());
+    }
+}
@@ -10,4 +10,5 @@ pub use proto::protobuf::*;
 #[cfg(feature = "prost-codec")]
 pub use proto::prost::*;
 
+pub mod error_details;
 pub mod util;
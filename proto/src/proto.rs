@@ -214,6 +214,7 @@ pub mod protobuf {
     }
     pub mod google {
         pub mod rpc {
+            pub mod error_details;
             pub mod status;
 
             pub use status::*;
@@ -0,0 +1,3246 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `google/rpc/error_details.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ErrorInfo {
+    // message fields
+    pub reason: ::std::string::String,
+    pub domain: ::std::string::String,
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorInfo {
+    fn default() -> &'a ErrorInfo {
+        <ErrorInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ErrorInfo {
+    pub fn new() -> ErrorInfo {
+        ::std::default::Default::default()
+    }
+
+    // string reason = 1;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+
+    // string domain = 2;
+
+
+    pub fn get_domain(&self) -> &str {
+        &self.domain
+    }
+    pub fn clear_domain(&mut self) {
+        self.domain.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_domain(&mut self, v: ::std::string::String) {
+        self.domain = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_domain(&mut self) -> &mut ::std::string::String {
+        &mut self.domain
+    }
+
+    // Take field
+    pub fn take_domain(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.domain, ::std::string::String::new())
+    }
+
+    // repeated .google.rpc.ErrorInfo.MetadataEntry metadata = 3;
+
+
+    pub fn get_metadata(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.metadata
+    }
+    pub fn clear_metadata(&mut self) {
+        self.metadata.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metadata(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.metadata = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_metadata(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.metadata
+    }
+
+    // Take field
+    pub fn take_metadata(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.metadata, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for ErrorInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.domain)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.metadata)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reason);
+        }
+        if !self.domain.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.domain);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.metadata);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.reason.is_empty() {
+            os.write_string(1, &self.reason)?;
+        }
+        if !self.domain.is_empty() {
+            os.write_string(2, &self.domain)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.metadata, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ErrorInfo {
+        ErrorInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &ErrorInfo| { &m.reason },
+                |m: &mut ErrorInfo| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "domain",
+                |m: &ErrorInfo| { &m.domain },
+                |m: &mut ErrorInfo| { &mut m.domain },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "metadata",
+                |m: &ErrorInfo| { &m.metadata },
+                |m: &mut ErrorInfo| { &mut m.metadata },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ErrorInfo>(
+                "ErrorInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ErrorInfo {
+        static instance: ::protobuf::rt::LazyV2<ErrorInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ErrorInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for ErrorInfo {
+    fn clear(&mut self) {
+        self.reason.clear();
+        self.domain.clear();
+        self.metadata.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ErrorInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RetryInfo {
+    // message fields
+    pub retry_delay: ::protobuf::SingularPtrField<::protobuf::well_known_types::Duration>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RetryInfo {
+    fn default() -> &'a RetryInfo {
+        <RetryInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RetryInfo {
+    pub fn new() -> RetryInfo {
+        ::std::default::Default::default()
+    }
+
+    // .google.protobuf.Duration retry_delay = 1;
+
+
+    pub fn get_retry_delay(&self) -> &::protobuf::well_known_types::Duration {
+        self.retry_delay.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Duration as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_retry_delay(&mut self) {
+        self.retry_delay.clear();
+    }
+
+    pub fn has_retry_delay(&self) -> bool {
+        self.retry_delay.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retry_delay(&mut self, v: ::protobuf::well_known_types::Duration) {
+        self.retry_delay = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_retry_delay(&mut self) -> &mut ::protobuf::well_known_types::Duration {
+        if self.retry_delay.is_none() {
+            self.retry_delay.set_default();
+        }
+        self.retry_delay.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_retry_delay(&mut self) -> ::protobuf::well_known_types::Duration {
+        self.retry_delay.take().unwrap_or_else(|| ::protobuf::well_known_types::Duration::new())
+    }
+}
+
+impl ::protobuf::Message for RetryInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.retry_delay {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.retry_delay)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.retry_delay.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.retry_delay.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RetryInfo {
+        RetryInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Duration>>(
+                "retry_delay",
+                |m: &RetryInfo| { &m.retry_delay },
+                |m: &mut RetryInfo| { &mut m.retry_delay },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RetryInfo>(
+                "RetryInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RetryInfo {
+        static instance: ::protobuf::rt::LazyV2<RetryInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RetryInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for RetryInfo {
+    fn clear(&mut self) {
+        self.retry_delay.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RetryInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetryInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DebugInfo {
+    // message fields
+    pub stack_entries: ::protobuf::RepeatedField<::std::string::String>,
+    pub detail: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DebugInfo {
+    fn default() -> &'a DebugInfo {
+        <DebugInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DebugInfo {
+    pub fn new() -> DebugInfo {
+        ::std::default::Default::default()
+    }
+
+    // repeated string stack_entries = 1;
+
+
+    pub fn get_stack_entries(&self) -> &[::std::string::String] {
+        &self.stack_entries
+    }
+    pub fn clear_stack_entries(&mut self) {
+        self.stack_entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stack_entries(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.stack_entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stack_entries(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.stack_entries
+    }
+
+    // Take field
+    pub fn take_stack_entries(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.stack_entries, ::protobuf::RepeatedField::new())
+    }
+
+    // string detail = 2;
+
+
+    pub fn get_detail(&self) -> &str {
+        &self.detail
+    }
+    pub fn clear_detail(&mut self) {
+        self.detail.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_detail(&mut self, v: ::std::string::String) {
+        self.detail = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_detail(&mut self) -> &mut ::std::string::String {
+        &mut self.detail
+    }
+
+    // Take field
+    pub fn take_detail(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.detail, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DebugInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.stack_entries)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.detail)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.stack_entries {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if !self.detail.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.detail);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.stack_entries {
+            os.write_string(1, &v)?;
+        };
+        if !self.detail.is_empty() {
+            os.write_string(2, &self.detail)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DebugInfo {
+        DebugInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stack_entries",
+                |m: &DebugInfo| { &m.stack_entries },
+                |m: &mut DebugInfo| { &mut m.stack_entries },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "detail",
+                |m: &DebugInfo| { &m.detail },
+                |m: &mut DebugInfo| { &mut m.detail },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DebugInfo>(
+                "DebugInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DebugInfo {
+        static instance: ::protobuf::rt::LazyV2<DebugInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DebugInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for DebugInfo {
+    fn clear(&mut self) {
+        self.stack_entries.clear();
+        self.detail.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DebugInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DebugInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuotaFailure {
+    // message fields
+    pub violations: ::protobuf::RepeatedField<QuotaFailure_Violation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuotaFailure {
+    fn default() -> &'a QuotaFailure {
+        <QuotaFailure as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuotaFailure {
+    pub fn new() -> QuotaFailure {
+        ::std::default::Default::default()
+    }
+
+    // repeated .google.rpc.QuotaFailure.Violation violations = 1;
+
+
+    pub fn get_violations(&self) -> &[QuotaFailure_Violation] {
+        &self.violations
+    }
+    pub fn clear_violations(&mut self) {
+        self.violations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_violations(&mut self, v: ::protobuf::RepeatedField<QuotaFailure_Violation>) {
+        self.violations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_violations(&mut self) -> &mut ::protobuf::RepeatedField<QuotaFailure_Violation> {
+        &mut self.violations
+    }
+
+    // Take field
+    pub fn take_violations(&mut self) -> ::protobuf::RepeatedField<QuotaFailure_Violation> {
+        ::std::mem::replace(&mut self.violations, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for QuotaFailure {
+    fn is_initialized(&self) -> bool {
+        for v in &self.violations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.violations)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.violations {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuotaFailure {
+        QuotaFailure::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QuotaFailure_Violation>>(
+                "violations",
+                |m: &QuotaFailure| { &m.violations },
+                |m: &mut QuotaFailure| { &mut m.violations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuotaFailure>(
+                "QuotaFailure",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuotaFailure {
+        static instance: ::protobuf::rt::LazyV2<QuotaFailure> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuotaFailure::new)
+    }
+}
+
+impl ::protobuf::Clear for QuotaFailure {
+    fn clear(&mut self) {
+        self.violations.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuotaFailure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuotaFailure {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuotaFailure_Violation {
+    // message fields
+    pub subject: ::std::string::String,
+    pub description: ::std::string::String,
+    pub api_service: ::std::string::String,
+    pub quota_metric: ::std::string::String,
+    pub quota_id: ::std::string::String,
+    pub quota_dimensions: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    pub quota_value: i64,
+    // message oneof groups
+    pub _future_quota_value: ::std::option::Option<QuotaFailure_Violation_oneof__future_quota_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuotaFailure_Violation {
+    fn default() -> &'a QuotaFailure_Violation {
+        <QuotaFailure_Violation as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum QuotaFailure_Violation_oneof__future_quota_value {
+    future_quota_value(i64),
+}
+
+impl QuotaFailure_Violation {
+    pub fn new() -> QuotaFailure_Violation {
+        ::std::default::Default::default()
+    }
+
+    // string subject = 1;
+
+
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        &mut self.subject
+    }
+
+    // Take field
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.subject, ::std::string::String::new())
+    }
+
+    // string description = 2;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+
+    // string api_service = 3;
+
+
+    pub fn get_api_service(&self) -> &str {
+        &self.api_service
+    }
+    pub fn clear_api_service(&mut self) {
+        self.api_service.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_api_service(&mut self, v: ::std::string::String) {
+        self.api_service = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_api_service(&mut self) -> &mut ::std::string::String {
+        &mut self.api_service
+    }
+
+    // Take field
+    pub fn take_api_service(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.api_service, ::std::string::String::new())
+    }
+
+    // string quota_metric = 4;
+
+
+    pub fn get_quota_metric(&self) -> &str {
+        &self.quota_metric
+    }
+    pub fn clear_quota_metric(&mut self) {
+        self.quota_metric.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quota_metric(&mut self, v: ::std::string::String) {
+        self.quota_metric = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_quota_metric(&mut self) -> &mut ::std::string::String {
+        &mut self.quota_metric
+    }
+
+    // Take field
+    pub fn take_quota_metric(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.quota_metric, ::std::string::String::new())
+    }
+
+    // string quota_id = 5;
+
+
+    pub fn get_quota_id(&self) -> &str {
+        &self.quota_id
+    }
+    pub fn clear_quota_id(&mut self) {
+        self.quota_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quota_id(&mut self, v: ::std::string::String) {
+        self.quota_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_quota_id(&mut self) -> &mut ::std::string::String {
+        &mut self.quota_id
+    }
+
+    // Take field
+    pub fn take_quota_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.quota_id, ::std::string::String::new())
+    }
+
+    // repeated .google.rpc.QuotaFailure.Violation.QuotaDimensionsEntry quota_dimensions = 6;
+
+
+    pub fn get_quota_dimensions(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.quota_dimensions
+    }
+    pub fn clear_quota_dimensions(&mut self) {
+        self.quota_dimensions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quota_dimensions(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.quota_dimensions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_quota_dimensions(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.quota_dimensions
+    }
+
+    // Take field
+    pub fn take_quota_dimensions(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.quota_dimensions, ::std::collections::HashMap::new())
+    }
+
+    // int64 quota_value = 7;
+
+
+    pub fn get_quota_value(&self) -> i64 {
+        self.quota_value
+    }
+    pub fn clear_quota_value(&mut self) {
+        self.quota_value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quota_value(&mut self, v: i64) {
+        self.quota_value = v;
+    }
+
+    // int64 future_quota_value = 8;
+
+
+    pub fn get_future_quota_value(&self) -> i64 {
+        match self._future_quota_value {
+            ::std::option::Option::Some(QuotaFailure_Violation_oneof__future_quota_value::future_quota_value(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_future_quota_value(&mut self) {
+        self._future_quota_value = ::std::option::Option::None;
+    }
+
+    pub fn has_future_quota_value(&self) -> bool {
+        match self._future_quota_value {
+            ::std::option::Option::Some(QuotaFailure_Violation_oneof__future_quota_value::future_quota_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_future_quota_value(&mut self, v: i64) {
+        self._future_quota_value = ::std::option::Option::Some(QuotaFailure_Violation_oneof__future_quota_value::future_quota_value(v))
+    }
+}
+
+impl ::protobuf::Message for QuotaFailure_Violation {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.subject)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.api_service)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.quota_metric)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.quota_id)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.quota_dimensions)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.quota_value = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self._future_quota_value = ::std::option::Option::Some(QuotaFailure_Violation_oneof__future_quota_value::future_quota_value(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.subject.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.subject);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.description);
+        }
+        if !self.api_service.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.api_service);
+        }
+        if !self.quota_metric.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.quota_metric);
+        }
+        if !self.quota_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.quota_id);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.quota_dimensions);
+        if self.quota_value != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.quota_value, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self._future_quota_value {
+            match v {
+                &QuotaFailure_Violation_oneof__future_quota_value::future_quota_value(v) => {
+                    my_size += ::protobuf::rt::value_size(8, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.subject.is_empty() {
+            os.write_string(1, &self.subject)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(2, &self.description)?;
+        }
+        if !self.api_service.is_empty() {
+            os.write_string(3, &self.api_service)?;
+        }
+        if !self.quota_metric.is_empty() {
+            os.write_string(4, &self.quota_metric)?;
+        }
+        if !self.quota_id.is_empty() {
+            os.write_string(5, &self.quota_id)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.quota_dimensions, os)?;
+        if self.quota_value != 0 {
+            os.write_int64(7, self.quota_value)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self._future_quota_value {
+            match v {
+                &QuotaFailure_Violation_oneof__future_quota_value::future_quota_value(v) => {
+                    os.write_int64(8, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuotaFailure_Violation {
+        QuotaFailure_Violation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "subject",
+                |m: &QuotaFailure_Violation| { &m.subject },
+                |m: &mut QuotaFailure_Violation| { &mut m.subject },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &QuotaFailure_Violation| { &m.description },
+                |m: &mut QuotaFailure_Violation| { &mut m.description },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "api_service",
+                |m: &QuotaFailure_Violation| { &m.api_service },
+                |m: &mut QuotaFailure_Violation| { &mut m.api_service },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "quota_metric",
+                |m: &QuotaFailure_Violation| { &m.quota_metric },
+                |m: &mut QuotaFailure_Violation| { &mut m.quota_metric },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "quota_id",
+                |m: &QuotaFailure_Violation| { &m.quota_id },
+                |m: &mut QuotaFailure_Violation| { &mut m.quota_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "quota_dimensions",
+                |m: &QuotaFailure_Violation| { &m.quota_dimensions },
+                |m: &mut QuotaFailure_Violation| { &mut m.quota_dimensions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "quota_value",
+                |m: &QuotaFailure_Violation| { &m.quota_value },
+                |m: &mut QuotaFailure_Violation| { &mut m.quota_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "future_quota_value",
+                QuotaFailure_Violation::has_future_quota_value,
+                QuotaFailure_Violation::get_future_quota_value,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuotaFailure_Violation>(
+                "QuotaFailure.Violation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuotaFailure_Violation {
+        static instance: ::protobuf::rt::LazyV2<QuotaFailure_Violation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuotaFailure_Violation::new)
+    }
+}
+
+impl ::protobuf::Clear for QuotaFailure_Violation {
+    fn clear(&mut self) {
+        self.subject.clear();
+        self.description.clear();
+        self.api_service.clear();
+        self.quota_metric.clear();
+        self.quota_id.clear();
+        self.quota_dimensions.clear();
+        self.quota_value = 0;
+        self._future_quota_value = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuotaFailure_Violation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuotaFailure_Violation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PreconditionFailure {
+    // message fields
+    pub violations: ::protobuf::RepeatedField<PreconditionFailure_Violation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PreconditionFailure {
+    fn default() -> &'a PreconditionFailure {
+        <PreconditionFailure as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PreconditionFailure {
+    pub fn new() -> PreconditionFailure {
+        ::std::default::Default::default()
+    }
+
+    // repeated .google.rpc.PreconditionFailure.Violation violations = 1;
+
+
+    pub fn get_violations(&self) -> &[PreconditionFailure_Violation] {
+        &self.violations
+    }
+    pub fn clear_violations(&mut self) {
+        self.violations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_violations(&mut self, v: ::protobuf::RepeatedField<PreconditionFailure_Violation>) {
+        self.violations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_violations(&mut self) -> &mut ::protobuf::RepeatedField<PreconditionFailure_Violation> {
+        &mut self.violations
+    }
+
+    // Take field
+    pub fn take_violations(&mut self) -> ::protobuf::RepeatedField<PreconditionFailure_Violation> {
+        ::std::mem::replace(&mut self.violations, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PreconditionFailure {
+    fn is_initialized(&self) -> bool {
+        for v in &self.violations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.violations)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.violations {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PreconditionFailure {
+        PreconditionFailure::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PreconditionFailure_Violation>>(
+                "violations",
+                |m: &PreconditionFailure| { &m.violations },
+                |m: &mut PreconditionFailure| { &mut m.violations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PreconditionFailure>(
+                "PreconditionFailure",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PreconditionFailure {
+        static instance: ::protobuf::rt::LazyV2<PreconditionFailure> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PreconditionFailure::new)
+    }
+}
+
+impl ::protobuf::Clear for PreconditionFailure {
+    fn clear(&mut self) {
+        self.violations.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PreconditionFailure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PreconditionFailure {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PreconditionFailure_Violation {
+    // message fields
+    pub field_type: ::std::string::String,
+    pub subject: ::std::string::String,
+    pub description: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PreconditionFailure_Violation {
+    fn default() -> &'a PreconditionFailure_Violation {
+        <PreconditionFailure_Violation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PreconditionFailure_Violation {
+    pub fn new() -> PreconditionFailure_Violation {
+        ::std::default::Default::default()
+    }
+
+    // string type = 1;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // string subject = 2;
+
+
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        &mut self.subject
+    }
+
+    // Take field
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.subject, ::std::string::String::new())
+    }
+
+    // string description = 3;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PreconditionFailure_Violation {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.subject)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        if !self.subject.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.subject);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.description);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        if !self.subject.is_empty() {
+            os.write_string(2, &self.subject)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(3, &self.description)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PreconditionFailure_Violation {
+        PreconditionFailure_Violation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type",
+                |m: &PreconditionFailure_Violation| { &m.field_type },
+                |m: &mut PreconditionFailure_Violation| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "subject",
+                |m: &PreconditionFailure_Violation| { &m.subject },
+                |m: &mut PreconditionFailure_Violation| { &mut m.subject },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &PreconditionFailure_Violation| { &m.description },
+                |m: &mut PreconditionFailure_Violation| { &mut m.description },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PreconditionFailure_Violation>(
+                "PreconditionFailure.Violation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PreconditionFailure_Violation {
+        static instance: ::protobuf::rt::LazyV2<PreconditionFailure_Violation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PreconditionFailure_Violation::new)
+    }
+}
+
+impl ::protobuf::Clear for PreconditionFailure_Violation {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.subject.clear();
+        self.description.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PreconditionFailure_Violation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PreconditionFailure_Violation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BadRequest {
+    // message fields
+    pub field_violations: ::protobuf::RepeatedField<BadRequest_FieldViolation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BadRequest {
+    fn default() -> &'a BadRequest {
+        <BadRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BadRequest {
+    pub fn new() -> BadRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .google.rpc.BadRequest.FieldViolation field_violations = 1;
+
+
+    pub fn get_field_violations(&self) -> &[BadRequest_FieldViolation] {
+        &self.field_violations
+    }
+    pub fn clear_field_violations(&mut self) {
+        self.field_violations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_violations(&mut self, v: ::protobuf::RepeatedField<BadRequest_FieldViolation>) {
+        self.field_violations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_field_violations(&mut self) -> &mut ::protobuf::RepeatedField<BadRequest_FieldViolation> {
+        &mut self.field_violations
+    }
+
+    // Take field
+    pub fn take_field_violations(&mut self) -> ::protobuf::RepeatedField<BadRequest_FieldViolation> {
+        ::std::mem::replace(&mut self.field_violations, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BadRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.field_violations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.field_violations)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.field_violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.field_violations {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BadRequest {
+        BadRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BadRequest_FieldViolation>>(
+                "field_violations",
+                |m: &BadRequest| { &m.field_violations },
+                |m: &mut BadRequest| { &mut m.field_violations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BadRequest>(
+                "BadRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BadRequest {
+        static instance: ::protobuf::rt::LazyV2<BadRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BadRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for BadRequest {
+    fn clear(&mut self) {
+        self.field_violations.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BadRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BadRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BadRequest_FieldViolation {
+    // message fields
+    pub field: ::std::string::String,
+    pub description: ::std::string::String,
+    pub reason: ::std::string::String,
+    pub localized_message: ::protobuf::SingularPtrField<LocalizedMessage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BadRequest_FieldViolation {
+    fn default() -> &'a BadRequest_FieldViolation {
+        <BadRequest_FieldViolation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BadRequest_FieldViolation {
+    pub fn new() -> BadRequest_FieldViolation {
+        ::std::default::Default::default()
+    }
+
+    // string field = 1;
+
+
+    pub fn get_field(&self) -> &str {
+        &self.field
+    }
+    pub fn clear_field(&mut self) {
+        self.field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field(&mut self, v: ::std::string::String) {
+        self.field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field(&mut self) -> &mut ::std::string::String {
+        &mut self.field
+    }
+
+    // Take field
+    pub fn take_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field, ::std::string::String::new())
+    }
+
+    // string description = 2;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+
+    // string reason = 3;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+
+    // .google.rpc.LocalizedMessage localized_message = 4;
+
+
+    pub fn get_localized_message(&self) -> &LocalizedMessage {
+        self.localized_message.as_ref().unwrap_or_else(|| <LocalizedMessage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_localized_message(&mut self) {
+        self.localized_message.clear();
+    }
+
+    pub fn has_localized_message(&self) -> bool {
+        self.localized_message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_localized_message(&mut self, v: LocalizedMessage) {
+        self.localized_message = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_localized_message(&mut self) -> &mut LocalizedMessage {
+        if self.localized_message.is_none() {
+            self.localized_message.set_default();
+        }
+        self.localized_message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_localized_message(&mut self) -> LocalizedMessage {
+        self.localized_message.take().unwrap_or_else(|| LocalizedMessage::new())
+    }
+}
+
+impl ::protobuf::Message for BadRequest_FieldViolation {
+    fn is_initialized(&self) -> bool {
+        for v in &self.localized_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.localized_message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.description);
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.reason);
+        }
+        if let Some(ref v) = self.localized_message.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field.is_empty() {
+            os.write_string(1, &self.field)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(2, &self.description)?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(3, &self.reason)?;
+        }
+        if let Some(ref v) = self.localized_message.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BadRequest_FieldViolation {
+        BadRequest_FieldViolation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field",
+                |m: &BadRequest_FieldViolation| { &m.field },
+                |m: &mut BadRequest_FieldViolation| { &mut m.field },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &BadRequest_FieldViolation| { &m.description },
+                |m: &mut BadRequest_FieldViolation| { &mut m.description },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &BadRequest_FieldViolation| { &m.reason },
+                |m: &mut BadRequest_FieldViolation| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LocalizedMessage>>(
+                "localized_message",
+                |m: &BadRequest_FieldViolation| { &m.localized_message },
+                |m: &mut BadRequest_FieldViolation| { &mut m.localized_message },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BadRequest_FieldViolation>(
+                "BadRequest.FieldViolation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BadRequest_FieldViolation {
+        static instance: ::protobuf::rt::LazyV2<BadRequest_FieldViolation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BadRequest_FieldViolation::new)
+    }
+}
+
+impl ::protobuf::Clear for BadRequest_FieldViolation {
+    fn clear(&mut self) {
+        self.field.clear();
+        self.description.clear();
+        self.reason.clear();
+        self.localized_message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BadRequest_FieldViolation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BadRequest_FieldViolation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestInfo {
+    // message fields
+    pub request_id: ::std::string::String,
+    pub serving_data: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestInfo {
+    fn default() -> &'a RequestInfo {
+        <RequestInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestInfo {
+    pub fn new() -> RequestInfo {
+        ::std::default::Default::default()
+    }
+
+    // string request_id = 1;
+
+
+    pub fn get_request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn clear_request_id(&mut self) {
+        self.request_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request_id(&mut self, v: ::std::string::String) {
+        self.request_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_request_id(&mut self) -> &mut ::std::string::String {
+        &mut self.request_id
+    }
+
+    // Take field
+    pub fn take_request_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.request_id, ::std::string::String::new())
+    }
+
+    // string serving_data = 2;
+
+
+    pub fn get_serving_data(&self) -> &str {
+        &self.serving_data
+    }
+    pub fn clear_serving_data(&mut self) {
+        self.serving_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_serving_data(&mut self, v: ::std::string::String) {
+        self.serving_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_serving_data(&mut self) -> &mut ::std::string::String {
+        &mut self.serving_data
+    }
+
+    // Take field
+    pub fn take_serving_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.serving_data, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.request_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.serving_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.request_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.request_id);
+        }
+        if !self.serving_data.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.serving_data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.request_id.is_empty() {
+            os.write_string(1, &self.request_id)?;
+        }
+        if !self.serving_data.is_empty() {
+            os.write_string(2, &self.serving_data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestInfo {
+        RequestInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "request_id",
+                |m: &RequestInfo| { &m.request_id },
+                |m: &mut RequestInfo| { &mut m.request_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "serving_data",
+                |m: &RequestInfo| { &m.serving_data },
+                |m: &mut RequestInfo| { &mut m.serving_data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestInfo>(
+                "RequestInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestInfo {
+        static instance: ::protobuf::rt::LazyV2<RequestInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestInfo {
+    fn clear(&mut self) {
+        self.request_id.clear();
+        self.serving_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceInfo {
+    // message fields
+    pub resource_type: ::std::string::String,
+    pub resource_name: ::std::string::String,
+    pub owner: ::std::string::String,
+    pub description: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResourceInfo {
+    fn default() -> &'a ResourceInfo {
+        <ResourceInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResourceInfo {
+    pub fn new() -> ResourceInfo {
+        ::std::default::Default::default()
+    }
+
+    // string resource_type = 1;
+
+
+    pub fn get_resource_type(&self) -> &str {
+        &self.resource_type
+    }
+    pub fn clear_resource_type(&mut self) {
+        self.resource_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource_type(&mut self, v: ::std::string::String) {
+        self.resource_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource_type(&mut self) -> &mut ::std::string::String {
+        &mut self.resource_type
+    }
+
+    // Take field
+    pub fn take_resource_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource_type, ::std::string::String::new())
+    }
+
+    // string resource_name = 2;
+
+
+    pub fn get_resource_name(&self) -> &str {
+        &self.resource_name
+    }
+    pub fn clear_resource_name(&mut self) {
+        self.resource_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource_name(&mut self, v: ::std::string::String) {
+        self.resource_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource_name(&mut self) -> &mut ::std::string::String {
+        &mut self.resource_name
+    }
+
+    // Take field
+    pub fn take_resource_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource_name, ::std::string::String::new())
+    }
+
+    // string owner = 3;
+
+
+    pub fn get_owner(&self) -> &str {
+        &self.owner
+    }
+    pub fn clear_owner(&mut self) {
+        self.owner.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner(&mut self, v: ::std::string::String) {
+        self.owner = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_owner(&mut self) -> &mut ::std::string::String {
+        &mut self.owner
+    }
+
+    // Take field
+    pub fn take_owner(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.owner, ::std::string::String::new())
+    }
+
+    // string description = 4;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ResourceInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource_type)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.owner)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.resource_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.resource_type);
+        }
+        if !self.resource_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.resource_name);
+        }
+        if !self.owner.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.owner);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.description);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.resource_type.is_empty() {
+            os.write_string(1, &self.resource_type)?;
+        }
+        if !self.resource_name.is_empty() {
+            os.write_string(2, &self.resource_name)?;
+        }
+        if !self.owner.is_empty() {
+            os.write_string(3, &self.owner)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(4, &self.description)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResourceInfo {
+        ResourceInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource_type",
+                |m: &ResourceInfo| { &m.resource_type },
+                |m: &mut ResourceInfo| { &mut m.resource_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource_name",
+                |m: &ResourceInfo| { &m.resource_name },
+                |m: &mut ResourceInfo| { &mut m.resource_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "owner",
+                |m: &ResourceInfo| { &m.owner },
+                |m: &mut ResourceInfo| { &mut m.owner },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &ResourceInfo| { &m.description },
+                |m: &mut ResourceInfo| { &mut m.description },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResourceInfo>(
+                "ResourceInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResourceInfo {
+        static instance: ::protobuf::rt::LazyV2<ResourceInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResourceInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for ResourceInfo {
+    fn clear(&mut self) {
+        self.resource_type.clear();
+        self.resource_name.clear();
+        self.owner.clear();
+        self.description.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Help {
+    // message fields
+    pub links: ::protobuf::RepeatedField<Help_Link>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Help {
+    fn default() -> &'a Help {
+        <Help as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Help {
+    pub fn new() -> Help {
+        ::std::default::Default::default()
+    }
+
+    // repeated .google.rpc.Help.Link links = 1;
+
+
+    pub fn get_links(&self) -> &[Help_Link] {
+        &self.links
+    }
+    pub fn clear_links(&mut self) {
+        self.links.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_links(&mut self, v: ::protobuf::RepeatedField<Help_Link>) {
+        self.links = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_links(&mut self) -> &mut ::protobuf::RepeatedField<Help_Link> {
+        &mut self.links
+    }
+
+    // Take field
+    pub fn take_links(&mut self) -> ::protobuf::RepeatedField<Help_Link> {
+        ::std::mem::replace(&mut self.links, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Help {
+    fn is_initialized(&self) -> bool {
+        for v in &self.links {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.links)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.links {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.links {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Help {
+        Help::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Help_Link>>(
+                "links",
+                |m: &Help| { &m.links },
+                |m: &mut Help| { &mut m.links },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Help>(
+                "Help",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Help {
+        static instance: ::protobuf::rt::LazyV2<Help> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Help::new)
+    }
+}
+
+impl ::protobuf::Clear for Help {
+    fn clear(&mut self) {
+        self.links.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Help {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Help {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Help_Link {
+    // message fields
+    pub description: ::std::string::String,
+    pub url: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Help_Link {
+    fn default() -> &'a Help_Link {
+        <Help_Link as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Help_Link {
+    pub fn new() -> Help_Link {
+        ::std::default::Default::default()
+    }
+
+    // string description = 1;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+
+    // string url = 2;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Help_Link {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.description);
+        }
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.url);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.description.is_empty() {
+            os.write_string(1, &self.description)?;
+        }
+        if !self.url.is_empty() {
+            os.write_string(2, &self.url)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Help_Link {
+        Help_Link::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &Help_Link| { &m.description },
+                |m: &mut Help_Link| { &mut m.description },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &Help_Link| { &m.url },
+                |m: &mut Help_Link| { &mut m.url },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Help_Link>(
+                "Help.Link",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Help_Link {
+        static instance: ::protobuf::rt::LazyV2<Help_Link> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Help_Link::new)
+    }
+}
+
+impl ::protobuf::Clear for Help_Link {
+    fn clear(&mut self) {
+        self.description.clear();
+        self.url.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Help_Link {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Help_Link {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LocalizedMessage {
+    // message fields
+    pub locale: ::std::string::String,
+    pub message: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LocalizedMessage {
+    fn default() -> &'a LocalizedMessage {
+        <LocalizedMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LocalizedMessage {
+    pub fn new() -> LocalizedMessage {
+        ::std::default::Default::default()
+    }
+
+    // string locale = 1;
+
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+    pub fn clear_locale(&mut self) {
+        self.locale.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locale(&mut self, v: ::std::string::String) {
+        self.locale = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locale(&mut self) -> &mut ::std::string::String {
+        &mut self.locale
+    }
+
+    // Take field
+    pub fn take_locale(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.locale, ::std::string::String::new())
+    }
+
+    // string message = 2;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for LocalizedMessage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.locale)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.locale.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.locale);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.locale.is_empty() {
+            os.write_string(1, &self.locale)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(2, &self.message)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LocalizedMessage {
+        LocalizedMessage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "locale",
+                |m: &LocalizedMessage| { &m.locale },
+                |m: &mut LocalizedMessage| { &mut m.locale },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &LocalizedMessage| { &m.message },
+                |m: &mut LocalizedMessage| { &mut m.message },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LocalizedMessage>(
+                "LocalizedMessage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LocalizedMessage {
+        static instance: ::protobuf::rt::LazyV2<LocalizedMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LocalizedMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for LocalizedMessage {
+    fn clear(&mut self) {
+        self.locale.clear();
+        self.message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LocalizedMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LocalizedMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1egoogle/rpc/error_details.proto\x12\ngoogle.rpc\x1a\x1egoogle/proto\
+    buf/duration.proto\"\xb9\x01\n\tErrorInfo\x12\x16\n\x06reason\x18\x01\
+    \x20\x01(\tR\x06reason\x12\x16\n\x06domain\x18\x02\x20\x01(\tR\x06domain\
+    \x12?\n\x08metadata\x18\x03\x20\x03(\x0b2#.google.rpc.ErrorInfo.Metadata\
+    EntryR\x08metadata\x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\
+    \x01\"G\n\tRetryInfo\x12:\n\x0bretry_delay\x18\x01\x20\x01(\x0b2\x19.goo\
+    gle.protobuf.DurationR\nretryDelay\"H\n\tDebugInfo\x12#\n\rstack_entries\
+    \x18\x01\x20\x03(\tR\x0cstackEntries\x12\x16\n\x06detail\x18\x02\x20\x01\
+    (\tR\x06detail\"\x8e\x04\n\x0cQuotaFailure\x12B\n\nviolations\x18\x01\
+    \x20\x03(\x0b2\".google.rpc.QuotaFailure.ViolationR\nviolations\x1a\xb9\
+    \x03\n\tViolation\x12\x18\n\x07subject\x18\x01\x20\x01(\tR\x07subject\
+    \x12\x20\n\x0bdescription\x18\x02\x20\x01(\tR\x0bdescription\x12\x1f\n\
+    \x0bapi_service\x18\x03\x20\x01(\tR\napiService\x12!\n\x0cquota_metric\
+    \x18\x04\x20\x01(\tR\x0bquotaMetric\x12\x19\n\x08quota_id\x18\x05\x20\
+    \x01(\tR\x07quotaId\x12b\n\x10quota_dimensions\x18\x06\x20\x03(\x0b27.go\
+    ogle.rpc.QuotaFailure.Violation.QuotaDimensionsEntryR\x0fquotaDimensions\
+    \x12\x1f\n\x0bquota_value\x18\x07\x20\x01(\x03R\nquotaValue\x121\n\x12fu\
+    ture_quota_value\x18\x08\x20\x01(\x03H\0R\x10futureQuotaValue\x88\x01\
+    \x01\x1aB\n\x14QuotaDimensionsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01B\x15\n\
+    \x13_future_quota_value\"\xbd\x01\n\x13PreconditionFailure\x12I\n\nviola\
+    tions\x18\x01\x20\x03(\x0b2).google.rpc.PreconditionFailure.ViolationR\n\
+    violations\x1a[\n\tViolation\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04ty\
+    pe\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07subject\x12\x20\n\x0bdesc\
+    ription\x18\x03\x20\x01(\tR\x0bdescription\"\x8c\x02\n\nBadRequest\x12P\
+    \n\x10field_violations\x18\x01\x20\x03(\x0b2%.google.rpc.BadRequest.Fiel\
+    dViolationR\x0ffieldViolations\x1a\xab\x01\n\x0eFieldViolation\x12\x14\n\
+    \x05field\x18\x01\x20\x01(\tR\x05field\x12\x20\n\x0bdescription\x18\x02\
+    \x20\x01(\tR\x0bdescription\x12\x16\n\x06reason\x18\x03\x20\x01(\tR\x06r\
+    eason\x12I\n\x11localized_message\x18\x04\x20\x01(\x0b2\x1c.google.rpc.L\
+    ocalizedMessageR\x10localizedMessage\"O\n\x0bRequestInfo\x12\x1d\n\nrequ\
+    est_id\x18\x01\x20\x01(\tR\trequestId\x12!\n\x0cserving_data\x18\x02\x20\
+    \x01(\tR\x0bservingData\"\x90\x01\n\x0cResourceInfo\x12#\n\rresource_typ\
+    e\x18\x01\x20\x01(\tR\x0cresourceType\x12#\n\rresource_name\x18\x02\x20\
+    \x01(\tR\x0cresourceName\x12\x14\n\x05owner\x18\x03\x20\x01(\tR\x05owner\
+    \x12\x20\n\x0bdescription\x18\x04\x20\x01(\tR\x0bdescription\"o\n\x04Hel\
+    p\x12+\n\x05links\x18\x01\x20\x03(\x0b2\x15.google.rpc.Help.LinkR\x05lin\
+    ks\x1a:\n\x04Link\x12\x20\n\x0bdescription\x18\x01\x20\x01(\tR\x0bdescri\
+    ption\x12\x10\n\x03url\x18\x02\x20\x01(\tR\x03url\"D\n\x10LocalizedMessa\
+    ge\x12\x16\n\x06locale\x18\x01\x20\x01(\tR\x06locale\x12\x18\n\x07messag\
+    e\x18\x02\x20\x01(\tR\x07messageBl\n\x0ecom.google.rpcB\x11ErrorDetailsP\
+    rotoP\x01Z?google.golang.org/genproto/googleapis/rpc/errdetails;errdetai\
+    ls\xa2\x02\x03RPCb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
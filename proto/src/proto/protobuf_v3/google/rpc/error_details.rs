@@ -0,0 +1,2245 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `google/rpc/error_details.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobufv3::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:google.rpc.ErrorInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ErrorInfo {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.ErrorInfo.reason)
+    pub reason: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.ErrorInfo.domain)
+    pub domain: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.ErrorInfo.metadata)
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.ErrorInfo.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorInfo {
+    fn default() -> &'a ErrorInfo {
+        <ErrorInfo as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ErrorInfo {
+    pub fn new() -> ErrorInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reason",
+            |m: &ErrorInfo| { &m.reason },
+            |m: &mut ErrorInfo| { &mut m.reason },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "domain",
+            |m: &ErrorInfo| { &m.domain },
+            |m: &mut ErrorInfo| { &mut m.domain },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "metadata",
+            |m: &ErrorInfo| { &m.metadata },
+            |m: &mut ErrorInfo| { &mut m.metadata },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ErrorInfo>(
+            "ErrorInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ErrorInfo {
+    const NAME: &'static str = "ErrorInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.reason = is.read_string()?;
+                },
+                18 => {
+                    self.domain = is.read_string()?;
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobufv3::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.metadata.insert(key, value);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.reason.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.reason);
+        }
+        if !self.domain.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.domain);
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobufv3::rt::string_size(1, &k);
+            entry_size += ::protobufv3::rt::string_size(2, &v);
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.reason.is_empty() {
+            os.write_string(1, &self.reason)?;
+        }
+        if !self.domain.is_empty() {
+            os.write_string(2, &self.domain)?;
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobufv3::rt::string_size(1, &k);
+            entry_size += ::protobufv3::rt::string_size(2, &v);
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ErrorInfo {
+        ErrorInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.reason.clear();
+        self.domain.clear();
+        self.metadata.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ErrorInfo {
+        static instance: ::protobufv3::rt::Lazy<ErrorInfo> = ::protobufv3::rt::Lazy::new();
+        instance.get(ErrorInfo::new)
+    }
+}
+
+impl ::protobufv3::MessageFull for ErrorInfo {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ErrorInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ErrorInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ErrorInfo {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:google.rpc.RetryInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RetryInfo {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.RetryInfo.retry_delay)
+    pub retry_delay: ::protobufv3::MessageField<::protobufv3::well_known_types::duration::Duration>,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.RetryInfo.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RetryInfo {
+    fn default() -> &'a RetryInfo {
+        <RetryInfo as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl RetryInfo {
+    pub fn new() -> RetryInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ::protobufv3::well_known_types::duration::Duration>(
+            "retry_delay",
+            |m: &RetryInfo| { &m.retry_delay },
+            |m: &mut RetryInfo| { &mut m.retry_delay },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<RetryInfo>(
+            "RetryInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for RetryInfo {
+    const NAME: &'static str = "RetryInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.retry_delay)?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.retry_delay.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if let Some(v) = self.retry_delay.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RetryInfo {
+        RetryInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.retry_delay.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RetryInfo {
+        static instance: RetryInfo = RetryInfo {
+            retry_delay: ::protobufv3::MessageField::none(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for RetryInfo {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RetryInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RetryInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for RetryInfo {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:google.rpc.DebugInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DebugInfo {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.DebugInfo.stack_entries)
+    pub stack_entries: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:google.rpc.DebugInfo.detail)
+    pub detail: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.DebugInfo.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DebugInfo {
+    fn default() -> &'a DebugInfo {
+        <DebugInfo as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl DebugInfo {
+    pub fn new() -> DebugInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "stack_entries",
+            |m: &DebugInfo| { &m.stack_entries },
+            |m: &mut DebugInfo| { &mut m.stack_entries },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "detail",
+            |m: &DebugInfo| { &m.detail },
+            |m: &mut DebugInfo| { &mut m.detail },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<DebugInfo>(
+            "DebugInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for DebugInfo {
+    const NAME: &'static str = "DebugInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.stack_entries.push(is.read_string()?);
+                },
+                18 => {
+                    self.detail = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.stack_entries {
+            my_size += ::protobufv3::rt::string_size(1, &value);
+        };
+        if !self.detail.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.detail);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.stack_entries {
+            os.write_string(1, &v)?;
+        };
+        if !self.detail.is_empty() {
+            os.write_string(2, &self.detail)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DebugInfo {
+        DebugInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.stack_entries.clear();
+        self.detail.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DebugInfo {
+        static instance: DebugInfo = DebugInfo {
+            stack_entries: ::std::vec::Vec::new(),
+            detail: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for DebugInfo {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DebugInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DebugInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for DebugInfo {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:google.rpc.QuotaFailure)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct QuotaFailure {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.QuotaFailure.violations)
+    pub violations: ::std::vec::Vec<quota_failure::Violation>,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.QuotaFailure.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a QuotaFailure {
+    fn default() -> &'a QuotaFailure {
+        <QuotaFailure as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl QuotaFailure {
+    pub fn new() -> QuotaFailure {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "violations",
+            |m: &QuotaFailure| { &m.violations },
+            |m: &mut QuotaFailure| { &mut m.violations },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<QuotaFailure>(
+            "QuotaFailure",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for QuotaFailure {
+    const NAME: &'static str = "QuotaFailure";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.violations.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.violations {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> QuotaFailure {
+        QuotaFailure::new()
+    }
+
+    fn clear(&mut self) {
+        self.violations.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static QuotaFailure {
+        static instance: QuotaFailure = QuotaFailure {
+            violations: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for QuotaFailure {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("QuotaFailure").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for QuotaFailure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for QuotaFailure {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `QuotaFailure`
+pub mod quota_failure {
+    // @@protoc_insertion_point(message:google.rpc.QuotaFailure.Violation)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct Violation {
+        // message fields
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.subject)
+        pub subject: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.description)
+        pub description: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.api_service)
+        pub api_service: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.quota_metric)
+        pub quota_metric: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.quota_id)
+        pub quota_id: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.quota_dimensions)
+        pub quota_dimensions: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.quota_value)
+        pub quota_value: i64,
+        // @@protoc_insertion_point(field:google.rpc.QuotaFailure.Violation.future_quota_value)
+        pub future_quota_value: ::std::option::Option<i64>,
+        // special fields
+        // @@protoc_insertion_point(special_field:google.rpc.QuotaFailure.Violation.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Violation {
+        fn default() -> &'a Violation {
+            <Violation as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl Violation {
+        pub fn new() -> Violation {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(8);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "subject",
+                |m: &Violation| { &m.subject },
+                |m: &mut Violation| { &mut m.subject },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "description",
+                |m: &Violation| { &m.description },
+                |m: &mut Violation| { &mut m.description },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "api_service",
+                |m: &Violation| { &m.api_service },
+                |m: &mut Violation| { &mut m.api_service },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "quota_metric",
+                |m: &Violation| { &m.quota_metric },
+                |m: &mut Violation| { &mut m.quota_metric },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "quota_id",
+                |m: &Violation| { &m.quota_id },
+                |m: &mut Violation| { &mut m.quota_id },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+                "quota_dimensions",
+                |m: &Violation| { &m.quota_dimensions },
+                |m: &mut Violation| { &mut m.quota_dimensions },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "quota_value",
+                |m: &Violation| { &m.quota_value },
+                |m: &mut Violation| { &mut m.quota_value },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_option_accessor::<_, _>(
+                "future_quota_value",
+                |m: &Violation| { &m.future_quota_value },
+                |m: &mut Violation| { &mut m.future_quota_value },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Violation>(
+                "QuotaFailure.Violation",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for Violation {
+        const NAME: &'static str = "Violation";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.subject = is.read_string()?;
+                    },
+                    18 => {
+                        self.description = is.read_string()?;
+                    },
+                    26 => {
+                        self.api_service = is.read_string()?;
+                    },
+                    34 => {
+                        self.quota_metric = is.read_string()?;
+                    },
+                    42 => {
+                        self.quota_id = is.read_string()?;
+                    },
+                    50 => {
+                        let len = is.read_raw_varint32()?;
+                        let old_limit = is.push_limit(len as u64)?;
+                        let mut key = ::std::default::Default::default();
+                        let mut value = ::std::default::Default::default();
+                        while let Some(tag) = is.read_raw_tag_or_eof()? {
+                            match tag {
+                                10 => key = is.read_string()?,
+                                18 => value = is.read_string()?,
+                                _ => ::protobufv3::rt::skip_field_for_tag(tag, is)?,
+                            };
+                        }
+                        is.pop_limit(old_limit);
+                        self.quota_dimensions.insert(key, value);
+                    },
+                    56 => {
+                        self.quota_value = is.read_int64()?;
+                    },
+                    64 => {
+                        self.future_quota_value = ::std::option::Option::Some(is.read_int64()?);
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.subject.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.subject);
+            }
+            if !self.description.is_empty() {
+                my_size += ::protobufv3::rt::string_size(2, &self.description);
+            }
+            if !self.api_service.is_empty() {
+                my_size += ::protobufv3::rt::string_size(3, &self.api_service);
+            }
+            if !self.quota_metric.is_empty() {
+                my_size += ::protobufv3::rt::string_size(4, &self.quota_metric);
+            }
+            if !self.quota_id.is_empty() {
+                my_size += ::protobufv3::rt::string_size(5, &self.quota_id);
+            }
+            for (k, v) in &self.quota_dimensions {
+                let mut entry_size = 0;
+                entry_size += ::protobufv3::rt::string_size(1, &k);
+                entry_size += ::protobufv3::rt::string_size(2, &v);
+                my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(entry_size) + entry_size
+            };
+            if self.quota_value != 0 {
+                my_size += ::protobufv3::rt::int64_size(7, self.quota_value);
+            }
+            if let Some(v) = self.future_quota_value {
+                my_size += ::protobufv3::rt::int64_size(8, v);
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.subject.is_empty() {
+                os.write_string(1, &self.subject)?;
+            }
+            if !self.description.is_empty() {
+                os.write_string(2, &self.description)?;
+            }
+            if !self.api_service.is_empty() {
+                os.write_string(3, &self.api_service)?;
+            }
+            if !self.quota_metric.is_empty() {
+                os.write_string(4, &self.quota_metric)?;
+            }
+            if !self.quota_id.is_empty() {
+                os.write_string(5, &self.quota_id)?;
+            }
+            for (k, v) in &self.quota_dimensions {
+                let mut entry_size = 0;
+                entry_size += ::protobufv3::rt::string_size(1, &k);
+                entry_size += ::protobufv3::rt::string_size(2, &v);
+                os.write_raw_varint32(50)?; // Tag.
+                os.write_raw_varint32(entry_size as u32)?;
+                os.write_string(1, &k)?;
+                os.write_string(2, &v)?;
+            };
+            if self.quota_value != 0 {
+                os.write_int64(7, self.quota_value)?;
+            }
+            if let Some(v) = self.future_quota_value {
+                os.write_int64(8, v)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Violation {
+            Violation::new()
+        }
+
+        fn clear(&mut self) {
+            self.subject.clear();
+            self.description.clear();
+            self.api_service.clear();
+            self.quota_metric.clear();
+            self.quota_id.clear();
+            self.quota_dimensions.clear();
+            self.quota_value = 0;
+            self.future_quota_value = ::std::option::Option::None;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Violation {
+            static instance: ::protobufv3::rt::Lazy<Violation> = ::protobufv3::rt::Lazy::new();
+            instance.get(Violation::new)
+        }
+    }
+
+    impl ::protobufv3::MessageFull for Violation {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("QuotaFailure.Violation").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Violation {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for Violation {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+// @@protoc_insertion_point(message:google.rpc.PreconditionFailure)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PreconditionFailure {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.PreconditionFailure.violations)
+    pub violations: ::std::vec::Vec<precondition_failure::Violation>,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.PreconditionFailure.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PreconditionFailure {
+    fn default() -> &'a PreconditionFailure {
+        <PreconditionFailure as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl PreconditionFailure {
+    pub fn new() -> PreconditionFailure {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "violations",
+            |m: &PreconditionFailure| { &m.violations },
+            |m: &mut PreconditionFailure| { &mut m.violations },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<PreconditionFailure>(
+            "PreconditionFailure",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for PreconditionFailure {
+    const NAME: &'static str = "PreconditionFailure";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.violations.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.violations {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PreconditionFailure {
+        PreconditionFailure::new()
+    }
+
+    fn clear(&mut self) {
+        self.violations.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PreconditionFailure {
+        static instance: PreconditionFailure = PreconditionFailure {
+            violations: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for PreconditionFailure {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PreconditionFailure").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PreconditionFailure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for PreconditionFailure {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `PreconditionFailure`
+pub mod precondition_failure {
+    // @@protoc_insertion_point(message:google.rpc.PreconditionFailure.Violation)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct Violation {
+        // message fields
+        // @@protoc_insertion_point(field:google.rpc.PreconditionFailure.Violation.type)
+        pub type_: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.PreconditionFailure.Violation.subject)
+        pub subject: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.PreconditionFailure.Violation.description)
+        pub description: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:google.rpc.PreconditionFailure.Violation.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Violation {
+        fn default() -> &'a Violation {
+            <Violation as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl Violation {
+        pub fn new() -> Violation {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "type",
+                |m: &Violation| { &m.type_ },
+                |m: &mut Violation| { &mut m.type_ },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "subject",
+                |m: &Violation| { &m.subject },
+                |m: &mut Violation| { &mut m.subject },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "description",
+                |m: &Violation| { &m.description },
+                |m: &mut Violation| { &mut m.description },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Violation>(
+                "PreconditionFailure.Violation",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for Violation {
+        const NAME: &'static str = "Violation";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.type_ = is.read_string()?;
+                    },
+                    18 => {
+                        self.subject = is.read_string()?;
+                    },
+                    26 => {
+                        self.description = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.type_.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.type_);
+            }
+            if !self.subject.is_empty() {
+                my_size += ::protobufv3::rt::string_size(2, &self.subject);
+            }
+            if !self.description.is_empty() {
+                my_size += ::protobufv3::rt::string_size(3, &self.description);
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.type_.is_empty() {
+                os.write_string(1, &self.type_)?;
+            }
+            if !self.subject.is_empty() {
+                os.write_string(2, &self.subject)?;
+            }
+            if !self.description.is_empty() {
+                os.write_string(3, &self.description)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Violation {
+            Violation::new()
+        }
+
+        fn clear(&mut self) {
+            self.type_.clear();
+            self.subject.clear();
+            self.description.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Violation {
+            static instance: Violation = Violation {
+                type_: ::std::string::String::new(),
+                subject: ::std::string::String::new(),
+                description: ::std::string::String::new(),
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for Violation {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("PreconditionFailure.Violation").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Violation {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for Violation {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+// @@protoc_insertion_point(message:google.rpc.BadRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BadRequest {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.BadRequest.field_violations)
+    pub field_violations: ::std::vec::Vec<bad_request::FieldViolation>,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.BadRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BadRequest {
+    fn default() -> &'a BadRequest {
+        <BadRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl BadRequest {
+    pub fn new() -> BadRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "field_violations",
+            |m: &BadRequest| { &m.field_violations },
+            |m: &mut BadRequest| { &mut m.field_violations },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<BadRequest>(
+            "BadRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for BadRequest {
+    const NAME: &'static str = "BadRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.field_violations.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.field_violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.field_violations {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BadRequest {
+        BadRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.field_violations.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BadRequest {
+        static instance: BadRequest = BadRequest {
+            field_violations: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for BadRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BadRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BadRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for BadRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `BadRequest`
+pub mod bad_request {
+    // @@protoc_insertion_point(message:google.rpc.BadRequest.FieldViolation)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct FieldViolation {
+        // message fields
+        // @@protoc_insertion_point(field:google.rpc.BadRequest.FieldViolation.field)
+        pub field: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.BadRequest.FieldViolation.description)
+        pub description: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.BadRequest.FieldViolation.reason)
+        pub reason: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.BadRequest.FieldViolation.localized_message)
+        pub localized_message: ::protobufv3::MessageField<super::LocalizedMessage>,
+        // special fields
+        // @@protoc_insertion_point(special_field:google.rpc.BadRequest.FieldViolation.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a FieldViolation {
+        fn default() -> &'a FieldViolation {
+            <FieldViolation as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl FieldViolation {
+        pub fn new() -> FieldViolation {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "field",
+                |m: &FieldViolation| { &m.field },
+                |m: &mut FieldViolation| { &mut m.field },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "description",
+                |m: &FieldViolation| { &m.description },
+                |m: &mut FieldViolation| { &mut m.description },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "reason",
+                |m: &FieldViolation| { &m.reason },
+                |m: &mut FieldViolation| { &mut m.reason },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, super::LocalizedMessage>(
+                "localized_message",
+                |m: &FieldViolation| { &m.localized_message },
+                |m: &mut FieldViolation| { &mut m.localized_message },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<FieldViolation>(
+                "BadRequest.FieldViolation",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for FieldViolation {
+        const NAME: &'static str = "FieldViolation";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.field = is.read_string()?;
+                    },
+                    18 => {
+                        self.description = is.read_string()?;
+                    },
+                    26 => {
+                        self.reason = is.read_string()?;
+                    },
+                    34 => {
+                        ::protobufv3::rt::read_singular_message_into_field(is, &mut self.localized_message)?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.field.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.field);
+            }
+            if !self.description.is_empty() {
+                my_size += ::protobufv3::rt::string_size(2, &self.description);
+            }
+            if !self.reason.is_empty() {
+                my_size += ::protobufv3::rt::string_size(3, &self.reason);
+            }
+            if let Some(v) = self.localized_message.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.field.is_empty() {
+                os.write_string(1, &self.field)?;
+            }
+            if !self.description.is_empty() {
+                os.write_string(2, &self.description)?;
+            }
+            if !self.reason.is_empty() {
+                os.write_string(3, &self.reason)?;
+            }
+            if let Some(v) = self.localized_message.as_ref() {
+                ::protobufv3::rt::write_message_field_with_cached_size(4, v, os)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> FieldViolation {
+            FieldViolation::new()
+        }
+
+        fn clear(&mut self) {
+            self.field.clear();
+            self.description.clear();
+            self.reason.clear();
+            self.localized_message.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static FieldViolation {
+            static instance: FieldViolation = FieldViolation {
+                field: ::std::string::String::new(),
+                description: ::std::string::String::new(),
+                reason: ::std::string::String::new(),
+                localized_message: ::protobufv3::MessageField::none(),
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for FieldViolation {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("BadRequest.FieldViolation").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for FieldViolation {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for FieldViolation {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+// @@protoc_insertion_point(message:google.rpc.RequestInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestInfo {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.RequestInfo.request_id)
+    pub request_id: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.RequestInfo.serving_data)
+    pub serving_data: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.RequestInfo.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RequestInfo {
+    fn default() -> &'a RequestInfo {
+        <RequestInfo as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl RequestInfo {
+    pub fn new() -> RequestInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "request_id",
+            |m: &RequestInfo| { &m.request_id },
+            |m: &mut RequestInfo| { &mut m.request_id },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "serving_data",
+            |m: &RequestInfo| { &m.serving_data },
+            |m: &mut RequestInfo| { &mut m.serving_data },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<RequestInfo>(
+            "RequestInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for RequestInfo {
+    const NAME: &'static str = "RequestInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.request_id = is.read_string()?;
+                },
+                18 => {
+                    self.serving_data = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.request_id.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.request_id);
+        }
+        if !self.serving_data.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.serving_data);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.request_id.is_empty() {
+            os.write_string(1, &self.request_id)?;
+        }
+        if !self.serving_data.is_empty() {
+            os.write_string(2, &self.serving_data)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RequestInfo {
+        RequestInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.request_id.clear();
+        self.serving_data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RequestInfo {
+        static instance: RequestInfo = RequestInfo {
+            request_id: ::std::string::String::new(),
+            serving_data: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for RequestInfo {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RequestInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RequestInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for RequestInfo {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:google.rpc.ResourceInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ResourceInfo {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.ResourceInfo.resource_type)
+    pub resource_type: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.ResourceInfo.resource_name)
+    pub resource_name: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.ResourceInfo.owner)
+    pub owner: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.ResourceInfo.description)
+    pub description: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.ResourceInfo.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ResourceInfo {
+    fn default() -> &'a ResourceInfo {
+        <ResourceInfo as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ResourceInfo {
+    pub fn new() -> ResourceInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "resource_type",
+            |m: &ResourceInfo| { &m.resource_type },
+            |m: &mut ResourceInfo| { &mut m.resource_type },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "resource_name",
+            |m: &ResourceInfo| { &m.resource_name },
+            |m: &mut ResourceInfo| { &mut m.resource_name },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner",
+            |m: &ResourceInfo| { &m.owner },
+            |m: &mut ResourceInfo| { &mut m.owner },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "description",
+            |m: &ResourceInfo| { &m.description },
+            |m: &mut ResourceInfo| { &mut m.description },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ResourceInfo>(
+            "ResourceInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ResourceInfo {
+    const NAME: &'static str = "ResourceInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.resource_type = is.read_string()?;
+                },
+                18 => {
+                    self.resource_name = is.read_string()?;
+                },
+                26 => {
+                    self.owner = is.read_string()?;
+                },
+                34 => {
+                    self.description = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.resource_type.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.resource_type);
+        }
+        if !self.resource_name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.resource_name);
+        }
+        if !self.owner.is_empty() {
+            my_size += ::protobufv3::rt::string_size(3, &self.owner);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobufv3::rt::string_size(4, &self.description);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.resource_type.is_empty() {
+            os.write_string(1, &self.resource_type)?;
+        }
+        if !self.resource_name.is_empty() {
+            os.write_string(2, &self.resource_name)?;
+        }
+        if !self.owner.is_empty() {
+            os.write_string(3, &self.owner)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(4, &self.description)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ResourceInfo {
+        ResourceInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.resource_type.clear();
+        self.resource_name.clear();
+        self.owner.clear();
+        self.description.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ResourceInfo {
+        static instance: ResourceInfo = ResourceInfo {
+            resource_type: ::std::string::String::new(),
+            resource_name: ::std::string::String::new(),
+            owner: ::std::string::String::new(),
+            description: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ResourceInfo {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ResourceInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ResourceInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ResourceInfo {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:google.rpc.Help)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Help {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.Help.links)
+    pub links: ::std::vec::Vec<help::Link>,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.Help.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Help {
+    fn default() -> &'a Help {
+        <Help as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl Help {
+    pub fn new() -> Help {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "links",
+            |m: &Help| { &m.links },
+            |m: &mut Help| { &mut m.links },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Help>(
+            "Help",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for Help {
+    const NAME: &'static str = "Help";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.links.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.links {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.links {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Help {
+        Help::new()
+    }
+
+    fn clear(&mut self) {
+        self.links.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Help {
+        static instance: Help = Help {
+            links: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for Help {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Help").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Help {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for Help {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Help`
+pub mod help {
+    // @@protoc_insertion_point(message:google.rpc.Help.Link)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    pub struct Link {
+        // message fields
+        // @@protoc_insertion_point(field:google.rpc.Help.Link.description)
+        pub description: ::std::string::String,
+        // @@protoc_insertion_point(field:google.rpc.Help.Link.url)
+        pub url: ::std::string::String,
+        // special fields
+        // @@protoc_insertion_point(special_field:google.rpc.Help.Link.special_fields)
+        pub special_fields: ::protobufv3::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Link {
+        fn default() -> &'a Link {
+            <Link as ::protobufv3::Message>::default_instance()
+        }
+    }
+
+    impl Link {
+        pub fn new() -> Link {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "description",
+                |m: &Link| { &m.description },
+                |m: &mut Link| { &mut m.description },
+            ));
+            fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "url",
+                |m: &Link| { &m.url },
+                |m: &mut Link| { &mut m.url },
+            ));
+            ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<Link>(
+                "Help.Link",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobufv3::Message for Link {
+        const NAME: &'static str = "Link";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        self.description = is.read_string()?;
+                    },
+                    18 => {
+                        self.url = is.read_string()?;
+                    },
+                    tag => {
+                        ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if !self.description.is_empty() {
+                my_size += ::protobufv3::rt::string_size(1, &self.description);
+            }
+            if !self.url.is_empty() {
+                my_size += ::protobufv3::rt::string_size(2, &self.url);
+            }
+            my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+            if !self.description.is_empty() {
+                os.write_string(1, &self.description)?;
+            }
+            if !self.url.is_empty() {
+                os.write_string(2, &self.url)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobufv3::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Link {
+            Link::new()
+        }
+
+        fn clear(&mut self) {
+            self.description.clear();
+            self.url.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Link {
+            static instance: Link = Link {
+                description: ::std::string::String::new(),
+                url: ::std::string::String::new(),
+                special_fields: ::protobufv3::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobufv3::MessageFull for Link {
+        fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Help.Link").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Link {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobufv3::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobufv3::reflect::ProtobufValue for Link {
+        type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+// @@protoc_insertion_point(message:google.rpc.LocalizedMessage)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LocalizedMessage {
+    // message fields
+    // @@protoc_insertion_point(field:google.rpc.LocalizedMessage.locale)
+    pub locale: ::std::string::String,
+    // @@protoc_insertion_point(field:google.rpc.LocalizedMessage.message)
+    pub message: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:google.rpc.LocalizedMessage.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LocalizedMessage {
+    fn default() -> &'a LocalizedMessage {
+        <LocalizedMessage as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl LocalizedMessage {
+    pub fn new() -> LocalizedMessage {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "locale",
+            |m: &LocalizedMessage| { &m.locale },
+            |m: &mut LocalizedMessage| { &mut m.locale },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &LocalizedMessage| { &m.message },
+            |m: &mut LocalizedMessage| { &mut m.message },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<LocalizedMessage>(
+            "LocalizedMessage",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for LocalizedMessage {
+    const NAME: &'static str = "LocalizedMessage";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.locale = is.read_string()?;
+                },
+                18 => {
+                    self.message = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.locale.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.locale);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.message);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.locale.is_empty() {
+            os.write_string(1, &self.locale)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(2, &self.message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LocalizedMessage {
+        LocalizedMessage::new()
+    }
+
+    fn clear(&mut self) {
+        self.locale.clear();
+        self.message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LocalizedMessage {
+        static instance: LocalizedMessage = LocalizedMessage {
+            locale: ::std::string::String::new(),
+            message: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for LocalizedMessage {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LocalizedMessage").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LocalizedMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for LocalizedMessage {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1egoogle/rpc/error_details.proto\x12\ngoogle.rpc\x1a\x1egoogle/proto\
+    buf/duration.proto\"\xb9\x01\n\tErrorInfo\x12\x16\n\x06reason\x18\x01\
+    \x20\x01(\tR\x06reason\x12\x16\n\x06domain\x18\x02\x20\x01(\tR\x06domain\
+    \x12?\n\x08metadata\x18\x03\x20\x03(\x0b2#.google.rpc.ErrorInfo.Metadata\
+    EntryR\x08metadata\x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\
+    \x01\"G\n\tRetryInfo\x12:\n\x0bretry_delay\x18\x01\x20\x01(\x0b2\x19.goo\
+    gle.protobuf.DurationR\nretryDelay\"H\n\tDebugInfo\x12#\n\rstack_entries\
+    \x18\x01\x20\x03(\tR\x0cstackEntries\x12\x16\n\x06detail\x18\x02\x20\x01\
+    (\tR\x06detail\"\x8e\x04\n\x0cQuotaFailure\x12B\n\nviolations\x18\x01\
+    \x20\x03(\x0b2\".google.rpc.QuotaFailure.ViolationR\nviolations\x1a\xb9\
+    \x03\n\tViolation\x12\x18\n\x07subject\x18\x01\x20\x01(\tR\x07subject\
+    \x12\x20\n\x0bdescription\x18\x02\x20\x01(\tR\x0bdescription\x12\x1f\n\
+    \x0bapi_service\x18\x03\x20\x01(\tR\napiService\x12!\n\x0cquota_metric\
+    \x18\x04\x20\x01(\tR\x0bquotaMetric\x12\x19\n\x08quota_id\x18\x05\x20\
+    \x01(\tR\x07quotaId\x12b\n\x10quota_dimensions\x18\x06\x20\x03(\x0b27.go\
+    ogle.rpc.QuotaFailure.Violation.QuotaDimensionsEntryR\x0fquotaDimensions\
+    \x12\x1f\n\x0bquota_value\x18\x07\x20\x01(\x03R\nquotaValue\x121\n\x12fu\
+    ture_quota_value\x18\x08\x20\x01(\x03H\0R\x10futureQuotaValue\x88\x01\
+    \x01\x1aB\n\x14QuotaDimensionsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01B\x15\n\
+    \x13_future_quota_value\"\xbd\x01\n\x13PreconditionFailure\x12I\n\nviola\
+    tions\x18\x01\x20\x03(\x0b2).google.rpc.PreconditionFailure.ViolationR\n\
+    violations\x1a[\n\tViolation\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04ty\
+    pe\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07subject\x12\x20\n\x0bdesc\
+    ription\x18\x03\x20\x01(\tR\x0bdescription\"\x8c\x02\n\nBadRequest\x12P\
+    \n\x10field_violations\x18\x01\x20\x03(\x0b2%.google.rpc.BadRequest.Fiel\
+    dViolationR\x0ffieldViolations\x1a\xab\x01\n\x0eFieldViolation\x12\x14\n\
+    \x05field\x18\x01\x20\x01(\tR\x05field\x12\x20\n\x0bdescription\x18\x02\
+    \x20\x01(\tR\x0bdescription\x12\x16\n\x06reason\x18\x03\x20\x01(\tR\x06r\
+    eason\x12I\n\x11localized_message\x18\x04\x20\x01(\x0b2\x1c.google.rpc.L\
+    ocalizedMessageR\x10localizedMessage\"O\n\x0bRequestInfo\x12\x1d\n\nrequ\
+    est_id\x18\x01\x20\x01(\tR\trequestId\x12!\n\x0cserving_data\x18\x02\x20\
+    \x01(\tR\x0bservingData\"\x90\x01\n\x0cResourceInfo\x12#\n\rresource_typ\
+    e\x18\x01\x20\x01(\tR\x0cresourceType\x12#\n\rresource_name\x18\x02\x20\
+    \x01(\tR\x0cresourceName\x12\x14\n\x05owner\x18\x03\x20\x01(\tR\x05owner\
+    \x12\x20\n\x0bdescription\x18\x04\x20\x01(\tR\x0bdescription\"o\n\x04Hel\
+    p\x12+\n\x05links\x18\x01\x20\x03(\x0b2\x15.google.rpc.Help.LinkR\x05lin\
+    ks\x1a:\n\x04Link\x12\x20\n\x0bdescription\x18\x01\x20\x01(\tR\x0bdescri\
+    ption\x12\x10\n\x03url\x18\x02\x20\x01(\tR\x03url\"D\n\x10LocalizedMessa\
+    ge\x12\x16\n\x06locale\x18\x01\x20\x01(\tR\x06locale\x12\x18\n\x07messag\
+    e\x18\x02\x20\x01(\tR\x07messageBl\n\x0ecom.google.rpcB\x11ErrorDetailsP\
+    rotoP\x01Z?google.golang.org/genproto/googleapis/rpc/errdetails;errdetai\
+    ls\xa2\x02\x03RPCb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobufv3::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobufv3::rt::Lazy<::protobufv3::descriptor::FileDescriptorProto> = ::protobufv3::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobufv3::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobufv3::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobufv3::rt::Lazy<::protobufv3::reflect::GeneratedFileDescriptor> = ::protobufv3::rt::Lazy::new();
+    static file_descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::FileDescriptor> = ::protobufv3::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(::protobufv3::well_known_types::duration::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(14);
+            messages.push(ErrorInfo::generated_message_descriptor_data());
+            messages.push(RetryInfo::generated_message_descriptor_data());
+            messages.push(DebugInfo::generated_message_descriptor_data());
+            messages.push(QuotaFailure::generated_message_descriptor_data());
+            messages.push(PreconditionFailure::generated_message_descriptor_data());
+            messages.push(BadRequest::generated_message_descriptor_data());
+            messages.push(RequestInfo::generated_message_descriptor_data());
+            messages.push(ResourceInfo::generated_message_descriptor_data());
+            messages.push(Help::generated_message_descriptor_data());
+            messages.push(LocalizedMessage::generated_message_descriptor_data());
+            messages.push(quota_failure::Violation::generated_message_descriptor_data());
+            messages.push(precondition_failure::Violation::generated_message_descriptor_data());
+            messages.push(bad_request::FieldViolation::generated_message_descriptor_data());
+            messages.push(help::Link::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobufv3::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobufv3::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
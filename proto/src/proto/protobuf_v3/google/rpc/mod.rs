@@ -1,3 +1,4 @@
 // @generated
 
+pub mod error_details;
 pub mod status;
@@ -0,0 +1,261 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Extracts the information needed by reflection from serialized file descriptors.
+//!
+//! Descriptors are decoded with the descriptor types of the enabled codec, but
+//! are sent to clients as registered.
+
+use grpcio::Result;
+
+#[cfg(feature = "prost-codec")]
+use prost::Message;
+#[cfg(feature = "prost-codec")]
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+    FileDescriptorSet, ServiceDescriptorProto,
+};
+#[cfg(feature = "protobuf-codec")]
+use protobuf::{
+    descriptor::{
+        DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+        FileDescriptorSet, ServiceDescriptorProto,
+    },
+    Message,
+};
+#[cfg(feature = "protobufv3-codec")]
+use protobufv3::{
+    descriptor::{
+        DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+        FileDescriptorSet, ServiceDescriptorProto,
+    },
+    Message,
+};
+
+/// An extension declared in a file.
+pub struct Extension {
+    /// Fully-qualified name of the extended message, without the leading dot.
+    pub extendee: String,
+    pub number: i32,
+}
+
+/// The summary of a serialized `google.protobuf.FileDescriptorProto`.
+#[derive(Default)]
+pub struct FileDescriptor {
+    pub name: String,
+    pub dependencies: Vec<String>,
+    /// Fully-qualified names of all symbols declared in the file.
+    pub symbols: Vec<String>,
+    /// Fully-qualified names of all services declared in the file.
+    pub services: Vec<String>,
+    /// Fully-qualified names of all messages declared in the file.
+    pub messages: Vec<String>,
+    pub extensions: Vec<Extension>,
+    /// The serialized descriptor.
+    pub encoded: Vec<u8>,
+}
+
+fn join(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+#[cfg(feature = "protobuf-codec")]
+fn decode_file(encoded: &[u8]) -> Result<FileDescriptorProto> {
+    Ok(FileDescriptorProto::parse_from_bytes(encoded)?)
+}
+
+#[cfg(feature = "protobufv3-codec")]
+fn decode_file(encoded: &[u8]) -> Result<FileDescriptorProto> {
+    Ok(FileDescriptorProto::parse_from_bytes(encoded)?)
+}
+
+#[cfg(feature = "prost-codec")]
+fn decode_file(encoded: &[u8]) -> Result<FileDescriptorProto> {
+    Ok(FileDescriptorProto::decode(encoded)?)
+}
+
+impl FileDescriptor {
+    /// Decodes a serialized `FileDescriptorProto`.
+    pub fn decode(encoded: Vec<u8>) -> Result<FileDescriptor> {
+        let proto = decode_file(&encoded)?;
+        let mut file = FileDescriptor::default();
+        file.add_file(&proto);
+        file.encoded = encoded;
+        Ok(file)
+    }
+}
+
+#[cfg(feature = "protobuf-codec")]
+impl FileDescriptor {
+    fn add_file(&mut self, file: &FileDescriptorProto) {
+        let package = file.get_package();
+        self.name = file.get_name().to_owned();
+        self.dependencies = file.get_dependency().to_vec();
+        for m in file.get_message_type() {
+            self.add_message(package, m);
+        }
+        for e in file.get_enum_type() {
+            self.add_enum(package, e);
+        }
+        for s in file.get_service() {
+            self.add_service(package, s);
+        }
+        for e in file.get_extension() {
+            self.add_extension(package, e);
+        }
+    }
+
+    /// Adds a `DescriptorProto` and everything nested in it.
+    fn add_message(&mut self, scope: &str, message: &DescriptorProto) {
+        let full_name = join(scope, message.get_name());
+        for f in message.get_field() {
+            self.symbols.push(join(&full_name, f.get_name()));
+        }
+        for o in message.get_oneof_decl() {
+            self.symbols.push(join(&full_name, o.get_name()));
+        }
+        for m in message.get_nested_type() {
+            self.add_message(&full_name, m);
+        }
+        for e in message.get_enum_type() {
+            self.add_enum(&full_name, e);
+        }
+        for e in message.get_extension() {
+            self.add_extension(&full_name, e);
+        }
+        self.messages.push(full_name.clone());
+        self.symbols.push(full_name);
+    }
+
+    /// Adds an `EnumDescriptorProto`.
+    ///
+    /// Enum values are siblings of the enum type rather than its children.
+    fn add_enum(&mut self, scope: &str, e: &EnumDescriptorProto) {
+        self.symbols.push(join(scope, e.get_name()));
+        for v in e.get_value() {
+            self.symbols.push(join(scope, v.get_name()));
+        }
+    }
+
+    /// Adds a `ServiceDescriptorProto` and its methods.
+    fn add_service(&mut self, scope: &str, service: &ServiceDescriptorProto) {
+        let full_name = join(scope, service.get_name());
+        for m in service.get_method() {
+            self.symbols.push(join(&full_name, m.get_name()));
+        }
+        self.services.push(full_name.clone());
+        self.symbols.push(full_name);
+    }
+
+    /// Adds a `FieldDescriptorProto` that declares an extension.
+    fn add_extension(&mut self, scope: &str, field: &FieldDescriptorProto) {
+        self.symbols.push(join(scope, field.get_name()));
+        self.extensions.push(Extension {
+            // Type names in descriptors are fully-qualified with a leading dot.
+            extendee: field.get_extendee().trim_start_matches('.').to_owned(),
+            number: field.get_number(),
+        });
+    }
+}
+
+#[cfg(any(feature = "protobufv3-codec", feature = "prost-codec"))]
+impl FileDescriptor {
+    fn add_file(&mut self, file: &FileDescriptorProto) {
+        let package = file.package();
+        self.name = file.name().to_owned();
+        self.dependencies = file.dependency.clone();
+        for m in &file.message_type {
+            self.add_message(package, m);
+        }
+        for e in &file.enum_type {
+            self.add_enum(package, e);
+        }
+        for s in &file.service {
+            self.add_service(package, s);
+        }
+        for e in &file.extension {
+            self.add_extension(package, e);
+        }
+    }
+
+    /// Adds a `DescriptorProto` and everything nested in it.
+    fn add_message(&mut self, scope: &str, message: &DescriptorProto) {
+        let full_name = join(scope, message.name());
+        for f in &message.field {
+            self.symbols.push(join(&full_name, f.name()));
+        }
+        for o in &message.oneof_decl {
+            self.symbols.push(join(&full_name, o.name()));
+        }
+        for m in &message.nested_type {
+            self.add_message(&full_name, m);
+        }
+        for e in &message.enum_type {
+            self.add_enum(&full_name, e);
+        }
+        for e in &message.extension {
+            self.add_extension(&full_name, e);
+        }
+        self.messages.push(full_name.clone());
+        self.symbols.push(full_name);
+    }
+
+    /// Adds an `EnumDescriptorProto`.
+    ///
+    /// Enum values are siblings of the enum type rather than its children.
+    fn add_enum(&mut self, scope: &str, e: &EnumDescriptorProto) {
+        self.symbols.push(join(scope, e.name()));
+        for v in &e.value {
+            self.symbols.push(join(scope, v.name()));
+        }
+    }
+
+    /// Adds a `ServiceDescriptorProto` and its methods.
+    fn add_service(&mut self, scope: &str, service: &ServiceDescriptorProto) {
+        let full_name = join(scope, service.name());
+        for m in &service.method {
+            self.symbols.push(join(&full_name, m.name()));
+        }
+        self.services.push(full_name.clone());
+        self.symbols.push(full_name);
+    }
+
+    /// Adds a `FieldDescriptorProto` that declares an extension.
+    fn add_extension(&mut self, scope: &str, field: &FieldDescriptorProto) {
+        self.symbols.push(join(scope, field.name()));
+        self.extensions.push(Extension {
+            // Type names in descriptors are fully-qualified with a leading dot.
+            extendee: field.extendee().trim_start_matches('.').to_owned(),
+            number: field.number(),
+        });
+    }
+}
+
+/// Splits a serialized `google.protobuf.FileDescriptorSet` into serialized
+/// `FileDescriptorProto`s.
+#[cfg(feature = "protobuf-codec")]
+pub fn split_file_descriptor_set(buf: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let set = FileDescriptorSet::parse_from_bytes(buf)?;
+    let files = set.get_file().iter().map(|f| f.write_to_bytes());
+    Ok(files.collect::<protobuf::ProtobufResult<_>>()?)
+}
+
+/// Splits a serialized `google.protobuf.FileDescriptorSet` into serialized
+/// `FileDescriptorProto`s.
+#[cfg(feature = "protobufv3-codec")]
+pub fn split_file_descriptor_set(buf: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let set = FileDescriptorSet::parse_from_bytes(buf)?;
+    let files = set.file.iter().map(|f| f.write_to_bytes());
+    Ok(files.collect::<protobufv3::Result<_>>()?)
+}
+
+/// Splits a serialized `google.protobuf.FileDescriptorSet` into serialized
+/// `FileDescriptorProto`s.
+#[cfg(feature = "prost-codec")]
+pub fn split_file_descriptor_set(buf: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let set = FileDescriptorSet::decode(buf)?;
+    Ok(set.file.iter().map(Message::encode_to_vec).collect())
+}
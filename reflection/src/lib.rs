@@ -0,0 +1,45 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! grpcio-reflection provides an implementation of gRPC server reflection service.
+//! For the detail design of the service, see
+//! https://github.com/grpc/grpc/blob/master/doc/server-reflection.md.
+//!
+//! ### Usage
+//!
+//! Reflection answers queries with the file descriptors registered to it. With
+//! rust-protobuf, generated files expose their descriptors directly:
+//! ```ignore
+//! use grpcio_reflection::ReflectionServiceBuilder;
+//!
+//! let service = ReflectionServiceBuilder::new()
+//!     .register_file_descriptor(helloworld::file_descriptor_proto())
+//!     .build()
+//!     .unwrap();
+//! ```
+//! With prost, ask `prost_build` to write a `FileDescriptorSet` and register it:
+//! ```ignore
+//! let service = ReflectionServiceBuilder::new()
+//!     .register_encoded_file_descriptor_set(include_bytes!("helloworld.bin").to_vec())
+//!     .build()
+//!     .unwrap();
+//! ```
+//! Then register both versions of the service so that both new and old tools
+//! can talk to the server.
+//! ```ignore
+//! use grpcio_reflection::{v1, v1alpha};
+//!
+//! let builder = builder
+//!     .register_service(v1::create_server_reflection(service.clone()))
+//!     .register_service(v1alpha::create_server_reflection(service));
+//! ```
+//!
+//! Exactly one of the codec features `protobuf-codec`, `protobufv3-codec` and
+//! `prost-codec` should be enabled, which decides the types of the generated
+//! messages in [`v1`] and [`v1alpha`].
+
+mod descriptor;
+pub mod proto;
+mod service;
+
+pub use self::proto::{v1, v1alpha};
+pub use self::service::{ReflectionService, ReflectionServiceBuilder};
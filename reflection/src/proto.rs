@@ -0,0 +1,62 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+#[cfg(feature = "prost-codec")]
+mod reexports {
+    pub mod v1 {
+        include!("proto/prost/v1/grpc.reflection.v1.rs");
+    }
+    pub mod v1alpha {
+        include!("proto/prost/v1alpha/grpc.reflection.v1alpha.rs");
+    }
+}
+
+#[cfg(feature = "protobuf-codec")]
+mod protobuf {
+    pub mod v1 {
+        #[allow(deprecated)]
+        pub mod reflection;
+        pub mod reflection_grpc;
+    }
+    pub mod v1alpha {
+        #[allow(deprecated)]
+        pub mod reflection;
+        pub mod reflection_grpc;
+    }
+}
+#[cfg(feature = "protobufv3-codec")]
+mod protobuf_v3 {
+    pub mod v1 {
+        #[allow(deprecated)]
+        pub mod reflection;
+        pub mod reflection_grpc;
+    }
+    pub mod v1alpha {
+        #[allow(deprecated)]
+        pub mod reflection;
+        pub mod reflection_grpc;
+    }
+}
+#[cfg(feature = "protobuf-codec")]
+mod reexports {
+    pub mod v1 {
+        pub use super::super::protobuf::v1::reflection::*;
+        // Following is included in reflection import above.
+        // pub use super::super::protobuf::v1::reflection_grpc::*;
+    }
+    pub mod v1alpha {
+        pub use super::super::protobuf::v1alpha::reflection::*;
+    }
+}
+#[cfg(feature = "protobufv3-codec")]
+mod reexports {
+    pub mod v1 {
+        pub use super::super::protobuf_v3::v1::reflection::*;
+        // Following is included in reflection import above.
+        // pub use super::super::protobuf_v3::v1::reflection_grpc::*;
+    }
+    pub mod v1alpha {
+        pub use super::super::protobuf_v3::v1alpha::reflection::*;
+    }
+}
+
+pub use self::reexports::*;
@@ -0,0 +1,2199 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `grpc/reflection/v1alpha/reflection.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServerReflectionRequest {
+    // message fields
+    pub host: ::std::string::String,
+    // message oneof groups
+    pub message_request: ::std::option::Option<ServerReflectionRequest_oneof_message_request>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ServerReflectionRequest {
+    fn default() -> &'a ServerReflectionRequest {
+        <ServerReflectionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ServerReflectionRequest_oneof_message_request {
+    file_by_filename(::std::string::String),
+    file_containing_symbol(::std::string::String),
+    file_containing_extension(ExtensionRequest),
+    all_extension_numbers_of_type(::std::string::String),
+    list_services(::std::string::String),
+}
+
+impl ServerReflectionRequest {
+    pub fn new() -> ServerReflectionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string host = 1;
+
+
+    pub fn get_host(&self) -> &str {
+        &self.host
+    }
+    pub fn clear_host(&mut self) {
+        self.host.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host(&mut self, v: ::std::string::String) {
+        self.host = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host(&mut self) -> &mut ::std::string::String {
+        &mut self.host
+    }
+
+    // Take field
+    pub fn take_host(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.host, ::std::string::String::new())
+    }
+
+    // string file_by_filename = 3;
+
+
+    pub fn get_file_by_filename(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_file_by_filename(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_file_by_filename(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_by_filename(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_by_filename(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_by_filename(&mut self) -> ::std::string::String {
+        if self.has_file_by_filename() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string file_containing_symbol = 4;
+
+
+    pub fn get_file_containing_symbol(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_file_containing_symbol(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_file_containing_symbol(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_containing_symbol(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_containing_symbol(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_containing_symbol(&mut self) -> ::std::string::String {
+        if self.has_file_containing_symbol() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .grpc.reflection.v1alpha.ExtensionRequest file_containing_extension = 5;
+
+
+    pub fn get_file_containing_extension(&self) -> &ExtensionRequest {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(ref v)) => v,
+            _ => <ExtensionRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_file_containing_extension(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_file_containing_extension(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_containing_extension(&mut self, v: ExtensionRequest) {
+        self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_containing_extension(&mut self) -> &mut ExtensionRequest {
+        if let ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(ExtensionRequest::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_containing_extension(&mut self) -> ExtensionRequest {
+        if self.has_file_containing_extension() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ExtensionRequest::new()
+        }
+    }
+
+    // string all_extension_numbers_of_type = 6;
+
+
+    pub fn get_all_extension_numbers_of_type(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_all_extension_numbers_of_type(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_all_extension_numbers_of_type(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_all_extension_numbers_of_type(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_all_extension_numbers_of_type(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_all_extension_numbers_of_type(&mut self) -> ::std::string::String {
+        if self.has_all_extension_numbers_of_type() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string list_services = 7;
+
+
+    pub fn get_list_services(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_list_services(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_list_services(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_services(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_services(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_services(&mut self) -> ::std::string::String {
+        if self.has_list_services() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ServerReflectionRequest {
+    fn is_initialized(&self) -> bool {
+        if let Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(ref v)) = self.message_request {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.host)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_by_filename(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_symbol(is.read_string()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::file_containing_extension(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(is.read_string()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_request = ::std::option::Option::Some(ServerReflectionRequest_oneof_message_request::list_services(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.host.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.host);
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_request {
+            match v {
+                &ServerReflectionRequest_oneof_message_request::file_by_filename(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+                &ServerReflectionRequest_oneof_message_request::file_containing_symbol(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+                &ServerReflectionRequest_oneof_message_request::file_containing_extension(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+                &ServerReflectionRequest_oneof_message_request::list_services(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.host.is_empty() {
+            os.write_string(1, &self.host)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_request {
+            match v {
+                &ServerReflectionRequest_oneof_message_request::file_by_filename(ref v) => {
+                    os.write_string(3, v)?;
+                },
+                &ServerReflectionRequest_oneof_message_request::file_containing_symbol(ref v) => {
+                    os.write_string(4, v)?;
+                },
+                &ServerReflectionRequest_oneof_message_request::file_containing_extension(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ServerReflectionRequest_oneof_message_request::all_extension_numbers_of_type(ref v) => {
+                    os.write_string(6, v)?;
+                },
+                &ServerReflectionRequest_oneof_message_request::list_services(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServerReflectionRequest {
+        ServerReflectionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "host",
+                |m: &ServerReflectionRequest| { &m.host },
+                |m: &mut ServerReflectionRequest| { &mut m.host },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "file_by_filename",
+                ServerReflectionRequest::has_file_by_filename,
+                ServerReflectionRequest::get_file_by_filename,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "file_containing_symbol",
+                ServerReflectionRequest::has_file_containing_symbol,
+                ServerReflectionRequest::get_file_containing_symbol,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ExtensionRequest>(
+                "file_containing_extension",
+                ServerReflectionRequest::has_file_containing_extension,
+                ServerReflectionRequest::get_file_containing_extension,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "all_extension_numbers_of_type",
+                ServerReflectionRequest::has_all_extension_numbers_of_type,
+                ServerReflectionRequest::get_all_extension_numbers_of_type,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "list_services",
+                ServerReflectionRequest::has_list_services,
+                ServerReflectionRequest::get_list_services,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ServerReflectionRequest>(
+                "ServerReflectionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ServerReflectionRequest {
+        static instance: ::protobuf::rt::LazyV2<ServerReflectionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ServerReflectionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ServerReflectionRequest {
+    fn clear(&mut self) {
+        self.host.clear();
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServerReflectionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServerReflectionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExtensionRequest {
+    // message fields
+    pub containing_type: ::std::string::String,
+    pub extension_number: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExtensionRequest {
+    fn default() -> &'a ExtensionRequest {
+        <ExtensionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExtensionRequest {
+    pub fn new() -> ExtensionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string containing_type = 1;
+
+
+    pub fn get_containing_type(&self) -> &str {
+        &self.containing_type
+    }
+    pub fn clear_containing_type(&mut self) {
+        self.containing_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_containing_type(&mut self, v: ::std::string::String) {
+        self.containing_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_containing_type(&mut self) -> &mut ::std::string::String {
+        &mut self.containing_type
+    }
+
+    // Take field
+    pub fn take_containing_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.containing_type, ::std::string::String::new())
+    }
+
+    // int32 extension_number = 2;
+
+
+    pub fn get_extension_number(&self) -> i32 {
+        self.extension_number
+    }
+    pub fn clear_extension_number(&mut self) {
+        self.extension_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extension_number(&mut self, v: i32) {
+        self.extension_number = v;
+    }
+}
+
+impl ::protobuf::Message for ExtensionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.containing_type)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.extension_number = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.containing_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.containing_type);
+        }
+        if self.extension_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.extension_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.containing_type.is_empty() {
+            os.write_string(1, &self.containing_type)?;
+        }
+        if self.extension_number != 0 {
+            os.write_int32(2, self.extension_number)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExtensionRequest {
+        ExtensionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "containing_type",
+                |m: &ExtensionRequest| { &m.containing_type },
+                |m: &mut ExtensionRequest| { &mut m.containing_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "extension_number",
+                |m: &ExtensionRequest| { &m.extension_number },
+                |m: &mut ExtensionRequest| { &mut m.extension_number },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExtensionRequest>(
+                "ExtensionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExtensionRequest {
+        static instance: ::protobuf::rt::LazyV2<ExtensionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExtensionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExtensionRequest {
+    fn clear(&mut self) {
+        self.containing_type.clear();
+        self.extension_number = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExtensionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExtensionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServerReflectionResponse {
+    // message fields
+    pub valid_host: ::std::string::String,
+    pub original_request: ::protobuf::SingularPtrField<ServerReflectionRequest>,
+    // message oneof groups
+    pub message_response: ::std::option::Option<ServerReflectionResponse_oneof_message_response>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ServerReflectionResponse {
+    fn default() -> &'a ServerReflectionResponse {
+        <ServerReflectionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ServerReflectionResponse_oneof_message_response {
+    file_descriptor_response(FileDescriptorResponse),
+    all_extension_numbers_response(ExtensionNumberResponse),
+    list_services_response(ListServiceResponse),
+    error_response(ErrorResponse),
+}
+
+impl ServerReflectionResponse {
+    pub fn new() -> ServerReflectionResponse {
+        ::std::default::Default::default()
+    }
+
+    // string valid_host = 1;
+
+
+    pub fn get_valid_host(&self) -> &str {
+        &self.valid_host
+    }
+    pub fn clear_valid_host(&mut self) {
+        self.valid_host.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_valid_host(&mut self, v: ::std::string::String) {
+        self.valid_host = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_valid_host(&mut self) -> &mut ::std::string::String {
+        &mut self.valid_host
+    }
+
+    // Take field
+    pub fn take_valid_host(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.valid_host, ::std::string::String::new())
+    }
+
+    // .grpc.reflection.v1alpha.ServerReflectionRequest original_request = 2;
+
+
+    pub fn get_original_request(&self) -> &ServerReflectionRequest {
+        self.original_request.as_ref().unwrap_or_else(|| <ServerReflectionRequest as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_original_request(&mut self) {
+        self.original_request.clear();
+    }
+
+    pub fn has_original_request(&self) -> bool {
+        self.original_request.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_original_request(&mut self, v: ServerReflectionRequest) {
+        self.original_request = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_original_request(&mut self) -> &mut ServerReflectionRequest {
+        if self.original_request.is_none() {
+            self.original_request.set_default();
+        }
+        self.original_request.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_original_request(&mut self) -> ServerReflectionRequest {
+        self.original_request.take().unwrap_or_else(|| ServerReflectionRequest::new())
+    }
+
+    // .grpc.reflection.v1alpha.FileDescriptorResponse file_descriptor_response = 4;
+
+
+    pub fn get_file_descriptor_response(&self) -> &FileDescriptorResponse {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(ref v)) => v,
+            _ => <FileDescriptorResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_file_descriptor_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_file_descriptor_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_descriptor_response(&mut self, v: FileDescriptorResponse) {
+        self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_descriptor_response(&mut self) -> &mut FileDescriptorResponse {
+        if let ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(FileDescriptorResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_descriptor_response(&mut self) -> FileDescriptorResponse {
+        if self.has_file_descriptor_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDescriptorResponse::new()
+        }
+    }
+
+    // .grpc.reflection.v1alpha.ExtensionNumberResponse all_extension_numbers_response = 5;
+
+
+    pub fn get_all_extension_numbers_response(&self) -> &ExtensionNumberResponse {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(ref v)) => v,
+            _ => <ExtensionNumberResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_all_extension_numbers_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_all_extension_numbers_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_all_extension_numbers_response(&mut self, v: ExtensionNumberResponse) {
+        self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_all_extension_numbers_response(&mut self) -> &mut ExtensionNumberResponse {
+        if let ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(ExtensionNumberResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_all_extension_numbers_response(&mut self) -> ExtensionNumberResponse {
+        if self.has_all_extension_numbers_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ExtensionNumberResponse::new()
+        }
+    }
+
+    // .grpc.reflection.v1alpha.ListServiceResponse list_services_response = 6;
+
+
+    pub fn get_list_services_response(&self) -> &ListServiceResponse {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(ref v)) => v,
+            _ => <ListServiceResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_list_services_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_list_services_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_services_response(&mut self, v: ListServiceResponse) {
+        self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_services_response(&mut self) -> &mut ListServiceResponse {
+        if let ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(ListServiceResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_services_response(&mut self) -> ListServiceResponse {
+        if self.has_list_services_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListServiceResponse::new()
+        }
+    }
+
+    // .grpc.reflection.v1alpha.ErrorResponse error_response = 7;
+
+
+    pub fn get_error_response(&self) -> &ErrorResponse {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(ref v)) => v,
+            _ => <ErrorResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_error_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_error_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_response(&mut self, v: ErrorResponse) {
+        self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_error_response(&mut self) -> &mut ErrorResponse {
+        if let ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(ErrorResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_error_response(&mut self) -> ErrorResponse {
+        if self.has_error_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ErrorResponse::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ServerReflectionResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.original_request {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(ref v)) = self.message_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(ref v)) = self.message_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ServerReflectionResponse_oneof_message_response::list_services_response(ref v)) = self.message_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ServerReflectionResponse_oneof_message_response::error_response(ref v)) = self.message_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.valid_host)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.original_request)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::file_descriptor_response(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::list_services_response(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message_response = ::std::option::Option::Some(ServerReflectionResponse_oneof_message_response::error_response(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.valid_host.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.valid_host);
+        }
+        if let Some(ref v) = self.original_request.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_response {
+            match v {
+                &ServerReflectionResponse_oneof_message_response::file_descriptor_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ServerReflectionResponse_oneof_message_response::list_services_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ServerReflectionResponse_oneof_message_response::error_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.valid_host.is_empty() {
+            os.write_string(1, &self.valid_host)?;
+        }
+        if let Some(ref v) = self.original_request.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_response {
+            match v {
+                &ServerReflectionResponse_oneof_message_response::file_descriptor_response(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ServerReflectionResponse_oneof_message_response::all_extension_numbers_response(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ServerReflectionResponse_oneof_message_response::list_services_response(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ServerReflectionResponse_oneof_message_response::error_response(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServerReflectionResponse {
+        ServerReflectionResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "valid_host",
+                |m: &ServerReflectionResponse| { &m.valid_host },
+                |m: &mut ServerReflectionResponse| { &mut m.valid_host },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServerReflectionRequest>>(
+                "original_request",
+                |m: &ServerReflectionResponse| { &m.original_request },
+                |m: &mut ServerReflectionResponse| { &mut m.original_request },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDescriptorResponse>(
+                "file_descriptor_response",
+                ServerReflectionResponse::has_file_descriptor_response,
+                ServerReflectionResponse::get_file_descriptor_response,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ExtensionNumberResponse>(
+                "all_extension_numbers_response",
+                ServerReflectionResponse::has_all_extension_numbers_response,
+                ServerReflectionResponse::get_all_extension_numbers_response,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListServiceResponse>(
+                "list_services_response",
+                ServerReflectionResponse::has_list_services_response,
+                ServerReflectionResponse::get_list_services_response,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ErrorResponse>(
+                "error_response",
+                ServerReflectionResponse::has_error_response,
+                ServerReflectionResponse::get_error_response,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ServerReflectionResponse>(
+                "ServerReflectionResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ServerReflectionResponse {
+        static instance: ::protobuf::rt::LazyV2<ServerReflectionResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ServerReflectionResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ServerReflectionResponse {
+    fn clear(&mut self) {
+        self.valid_host.clear();
+        self.original_request.clear();
+        self.message_response = ::std::option::Option::None;
+        self.message_response = ::std::option::Option::None;
+        self.message_response = ::std::option::Option::None;
+        self.message_response = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServerReflectionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServerReflectionResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDescriptorResponse {
+    // message fields
+    pub file_descriptor_proto: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDescriptorResponse {
+    fn default() -> &'a FileDescriptorResponse {
+        <FileDescriptorResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDescriptorResponse {
+    pub fn new() -> FileDescriptorResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated bytes file_descriptor_proto = 1;
+
+
+    pub fn get_file_descriptor_proto(&self) -> &[::std::vec::Vec<u8>] {
+        &self.file_descriptor_proto
+    }
+    pub fn clear_file_descriptor_proto(&mut self) {
+        self.file_descriptor_proto.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_descriptor_proto(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.file_descriptor_proto = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_descriptor_proto(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.file_descriptor_proto
+    }
+
+    // Take field
+    pub fn take_file_descriptor_proto(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.file_descriptor_proto, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for FileDescriptorResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.file_descriptor_proto)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.file_descriptor_proto {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.file_descriptor_proto {
+            os.write_bytes(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDescriptorResponse {
+        FileDescriptorResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "file_descriptor_proto",
+                |m: &FileDescriptorResponse| { &m.file_descriptor_proto },
+                |m: &mut FileDescriptorResponse| { &mut m.file_descriptor_proto },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDescriptorResponse>(
+                "FileDescriptorResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDescriptorResponse {
+        static instance: ::protobuf::rt::LazyV2<FileDescriptorResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDescriptorResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDescriptorResponse {
+    fn clear(&mut self) {
+        self.file_descriptor_proto.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDescriptorResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDescriptorResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExtensionNumberResponse {
+    // message fields
+    pub base_type_name: ::std::string::String,
+    pub extension_number: ::std::vec::Vec<i32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExtensionNumberResponse {
+    fn default() -> &'a ExtensionNumberResponse {
+        <ExtensionNumberResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExtensionNumberResponse {
+    pub fn new() -> ExtensionNumberResponse {
+        ::std::default::Default::default()
+    }
+
+    // string base_type_name = 1;
+
+
+    pub fn get_base_type_name(&self) -> &str {
+        &self.base_type_name
+    }
+    pub fn clear_base_type_name(&mut self) {
+        self.base_type_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_type_name(&mut self, v: ::std::string::String) {
+        self.base_type_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base_type_name(&mut self) -> &mut ::std::string::String {
+        &mut self.base_type_name
+    }
+
+    // Take field
+    pub fn take_base_type_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.base_type_name, ::std::string::String::new())
+    }
+
+    // repeated int32 extension_number = 2;
+
+
+    pub fn get_extension_number(&self) -> &[i32] {
+        &self.extension_number
+    }
+    pub fn clear_extension_number(&mut self) {
+        self.extension_number.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extension_number(&mut self, v: ::std::vec::Vec<i32>) {
+        self.extension_number = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_extension_number(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.extension_number
+    }
+
+    // Take field
+    pub fn take_extension_number(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.extension_number, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for ExtensionNumberResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.base_type_name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.extension_number)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.base_type_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.base_type_name);
+        }
+        for value in &self.extension_number {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.base_type_name.is_empty() {
+            os.write_string(1, &self.base_type_name)?;
+        }
+        for v in &self.extension_number {
+            os.write_int32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExtensionNumberResponse {
+        ExtensionNumberResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "base_type_name",
+                |m: &ExtensionNumberResponse| { &m.base_type_name },
+                |m: &mut ExtensionNumberResponse| { &mut m.base_type_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "extension_number",
+                |m: &ExtensionNumberResponse| { &m.extension_number },
+                |m: &mut ExtensionNumberResponse| { &mut m.extension_number },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExtensionNumberResponse>(
+                "ExtensionNumberResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExtensionNumberResponse {
+        static instance: ::protobuf::rt::LazyV2<ExtensionNumberResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExtensionNumberResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ExtensionNumberResponse {
+    fn clear(&mut self) {
+        self.base_type_name.clear();
+        self.extension_number.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExtensionNumberResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExtensionNumberResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListServiceResponse {
+    // message fields
+    pub service: ::protobuf::RepeatedField<ServiceResponse>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListServiceResponse {
+    fn default() -> &'a ListServiceResponse {
+        <ListServiceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListServiceResponse {
+    pub fn new() -> ListServiceResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .grpc.reflection.v1alpha.ServiceResponse service = 1;
+
+
+    pub fn get_service(&self) -> &[ServiceResponse] {
+        &self.service
+    }
+    pub fn clear_service(&mut self) {
+        self.service.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ::protobuf::RepeatedField<ServiceResponse>) {
+        self.service = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service(&mut self) -> &mut ::protobuf::RepeatedField<ServiceResponse> {
+        &mut self.service
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> ::protobuf::RepeatedField<ServiceResponse> {
+        ::std::mem::replace(&mut self.service, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ListServiceResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.service {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.service)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.service {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.service {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListServiceResponse {
+        ListServiceResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceResponse>>(
+                "service",
+                |m: &ListServiceResponse| { &m.service },
+                |m: &mut ListServiceResponse| { &mut m.service },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ListServiceResponse>(
+                "ListServiceResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ListServiceResponse {
+        static instance: ::protobuf::rt::LazyV2<ListServiceResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ListServiceResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ListServiceResponse {
+    fn clear(&mut self) {
+        self.service.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListServiceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListServiceResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServiceResponse {
+    // message fields
+    pub name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ServiceResponse {
+    fn default() -> &'a ServiceResponse {
+        <ServiceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ServiceResponse {
+    pub fn new() -> ServiceResponse {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ServiceResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServiceResponse {
+        ServiceResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ServiceResponse| { &m.name },
+                |m: &mut ServiceResponse| { &mut m.name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ServiceResponse>(
+                "ServiceResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ServiceResponse {
+        static instance: ::protobuf::rt::LazyV2<ServiceResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ServiceResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ServiceResponse {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServiceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServiceResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ErrorResponse {
+    // message fields
+    pub error_code: i32,
+    pub error_message: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorResponse {
+    fn default() -> &'a ErrorResponse {
+        <ErrorResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ErrorResponse {
+    pub fn new() -> ErrorResponse {
+        ::std::default::Default::default()
+    }
+
+    // int32 error_code = 1;
+
+
+    pub fn get_error_code(&self) -> i32 {
+        self.error_code
+    }
+    pub fn clear_error_code(&mut self) {
+        self.error_code = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_code(&mut self, v: i32) {
+        self.error_code = v;
+    }
+
+    // string error_message = 2;
+
+
+    pub fn get_error_message(&self) -> &str {
+        &self.error_message
+    }
+    pub fn clear_error_message(&mut self) {
+        self.error_message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_message(&mut self, v: ::std::string::String) {
+        self.error_message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error_message(&mut self) -> &mut ::std::string::String {
+        &mut self.error_message
+    }
+
+    // Take field
+    pub fn take_error_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error_message, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ErrorResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.error_code = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error_message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.error_code != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.error_code, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error_message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.error_code != 0 {
+            os.write_int32(1, self.error_code)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(2, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ErrorResponse {
+        ErrorResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "error_code",
+                |m: &ErrorResponse| { &m.error_code },
+                |m: &mut ErrorResponse| { &mut m.error_code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error_message",
+                |m: &ErrorResponse| { &m.error_message },
+                |m: &mut ErrorResponse| { &mut m.error_message },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ErrorResponse>(
+                "ErrorResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ErrorResponse {
+        static instance: ::protobuf::rt::LazyV2<ErrorResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ErrorResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ErrorResponse {
+    fn clear(&mut self) {
+        self.error_code = 0;
+        self.error_message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ErrorResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n(grpc/reflection/v1alpha/reflection.proto\x12\x17grpc.reflection.v1alp\
+    ha\"\xf8\x02\n\x17ServerReflectionRequest\x12\x12\n\x04host\x18\x01\x20\
+    \x01(\tR\x04host\x12*\n\x10file_by_filename\x18\x03\x20\x01(\tH\0R\x0efi\
+    leByFilename\x126\n\x16file_containing_symbol\x18\x04\x20\x01(\tH\0R\x14\
+    fileContainingSymbol\x12g\n\x19file_containing_extension\x18\x05\x20\x01\
+    (\x0b2).grpc.reflection.v1alpha.ExtensionRequestH\0R\x17fileContainingEx\
+    tension\x12B\n\x1dall_extension_numbers_of_type\x18\x06\x20\x01(\tH\0R\
+    \x19allExtensionNumbersOfType\x12%\n\rlist_services\x18\x07\x20\x01(\tH\
+    \0R\x0clistServicesB\x11\n\x0fmessage_request\"f\n\x10ExtensionRequest\
+    \x12'\n\x0fcontaining_type\x18\x01\x20\x01(\tR\x0econtainingType\x12)\n\
+    \x10extension_number\x18\x02\x20\x01(\x05R\x0fextensionNumber\"\xc7\x04\
+    \n\x18ServerReflectionResponse\x12\x1d\n\nvalid_host\x18\x01\x20\x01(\tR\
+    \tvalidHost\x12[\n\x10original_request\x18\x02\x20\x01(\x0b20.grpc.refle\
+    ction.v1alpha.ServerReflectionRequestR\x0foriginalRequest\x12k\n\x18file\
+    _descriptor_response\x18\x04\x20\x01(\x0b2/.grpc.reflection.v1alpha.File\
+    DescriptorResponseH\0R\x16fileDescriptorResponse\x12w\n\x1eall_extension\
+    _numbers_response\x18\x05\x20\x01(\x0b20.grpc.reflection.v1alpha.Extensi\
+    onNumberResponseH\0R\x1ballExtensionNumbersResponse\x12d\n\x16list_servi\
+    ces_response\x18\x06\x20\x01(\x0b2,.grpc.reflection.v1alpha.ListServiceR\
+    esponseH\0R\x14listServicesResponse\x12O\n\x0eerror_response\x18\x07\x20\
+    \x01(\x0b2&.grpc.reflection.v1alpha.ErrorResponseH\0R\rerrorResponseB\
+    \x12\n\x10message_response\"L\n\x16FileDescriptorResponse\x122\n\x15file\
+    _descriptor_proto\x18\x01\x20\x03(\x0cR\x13fileDescriptorProto\"j\n\x17E\
+    xtensionNumberResponse\x12$\n\x0ebase_type_name\x18\x01\x20\x01(\tR\x0cb\
+    aseTypeName\x12)\n\x10extension_number\x18\x02\x20\x03(\x05R\x0fextensio\
+    nNumber\"Y\n\x13ListServiceResponse\x12B\n\x07service\x18\x01\x20\x03(\
+    \x0b2(.grpc.reflection.v1alpha.ServiceResponseR\x07service\"%\n\x0fServi\
+    ceResponse\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"S\n\rErrorResp\
+    onse\x12\x1d\n\nerror_code\x18\x01\x20\x01(\x05R\terrorCode\x12#\n\rerro\
+    r_message\x18\x02\x20\x01(\tR\x0cerrorMessage2\x93\x01\n\x10ServerReflec\
+    tion\x12\x7f\n\x14ServerReflectionInfo\x120.grpc.reflection.v1alpha.Serv\
+    erReflectionRequest\x1a1.grpc.reflection.v1alpha.ServerReflectionRespons\
+    e(\x010\x01b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
+
+pub use super::reflection_grpc::*;
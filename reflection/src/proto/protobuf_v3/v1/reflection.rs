@@ -0,0 +1,1837 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `grpc/reflection/v1/reflection.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobufv3::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ServerReflectionRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ServerReflectionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ServerReflectionRequest.host)
+    pub host: ::std::string::String,
+    // message oneof groups
+    pub message_request: ::std::option::Option<server_reflection_request::Message_request>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ServerReflectionRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ServerReflectionRequest {
+    fn default() -> &'a ServerReflectionRequest {
+        <ServerReflectionRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ServerReflectionRequest {
+    pub fn new() -> ServerReflectionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string file_by_filename = 3;
+
+    pub fn file_by_filename(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_file_by_filename(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_file_by_filename(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_by_filename(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_by_filename(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_by_filename(&mut self) -> ::std::string::String {
+        if self.has_file_by_filename() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string file_containing_symbol = 4;
+
+    pub fn file_containing_symbol(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_file_containing_symbol(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_file_containing_symbol(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_containing_symbol(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_containing_symbol(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_containing_symbol(&mut self) -> ::std::string::String {
+        if self.has_file_containing_symbol() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .grpc.reflection.v1.ExtensionRequest file_containing_extension = 5;
+
+    pub fn file_containing_extension(&self) -> &ExtensionRequest {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(ref v)) => v,
+            _ => <ExtensionRequest as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_file_containing_extension(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_file_containing_extension(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_containing_extension(&mut self, v: ExtensionRequest) {
+        self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_containing_extension(&mut self) -> &mut ExtensionRequest {
+        if let ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(ExtensionRequest::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_containing_extension(&mut self) -> ExtensionRequest {
+        if self.has_file_containing_extension() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ExtensionRequest::new()
+        }
+    }
+
+    // string all_extension_numbers_of_type = 6;
+
+    pub fn all_extension_numbers_of_type(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_all_extension_numbers_of_type(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_all_extension_numbers_of_type(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_all_extension_numbers_of_type(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_all_extension_numbers_of_type(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_all_extension_numbers_of_type(&mut self) -> ::std::string::String {
+        if self.has_all_extension_numbers_of_type() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string list_services = 7;
+
+    pub fn list_services(&self) -> &str {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_list_services(&mut self) {
+        self.message_request = ::std::option::Option::None;
+    }
+
+    pub fn has_list_services(&self) -> bool {
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_services(&mut self, v: ::std::string::String) {
+        self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_services(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(_)) = self.message_request {
+        } else {
+            self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(::std::string::String::new()));
+        }
+        match self.message_request {
+            ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_services(&mut self) -> ::std::string::String {
+        if self.has_list_services() {
+            match self.message_request.take() {
+                ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "host",
+            |m: &ServerReflectionRequest| { &m.host },
+            |m: &mut ServerReflectionRequest| { &mut m.host },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "file_by_filename",
+            ServerReflectionRequest::has_file_by_filename,
+            ServerReflectionRequest::file_by_filename,
+            ServerReflectionRequest::set_file_by_filename,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "file_containing_symbol",
+            ServerReflectionRequest::has_file_containing_symbol,
+            ServerReflectionRequest::file_containing_symbol,
+            ServerReflectionRequest::set_file_containing_symbol,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ExtensionRequest>(
+            "file_containing_extension",
+            ServerReflectionRequest::has_file_containing_extension,
+            ServerReflectionRequest::file_containing_extension,
+            ServerReflectionRequest::mut_file_containing_extension,
+            ServerReflectionRequest::set_file_containing_extension,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "all_extension_numbers_of_type",
+            ServerReflectionRequest::has_all_extension_numbers_of_type,
+            ServerReflectionRequest::all_extension_numbers_of_type,
+            ServerReflectionRequest::set_all_extension_numbers_of_type,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "list_services",
+            ServerReflectionRequest::has_list_services,
+            ServerReflectionRequest::list_services,
+            ServerReflectionRequest::set_list_services,
+        ));
+        oneofs.push(server_reflection_request::Message_request::generated_oneof_descriptor_data());
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ServerReflectionRequest>(
+            "ServerReflectionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ServerReflectionRequest {
+    const NAME: &'static str = "ServerReflectionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.host = is.read_string()?;
+                },
+                26 => {
+                    self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileByFilename(is.read_string()?));
+                },
+                34 => {
+                    self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingSymbol(is.read_string()?));
+                },
+                42 => {
+                    self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::FileContainingExtension(is.read_message()?));
+                },
+                50 => {
+                    self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::AllExtensionNumbersOfType(is.read_string()?));
+                },
+                58 => {
+                    self.message_request = ::std::option::Option::Some(server_reflection_request::Message_request::ListServices(is.read_string()?));
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.host.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.host);
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_request {
+            match v {
+                &server_reflection_request::Message_request::FileByFilename(ref v) => {
+                    my_size += ::protobufv3::rt::string_size(3, &v);
+                },
+                &server_reflection_request::Message_request::FileContainingSymbol(ref v) => {
+                    my_size += ::protobufv3::rt::string_size(4, &v);
+                },
+                &server_reflection_request::Message_request::FileContainingExtension(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &server_reflection_request::Message_request::AllExtensionNumbersOfType(ref v) => {
+                    my_size += ::protobufv3::rt::string_size(6, &v);
+                },
+                &server_reflection_request::Message_request::ListServices(ref v) => {
+                    my_size += ::protobufv3::rt::string_size(7, &v);
+                },
+            };
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.host.is_empty() {
+            os.write_string(1, &self.host)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_request {
+            match v {
+                &server_reflection_request::Message_request::FileByFilename(ref v) => {
+                    os.write_string(3, v)?;
+                },
+                &server_reflection_request::Message_request::FileContainingSymbol(ref v) => {
+                    os.write_string(4, v)?;
+                },
+                &server_reflection_request::Message_request::FileContainingExtension(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &server_reflection_request::Message_request::AllExtensionNumbersOfType(ref v) => {
+                    os.write_string(6, v)?;
+                },
+                &server_reflection_request::Message_request::ListServices(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ServerReflectionRequest {
+        ServerReflectionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.host.clear();
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.message_request = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ServerReflectionRequest {
+        static instance: ServerReflectionRequest = ServerReflectionRequest {
+            host: ::std::string::String::new(),
+            message_request: ::std::option::Option::None,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ServerReflectionRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServerReflectionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ServerReflectionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ServerReflectionRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ServerReflectionRequest`
+pub mod server_reflection_request {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:grpc.reflection.v1.ServerReflectionRequest.message_request)
+    pub enum Message_request {
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionRequest.file_by_filename)
+        FileByFilename(::std::string::String),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionRequest.file_containing_symbol)
+        FileContainingSymbol(::std::string::String),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionRequest.file_containing_extension)
+        FileContainingExtension(super::ExtensionRequest),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionRequest.all_extension_numbers_of_type)
+        AllExtensionNumbersOfType(::std::string::String),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionRequest.list_services)
+        ListServices(::std::string::String),
+    }
+
+    impl ::protobufv3::Oneof for Message_request {
+    }
+
+    impl ::protobufv3::OneofFull for Message_request {
+        fn descriptor() -> ::protobufv3::reflect::OneofDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::OneofDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| <super::ServerReflectionRequest as ::protobufv3::MessageFull>::descriptor().oneof_by_name("message_request").unwrap()).clone()
+        }
+    }
+
+    impl Message_request {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobufv3::reflect::GeneratedOneofDescriptorData {
+            ::protobufv3::reflect::GeneratedOneofDescriptorData::new::<Message_request>("message_request")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ExtensionRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ExtensionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ExtensionRequest.containing_type)
+    pub containing_type: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ExtensionRequest.extension_number)
+    pub extension_number: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ExtensionRequest.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ExtensionRequest {
+    fn default() -> &'a ExtensionRequest {
+        <ExtensionRequest as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ExtensionRequest {
+    pub fn new() -> ExtensionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "containing_type",
+            |m: &ExtensionRequest| { &m.containing_type },
+            |m: &mut ExtensionRequest| { &mut m.containing_type },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "extension_number",
+            |m: &ExtensionRequest| { &m.extension_number },
+            |m: &mut ExtensionRequest| { &mut m.extension_number },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ExtensionRequest>(
+            "ExtensionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ExtensionRequest {
+    const NAME: &'static str = "ExtensionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.containing_type = is.read_string()?;
+                },
+                16 => {
+                    self.extension_number = is.read_int32()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.containing_type.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.containing_type);
+        }
+        if self.extension_number != 0 {
+            my_size += ::protobufv3::rt::int32_size(2, self.extension_number);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.containing_type.is_empty() {
+            os.write_string(1, &self.containing_type)?;
+        }
+        if self.extension_number != 0 {
+            os.write_int32(2, self.extension_number)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ExtensionRequest {
+        ExtensionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.containing_type.clear();
+        self.extension_number = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ExtensionRequest {
+        static instance: ExtensionRequest = ExtensionRequest {
+            containing_type: ::std::string::String::new(),
+            extension_number: 0,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ExtensionRequest {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExtensionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ExtensionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ExtensionRequest {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ServerReflectionResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ServerReflectionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ServerReflectionResponse.valid_host)
+    pub valid_host: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ServerReflectionResponse.original_request)
+    pub original_request: ::protobufv3::MessageField<ServerReflectionRequest>,
+    // message oneof groups
+    pub message_response: ::std::option::Option<server_reflection_response::Message_response>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ServerReflectionResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ServerReflectionResponse {
+    fn default() -> &'a ServerReflectionResponse {
+        <ServerReflectionResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ServerReflectionResponse {
+    pub fn new() -> ServerReflectionResponse {
+        ::std::default::Default::default()
+    }
+
+    // .grpc.reflection.v1.FileDescriptorResponse file_descriptor_response = 4;
+
+    pub fn file_descriptor_response(&self) -> &FileDescriptorResponse {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(ref v)) => v,
+            _ => <FileDescriptorResponse as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_file_descriptor_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_file_descriptor_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_descriptor_response(&mut self, v: FileDescriptorResponse) {
+        self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_descriptor_response(&mut self) -> &mut FileDescriptorResponse {
+        if let ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(FileDescriptorResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_descriptor_response(&mut self) -> FileDescriptorResponse {
+        if self.has_file_descriptor_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDescriptorResponse::new()
+        }
+    }
+
+    // .grpc.reflection.v1.ExtensionNumberResponse all_extension_numbers_response = 5;
+
+    pub fn all_extension_numbers_response(&self) -> &ExtensionNumberResponse {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(ref v)) => v,
+            _ => <ExtensionNumberResponse as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_all_extension_numbers_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_all_extension_numbers_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_all_extension_numbers_response(&mut self, v: ExtensionNumberResponse) {
+        self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_all_extension_numbers_response(&mut self) -> &mut ExtensionNumberResponse {
+        if let ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(ExtensionNumberResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_all_extension_numbers_response(&mut self) -> ExtensionNumberResponse {
+        if self.has_all_extension_numbers_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ExtensionNumberResponse::new()
+        }
+    }
+
+    // .grpc.reflection.v1.ListServiceResponse list_services_response = 6;
+
+    pub fn list_services_response(&self) -> &ListServiceResponse {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(ref v)) => v,
+            _ => <ListServiceResponse as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_list_services_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_list_services_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_services_response(&mut self, v: ListServiceResponse) {
+        self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_services_response(&mut self) -> &mut ListServiceResponse {
+        if let ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(ListServiceResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_services_response(&mut self) -> ListServiceResponse {
+        if self.has_list_services_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListServiceResponse::new()
+        }
+    }
+
+    // .grpc.reflection.v1.ErrorResponse error_response = 7;
+
+    pub fn error_response(&self) -> &ErrorResponse {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(ref v)) => v,
+            _ => <ErrorResponse as ::protobufv3::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_error_response(&mut self) {
+        self.message_response = ::std::option::Option::None;
+    }
+
+    pub fn has_error_response(&self) -> bool {
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_response(&mut self, v: ErrorResponse) {
+        self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_error_response(&mut self) -> &mut ErrorResponse {
+        if let ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(_)) = self.message_response {
+        } else {
+            self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(ErrorResponse::new()));
+        }
+        match self.message_response {
+            ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_error_response(&mut self) -> ErrorResponse {
+        if self.has_error_response() {
+            match self.message_response.take() {
+                ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ErrorResponse::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "valid_host",
+            |m: &ServerReflectionResponse| { &m.valid_host },
+            |m: &mut ServerReflectionResponse| { &mut m.valid_host },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_message_field_accessor::<_, ServerReflectionRequest>(
+            "original_request",
+            |m: &ServerReflectionResponse| { &m.original_request },
+            |m: &mut ServerReflectionResponse| { &mut m.original_request },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileDescriptorResponse>(
+            "file_descriptor_response",
+            ServerReflectionResponse::has_file_descriptor_response,
+            ServerReflectionResponse::file_descriptor_response,
+            ServerReflectionResponse::mut_file_descriptor_response,
+            ServerReflectionResponse::set_file_descriptor_response,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ExtensionNumberResponse>(
+            "all_extension_numbers_response",
+            ServerReflectionResponse::has_all_extension_numbers_response,
+            ServerReflectionResponse::all_extension_numbers_response,
+            ServerReflectionResponse::mut_all_extension_numbers_response,
+            ServerReflectionResponse::set_all_extension_numbers_response,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ListServiceResponse>(
+            "list_services_response",
+            ServerReflectionResponse::has_list_services_response,
+            ServerReflectionResponse::list_services_response,
+            ServerReflectionResponse::mut_list_services_response,
+            ServerReflectionResponse::set_list_services_response,
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ErrorResponse>(
+            "error_response",
+            ServerReflectionResponse::has_error_response,
+            ServerReflectionResponse::error_response,
+            ServerReflectionResponse::mut_error_response,
+            ServerReflectionResponse::set_error_response,
+        ));
+        oneofs.push(server_reflection_response::Message_response::generated_oneof_descriptor_data());
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ServerReflectionResponse>(
+            "ServerReflectionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ServerReflectionResponse {
+    const NAME: &'static str = "ServerReflectionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.valid_host = is.read_string()?;
+                },
+                18 => {
+                    ::protobufv3::rt::read_singular_message_into_field(is, &mut self.original_request)?;
+                },
+                34 => {
+                    self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::FileDescriptorResponse(is.read_message()?));
+                },
+                42 => {
+                    self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::AllExtensionNumbersResponse(is.read_message()?));
+                },
+                50 => {
+                    self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::ListServicesResponse(is.read_message()?));
+                },
+                58 => {
+                    self.message_response = ::std::option::Option::Some(server_reflection_response::Message_response::ErrorResponse(is.read_message()?));
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.valid_host.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.valid_host);
+        }
+        if let Some(v) = self.original_request.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_response {
+            match v {
+                &server_reflection_response::Message_response::FileDescriptorResponse(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &server_reflection_response::Message_response::AllExtensionNumbersResponse(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &server_reflection_response::Message_response::ListServicesResponse(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+                &server_reflection_response::Message_response::ErrorResponse(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.valid_host.is_empty() {
+            os.write_string(1, &self.valid_host)?;
+        }
+        if let Some(v) = self.original_request.as_ref() {
+            ::protobufv3::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message_response {
+            match v {
+                &server_reflection_response::Message_response::FileDescriptorResponse(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &server_reflection_response::Message_response::AllExtensionNumbersResponse(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &server_reflection_response::Message_response::ListServicesResponse(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &server_reflection_response::Message_response::ErrorResponse(ref v) => {
+                    ::protobufv3::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ServerReflectionResponse {
+        ServerReflectionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.valid_host.clear();
+        self.original_request.clear();
+        self.message_response = ::std::option::Option::None;
+        self.message_response = ::std::option::Option::None;
+        self.message_response = ::std::option::Option::None;
+        self.message_response = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ServerReflectionResponse {
+        static instance: ServerReflectionResponse = ServerReflectionResponse {
+            valid_host: ::std::string::String::new(),
+            original_request: ::protobufv3::MessageField::none(),
+            message_response: ::std::option::Option::None,
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ServerReflectionResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServerReflectionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ServerReflectionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ServerReflectionResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ServerReflectionResponse`
+pub mod server_reflection_response {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:grpc.reflection.v1.ServerReflectionResponse.message_response)
+    pub enum Message_response {
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionResponse.file_descriptor_response)
+        FileDescriptorResponse(super::FileDescriptorResponse),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionResponse.all_extension_numbers_response)
+        AllExtensionNumbersResponse(super::ExtensionNumberResponse),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionResponse.list_services_response)
+        ListServicesResponse(super::ListServiceResponse),
+        // @@protoc_insertion_point(oneof_field:grpc.reflection.v1.ServerReflectionResponse.error_response)
+        ErrorResponse(super::ErrorResponse),
+    }
+
+    impl ::protobufv3::Oneof for Message_response {
+    }
+
+    impl ::protobufv3::OneofFull for Message_response {
+        fn descriptor() -> ::protobufv3::reflect::OneofDescriptor {
+            static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::OneofDescriptor> = ::protobufv3::rt::Lazy::new();
+            descriptor.get(|| <super::ServerReflectionResponse as ::protobufv3::MessageFull>::descriptor().oneof_by_name("message_response").unwrap()).clone()
+        }
+    }
+
+    impl Message_response {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobufv3::reflect::GeneratedOneofDescriptorData {
+            ::protobufv3::reflect::GeneratedOneofDescriptorData::new::<Message_response>("message_response")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.FileDescriptorResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FileDescriptorResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.FileDescriptorResponse.file_descriptor_proto)
+    pub file_descriptor_proto: ::std::vec::Vec<::std::vec::Vec<u8>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.FileDescriptorResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FileDescriptorResponse {
+    fn default() -> &'a FileDescriptorResponse {
+        <FileDescriptorResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl FileDescriptorResponse {
+    pub fn new() -> FileDescriptorResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "file_descriptor_proto",
+            |m: &FileDescriptorResponse| { &m.file_descriptor_proto },
+            |m: &mut FileDescriptorResponse| { &mut m.file_descriptor_proto },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<FileDescriptorResponse>(
+            "FileDescriptorResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for FileDescriptorResponse {
+    const NAME: &'static str = "FileDescriptorResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.file_descriptor_proto.push(is.read_bytes()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.file_descriptor_proto {
+            my_size += ::protobufv3::rt::bytes_size(1, &value);
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.file_descriptor_proto {
+            os.write_bytes(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FileDescriptorResponse {
+        FileDescriptorResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.file_descriptor_proto.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FileDescriptorResponse {
+        static instance: FileDescriptorResponse = FileDescriptorResponse {
+            file_descriptor_proto: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for FileDescriptorResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FileDescriptorResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FileDescriptorResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for FileDescriptorResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ExtensionNumberResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ExtensionNumberResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ExtensionNumberResponse.base_type_name)
+    pub base_type_name: ::std::string::String,
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ExtensionNumberResponse.extension_number)
+    pub extension_number: ::std::vec::Vec<i32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ExtensionNumberResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ExtensionNumberResponse {
+    fn default() -> &'a ExtensionNumberResponse {
+        <ExtensionNumberResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ExtensionNumberResponse {
+    pub fn new() -> ExtensionNumberResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "base_type_name",
+            |m: &ExtensionNumberResponse| { &m.base_type_name },
+            |m: &mut ExtensionNumberResponse| { &mut m.base_type_name },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "extension_number",
+            |m: &ExtensionNumberResponse| { &m.extension_number },
+            |m: &mut ExtensionNumberResponse| { &mut m.extension_number },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ExtensionNumberResponse>(
+            "ExtensionNumberResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ExtensionNumberResponse {
+    const NAME: &'static str = "ExtensionNumberResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.base_type_name = is.read_string()?;
+                },
+                18 => {
+                    is.read_repeated_packed_int32_into(&mut self.extension_number)?;
+                },
+                16 => {
+                    self.extension_number.push(is.read_int32()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.base_type_name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.base_type_name);
+        }
+        my_size += ::protobufv3::rt::vec_packed_int32_size(2, &self.extension_number);
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.base_type_name.is_empty() {
+            os.write_string(1, &self.base_type_name)?;
+        }
+        os.write_repeated_packed_int32(2, &self.extension_number)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ExtensionNumberResponse {
+        ExtensionNumberResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.base_type_name.clear();
+        self.extension_number.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ExtensionNumberResponse {
+        static instance: ExtensionNumberResponse = ExtensionNumberResponse {
+            base_type_name: ::std::string::String::new(),
+            extension_number: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ExtensionNumberResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExtensionNumberResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ExtensionNumberResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ExtensionNumberResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ListServiceResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ListServiceResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ListServiceResponse.service)
+    pub service: ::std::vec::Vec<ServiceResponse>,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ListServiceResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListServiceResponse {
+    fn default() -> &'a ListServiceResponse {
+        <ListServiceResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ListServiceResponse {
+    pub fn new() -> ListServiceResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "service",
+            |m: &ListServiceResponse| { &m.service },
+            |m: &mut ListServiceResponse| { &mut m.service },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ListServiceResponse>(
+            "ListServiceResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ListServiceResponse {
+    const NAME: &'static str = "ListServiceResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.service.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.service {
+            let len = value.compute_size();
+            my_size += 1 + ::protobufv3::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        for v in &self.service {
+            ::protobufv3::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListServiceResponse {
+        ListServiceResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.service.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListServiceResponse {
+        static instance: ListServiceResponse = ListServiceResponse {
+            service: ::std::vec::Vec::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ListServiceResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListServiceResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListServiceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ListServiceResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ServiceResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ServiceResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ServiceResponse.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ServiceResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ServiceResponse {
+    fn default() -> &'a ServiceResponse {
+        <ServiceResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ServiceResponse {
+    pub fn new() -> ServiceResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ServiceResponse| { &m.name },
+            |m: &mut ServiceResponse| { &mut m.name },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ServiceResponse>(
+            "ServiceResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ServiceResponse {
+    const NAME: &'static str = "ServiceResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobufv3::rt::string_size(1, &self.name);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ServiceResponse {
+        ServiceResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ServiceResponse {
+        static instance: ServiceResponse = ServiceResponse {
+            name: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ServiceResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServiceResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ServiceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ServiceResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:grpc.reflection.v1.ErrorResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ErrorResponse {
+    // message fields
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ErrorResponse.error_code)
+    pub error_code: i32,
+    // @@protoc_insertion_point(field:grpc.reflection.v1.ErrorResponse.error_message)
+    pub error_message: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:grpc.reflection.v1.ErrorResponse.special_fields)
+    pub special_fields: ::protobufv3::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorResponse {
+    fn default() -> &'a ErrorResponse {
+        <ErrorResponse as ::protobufv3::Message>::default_instance()
+    }
+}
+
+impl ErrorResponse {
+    pub fn new() -> ErrorResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobufv3::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_code",
+            |m: &ErrorResponse| { &m.error_code },
+            |m: &mut ErrorResponse| { &mut m.error_code },
+        ));
+        fields.push(::protobufv3::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_message",
+            |m: &ErrorResponse| { &m.error_message },
+            |m: &mut ErrorResponse| { &mut m.error_message },
+        ));
+        ::protobufv3::reflect::GeneratedMessageDescriptorData::new_2::<ErrorResponse>(
+            "ErrorResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobufv3::Message for ErrorResponse {
+    const NAME: &'static str = "ErrorResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobufv3::CodedInputStream<'_>) -> ::protobufv3::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.error_code = is.read_int32()?;
+                },
+                18 => {
+                    self.error_message = is.read_string()?;
+                },
+                tag => {
+                    ::protobufv3::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.error_code != 0 {
+            my_size += ::protobufv3::rt::int32_size(1, self.error_code);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobufv3::rt::string_size(2, &self.error_message);
+        }
+        my_size += ::protobufv3::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobufv3::CodedOutputStream<'_>) -> ::protobufv3::Result<()> {
+        if self.error_code != 0 {
+            os.write_int32(1, self.error_code)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(2, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobufv3::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobufv3::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ErrorResponse {
+        ErrorResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.error_code = 0;
+        self.error_message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ErrorResponse {
+        static instance: ErrorResponse = ErrorResponse {
+            error_code: 0,
+            error_message: ::std::string::String::new(),
+            special_fields: ::protobufv3::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobufv3::MessageFull for ErrorResponse {
+    fn descriptor() -> ::protobufv3::reflect::MessageDescriptor {
+        static descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::MessageDescriptor> = ::protobufv3::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ErrorResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobufv3::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobufv3::reflect::ProtobufValue for ErrorResponse {
+    type RuntimeType = ::protobufv3::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n#grpc/reflection/v1/reflection.proto\x12\x12grpc.reflection.v1\"\xf3\
+    \x02\n\x17ServerReflectionRequest\x12\x12\n\x04host\x18\x01\x20\x01(\tR\
+    \x04host\x12*\n\x10file_by_filename\x18\x03\x20\x01(\tH\0R\x0efileByFile\
+    name\x126\n\x16file_containing_symbol\x18\x04\x20\x01(\tH\0R\x14fileCont\
+    ainingSymbol\x12b\n\x19file_containing_extension\x18\x05\x20\x01(\x0b2$.\
+    grpc.reflection.v1.ExtensionRequestH\0R\x17fileContainingExtension\x12B\
+    \n\x1dall_extension_numbers_of_type\x18\x06\x20\x01(\tH\0R\x19allExtensi\
+    onNumbersOfType\x12%\n\rlist_services\x18\x07\x20\x01(\tH\0R\x0clistServ\
+    icesB\x11\n\x0fmessage_request\"f\n\x10ExtensionRequest\x12'\n\x0fcontai\
+    ning_type\x18\x01\x20\x01(\tR\x0econtainingType\x12)\n\x10extension_numb\
+    er\x18\x02\x20\x01(\x05R\x0fextensionNumber\"\xae\x04\n\x18ServerReflect\
+    ionResponse\x12\x1d\n\nvalid_host\x18\x01\x20\x01(\tR\tvalidHost\x12V\n\
+    \x10original_request\x18\x02\x20\x01(\x0b2+.grpc.reflection.v1.ServerRef\
+    lectionRequestR\x0foriginalRequest\x12f\n\x18file_descriptor_response\
+    \x18\x04\x20\x01(\x0b2*.grpc.reflection.v1.FileDescriptorResponseH\0R\
+    \x16fileDescriptorResponse\x12r\n\x1eall_extension_numbers_response\x18\
+    \x05\x20\x01(\x0b2+.grpc.reflection.v1.ExtensionNumberResponseH\0R\x1bal\
+    lExtensionNumbersResponse\x12_\n\x16list_services_response\x18\x06\x20\
+    \x01(\x0b2'.grpc.reflection.v1.ListServiceResponseH\0R\x14listServicesRe\
+    sponse\x12J\n\x0eerror_response\x18\x07\x20\x01(\x0b2!.grpc.reflection.v\
+    1.ErrorResponseH\0R\rerrorResponseB\x12\n\x10message_response\"L\n\x16Fi\
+    leDescriptorResponse\x122\n\x15file_descriptor_proto\x18\x01\x20\x03(\
+    \x0cR\x13fileDescriptorProto\"j\n\x17ExtensionNumberResponse\x12$\n\x0eb\
+    ase_type_name\x18\x01\x20\x01(\tR\x0cbaseTypeName\x12)\n\x10extension_nu\
+    mber\x18\x02\x20\x03(\x05R\x0fextensionNumber\"T\n\x13ListServiceRespons\
+    e\x12=\n\x07service\x18\x01\x20\x03(\x0b2#.grpc.reflection.v1.ServiceRes\
+    ponseR\x07service\"%\n\x0fServiceResponse\x12\x12\n\x04name\x18\x01\x20\
+    \x01(\tR\x04name\"S\n\rErrorResponse\x12\x1d\n\nerror_code\x18\x01\x20\
+    \x01(\x05R\terrorCode\x12#\n\rerror_message\x18\x02\x20\x01(\tR\x0cerror\
+    Message2\x89\x01\n\x10ServerReflection\x12u\n\x14ServerReflectionInfo\
+    \x12+.grpc.reflection.v1.ServerReflectionRequest\x1a,.grpc.reflection.v1\
+    .ServerReflectionResponse(\x010\x01Bf\n\x15io.grpc.reflection.v1B\x15Ser\
+    verReflectionProtoP\x01Z4google.golang.org/grpc/reflection/grpc_reflecti\
+    on_v1b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobufv3::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobufv3::rt::Lazy<::protobufv3::descriptor::FileDescriptorProto> = ::protobufv3::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobufv3::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobufv3::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobufv3::rt::Lazy<::protobufv3::reflect::GeneratedFileDescriptor> = ::protobufv3::rt::Lazy::new();
+    static file_descriptor: ::protobufv3::rt::Lazy<::protobufv3::reflect::FileDescriptor> = ::protobufv3::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(8);
+            messages.push(ServerReflectionRequest::generated_message_descriptor_data());
+            messages.push(ExtensionRequest::generated_message_descriptor_data());
+            messages.push(ServerReflectionResponse::generated_message_descriptor_data());
+            messages.push(FileDescriptorResponse::generated_message_descriptor_data());
+            messages.push(ExtensionNumberResponse::generated_message_descriptor_data());
+            messages.push(ListServiceResponse::generated_message_descriptor_data());
+            messages.push(ServiceResponse::generated_message_descriptor_data());
+            messages.push(ErrorResponse::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobufv3::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobufv3::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
+
+pub use super::reflection_grpc::*;
@@ -0,0 +1,340 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use futures_util::{SinkExt as _, TryStreamExt as _};
+use grpcio::{DuplexSink, Error, RequestStream, Result, RpcContext, RpcStatusCode, WriteFlags};
+
+use crate::descriptor::{self, FileDescriptor};
+use crate::proto::{v1, v1alpha};
+
+/// A query of `ServerReflectionRequest`, independent of the service version and
+/// the codec.
+enum Query<'a> {
+    FileByFilename(&'a str),
+    FileContainingSymbol(&'a str),
+    FileContainingExtension(&'a str, i32),
+    AllExtensionNumbersOfType(&'a str),
+    ListServices,
+}
+
+/// The answer to a [`Query`].
+enum Answer {
+    Files(Vec<Vec<u8>>),
+    ExtensionNumbers(String, Vec<i32>),
+    Services(Vec<String>),
+    Error(RpcStatusCode, &'static str),
+}
+
+#[derive(Default)]
+struct Inner {
+    files: HashMap<String, FileDescriptor>,
+    /// Symbol name -> file name.
+    symbols: HashMap<String, String>,
+    /// Message name -> extension number -> file name.
+    extensions: HashMap<String, BTreeMap<i32, String>>,
+    services: Vec<String>,
+}
+
+impl Inner {
+    fn add_file(&mut self, file: FileDescriptor) -> Result<()> {
+        if self.files.contains_key(&file.name) {
+            return Err(Error::Codec(
+                format!("file {} is registered twice", file.name).into(),
+            ));
+        }
+        for s in &file.symbols {
+            if let Some(f) = self.symbols.insert(s.clone(), file.name.clone()) {
+                return Err(Error::Codec(
+                    format!("symbol {} is defined in both {} and {}", s, f, file.name).into(),
+                ));
+            }
+        }
+        for m in &file.messages {
+            self.extensions.entry(m.clone()).or_default();
+        }
+        for e in &file.extensions {
+            self.extensions
+                .entry(e.extendee.clone())
+                .or_default()
+                .insert(e.number, file.name.clone());
+        }
+        self.services.extend(file.services.iter().cloned());
+        self.files.insert(file.name.clone(), file);
+        Ok(())
+    }
+
+    /// Collects the file and all its registered transitive dependencies.
+    fn file_with_dependencies(&self, name: &str) -> Answer {
+        let mut files = vec![];
+        let mut visited = HashSet::new();
+        let mut pending = vec![name];
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name) {
+                continue;
+            }
+            // Dependencies are allowed to be missing, clients are expected to
+            // resolve them by themselves.
+            if let Some(file) = self.files.get(name) {
+                files.push(file.encoded.clone());
+                pending.extend(file.dependencies.iter().map(String::as_str));
+            }
+        }
+        Answer::Files(files)
+    }
+
+    fn handle(&self, query: Option<Query<'_>>) -> Answer {
+        match query {
+            Some(Query::FileByFilename(name)) => {
+                if !self.files.contains_key(name) {
+                    return Answer::Error(RpcStatusCode::NOT_FOUND, "File not found.");
+                }
+                self.file_with_dependencies(name)
+            }
+            Some(Query::FileContainingSymbol(symbol)) => match self.symbols.get(symbol) {
+                Some(name) => self.file_with_dependencies(name),
+                None => Answer::Error(RpcStatusCode::NOT_FOUND, "Symbol not found."),
+            },
+            Some(Query::FileContainingExtension(ty, number)) => {
+                match self.extensions.get(ty).and_then(|e| e.get(&number)) {
+                    Some(name) => self.file_with_dependencies(name),
+                    None => Answer::Error(RpcStatusCode::NOT_FOUND, "Extension not found."),
+                }
+            }
+            Some(Query::AllExtensionNumbersOfType(ty)) => match self.extensions.get(ty) {
+                Some(e) => Answer::ExtensionNumbers(ty.to_owned(), e.keys().cloned().collect()),
+                None => Answer::Error(RpcStatusCode::NOT_FOUND, "Type not found."),
+            },
+            Some(Query::ListServices) => Answer::Services(self.services.clone()),
+            None => Answer::Error(RpcStatusCode::UNIMPLEMENTED, ""),
+        }
+    }
+}
+
+/// [`ReflectionService`] factory in order to register file descriptors.
+#[derive(Default)]
+pub struct ReflectionServiceBuilder {
+    files: Vec<Vec<u8>>,
+    file_sets: Vec<Vec<u8>>,
+}
+
+impl ReflectionServiceBuilder {
+    /// Initialize a new [`ReflectionServiceBuilder`].
+    pub fn new() -> ReflectionServiceBuilder {
+        ReflectionServiceBuilder::default()
+    }
+
+    /// Register a serialized `google.protobuf.FileDescriptorProto`.
+    pub fn register_encoded_file_descriptor(
+        mut self,
+        file: impl Into<Vec<u8>>,
+    ) -> ReflectionServiceBuilder {
+        self.files.push(file.into());
+        self
+    }
+
+    /// Register all files in a serialized `google.protobuf.FileDescriptorSet`.
+    ///
+    /// It's the easiest way to use reflection with prost, which can be told to
+    /// generate the set by `prost_build::Config::file_descriptor_set_path`.
+    pub fn register_encoded_file_descriptor_set(
+        mut self,
+        set: impl Into<Vec<u8>>,
+    ) -> ReflectionServiceBuilder {
+        self.file_sets.push(set.into());
+        self
+    }
+
+    /// Register a file descriptor generated by rust-protobuf.
+    ///
+    /// Generated files expose it as `file_descriptor_proto()`. Dependencies are
+    /// not registered automatically.
+    #[cfg(feature = "protobuf-codec")]
+    pub fn register_file_descriptor(
+        self,
+        file: &protobuf::descriptor::FileDescriptorProto,
+    ) -> ReflectionServiceBuilder {
+        use protobuf::Message;
+
+        self.register_encoded_file_descriptor(file.write_to_bytes().unwrap())
+    }
+
+    /// Register a file descriptor generated by rust-protobuf together with all
+    /// its dependencies.
+    ///
+    /// Generated files expose it as `file_descriptor()`.
+    #[cfg(feature = "protobufv3-codec")]
+    pub fn register_file_descriptor(
+        mut self,
+        file: &protobufv3::reflect::FileDescriptor,
+    ) -> ReflectionServiceBuilder {
+        use protobufv3::Message;
+
+        let mut pending = vec![file.clone()];
+        let mut visited = HashSet::new();
+        while let Some(file) = pending.pop() {
+            if !visited.insert(file.proto().name().to_owned()) {
+                continue;
+            }
+            self = self.register_encoded_file_descriptor(file.proto().write_to_bytes().unwrap());
+            pending.extend(file.deps().iter().cloned());
+        }
+        self
+    }
+
+    /// Finalize the [`ReflectionServiceBuilder`] and build the [`ReflectionService`].
+    ///
+    /// An error is returned if any descriptor can't be decoded or the same file or
+    /// symbol is registered more than once.
+    pub fn build(self) -> Result<ReflectionService> {
+        let mut inner = Inner::default();
+        let mut files = self.files;
+        for set in &self.file_sets {
+            files.extend(descriptor::split_file_descriptor_set(set)?);
+        }
+        for file in files {
+            inner.add_file(FileDescriptor::decode(file)?)?;
+        }
+        inner.services.sort();
+        Ok(ReflectionService {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+/// An implementation of `ServerReflection` service that answers queries with
+/// registered file descriptors.
+#[derive(Clone)]
+pub struct ReflectionService {
+    inner: Arc<Inner>,
+}
+
+impl ReflectionService {
+    fn serve<Req, Resp>(
+        &self,
+        ctx: RpcContext,
+        mut stream: RequestStream<Req>,
+        mut sink: DuplexSink<Resp>,
+        handle: fn(&Inner, Req) -> Resp,
+    ) where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+    {
+        let inner = self.inner.clone();
+        ctx.spawn(async move {
+            // Errors can only be caused by the client or the connection, nothing
+            // can be done about them.
+            let _ = async {
+                while let Some(req) = stream.try_next().await? {
+                    let resp = handle(&inner, req);
+                    sink.send((resp, WriteFlags::default())).await?;
+                }
+                sink.close().await
+            }
+            .await;
+        })
+    }
+}
+
+/// Implements `ServerReflection` of the given version.
+///
+/// Messages of all codecs have the same field names, only the oneof types and
+/// their variants are named differently, so they are imported under the names
+/// used by prost.
+macro_rules! impl_server_reflection {
+    ($pkg:ident) => {
+        impl $pkg::ServerReflection for ReflectionService {
+            // Conversions and default fields are only needed by some codecs.
+            #[allow(clippy::needless_update, clippy::useless_conversion)]
+            fn server_reflection_info(
+                &mut self,
+                ctx: RpcContext,
+                stream: RequestStream<$pkg::ServerReflectionRequest>,
+                sink: DuplexSink<$pkg::ServerReflectionResponse>,
+            ) {
+                self.serve(ctx, stream, sink, |inner, req| {
+                    #[cfg(feature = "prost-codec")]
+                    use $pkg::{
+                        server_reflection_request::MessageRequest::*,
+                        server_reflection_response::MessageResponse::*,
+                    };
+                    #[cfg(feature = "protobufv3-codec")]
+                    use $pkg::{
+                        server_reflection_request::Message_request::*,
+                        server_reflection_response::Message_response::*,
+                    };
+                    #[cfg(feature = "protobuf-codec")]
+                    use $pkg::{
+                        ServerReflectionRequest_oneof_message_request::{
+                            all_extension_numbers_of_type as AllExtensionNumbersOfType,
+                            file_by_filename as FileByFilename,
+                            file_containing_extension as FileContainingExtension,
+                            file_containing_symbol as FileContainingSymbol,
+                            list_services as ListServices,
+                        },
+                        ServerReflectionResponse_oneof_message_response::{
+                            all_extension_numbers_response as AllExtensionNumbersResponse,
+                            error_response as ErrorResponse,
+                            file_descriptor_response as FileDescriptorResponse,
+                            list_services_response as ListServicesResponse,
+                        },
+                    };
+
+                    let query = req.message_request.as_ref().and_then(|r| match r {
+                        FileByFilename(name) => Some(Query::FileByFilename(name)),
+                        FileContainingSymbol(symbol) => Some(Query::FileContainingSymbol(symbol)),
+                        FileContainingExtension(e) => Some(Query::FileContainingExtension(
+                            &e.containing_type,
+                            e.extension_number,
+                        )),
+                        AllExtensionNumbersOfType(ty) => Some(Query::AllExtensionNumbersOfType(ty)),
+                        ListServices(_) => Some(Query::ListServices),
+                    });
+                    let message_response = match inner.handle(query) {
+                        Answer::Files(files) => {
+                            FileDescriptorResponse($pkg::FileDescriptorResponse {
+                                file_descriptor_proto: files.into(),
+                                ..Default::default()
+                            })
+                        }
+                        Answer::ExtensionNumbers(ty, numbers) => {
+                            AllExtensionNumbersResponse($pkg::ExtensionNumberResponse {
+                                base_type_name: ty,
+                                extension_number: numbers,
+                                ..Default::default()
+                            })
+                        }
+                        Answer::Services(names) => {
+                            let service: Vec<_> = names
+                                .into_iter()
+                                .map(|name| $pkg::ServiceResponse {
+                                    name,
+                                    ..Default::default()
+                                })
+                                .collect();
+                            ListServicesResponse($pkg::ListServiceResponse {
+                                service: service.into(),
+                                ..Default::default()
+                            })
+                        }
+                        Answer::Error(code, msg) => ErrorResponse($pkg::ErrorResponse {
+                            error_code: code.into(),
+                            error_message: msg.to_owned(),
+                            ..Default::default()
+                        }),
+                    };
+                    $pkg::ServerReflectionResponse {
+                        valid_host: req.host.clone(),
+                        original_request: Some(req).into(),
+                        message_response: Some(message_response),
+                        ..Default::default()
+                    }
+                })
+            }
+        }
+    };
+}
+
+impl_server_reflection!(v1);
+impl_server_reflection!(v1alpha);
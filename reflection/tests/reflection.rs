@@ -0,0 +1,221 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+#![cfg(feature = "protobuf-codec")]
+
+use futures_executor::block_on;
+use futures_util::{SinkExt as _, TryStreamExt as _};
+use grpcio::*;
+use grpcio_reflection::v1::ServerReflectionRequest_oneof_message_request as MessageRequest;
+use grpcio_reflection::v1::ServerReflectionResponse_oneof_message_response as MessageResponse;
+use grpcio_reflection::v1::*;
+use grpcio_reflection::*;
+use protobuf::descriptor::*;
+use protobuf::Message;
+use std::sync::*;
+
+/// package foo;
+/// message Base { int32 id = 1; message Inner {} }
+/// enum Kind { KIND_A = 0; }
+fn base_file() -> FileDescriptorProto {
+    let mut id = FieldDescriptorProto::new();
+    id.set_name("id".to_owned());
+    id.set_number(1);
+    let mut inner = DescriptorProto::new();
+    inner.set_name("Inner".to_owned());
+    let mut base = DescriptorProto::new();
+    base.set_name("Base".to_owned());
+    base.set_field(vec![id].into());
+    base.set_nested_type(vec![inner].into());
+    let mut kind_a = EnumValueDescriptorProto::new();
+    kind_a.set_name("KIND_A".to_owned());
+    let mut kind = EnumDescriptorProto::new();
+    kind.set_name("Kind".to_owned());
+    kind.set_value(vec![kind_a].into());
+
+    let mut file = FileDescriptorProto::new();
+    file.set_name("foo/base.proto".to_owned());
+    file.set_package("foo".to_owned());
+    file.set_message_type(vec![base].into());
+    file.set_enum_type(vec![kind].into());
+    file
+}
+
+/// package foo;
+/// import "foo/base.proto";
+/// service Echo { rpc Call(Base) returns (Base); }
+/// extend Base { int32 ext = 100; }
+fn service_file() -> FileDescriptorProto {
+    let mut call = MethodDescriptorProto::new();
+    call.set_name("Call".to_owned());
+    let mut echo = ServiceDescriptorProto::new();
+    echo.set_name("Echo".to_owned());
+    echo.set_method(vec![call].into());
+    let mut ext = FieldDescriptorProto::new();
+    ext.set_name("ext".to_owned());
+    ext.set_extendee(".foo.Base".to_owned());
+    ext.set_number(100);
+
+    let mut file = FileDescriptorProto::new();
+    file.set_name("foo/service.proto".to_owned());
+    file.set_package("foo".to_owned());
+    file.set_dependency(vec!["foo/base.proto".to_owned()].into());
+    file.set_service(vec![echo].into());
+    file.set_extension(vec![ext].into());
+    file
+}
+
+fn encoded(files: &[FileDescriptorProto]) -> Vec<Vec<u8>> {
+    files.iter().map(|f| f.write_to_bytes().unwrap()).collect()
+}
+
+fn query(
+    (mut sink, mut receiver): (
+        ClientDuplexSender<ServerReflectionRequest>,
+        ClientDuplexReceiver<ServerReflectionResponse>,
+    ),
+    req: MessageRequest,
+) -> MessageResponse {
+    let req = ServerReflectionRequest {
+        host: "localhost".to_owned(),
+        message_request: Some(req),
+        ..Default::default()
+    };
+    block_on(async {
+        sink.send((req.clone(), WriteFlags::default()))
+            .await
+            .unwrap();
+        sink.close().await.unwrap();
+        let resp = receiver.try_next().await.unwrap().unwrap();
+        assert_eq!(resp.valid_host, "localhost");
+        assert_eq!(resp.get_original_request(), &req);
+        assert_eq!(receiver.try_next().await.unwrap(), None);
+        resp.message_response.unwrap()
+    })
+}
+
+fn files(resp: MessageResponse) -> Vec<Vec<u8>> {
+    match resp {
+        MessageResponse::file_descriptor_response(r) => r.file_descriptor_proto.into_vec(),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[test]
+fn test_reflection() {
+    let mut set = FileDescriptorSet::new();
+    set.set_file(vec![service_file()].into());
+    let service = ReflectionServiceBuilder::new()
+        .register_file_descriptor(&base_file())
+        .register_encoded_file_descriptor_set(set.write_to_bytes().unwrap())
+        .build()
+        .unwrap();
+
+    let env = Arc::new(Environment::new(1));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(v1::create_server_reflection(service.clone()))
+        .register_service(v1alpha::create_server_reflection(service))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let v1_client = v1::ServerReflectionClient::new(ch.clone());
+    let v1alpha_client = v1alpha::ServerReflectionClient::new(ch);
+
+    let call = || v1_client.server_reflection_info().unwrap();
+    let resp = query(call(), MessageRequest::list_services(String::new()));
+    let echo = ServiceResponse {
+        name: "foo.Echo".to_owned(),
+        ..Default::default()
+    };
+    let expected = ListServiceResponse {
+        service: vec![echo].into(),
+        ..Default::default()
+    };
+    assert_eq!(resp, MessageResponse::list_services_response(expected));
+
+    // The older version answers the same.
+    let (mut sink, mut receiver) = v1alpha_client.server_reflection_info().unwrap();
+    let mut req = v1alpha::ServerReflectionRequest::default();
+    req.set_list_services(String::new());
+    let resp = block_on(async {
+        sink.send((req, WriteFlags::default())).await.unwrap();
+        sink.close().await.unwrap();
+        receiver.try_next().await.unwrap().unwrap()
+    });
+    let names: Vec<_> = resp
+        .get_list_services_response()
+        .get_service()
+        .iter()
+        .map(|s| s.get_name())
+        .collect();
+    assert_eq!(names, vec!["foo.Echo"]);
+
+    // Dependencies should be sent as well.
+    let resp = query(
+        call(),
+        MessageRequest::file_containing_symbol("foo.Echo.Call".to_owned()),
+    );
+    assert_eq!(files(resp), encoded(&[service_file(), base_file()]));
+    let resp = query(
+        call(),
+        MessageRequest::file_by_filename("foo/base.proto".to_owned()),
+    );
+    assert_eq!(files(resp), encoded(&[base_file()]));
+    for symbol in &[
+        "foo.Base",
+        "foo.Base.id",
+        "foo.Base.Inner",
+        "foo.Kind",
+        "foo.KIND_A",
+    ] {
+        let resp = query(
+            call(),
+            MessageRequest::file_containing_symbol(symbol.to_string()),
+        );
+        assert_eq!(files(resp), encoded(&[base_file()]), "{}", symbol);
+    }
+
+    let req = ExtensionRequest {
+        containing_type: "foo.Base".to_owned(),
+        extension_number: 100,
+        ..Default::default()
+    };
+    let resp = query(call(), MessageRequest::file_containing_extension(req));
+    assert_eq!(files(resp), encoded(&[service_file(), base_file()]));
+    let resp = query(
+        call(),
+        MessageRequest::all_extension_numbers_of_type("foo.Base".to_owned()),
+    );
+    let expected = ExtensionNumberResponse {
+        base_type_name: "foo.Base".to_owned(),
+        extension_number: vec![100],
+        ..Default::default()
+    };
+    assert_eq!(
+        resp,
+        MessageResponse::all_extension_numbers_response(expected)
+    );
+
+    let resp = query(
+        call(),
+        MessageRequest::file_containing_symbol("foo.Missing".to_owned()),
+    );
+    let expected = ErrorResponse {
+        error_code: RpcStatusCode::NOT_FOUND.into(),
+        error_message: "Symbol not found.".to_owned(),
+        ..Default::default()
+    };
+    assert_eq!(resp, MessageResponse::error_response(expected));
+}
+
+#[test]
+fn test_duplicated_registration() {
+    let res = ReflectionServiceBuilder::new()
+        .register_file_descriptor(&base_file())
+        .register_file_descriptor(&base_file())
+        .build();
+    assert!(res.is_err());
+}
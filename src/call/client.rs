@@ -1,6 +1,7 @@
 // Copyright 2019 TiKV Project Authors. Licensed under Apache-2.0.
 
 use std::future::Future;
+use std::ops::BitOr;
 use std::pin::Pin;
 use std::ptr;
 use std::sync::Arc;
@@ -15,12 +16,19 @@ use parking_lot::Mutex;
 
 use super::{ShareCall, ShareCallHolder, SinkBase, WriteFlags};
 use crate::buf::GrpcSlice;
+use crate::call::server::{Deadline, RpcContext};
 use crate::call::{check_run, Call, MessageReader, Method};
-use crate::channel::Channel;
+use crate::channel::{
+    compression_algorithm_for_level, Channel, CompressionAlgorithms, CompressionLevel,
+};
 use crate::codec::{DeserializeFn, SerializeFn};
 use crate::error::{Error, Result};
-use crate::metadata::{Metadata, UnownedMetadata};
+use crate::interceptor::CallInterceptor;
+use crate::metadata::{Metadata, MetadataBuilder, UnownedMetadata};
+use crate::server::CheckResult;
 use crate::task::{BatchFuture, BatchType};
+#[cfg(feature = "_secure")]
+use crate::CallCredentials;
 
 /// Update the flag bit in res.
 #[inline]
@@ -32,13 +40,53 @@ pub fn change_flag(res: &mut u32, flag: u32, set: bool) {
     }
 }
 
+/// Things that a client call inherits from the server call it's made on behalf of.
+///
+/// See [`CallOption::propagate_from`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PropagationMask(u32);
+
+impl PropagationMask {
+    // Following values are defined in grpc/impl/propagation_bits.h.
+    /// The client call can't outlive the deadline of the server call.
+    pub const DEADLINE: PropagationMask = PropagationMask(0x1);
+    /// The client call is canceled when the server call is canceled.
+    pub const CANCELLATION: PropagationMask = PropagationMask(0x8);
+    /// Propagate both the deadline and cancellation.
+    pub const ALL: PropagationMask = PropagationMask(0x1 | 0x8);
+
+    /// Check if all the bits of `other` are set.
+    pub fn contains(self, other: PropagationMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for PropagationMask {
+    type Output = PropagationMask;
+
+    fn bitor(self, rhs: PropagationMask) -> PropagationMask {
+        PropagationMask(self.0 | rhs.0)
+    }
+}
+
+/// A reference to the server call that a client call is made on behalf of.
+struct ParentCall(Call);
+
+// The call is only used to create child calls, which is thread safe.
+unsafe impl Sync for ParentCall {}
+
 /// Options for calls made by client.
 #[derive(Clone, Default)]
 pub struct CallOption {
     timeout: Option<Duration>,
+    deadline: Option<Deadline>,
     write_flags: WriteFlags,
     call_flags: u32,
     headers: Option<Metadata>,
+    compression: Option<CompressionAlgorithms>,
+    parent: Option<(Arc<ParentCall>, PropagationMask)>,
+    #[cfg(feature = "_secure")]
+    credentials: Option<Arc<CallCredentials>>,
 }
 
 impl CallOption {
@@ -69,6 +117,62 @@ impl CallOption {
         self.timeout
     }
 
+    /// Set an absolute deadline.
+    ///
+    /// If a timeout is also set, the earlier one takes effect.
+    pub fn deadline(mut self, deadline: impl Into<Deadline>) -> CallOption {
+        self.deadline = Some(deadline.into());
+        self
+    }
+
+    /// Get the absolute deadline.
+    pub fn get_deadline(&self) -> Option<Deadline> {
+        self.deadline
+    }
+
+    /// Make the call a child of the server call that `ctx` belongs to.
+    ///
+    /// The child call inherits the things selected by `mask`: it can't outlive
+    /// the deadline of the parent call with [`PropagationMask::DEADLINE`], and is
+    /// canceled together with the parent call with [`PropagationMask::CANCELLATION`].
+    /// It's useful for handlers that fan out to other services.
+    pub fn propagate_from(mut self, ctx: &RpcContext<'_>, mask: PropagationMask) -> CallOption {
+        self.parent = Some((Arc::new(ParentCall(ctx.call())), mask));
+        self
+    }
+
+    /// Get the deadline of the call, which is the earlier one of the timeout and
+    /// the absolute deadline.
+    pub(crate) fn call_deadline(&self) -> Deadline {
+        let deadline = self.deadline.unwrap_or_else(Deadline::infinite);
+        match self.timeout {
+            Some(timeout) => deadline.min(Deadline::after(timeout)),
+            None => deadline,
+        }
+    }
+
+    /// Get the raw parent call and the propagation mask.
+    pub(crate) fn parent_call(&self) -> (*mut grpc_sys::grpc_call, u32) {
+        match &self.parent {
+            Some((parent, mask)) => (parent.0.call, mask.0),
+            None => (ptr::null_mut(), 0),
+        }
+    }
+
+    /// Set the credentials of the call, which are sent together with the ones
+    /// attached to the channel credentials.
+    #[cfg(feature = "_secure")]
+    pub fn credentials(mut self, creds: CallCredentials) -> CallOption {
+        self.credentials = Some(Arc::new(creds));
+        self
+    }
+
+    /// Get the credentials of the call.
+    #[cfg(feature = "_secure")]
+    pub(crate) fn get_credentials(&self) -> Option<&CallCredentials> {
+        self.credentials.as_deref()
+    }
+
     /// Set the headers to be sent with the call.
     pub fn headers(mut self, meta: Metadata) -> CallOption {
         self.headers = Some(meta);
@@ -79,6 +183,80 @@ impl CallOption {
     pub fn get_headers(&self) -> Option<&Metadata> {
         self.headers.as_ref()
     }
+
+    /// Compress the messages sent by the call with `algo`, which overrides the
+    /// default algorithm of the channel.
+    ///
+    /// Messages written with `force_no_compress` are still sent uncompressed.
+    pub fn compression_algorithm(mut self, algo: CompressionAlgorithms) -> CallOption {
+        self.compression = Some(algo);
+        self
+    }
+
+    /// Compress the messages sent by the call with the algorithm that matches
+    /// `level`.
+    pub fn compression_level(mut self, level: CompressionLevel) -> CallOption {
+        self.compression = Some(compression_algorithm_for_level(level));
+        self
+    }
+
+    /// Get the compression algorithm of the call.
+    pub fn get_compression_algorithm(&self) -> Option<CompressionAlgorithms> {
+        self.compression
+    }
+
+    /// Merge `headers` into the headers of the call, existing entries with the
+    /// same keys are replaced.
+    pub(crate) fn merge_headers(&mut self, headers: &Metadata) {
+        match &mut self.headers {
+            Some(h) => h.merge(headers),
+            None => self.headers = Some(headers.clone()),
+        }
+    }
+
+    /// Merge the compression algorithm into headers so that it can be picked
+    /// up by gRPC C core.
+    fn prepare_headers(&mut self) {
+        if let Some(algo) = self.compression {
+            self.headers
+                .get_or_insert_with(|| MetadataBuilder::new().build())
+                .set_compression_algorithm(algo);
+        }
+    }
+}
+
+/// Pass the request payload through the interceptors before starting the call.
+fn intercept_payload(
+    interceptors: &mut Option<Box<dyn CallInterceptor>>,
+    payload: &mut GrpcSlice,
+) -> Result<()> {
+    if let Some(chain) = interceptors {
+        if let CheckResult::Abort(status) = chain.intercept_send(payload) {
+            let e = Error::RpcFailure(status);
+            chain.intercept_finish(Err(&e));
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Pass the response through the interceptors and deserialize it.
+///
+/// The interceptors are also notified the final result.
+fn intercept_resp<T>(
+    interceptors: &mut Option<Box<dyn CallInterceptor>>,
+    res: Result<MessageReader>,
+    resp_de: DeserializeFn<T>,
+) -> Result<T> {
+    let chain = match interceptors {
+        Some(chain) => chain,
+        None => return res.and_then(resp_de),
+    };
+    let res = res
+        .and_then(|reader| chain.intercept_recv(reader).map_err(Error::RpcFailure))
+        .and_then(resp_de);
+    chain.intercept_finish(res.as_ref().map(|_| ()));
+    res
 }
 
 impl Call {
@@ -87,10 +265,13 @@ impl Call {
         method: &Method<Req, Resp>,
         req: &Req,
         mut opt: CallOption,
+        mut interceptors: Option<Box<dyn CallInterceptor>>,
     ) -> Result<ClientUnaryReceiver<Resp>> {
         let call = channel.create_call(method, &opt)?;
+        opt.prepare_headers();
         let mut payload = GrpcSlice::default();
         (method.req_ser())(req, &mut payload)?;
+        intercept_payload(&mut interceptors, &mut payload)?;
         let cq_f = check_run(BatchType::CheckRead, |ctx, tag| unsafe {
             grpc_sys::grpcwrap_call_start_unary(
                 call.call,
@@ -104,15 +285,22 @@ impl Call {
                 tag,
             )
         });
-        Ok(ClientUnaryReceiver::new(call, cq_f, method.resp_de()))
+        Ok(ClientUnaryReceiver::new(
+            call,
+            cq_f,
+            method.resp_de(),
+            interceptors,
+        ))
     }
 
     pub fn client_streaming<Req, Resp>(
         channel: &Channel,
         method: &Method<Req, Resp>,
         mut opt: CallOption,
+        interceptors: Option<Box<dyn CallInterceptor>>,
     ) -> Result<(ClientCStreamSender<Req>, ClientCStreamReceiver<Resp>)> {
         let call = channel.create_call(method, &opt)?;
+        opt.prepare_headers();
         let cq_f = check_run(BatchType::CheckRead, |ctx, tag| unsafe {
             grpc_sys::grpcwrap_call_start_client_streaming(
                 call.call,
@@ -125,7 +313,7 @@ impl Call {
             )
         });
 
-        let share_call = Arc::new(Mutex::new(ShareCall::new(call, cq_f)));
+        let share_call = Arc::new(Mutex::new(ShareCall::new(call, cq_f, interceptors)));
         let sink = ClientCStreamSender::new(share_call.clone(), method.req_ser(), opt.call_flags);
         let recv = ClientCStreamReceiver::new(share_call, method.resp_de());
         Ok((sink, recv))
@@ -136,10 +324,13 @@ impl Call {
         method: &Method<Req, Resp>,
         req: &Req,
         mut opt: CallOption,
+        mut interceptors: Option<Box<dyn CallInterceptor>>,
     ) -> Result<ClientSStreamReceiver<Resp>> {
         let call = channel.create_call(method, &opt)?;
+        opt.prepare_headers();
         let mut payload = GrpcSlice::default();
         (method.req_ser())(req, &mut payload)?;
+        intercept_payload(&mut interceptors, &mut payload)?;
         let cq_f = check_run(BatchType::Finish, |ctx, tag| unsafe {
             grpc_sys::grpcwrap_call_start_server_streaming(
                 call.call,
@@ -163,6 +354,7 @@ impl Call {
             cq_f,
             method.resp_de(),
             headers_f,
+            interceptors,
         ))
     }
 
@@ -170,8 +362,10 @@ impl Call {
         channel: &Channel,
         method: &Method<Req, Resp>,
         mut opt: CallOption,
+        interceptors: Option<Box<dyn CallInterceptor>>,
     ) -> Result<(ClientDuplexSender<Req>, ClientDuplexReceiver<Resp>)> {
         let call = channel.create_call(method, &opt)?;
+        opt.prepare_headers();
         let cq_f = check_run(BatchType::Finish, |ctx, tag| unsafe {
             grpc_sys::grpcwrap_call_start_duplex_streaming(
                 call.call,
@@ -188,7 +382,7 @@ impl Call {
             grpc_sys::grpcwrap_call_recv_initial_metadata(call.call, ctx, tag)
         });
 
-        let share_call = Arc::new(Mutex::new(ShareCall::new(call, cq_f)));
+        let share_call = Arc::new(Mutex::new(ShareCall::new(call, cq_f, interceptors)));
         let sink = ClientDuplexSender::new(share_call.clone(), method.req_ser(), opt.call_flags);
         let recv = ClientDuplexReceiver::new(share_call, method.resp_de(), headers_f);
         Ok((sink, recv))
@@ -207,10 +401,16 @@ pub struct ClientUnaryReceiver<T> {
     message: Option<T>,
     initial_metadata: UnownedMetadata,
     trailing_metadata: UnownedMetadata,
+    interceptors: Option<Box<dyn CallInterceptor>>,
 }
 
 impl<T> ClientUnaryReceiver<T> {
-    fn new(call: Call, resp_f: BatchFuture, resp_de: DeserializeFn<T>) -> ClientUnaryReceiver<T> {
+    fn new(
+        call: Call,
+        resp_f: BatchFuture,
+        resp_de: DeserializeFn<T>,
+        interceptors: Option<Box<dyn CallInterceptor>>,
+    ) -> ClientUnaryReceiver<T> {
         ClientUnaryReceiver {
             call,
             resp_f,
@@ -219,6 +419,7 @@ impl<T> ClientUnaryReceiver<T> {
             message: None,
             initial_metadata: UnownedMetadata::empty(),
             trailing_metadata: UnownedMetadata::empty(),
+            interceptors,
         }
     }
 
@@ -238,10 +439,13 @@ impl<T> ClientUnaryReceiver<T> {
             return Ok(());
         }
 
-        let data = Pin::new(&mut self.resp_f).await?;
-        self.initial_metadata = data.initial_metadata;
-        self.trailing_metadata = data.trailing_metadata;
-        self.message = Some(self.resp_de(data.message_reader.unwrap())?);
+        let res = Pin::new(&mut self.resp_f).await.map(|data| {
+            self.initial_metadata = data.initial_metadata;
+            self.trailing_metadata = data.trailing_metadata;
+            data.message_reader.unwrap()
+        });
+        let msg = intercept_resp(&mut self.interceptors, res, self.resp_de)?;
+        self.message = Some(msg);
         self.finished = true;
         Ok(())
     }
@@ -286,11 +490,14 @@ impl<T: Unpin> Future for ClientUnaryReceiver<T> {
             panic!("future should not be polled twice.");
         }
 
-        let data = ready!(Pin::new(&mut self.resp_f).poll(cx)?);
-        self.initial_metadata = data.initial_metadata;
-        self.trailing_metadata = data.trailing_metadata;
-        self.finished = true;
-        Poll::Ready(self.resp_de(data.message_reader.unwrap()))
+        let t = &mut *self;
+        let res = ready!(Pin::new(&mut t.resp_f).poll(cx)).map(|data| {
+            t.initial_metadata = data.initial_metadata;
+            t.trailing_metadata = data.trailing_metadata;
+            t.finished = true;
+            data.message_reader.unwrap()
+        });
+        Poll::Ready(intercept_resp(&mut t.interceptors, res, t.resp_de))
     }
 }
 
@@ -339,15 +546,22 @@ impl<T> ClientCStreamReceiver<T> {
         if self.finished {
             return Ok(());
         }
-        let data = poll_fn(|cx| {
+        let res = poll_fn(|cx| {
             let mut call = self.call.lock();
             call.poll_finish(cx)
         })
-        .await?;
+        .await
+        .map(|data| {
+            self.initial_metadata = data.initial_metadata;
+            self.trailing_metadata = data.trailing_metadata;
+            data.message_reader.unwrap()
+        });
 
-        self.message = Some(self.resp_de(data.message_reader.unwrap())?);
-        self.initial_metadata = data.initial_metadata;
-        self.trailing_metadata = data.trailing_metadata;
+        let msg = {
+            let mut call = self.call.lock();
+            intercept_resp(&mut call.interceptors, res, self.resp_de)?
+        };
+        self.message = Some(msg);
         self.finished = true;
         Ok(())
     }
@@ -393,14 +607,18 @@ impl<T: Unpin> Future for ClientCStreamReceiver<T> {
             panic!("future should not be polled twice.");
         }
 
-        let data = {
-            let mut call = self.call.lock();
-            ready!(call.poll_finish(cx)?)
+        let t = &mut *self;
+        let mut call = t.call.lock();
+        let res = match ready!(call.poll_finish(cx)) {
+            Ok(data) => {
+                t.initial_metadata = data.initial_metadata;
+                t.trailing_metadata = data.trailing_metadata;
+                t.finished = true;
+                Ok(data.message_reader.unwrap())
+            }
+            Err(e) => Err(e),
         };
-        self.initial_metadata = data.initial_metadata;
-        self.trailing_metadata = data.trailing_metadata;
-        self.finished = true;
-        Poll::Ready((self.resp_de)(data.message_reader.unwrap()))
+        Poll::Ready(intercept_resp(&mut call.interceptors, res, t.resp_de))
     }
 }
 
@@ -553,6 +771,16 @@ impl<H: ShareCallHolder + Unpin, T> ResponseStreamImpl<H, T> {
     }
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<T>>> {
+        let res = ready!(self.as_mut().poll_impl(cx));
+        match &res {
+            None => self.call.call(|c| c.intercept_finish(Ok(()))),
+            Some(Err(e)) => self.call.call(|c| c.intercept_finish(Err(e))),
+            Some(Ok(_)) => {}
+        }
+        Poll::Ready(res)
+    }
+
+    fn poll_impl(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<T>>> {
         if !self.finished {
             let t = &mut *self;
             let finished = &mut t.finished;
@@ -587,6 +815,7 @@ impl<H: ShareCallHolder + Unpin, T> ResponseStreamImpl<H, T> {
             let msg_f = self.call.call(|c| c.call.start_recv_message())?;
             self.msg_f = Some(msg_f);
             if let Some(data) = bytes {
+                let data = self.call.call(|c| c.intercept_recv(data))?;
                 let msg = (self.resp_de)(data)?;
                 return Poll::Ready(Some(Ok(msg)));
             }
@@ -625,8 +854,9 @@ impl<Resp> ClientSStreamReceiver<Resp> {
         finish_f: BatchFuture,
         de: DeserializeFn<Resp>,
         headers_f: BatchFuture,
+        interceptors: Option<Box<dyn CallInterceptor>>,
     ) -> ClientSStreamReceiver<Resp> {
-        let share_call = ShareCall::new(call, finish_f);
+        let share_call = ShareCall::new(call, finish_f, interceptors);
         ClientSStreamReceiver {
             imp: ResponseStreamImpl::new(share_call, de, headers_f),
         }
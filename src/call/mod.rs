@@ -3,7 +3,7 @@
 pub mod client;
 pub mod server;
 
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt::{self, Debug, Display};
 use std::future::Future;
 use std::pin::Pin;
@@ -19,9 +19,12 @@ use libc::c_void;
 use parking_lot::Mutex;
 
 use crate::buf::{GrpcByteBuffer, GrpcByteBufferReader, GrpcSlice};
+use crate::channel::CompressionAlgorithms;
 use crate::codec::{DeserializeFn, Marshaller, SerializeFn};
 use crate::error::{Error, Result};
 use crate::grpc_sys::grpc_status_code::*;
+use crate::interceptor::CallInterceptor;
+use crate::server::{CallGuard, CheckResult};
 use crate::task::{self, BatchFuture, BatchResult, BatchType, CallTag};
 
 /// An gRPC status code structure.
@@ -57,6 +60,14 @@ macro_rules! status_codes {
         $(
             pub const $konst: RpcStatusCode = RpcStatusCode($num);
         )+
+
+            /// Gets the canonical name of the code, like `UNAVAILABLE`.
+            pub(crate) fn name(self) -> Option<&'static str> {
+                match self {
+                    $(RpcStatusCode($num) => Some(stringify!($konst)),)+
+                    RpcStatusCode(_) => None,
+                }
+            }
         }
 
         impl Debug for RpcStatusCode {
@@ -65,10 +76,7 @@ macro_rules! status_codes {
                     f,
                     "{}-{}",
                     self.0,
-                    match self {
-                        $(RpcStatusCode($num) => stringify!($konst),)+
-                        RpcStatusCode(_) => "INVALID_STATUS_CODE",
-                    }
+                    self.name().unwrap_or("INVALID_STATUS_CODE")
                 )
             }
         }
@@ -486,13 +494,7 @@ impl Call {
                 Some(p) => p.as_mut_ptr(),
                 None => ptr::null_mut(),
             };
-            let mut trailing_metadata: Option<Metadata> = if status.details.is_empty() {
-                None
-            } else {
-                let mut builder = MetadataBuilder::new();
-                builder.set_binary_error_details(&status.details);
-                Some(builder.build())
-            };
+            let mut trailing_metadata = error_details_metadata(status);
             grpc_sys::grpcwrap_call_send_status_from_server(
                 self.call,
                 ctx,
@@ -532,6 +534,7 @@ impl Call {
             } else {
                 (status.message.as_ptr(), status.message.len())
             };
+            let mut trailing_metadata = error_details_metadata(status);
             grpc_sys::grpcwrap_call_send_status_from_server(
                 call_ptr,
                 batch_ptr,
@@ -540,7 +543,9 @@ impl Call {
                 msg_len,
                 (&mut MetadataBuilder::new().build()) as *mut _ as _,
                 0,
-                ptr::null_mut(),
+                trailing_metadata
+                    .as_mut()
+                    .map_or_else(ptr::null_mut, |m| m as *mut _ as _),
                 ptr::null_mut(),
                 0,
                 tag_ptr as *mut c_void,
@@ -566,6 +571,38 @@ impl Call {
             grpc_sys::grpc_call_cancel(self.call, ptr::null_mut());
         }
     }
+
+    /// Cancel the rpc call with the given status.
+    ///
+    /// On server side, the status will be sent to the client if it has not
+    /// been sent yet.
+    fn cancel_with_status(&self, status: &RpcStatus) {
+        match self.cq.borrow() {
+            // Queue is shutdown, ignore.
+            Err(Error::QueueShutdown) => return,
+            Err(e) => panic!("unexpected error when canceling call: {:?}", e),
+            _ => {}
+        }
+        let msg = CString::new(status.message.replace('\0', "")).unwrap();
+        unsafe {
+            grpc_sys::grpc_call_cancel_with_status(
+                self.call,
+                status.code().into(),
+                msg.as_ptr(),
+                ptr::null_mut(),
+            );
+        }
+    }
+}
+
+/// Builds the trailing metadata that carries the details of `status`.
+fn error_details_metadata(status: &RpcStatus) -> Option<Metadata> {
+    if status.details.is_empty() {
+        return None;
+    }
+    let mut builder = MetadataBuilder::new();
+    builder.set_binary_error_details(&status.details);
+    Some(builder.build())
 }
 
 impl Drop for Call {
@@ -580,19 +617,30 @@ impl Drop for Call {
 /// hence we need to share the call in the both sides and abort the sink
 /// once the call is canceled or finished early.
 struct ShareCall {
+    // Keeps a server side call counted as in flight. It's declared first so
+    // that it's dropped before the call is released, otherwise the server may
+    // finish shutting down before the call is counted as finished.
+    in_flight: Option<CallGuard>,
     call: Call,
     close_f: BatchFuture,
     finished: bool,
     status: Option<RpcStatus>,
+    interceptors: Option<Box<dyn CallInterceptor>>,
 }
 
 impl ShareCall {
-    fn new(call: Call, close_f: BatchFuture) -> ShareCall {
+    fn new(
+        call: Call,
+        close_f: BatchFuture,
+        interceptors: Option<Box<dyn CallInterceptor>>,
+    ) -> ShareCall {
         ShareCall {
             call,
             close_f,
             finished: false,
             status: None,
+            interceptors,
+            in_flight: None,
         }
     }
 
@@ -626,6 +674,61 @@ impl ShareCall {
 
         task::check_alive(&self.close_f)
     }
+
+    /// Pass the received message through the interceptors.
+    ///
+    /// If any interceptor aborts, the call is canceled with the returned status.
+    fn intercept_recv(&mut self, reader: MessageReader) -> Result<MessageReader> {
+        self.intercept_request(reader)
+            .map_err(|status| self.abort(status))
+    }
+
+    /// Pass the received message through the interceptors without aborting the call.
+    fn intercept_request(
+        &mut self,
+        reader: MessageReader,
+    ) -> std::result::Result<MessageReader, RpcStatus> {
+        match &mut self.interceptors {
+            None => Ok(reader),
+            Some(chain) => chain.intercept_recv(reader),
+        }
+    }
+
+    /// Pass the message to be sent through the interceptors.
+    ///
+    /// If any interceptor aborts, the call is canceled with the returned status.
+    fn intercept_send(&mut self, msg: &mut GrpcSlice) -> Result<()> {
+        let res = match &mut self.interceptors {
+            None => return Ok(()),
+            Some(chain) => chain.intercept_send(msg),
+        };
+        match res {
+            CheckResult::Continue => Ok(()),
+            CheckResult::Abort(status) => Err(self.abort(status)),
+        }
+    }
+
+    /// Pass the status to be sent through the interceptors.
+    fn intercept_status(&mut self, status: &mut RpcStatus) {
+        if let Some(chain) = &mut self.interceptors {
+            chain.intercept_status(status);
+        }
+    }
+
+    /// Notify the interceptors the result of the call.
+    fn intercept_finish(&mut self, res: std::result::Result<(), &Error>) {
+        if let Some(chain) = &mut self.interceptors {
+            chain.intercept_finish(res);
+        }
+    }
+
+    fn abort(&mut self, mut status: RpcStatus) -> Error {
+        self.intercept_status(&mut status);
+        self.call.cancel_with_status(&status);
+        let e = Error::RpcFailure(status);
+        self.intercept_finish(Err(&e));
+        e
+    }
 }
 
 /// A helper trait that allows executing function on the internal `ShareCall` struct.
@@ -765,6 +868,8 @@ struct SinkBase {
     batch_f: Option<BatchFuture>,
     headers: Metadata,
     send_metadata: bool,
+    // Compression algorithm to be set in headers.
+    compression: Option<CompressionAlgorithms>,
     // Flag to indicate if enhance batch strategy. This behavior will modify the `buffer_hint` to batch
     // messages as much as possible.
     enhance_buffer_strategy: bool,
@@ -783,6 +888,7 @@ impl SinkBase {
             batch_f: None,
             headers: MetadataBuilder::new().build(),
             send_metadata,
+            compression: None,
             enhance_buffer_strategy: false,
             buffer: GrpcSlice::default(),
             buf_flags: None,
@@ -802,6 +908,7 @@ impl SinkBase {
         // note: only the first message can enter this code block.
         if self.send_metadata {
             ser(t, &mut self.buffer)?;
+            call.call(|c| c.intercept_send(&mut self.buffer))?;
             self.buf_flags = Some(flags);
             self.start_send_buffer_message(false, call, call_flags)?;
             self.send_metadata = false;
@@ -815,6 +922,7 @@ impl SinkBase {
         }
 
         ser(t, &mut self.buffer)?;
+        call.call(|c| c.intercept_send(&mut self.buffer))?;
         let hint = flags.get_buffer_hint();
         self.last_buf_hint &= hint;
         self.buf_flags = Some(flags);
@@ -872,6 +980,9 @@ impl SinkBase {
         flags = flags.buffer_hint(buffer_hint);
 
         let headers = if self.send_metadata {
+            if let Some(algo) = self.compression {
+                self.headers.set_compression_algorithm(algo);
+            }
             Some(&mut self.headers)
         } else {
             None
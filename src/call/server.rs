@@ -5,8 +5,8 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Duration;
-use std::{result, slice};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, result, slice};
 
 use crate::grpc_sys::{
     self, gpr_clock_type, gpr_timespec, grpc_call_error, grpcwrap_request_call_context,
@@ -20,13 +20,16 @@ use crate::buf::GrpcSlice;
 use crate::call::{
     BatchContext, Call, MessageReader, MethodType, RpcStatusCode, SinkBase, StreamingBase,
 };
+use crate::channel::{compression_algorithm_for_level, CompressionAlgorithms, CompressionLevel};
 use crate::codec::{DeserializeFn, SerializeFn};
 use crate::cq::CompletionQueue;
 use crate::error::{Error, Result};
-use crate::metadata::Metadata;
-use crate::server::ServerChecker;
-use crate::server::{BoxHandler, RequestCallContext};
-use crate::task::{BatchFuture, CallTag, Executor, Kicker};
+use crate::interceptor::{CallInterceptor, ServerInterceptorChain};
+use crate::limit::Acquire;
+use crate::metadata::{Metadata, MetadataBuilder};
+use crate::server::{CallGuard, RequestCallContext};
+use crate::task::{BatchFuture, CallTag, Executor, Kicker, Runtime};
+use crate::trace::TraceContext;
 use crate::CheckResult;
 
 /// A time point that an rpc or operation should finished before it.
@@ -45,6 +48,24 @@ impl Deadline {
         }
     }
 
+    /// Build a deadline that is `dur` later than now.
+    pub fn after(dur: Duration) -> Deadline {
+        Deadline::new(dur.into())
+    }
+
+    /// Build a deadline at the given time point.
+    pub fn at(time: SystemTime) -> Deadline {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let mut spec = gpr_timespec::from(since_epoch);
+        spec.clock_type = gpr_clock_type::GPR_CLOCK_REALTIME;
+        Deadline::new(spec)
+    }
+
+    /// Build a deadline that never expires.
+    pub fn infinite() -> Deadline {
+        Deadline::new(gpr_timespec::inf_future())
+    }
+
     /// Checks if the deadline is exceeded.
     pub fn exceeded(self) -> bool {
         unsafe {
@@ -53,6 +74,36 @@ impl Deadline {
         }
     }
 
+    /// Checks if the deadline never expires.
+    pub fn is_infinite(self) -> bool {
+        unsafe { grpc_sys::gpr_time_cmp(self.spec, gpr_timespec::inf_future()) == 0 }
+    }
+
+    /// Get the time left before the deadline.
+    ///
+    /// Zero is returned if the deadline is exceeded. For an infinite deadline,
+    /// the returned duration is too long to be meaningful, check it by
+    /// [`Deadline::is_infinite`] first.
+    pub fn remaining(self) -> Duration {
+        let left = unsafe {
+            let now = grpc_sys::gpr_now(gpr_clock_type::GPR_CLOCK_REALTIME);
+            grpc_sys::gpr_time_sub(self.spec, now)
+        };
+        if left.tv_sec < 0 {
+            return Duration::ZERO;
+        }
+        Duration::new(left.tv_sec as u64, left.tv_nsec as u32)
+    }
+
+    /// Returns the earlier one of the two deadlines.
+    pub(crate) fn min(self, other: Deadline) -> Deadline {
+        if unsafe { grpc_sys::gpr_time_cmp(self.spec, other.spec) } <= 0 {
+            self
+        } else {
+            other
+        }
+    }
+
     pub(crate) fn spec(self) -> gpr_timespec {
         self.spec
     }
@@ -64,7 +115,23 @@ impl From<Duration> for Deadline {
     /// The deadline will be `now + duration`.
     #[inline]
     fn from(dur: Duration) -> Deadline {
-        Deadline::new(dur.into())
+        Deadline::after(dur)
+    }
+}
+
+impl From<SystemTime> for Deadline {
+    #[inline]
+    fn from(time: SystemTime) -> Deadline {
+        Deadline::at(time)
+    }
+}
+
+impl fmt::Debug for Deadline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinite() {
+            return f.write_str("Deadline(infinite)");
+        }
+        f.debug_tuple("Deadline").field(&self.remaining()).finish()
     }
 }
 
@@ -92,16 +159,13 @@ impl RequestContext {
         cq: &CompletionQueue,
         rc: &mut RequestCallContext,
     ) -> result::Result<(), Self> {
-        let checker = rc.get_checker();
         let handler = unsafe { rc.get_handler(self.method()) };
-        match handler {
-            Some(handler) => match handler.method_type() {
-                MethodType::Unary | MethodType::ServerStreaming => Err(self),
-                _ => {
-                    execute(self, cq, None, handler, checker);
-                    Ok(())
-                }
-            },
+        match handler.map(|h| h.method_type()) {
+            Some(MethodType::Unary) | Some(MethodType::ServerStreaming) => Err(self),
+            Some(_) => {
+                execute(self, cq, None, rc);
+                Ok(())
+            }
             None => {
                 execute_unimplemented(self, cq.clone());
                 Ok(())
@@ -207,6 +271,19 @@ impl Drop for RequestContext {
     }
 }
 
+/// A [`RequestContext`] carried by the futures that wait for concurrency permits
+/// and async checkers before the call is dispatched.
+struct PendingRequest(RequestContext);
+
+// SAFETY: The futures holding `PendingRequest` are spawned by the `Executor` of
+// the completion queue the call is bound to. They are polled only by the poll
+// thread of that queue, wakers just kick the queue, so the request never
+// outlives or leaves the thread that received it. gRPC C core doesn't require
+// the request context to stay on one thread anyway, the only state tied to the
+// poll thread is the handler registry in `RequestCallContext`, which is
+// accessed by `dispatch` on the same poll thread.
+unsafe impl Send for PendingRequest {}
+
 /// A context for handling client side unary request.
 pub struct UnaryRequestContext {
     request: RequestContext,
@@ -245,10 +322,8 @@ impl UnaryRequestContext {
         cq: &CompletionQueue,
         reader: Option<MessageReader>,
     ) {
-        let checker = rc.get_checker();
-        let handler = unsafe { rc.get_handler(self.request.method()).unwrap() };
         if reader.is_some() {
-            return execute(self.request, cq, reader, handler, checker);
+            return execute(self.request, cq, reader, rc);
         }
 
         let status = RpcStatus::with_message(RpcStatusCode::INTERNAL, "No payload".to_owned());
@@ -289,7 +364,10 @@ impl<T> Stream for RequestStream<T> {
         let t = &mut *self;
         match ready!(t.base.poll(cx, &mut t.call, false)?) {
             None => Poll::Ready(None),
-            Some(data) => Poll::Ready(Some((t.de)(data))),
+            Some(data) => {
+                let data = t.call.lock().intercept_recv(data)?;
+                Poll::Ready(Some((t.de)(data)))
+            }
         }
     }
 }
@@ -339,16 +417,18 @@ macro_rules! impl_unary_sink {
             ser: SerializeFn<T>,
             headers: Option<Metadata>,
             call_flags: u32,
+            compression: ResponseCompression,
         }
 
         impl<T> $t<T> {
-            fn new(call: $holder, ser: SerializeFn<T>) -> $t<T> {
+            fn new(call: $holder, ser: SerializeFn<T>, compression: ResponseCompression) -> $t<T> {
                 $t {
                     call: Some(call),
                     write_flags: 0,
                     ser,
                     headers: None,
                     call_flags: 0,
+                    compression,
                 }
             }
 
@@ -357,6 +437,17 @@ macro_rules! impl_unary_sink {
                 self.headers = Some(meta);
             }
 
+            /// Compress the response with `algo`, which overrides the default
+            /// algorithm of the server.
+            pub fn set_compression_algorithm(&mut self, algo: CompressionAlgorithms) {
+                *self.compression.lock() = Some(algo);
+            }
+
+            /// Compress the response with the algorithm that matches `level`.
+            pub fn set_compression_level(&mut self, level: CompressionLevel) {
+                *self.compression.lock() = Some(compression_algorithm_for_level(level));
+            }
+
             #[inline]
             pub fn set_call_flags(&mut self, flags: u32) {
                 // TODO: implement a server-side call flags interface similar to the client-side .CallOption.
@@ -371,11 +462,14 @@ macro_rules! impl_unary_sink {
                 self.complete(status, None)
             }
 
-            fn complete(mut self, status: RpcStatus, t: Option<T>) -> $rt {
+            fn complete(mut self, mut status: RpcStatus, t: Option<T>) -> $rt {
                 let mut data = match t {
                     Some(t) => {
                         let mut buf = GrpcSlice::default();
-                        if let Err(e) = (self.ser)(&t, &mut buf) {
+                        let res = (self.ser)(&t, &mut buf).and_then(|_| {
+                            self.call.as_mut().unwrap().call(|c| c.intercept_send(&mut buf))
+                        });
+                        if let Err(e) = res {
                             return $rt {
                                 call: self.call.take().unwrap(),
                                 cq_f: None,
@@ -387,11 +481,18 @@ macro_rules! impl_unary_sink {
                     None => None,
                 };
 
+                let compression = *self.compression.lock();
+                if let Some(algo) = compression {
+                    self.headers
+                        .get_or_insert_with(|| MetadataBuilder::new().build())
+                        .set_compression_algorithm(algo);
+                }
                 let headers = &mut self.headers;
                 let call_flags = self.call_flags;
                 let write_flags = self.write_flags;
 
                 let res = self.call.as_mut().unwrap().call(|c| {
+                    c.intercept_status(&mut status);
                     c.call
                         .start_send_status_from_server(&status, headers, call_flags, true, &mut data, write_flags)
                 });
@@ -458,10 +559,11 @@ macro_rules! impl_stream_sink {
             flushed: bool,
             closed: bool,
             ser: SerializeFn<T>,
+            compression: ResponseCompression,
         }
 
         impl<T> $t<T> {
-            fn new(call: $holder, ser: SerializeFn<T>) -> $t<T> {
+            fn new(call: $holder, ser: SerializeFn<T>, compression: ResponseCompression) -> $t<T> {
                 $t {
                     call: Some(call),
                     base: SinkBase::new(true),
@@ -470,6 +572,7 @@ macro_rules! impl_stream_sink {
                     flushed: false,
                     closed: false,
                     ser,
+                    compression,
                 }
             }
 
@@ -477,6 +580,22 @@ macro_rules! impl_stream_sink {
                 self.base.headers = meta;
             }
 
+            /// Compress the messages with `algo`, which overrides the default
+            /// algorithm of the server.
+            ///
+            /// It should be called before sending the first message. Messages
+            /// written with `force_no_compress` are still sent uncompressed.
+            pub fn set_compression_algorithm(&mut self, algo: CompressionAlgorithms) {
+                *self.compression.lock() = Some(algo);
+            }
+
+            /// Compress the messages with the algorithm that matches `level`.
+            ///
+            /// It should be called before sending the first message.
+            pub fn set_compression_level(&mut self, level: CompressionLevel) {
+                *self.compression.lock() = Some(compression_algorithm_for_level(level));
+            }
+
             /// By default it always sends messages with their configured buffer hint. But when the
             /// `enhance_batch` is enabled, messages will be batched together as many as possible.
             /// The rules are listed as below:
@@ -494,10 +613,11 @@ macro_rules! impl_stream_sink {
                 self.status = status;
             }
 
-            pub fn fail(mut self, status: RpcStatus) -> $ft {
+            pub fn fail(mut self, mut status: RpcStatus) -> $ft {
                 assert!(self.flush_f.is_none());
                 let send_metadata = self.base.send_metadata;
                 let res = self.call.as_mut().unwrap().call(|c| {
+                    c.intercept_status(&mut status);
                     c.call
                         .start_send_status_from_server(&status, &mut None, 0, send_metadata, &mut None, 0)
                 });
@@ -544,6 +664,7 @@ macro_rules! impl_stream_sink {
             #[inline]
             fn start_send(mut self: Pin<&mut Self>, (msg, flags): (T, WriteFlags)) -> Result<()> {
                 let t = &mut *self;
+                t.base.compression = *t.compression.lock();
                 t.base.start_send(t.call.as_mut().unwrap(), &msg, flags, t.ser, 0)
             }
 
@@ -562,8 +683,9 @@ macro_rules! impl_stream_sink {
 
                     let send_metadata = self.base.send_metadata;
                     let t = &mut *self;
-                    let status = &t.status;
+                    let status = &mut t.status;
                     let flush_f = t.call.as_mut().unwrap().call(|c| {
+                        c.intercept_status(status);
                         c.call
                             .start_send_status_from_server(status, &mut None, 0, send_metadata, &mut None, 0)
                     })?;
@@ -640,19 +762,38 @@ impl_stream_sink!(
     Arc<Mutex<ShareCall>>
 );
 
+/// The compression algorithm of responses, shared by the context and the sink
+/// of a call.
+type ResponseCompression = Arc<Mutex<Option<CompressionAlgorithms>>>;
+
 /// A context for rpc handling.
 pub struct RpcContext<'a> {
+    // Dropped before the call is released, see `ShareCall`.
+    in_flight: Option<CallGuard>,
     ctx: RequestContext,
     executor: Executor<'a>,
+    runtime: Runtime,
     deadline: Deadline,
+    interceptors: Option<Box<dyn CallInterceptor>>,
+    trace: Option<TraceContext>,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    compression: ResponseCompression,
 }
 
 impl RpcContext<'_> {
-    fn new(ctx: RequestContext, cq: &CompletionQueue) -> RpcContext<'_> {
+    fn new(ctx: RequestContext, cq: &CompletionQueue, runtime: Runtime) -> RpcContext<'_> {
         RpcContext {
             deadline: ctx.deadline(),
             ctx,
             executor: Executor::new(cq),
+            runtime,
+            interceptors: None,
+            trace: None,
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::none(),
+            in_flight: None,
+            compression: ResponseCompression::default(),
         }
     }
 
@@ -686,6 +827,22 @@ impl RpcContext<'_> {
         self.ctx.peer()
     }
 
+    /// Compress the responses with `algo`, which overrides the default
+    /// algorithm of the server.
+    ///
+    /// It's the same as setting it on the sink of the call, and should be
+    /// called before the first response is sent.
+    pub fn set_compression_algorithm(&self, algo: CompressionAlgorithms) {
+        *self.compression.lock() = Some(algo);
+    }
+
+    /// Compress the responses with the algorithm that matches `level`.
+    ///
+    /// It should be called before the first response is sent.
+    pub fn set_compression_level(&self, level: CompressionLevel) {
+        *self.compression.lock() = Some(compression_algorithm_for_level(level));
+    }
+
     /// Wrapper around the gRPC Core AuthContext
     ///
     /// If the server binds in non-secure mode, this will return None
@@ -694,15 +851,52 @@ impl RpcContext<'_> {
         self.ctx.auth_context()
     }
 
+    /// Get the trace context of the call.
+    ///
+    /// It's only available when a propagator is set by
+    /// [`ServerBuilder::trace_propagator`](crate::ServerBuilder::trace_propagator).
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace.as_ref()
+    }
+
+    /// Get the span of the call.
+    ///
+    /// It's disabled unless a propagator is set by
+    /// [`ServerBuilder::trace_propagator`](crate::ServerBuilder::trace_propagator).
+    #[cfg(feature = "tracing")]
+    pub fn span(&self) -> &tracing::Span {
+        &self.span
+    }
+
     /// Spawn the future into current gRPC poll thread.
     ///
     /// This can reduce a lot of context switching, but please make
     /// sure there is no heavy work in the future.
+    ///
+    /// If the server is built with a Tokio runtime handle, the future is spawned
+    /// on Tokio instead, see `ServerBuilder::spawn_on_tokio`. If the call has
+    /// a trace context, it's set as current whenever the future is polled.
     pub fn spawn<F>(&self, f: F)
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        self.executor.spawn(f, self.kicker())
+        match &self.trace {
+            None => self.spawn_inner(f),
+            Some(trace) => {
+                #[cfg(feature = "tracing")]
+                let f = tracing::Instrument::instrument(f, self.span.clone());
+                self.spawn_inner(trace.clone().instrument(f))
+            }
+        }
+    }
+
+    fn spawn_inner<F>(&self, f: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        if let Some(f) = self.runtime.try_spawn(f) {
+            self.executor.spawn(f, self.kicker())
+        }
     }
 }
 
@@ -720,7 +914,7 @@ macro_rules! accept_call {
 
 // Helper function to call a unary handler.
 pub fn execute_unary<P, Q, F>(
-    ctx: RpcContext<'_>,
+    mut ctx: RpcContext<'_>,
     ser: SerializeFn<Q>,
     de: DeserializeFn<P>,
     payload: MessageReader,
@@ -730,24 +924,31 @@ pub fn execute_unary<P, Q, F>(
 {
     let mut call = ctx.call();
     let close_f = accept_call!(call);
-    let request = match de(payload) {
-        Ok(f) => f,
-        Err(e) => {
-            let status = RpcStatus::with_message(
+    let mut call = ShareCall::new(call, close_f, ctx.interceptors.take());
+    call.in_flight = ctx.in_flight.take();
+    let request = call.intercept_request(payload).and_then(|payload| {
+        de(payload).map_err(|e| {
+            RpcStatus::with_message(
                 RpcStatusCode::INTERNAL,
                 format!("Failed to deserialize response message: {e:?}"),
-            );
-            call.abort(&status);
+            )
+        })
+    });
+    let request = match request {
+        Ok(t) => t,
+        Err(mut status) => {
+            call.intercept_status(&mut status);
+            call.call.abort(&status);
             return;
         }
     };
-    let sink = UnarySink::new(ShareCall::new(call, close_f), ser);
+    let sink = UnarySink::new(call, ser, ctx.compression.clone());
     f(ctx, request, sink)
 }
 
 // Helper function to call client streaming handler.
 pub fn execute_client_streaming<P, Q, F>(
-    ctx: RpcContext<'_>,
+    mut ctx: RpcContext<'_>,
     ser: SerializeFn<Q>,
     de: DeserializeFn<P>,
     f: &mut F,
@@ -756,16 +957,18 @@ pub fn execute_client_streaming<P, Q, F>(
 {
     let mut call = ctx.call();
     let close_f = accept_call!(call);
-    let call = Arc::new(Mutex::new(ShareCall::new(call, close_f)));
+    let mut call = ShareCall::new(call, close_f, ctx.interceptors.take());
+    call.in_flight = ctx.in_flight.take();
+    let call = Arc::new(Mutex::new(call));
 
     let req_s = RequestStream::new(call.clone(), de);
-    let sink = ClientStreamingSink::new(call, ser);
+    let sink = ClientStreamingSink::new(call, ser, ctx.compression.clone());
     f(ctx, req_s, sink)
 }
 
 // Helper function to call server streaming handler.
 pub fn execute_server_streaming<P, Q, F>(
-    ctx: RpcContext<'_>,
+    mut ctx: RpcContext<'_>,
     ser: SerializeFn<Q>,
     de: DeserializeFn<P>,
     payload: MessageReader,
@@ -775,26 +978,32 @@ pub fn execute_server_streaming<P, Q, F>(
 {
     let mut call = ctx.call();
     let close_f = accept_call!(call);
-
-    let request = match de(payload) {
-        Ok(t) => t,
-        Err(e) => {
-            let status = RpcStatus::with_message(
+    let mut call = ShareCall::new(call, close_f, ctx.interceptors.take());
+    call.in_flight = ctx.in_flight.take();
+    let request = call.intercept_request(payload).and_then(|payload| {
+        de(payload).map_err(|e| {
+            RpcStatus::with_message(
                 RpcStatusCode::INTERNAL,
                 format!("Failed to deserialize response message: {e:?}"),
-            );
-            call.abort(&status);
+            )
+        })
+    });
+    let request = match request {
+        Ok(t) => t,
+        Err(mut status) => {
+            call.intercept_status(&mut status);
+            call.call.abort(&status);
             return;
         }
     };
 
-    let sink = ServerStreamingSink::new(ShareCall::new(call, close_f), ser);
+    let sink = ServerStreamingSink::new(call, ser, ctx.compression.clone());
     f(ctx, request, sink)
 }
 
 // Helper function to call duplex streaming handler.
 pub fn execute_duplex_streaming<P, Q, F>(
-    ctx: RpcContext<'_>,
+    mut ctx: RpcContext<'_>,
     ser: SerializeFn<Q>,
     de: DeserializeFn<P>,
     f: &mut F,
@@ -803,10 +1012,12 @@ pub fn execute_duplex_streaming<P, Q, F>(
 {
     let mut call = ctx.call();
     let close_f = accept_call!(call);
-    let call = Arc::new(Mutex::new(ShareCall::new(call, close_f)));
+    let mut call = ShareCall::new(call, close_f, ctx.interceptors.take());
+    call.in_flight = ctx.in_flight.take();
+    let call = Arc::new(Mutex::new(call));
 
     let req_s = RequestStream::new(call.clone(), de);
-    let sink = DuplexSink::new(call, ser);
+    let sink = DuplexSink::new(call, ser, ctx.compression.clone());
     f(ctx, req_s, sink)
 }
 
@@ -826,12 +1037,66 @@ fn execute(
     ctx: RequestContext,
     cq: &CompletionQueue,
     payload: Option<MessageReader>,
-    f: &mut BoxHandler,
-    mut checkers: Vec<Box<dyn ServerChecker>>,
+    rc: &mut RequestCallContext,
+) {
+    let mut guard = rc.track_call();
+    let mut limiters = rc.limiters(ctx.method()).into_iter();
+    // Permits are taken one by one in the order of `limiters`, so a call never
+    // holds a later permit while waiting for an earlier one, which could
+    // deadlock with a call waiting the other way around.
+    let queued = loop {
+        let limiter = match limiters.next() {
+            Some(limiter) => limiter,
+            None => return check(ctx, cq, payload, rc, guard),
+        };
+        match limiter.acquire() {
+            Acquire::Acquired(permit) => guard.permits.push(permit),
+            Acquire::Queued(permit) => break permit,
+            Acquire::Rejected(status) => {
+                ctx.call(cq.clone()).abort(&status);
+                return;
+            }
+        }
+    };
+
+    let executor = Executor::new(cq);
+    let kicker = Kicker::from_call(ctx.call(cq.clone()));
+    let cq = cq.clone();
+    let mut rc = rc.clone();
+    let pending = PendingRequest(ctx);
+    // Like async checkers, the future is always polled by the poll thread of `cq`.
+    executor.spawn(
+        async move {
+            guard.permits.push(queued.await);
+            for limiter in limiters {
+                let permit = match limiter.acquire() {
+                    Acquire::Acquired(permit) => permit,
+                    Acquire::Queued(permit) => permit.await,
+                    Acquire::Rejected(status) => {
+                        pending.0.call(cq.clone()).abort(&status);
+                        return;
+                    }
+                };
+                guard.permits.push(permit);
+            }
+            check(pending.0, &cq, payload, &mut rc, guard)
+        },
+        kicker,
+    )
+}
+
+// Runs the checkers before dispatching the call.
+fn check(
+    ctx: RequestContext,
+    cq: &CompletionQueue,
+    payload: Option<MessageReader>,
+    rc: &mut RequestCallContext,
+    guard: CallGuard,
 ) {
-    let rpc_ctx = RpcContext::new(ctx, cq);
+    let mut rpc_ctx = RpcContext::new(ctx, cq, rc.runtime().clone());
+    rpc_ctx.in_flight = Some(guard);
 
-    for handler in checkers.iter_mut() {
+    for handler in rc.get_checker().iter_mut() {
         match handler.check(&rpc_ctx) {
             CheckResult::Continue => {}
             CheckResult::Abort(status) => {
@@ -841,5 +1106,82 @@ fn execute(
         }
     }
 
-    f.handle(rpc_ctx, payload)
+    let async_checkers = rc.get_async_checkers();
+    if async_checkers.is_empty() {
+        return dispatch(rpc_ctx, payload, rc);
+    }
+
+    let checks: Vec<_> = async_checkers
+        .into_iter()
+        .map(|mut checker| checker.check(&rpc_ctx))
+        .collect();
+    let kicker = rpc_ctx.kicker();
+    let RpcContext {
+        ctx,
+        executor,
+        in_flight,
+        ..
+    } = rpc_ctx;
+    let cq = cq.clone();
+    let mut rc = rc.clone();
+    let pending = PendingRequest(ctx);
+    // The future is always polled by the poll thread of `cq`, which owns the
+    // handler registry of `rc`.
+    executor.spawn(
+        async move {
+            for check in checks {
+                if let CheckResult::Abort(status) = check.await {
+                    pending.0.call(cq).abort(&status);
+                    return;
+                }
+            }
+            let mut rpc_ctx = RpcContext::new(pending.0, &cq, rc.runtime().clone());
+            rpc_ctx.in_flight = in_flight;
+            dispatch(rpc_ctx, payload, &mut rc)
+        },
+        kicker,
+    )
+}
+
+// Starts the interceptors and invokes the handler.
+fn dispatch(
+    mut rpc_ctx: RpcContext<'_>,
+    payload: Option<MessageReader>,
+    rc: &mut RequestCallContext,
+) {
+    #[allow(unused_mut)]
+    let mut interceptors = rc.get_interceptors();
+    if let Some(propagator) = rc.trace_propagator() {
+        let trace = match propagator.extract(rpc_ctx.request_headers()) {
+            Some(parent) => parent.child(),
+            None => TraceContext::new_root(),
+        };
+        #[cfg(feature = "tracing")]
+        {
+            let (span, interceptor) = crate::trace::server_span(&rpc_ctx, &trace);
+            // Put it outermost so that it sees the final status.
+            interceptors.insert(0, interceptor);
+            rpc_ctx.span = span;
+        }
+        rpc_ctx.trace = Some(trace);
+    }
+    if !interceptors.is_empty() {
+        let mut chain = ServerInterceptorChain::new(interceptors);
+        if let CheckResult::Abort(mut status) = chain.start(&rpc_ctx) {
+            chain.intercept_status(&mut status);
+            rpc_ctx.call().abort(&status);
+            return;
+        }
+        rpc_ctx.interceptors = Some(Box::new(chain));
+    }
+
+    let f = unsafe { rc.get_handler(rpc_ctx.method()).unwrap() };
+    match rpc_ctx.trace.clone() {
+        None => f.handle(rpc_ctx, payload),
+        Some(trace) => {
+            #[cfg(feature = "tracing")]
+            let _enter = rpc_ctx.span.clone().entered();
+            trace.in_scope(|| f.handle(rpc_ctx, payload))
+        }
+    }
 }
@@ -19,10 +19,11 @@ use crate::call::{Call, Method};
 use crate::cq::CompletionQueue;
 use crate::env::Environment;
 use crate::error::Result;
+use crate::interceptor::ClientInterceptor;
 use crate::task::CallTag;
-use crate::task::Kicker;
+use crate::task::{Kicker, Runtime};
 use crate::{CallOption, ChannelCredentials};
-use crate::{ResourceQuota, RpcStatusCode};
+use crate::{ResourceQuota, RpcStatusCode, ServiceConfig};
 
 pub use crate::grpc_sys::{
     grpc_compression_algorithm as CompressionAlgorithms,
@@ -46,6 +47,26 @@ fn dur_to_ms(dur: Duration) -> i32 {
     cmp::min(i32::MAX as u64, millis) as i32
 }
 
+/// Gets the name of `algo` used by the `grpc-encoding` header.
+pub(crate) fn compression_algorithm_name(algo: CompressionAlgorithms) -> &'static str {
+    unsafe {
+        let mut name = ptr::null();
+        assert_eq!(
+            grpc_sys::grpc_compression_algorithm_name(algo, &mut name),
+            1,
+            "invalid compression algorithm {algo:?}"
+        );
+        CStr::from_ptr(name).to_str().unwrap()
+    }
+}
+
+/// Chooses the compression algorithm for `level`, assuming all algorithms are
+/// accepted by the peer.
+pub(crate) fn compression_algorithm_for_level(level: CompressionLevel) -> CompressionAlgorithms {
+    let all = (1 << CompressionAlgorithms::GRPC_COMPRESS_ALGORITHMS_COUNT as u32) - 1;
+    unsafe { grpc_sys::grpc_compression_algorithm_for_level(level, all) }
+}
+
 enum Options {
     Integer(i32),
     String(CString),
@@ -74,6 +95,8 @@ pub struct ChannelBuilder {
     env: Arc<Environment>,
     options: HashMap<Cow<'static, [u8]>, Options>,
     credentials: Option<ChannelCredentials>,
+    interceptors: Vec<Box<dyn ClientInterceptor>>,
+    runtime: Runtime,
 }
 
 impl ChannelBuilder {
@@ -83,6 +106,8 @@ impl ChannelBuilder {
             env,
             options: HashMap::new(),
             credentials: None,
+            interceptors: Vec::new(),
+            runtime: Runtime::default(),
         }
     }
 
@@ -430,6 +455,19 @@ impl ChannelBuilder {
         self
     }
 
+    /// Set the default service config of the channel.
+    ///
+    /// It's ignored if the name resolver returns a service config. Note retries
+    /// configured by the service config take effect only when retry is enabled,
+    /// see [`enable_retry`](ChannelBuilder::enable_retry).
+    pub fn service_config(mut self, config: ServiceConfig) -> ChannelBuilder {
+        self.options.insert(
+            Cow::Borrowed(grpcio_sys::GRPC_ARG_SERVICE_CONFIG),
+            Options::String(CString::new(config.as_json()).unwrap()),
+        );
+        self
+    }
+
     /// Set a raw integer configuration.
     ///
     /// This method is only for bench usage, users should use the encapsulated API instead.
@@ -450,6 +488,34 @@ impl ChannelBuilder {
         self
     }
 
+    /// Add an interceptor to all calls made through the channel.
+    ///
+    /// Interceptors registered on the channel are invoked before the ones registered
+    /// on [`Client`](crate::Client).
+    pub fn add_interceptor<I: ClientInterceptor + 'static>(
+        mut self,
+        interceptor: I,
+    ) -> ChannelBuilder {
+        self.interceptors.push(Box::new(interceptor));
+        self
+    }
+
+    /// Spawn the futures passed to [`Client::spawn`](crate::Client::spawn) on
+    /// Tokio instead of the gRPC poll threads.
+    ///
+    /// Nothing else is moved to Tokio. Note that it's not necessary to set it
+    /// to drive calls from Tokio.
+    /// Receivers and senders returned by [`Client`](crate::Client) can be awaited
+    /// in or `tokio::spawn`ed into any runtime directly: they are woken by the
+    /// poll threads when events arrive, no extra hop through the poll threads is
+    /// involved. Avoid the blocking methods like `Client::unary_call` in Tokio
+    /// worker threads though, they block the worker until the call finishes.
+    #[cfg(feature = "tokio")]
+    pub fn spawn_on_tokio(mut self, handle: tokio::runtime::Handle) -> ChannelBuilder {
+        self.runtime = Runtime::tokio(handle);
+        self
+    }
+
     /// Build `ChannelArgs` from the current configuration.
     #[allow(clippy::useless_conversion)]
     #[allow(clippy::cmp_owned)]
@@ -507,6 +573,15 @@ impl ChannelBuilder {
             unsafe { grpcio_sys::grpc_channel_create(addr_ptr, creds.as_mut_ptr(), args.args) };
 
         unsafe { Channel::new(self.env.pick_cq(), self.env, channel) }
+            .with_credentials(creds)
+            .with_interceptors(self.interceptors)
+            .with_runtime(self.runtime)
+    }
+
+    /// Whether credentials other than insecure ones are set.
+    #[cfg(unix)]
+    pub(crate) fn has_secure_credentials(&self) -> bool {
+        self.credentials.as_ref().is_some_and(|c| !c.is_insecure())
     }
 
     /// Build a [`Channel`] taking over an established connection from
@@ -534,6 +609,9 @@ impl ChannelBuilder {
             grpcio_sys::grpc_channel_create_from_fd(target_ptr, fd, creds.as_mut_ptr(), args.args);
 
         Channel::new(self.env.pick_cq(), self.env, channel)
+            .with_credentials(creds)
+            .with_interceptors(self.interceptors)
+            .with_runtime(self.runtime)
     }
 
     /// Build a [`Channel`] taking over an established connection from a file
@@ -606,6 +684,8 @@ impl Drop for ChannelArgs {
 struct ChannelInner {
     _env: Arc<Environment>,
     channel: *mut grpc_channel,
+    // Some credentials need to outlive the channel, like the ones with a fetcher.
+    _credentials: Option<ChannelCredentials>,
 }
 
 impl ChannelInner {
@@ -636,6 +716,8 @@ impl Drop for ChannelInner {
 pub struct Channel {
     inner: Arc<ChannelInner>,
     cq: CompletionQueue,
+    interceptors: Arc<Vec<Box<dyn ClientInterceptor>>>,
+    runtime: Runtime,
 }
 
 #[allow(clippy::non_send_fields_in_send_ty)]
@@ -658,11 +740,42 @@ impl Channel {
         channel: *mut grpc_channel,
     ) -> Channel {
         Channel {
-            inner: Arc::new(ChannelInner { _env: env, channel }),
+            inner: Arc::new(ChannelInner {
+                _env: env,
+                channel,
+                _credentials: None,
+            }),
             cq,
+            interceptors: Arc::default(),
+            runtime: Runtime::default(),
         }
     }
 
+    fn with_interceptors(mut self, interceptors: Vec<Box<dyn ClientInterceptor>>) -> Channel {
+        self.interceptors = Arc::new(interceptors);
+        self
+    }
+
+    fn with_credentials(mut self, creds: ChannelCredentials) -> Channel {
+        // The channel is just created, so it's not shared yet.
+        Arc::get_mut(&mut self.inner).unwrap()._credentials = Some(creds);
+        self
+    }
+
+    fn with_runtime(mut self, runtime: Runtime) -> Channel {
+        self.runtime = runtime;
+        self
+    }
+
+    pub(crate) fn runtime(&self) -> &Runtime {
+        &self.runtime
+    }
+
+    /// Get the interceptors registered on the channel.
+    pub(crate) fn interceptors(&self) -> &[Box<dyn ClientInterceptor>] {
+        &self.interceptors
+    }
+
     /// Create a lame channel that will fail all its operations.
     pub fn lame(env: Arc<Environment>, target: &str) -> Channel {
         unsafe {
@@ -772,23 +885,31 @@ impl Channel {
             let cq = cq_ref.as_ptr();
             let method_ptr = method.name.as_ptr();
             let method_len = method.name.len();
-            let timeout = opt
-                .get_timeout()
-                .map_or_else(gpr_timespec::inf_future, gpr_timespec::from);
+            let deadline = opt.call_deadline().spec();
+            let (parent, propagation_mask) = opt.parent_call();
             grpc_sys::grpcwrap_channel_create_call(
                 ch,
-                ptr::null_mut(),
-                0,
+                parent,
+                propagation_mask,
                 cq,
                 method_ptr as *const _,
                 method_len,
                 ptr::null(),
                 0,
-                timeout,
+                deadline,
             )
         };
+        let call = unsafe { Call::from_raw(raw_call, self.cq.clone()) };
+
+        #[cfg(feature = "_secure")]
+        if let Some(creds) = opt.get_credentials() {
+            let code = unsafe { grpc_sys::grpc_call_set_credentials(raw_call, creds.as_mut_ptr()) };
+            if code != grpc_sys::grpc_call_error::GRPC_CALL_OK {
+                return Err(crate::Error::CallFailure(code));
+            }
+        }
 
-        unsafe { Ok(Call::from_raw(raw_call, self.cq.clone())) }
+        Ok(call)
     }
 
     pub(crate) fn cq(&self) -> &CompletionQueue {
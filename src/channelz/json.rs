@@ -0,0 +1,243 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! A minimal JSON reader for the documents rendered by gRPC C core.
+//!
+//! Numbers are kept as text, callers parse them into the type they expect.
+
+use std::collections::BTreeMap;
+
+pub type Object = BTreeMap<String, Value>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Object),
+}
+
+struct Parser<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, msg: &str) -> String {
+        format!("{} at offset {}", msg, self.pos)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.buf.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.buf.get(self.pos).cloned()
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), String> {
+        if self.peek() != Some(c) {
+            return Err(self.error(&format!("expect '{}'", c as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, s: &str, v: Value) -> Result<Value, String> {
+        if !self.buf[self.pos..].starts_with(s.as_bytes()) {
+            return Err(self.error("invalid literal"));
+        }
+        self.pos += s.len();
+        Ok(v)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some(b'{') => self.object().map(Value::Object),
+            Some(b'[') => self.array().map(Value::Array),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => Ok(Value::Number(self.number())),
+            _ => Err(self.error("expect value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Object, String> {
+        self.expect(b'{')?;
+        let mut o = Object::new();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(o);
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expect key"));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            o.insert(key, self.value()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(o);
+                }
+                _ => return Err(self.error("expect ',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Vec<Value>, String> {
+        self.expect(b'[')?;
+        let mut a = vec![];
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(a);
+        }
+        loop {
+            a.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(a);
+                }
+                _ => return Err(self.error("expect ',' or ']'")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> String {
+        let start = self.pos;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.buf.get(self.pos) {
+            self.pos += 1;
+        }
+        // Only ASCII characters are consumed.
+        String::from_utf8_lossy(&self.buf[start..self.pos]).into_owned()
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self
+            .buf
+            .get(self.pos..self.pos + 4)
+            .and_then(|d| std::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(digits)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut s = Vec::new();
+        loop {
+            let c = *self
+                .buf
+                .get(self.pos)
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.pos += 1;
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let e = *self
+                        .buf
+                        .get(self.pos)
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.pos += 1;
+                    let c = match e {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code)
+                                && self.buf[self.pos..].starts_with(b"\\u")
+                            {
+                                self.pos += 2;
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("invalid surrogate pair"));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    s.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                c => s.push(c),
+            }
+        }
+        // The input is a `str` and escapes are encoded properly.
+        String::from_utf8(s).map_err(|_| self.error("invalid utf8"))
+    }
+}
+
+/// Parses a JSON document.
+pub fn parse(json: &str) -> Result<Value, String> {
+    let mut p = Parser {
+        buf: json.as_bytes(),
+        pos: 0,
+    };
+    let v = p.value()?;
+    if p.peek().is_some() {
+        return Err(p.error("trailing characters"));
+    }
+    Ok(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let v = parse(
+            r#" {"a": [1, -2.5e3, "x\"é😀\u00e9\ud83d\ude00"], "b": {"c": null, "d": true}} "#,
+        )
+        .unwrap();
+        let mut b = Object::new();
+        b.insert("c".to_owned(), Value::Null);
+        b.insert("d".to_owned(), Value::Bool(true));
+        let mut exp = Object::new();
+        exp.insert(
+            "a".to_owned(),
+            Value::Array(vec![
+                Value::Number("1".to_owned()),
+                Value::Number("-2.5e3".to_owned()),
+                Value::String("x\"\u{e9}\u{1f600}\u{e9}\u{1f600}".to_owned()),
+            ]),
+        );
+        exp.insert("b".to_owned(), Value::Object(b));
+        assert_eq!(v, Value::Object(exp));
+
+        for s in &[
+            "",
+            "{",
+            "[1,]",
+            r#"{"a" 1}"#,
+            r#""\x""#,
+            "nul",
+            "{} {}",
+            // Unpaired high surrogate.
+            r#""\ud800""#,
+            // High surrogate followed by a non low surrogate.
+            r#""\ud800\u0041""#,
+        ] {
+            assert!(parse(s).is_err(), "{}", s);
+        }
+    }
+}
@@ -5,10 +5,19 @@
 //! identified by an positive unique integer, which is allocated in order. For more
 //! explanation, see <https://github.com/grpc/proposal/blob/master/A14-channelz.md>.
 //!
-//! A full support requires a service that allow remote querying. But for now it's
-//! too complicated to add full support. Because gRPC C core exposes the information
-//! using JSON format, and there is no protobuf library that supports parsing json
-//! format in Rust. So this module only provides safe APIs to access the informations.
+//! gRPC C core exposes the information using JSON format, this module provides
+//! safe APIs to access the informations. With the `channelz-types` feature, the
+//! JSON is also parsed into typed structs. To allow remote querying, register the
+//! `grpc.channelz.v1.Channelz` service provided by the grpcio-channelz crate, which
+//! converts the typed structs into protobuf messages.
+
+#[cfg(feature = "channelz-types")]
+mod json;
+#[cfg(feature = "channelz-types")]
+mod types;
+
+#[cfg(feature = "channelz-types")]
+pub use self::types::*;
 
 use std::ffi::CStr;
 use std::{cmp, str};
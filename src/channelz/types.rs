@@ -0,0 +1,721 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Typed channelz data model parsed from the JSON returned by gRPC C core.
+//!
+//! C core renders entities following the proto3 JSON mapping of
+//! `grpc.channelz.v1`. Fields that are absent in JSON get their default values.
+
+use std::convert::TryFrom;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::json::{self, Object, Value};
+use crate::error::{Error, Result};
+use crate::ConnectivityState;
+
+/// A reference to a channelz entity.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EntityRef {
+    /// The globally unique id of the entity.
+    pub id: u64,
+    /// An optional name associated with the entity.
+    pub name: String,
+}
+
+/// Statistics of calls on a channel, subchannel or server.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallCounters {
+    pub calls_started: i64,
+    pub calls_succeeded: i64,
+    pub calls_failed: i64,
+    pub last_call_started: Option<SystemTime>,
+}
+
+/// Severity of a trace event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceSeverity {
+    Unknown,
+    Info,
+    Warning,
+    Error,
+}
+
+/// An interesting thing that happened to a channel, subchannel or server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub description: String,
+    pub severity: TraceSeverity,
+    pub timestamp: Option<SystemTime>,
+    /// The channel this event refers to, e.g. a child channel is created.
+    pub channel_ref: Option<EntityRef>,
+    /// The subchannel this event refers to, e.g. a subchannel is created.
+    pub subchannel_ref: Option<EntityRef>,
+}
+
+/// Recent events of a channel, subchannel or server.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelTrace {
+    /// Number of events ever logged, which can be larger than the length of
+    /// `events` as old events are garbage collected.
+    pub num_events_logged: i64,
+    pub creation_timestamp: Option<SystemTime>,
+    pub events: Vec<TraceEvent>,
+}
+
+/// Information of a channel or a subchannel.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelInfo {
+    pub id: u64,
+    pub name: String,
+    /// `None` if the state is unknown.
+    pub state: Option<ConnectivityState>,
+    /// The target this channel originally tried to connect to.
+    pub target: String,
+    pub calls: CallCounters,
+    pub trace: Option<ChannelTrace>,
+    pub channel_refs: Vec<EntityRef>,
+    pub subchannel_refs: Vec<EntityRef>,
+    pub socket_refs: Vec<EntityRef>,
+}
+
+/// Information of a server.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServerInfo {
+    pub id: u64,
+    pub name: String,
+    pub calls: CallCounters,
+    pub trace: Option<ChannelTrace>,
+    /// The sockets the server is listening on.
+    pub listen_sockets: Vec<EntityRef>,
+}
+
+/// Address of a socket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SocketAddress {
+    Tcp(SocketAddr),
+    /// Path of a unix domain socket.
+    Uds(String),
+    /// An address type not included above, only the human readable name is kept.
+    Other(String),
+}
+
+/// Security details of a socket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecurityInfo {
+    Tls {
+        /// The cipher suite name in the RFC 4346 format, or some other way to
+        /// describe it if the standard name is not available.
+        cipher_suite: String,
+        /// The DER encoded certificate used by this endpoint.
+        local_certificate: Vec<u8>,
+        /// The DER encoded certificate used by the remote endpoint.
+        remote_certificate: Vec<u8>,
+    },
+    /// Other security model, only the human readable name is kept.
+    Other(String),
+}
+
+/// Information of a socket.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SocketInfo {
+    pub id: u64,
+    pub name: String,
+    pub streams_started: i64,
+    pub streams_succeeded: i64,
+    pub streams_failed: i64,
+    pub messages_sent: i64,
+    pub messages_received: i64,
+    pub keep_alives_sent: i64,
+    pub last_local_stream_created: Option<SystemTime>,
+    pub last_remote_stream_created: Option<SystemTime>,
+    pub last_message_sent: Option<SystemTime>,
+    pub last_message_received: Option<SystemTime>,
+    pub local_flow_control_window: Option<i64>,
+    pub remote_flow_control_window: Option<i64>,
+    pub local: Option<SocketAddress>,
+    pub remote: Option<SocketAddress>,
+    /// The name of the remote endpoint if different than the original target.
+    pub remote_name: String,
+    pub security: Option<SecurityInfo>,
+}
+
+/// A page of entities.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Whether it's the last page. Entities created later can still show up
+    /// in following queries.
+    pub end: bool,
+}
+
+fn invalid(what: &str, v: &Value) -> Error {
+    Error::Codec(format!("invalid channelz {what}: {v:?}").into())
+}
+
+fn parse_json(json: &str) -> Result<Option<Object>> {
+    // An empty string means the entity doesn't exist.
+    if json.is_empty() {
+        return Ok(None);
+    }
+    match json::parse(json) {
+        Ok(Value::Object(o)) => Ok(Some(o)),
+        Ok(v) => Err(invalid("response", &v)),
+        Err(e) => Err(Error::Codec(format!("invalid channelz json: {e}").into())),
+    }
+}
+
+fn object<'a>(o: &'a Object, key: &str) -> Result<Option<&'a Object>> {
+    match o.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Object(o)) => Ok(Some(o)),
+        Some(v) => Err(invalid(key, v)),
+    }
+}
+
+fn objects<'a>(o: &'a Object, key: &str) -> Result<Vec<&'a Object>> {
+    match o.get(key) {
+        None | Some(Value::Null) => Ok(vec![]),
+        Some(Value::Array(a)) => a
+            .iter()
+            .map(|v| match v {
+                Value::Object(o) => Ok(o),
+                _ => Err(invalid(key, v)),
+            })
+            .collect(),
+        Some(v) => Err(invalid(key, v)),
+    }
+}
+
+fn string(o: &Object, key: &str) -> Result<String> {
+    match o.get(key) {
+        None | Some(Value::Null) => Ok(String::new()),
+        Some(Value::String(s)) => Ok(s.clone()),
+        Some(v) => Err(invalid(key, v)),
+    }
+}
+
+fn optional_i64(o: &Object, key: &str) -> Result<Option<i64>> {
+    // 64 bits integers are encoded as strings.
+    let v = match o.get(key) {
+        None | Some(Value::Null) => return Ok(None),
+        Some(v) => v,
+    };
+    let n = match v {
+        Value::Number(n) | Value::String(n) => n.parse().ok(),
+        _ => None,
+    };
+    n.map(Some).ok_or_else(|| invalid(key, v))
+}
+
+fn int(o: &Object, key: &str) -> Result<i64> {
+    optional_i64(o, key).map(Option::unwrap_or_default)
+}
+
+fn id(o: &Object, key: &str) -> Result<u64> {
+    Ok(int(o, key)? as u64)
+}
+
+fn bool(o: &Object, key: &str) -> Result<bool> {
+    match o.get(key) {
+        None | Some(Value::Null) => Ok(false),
+        Some(Value::Bool(b)) => Ok(*b),
+        Some(v) => Err(invalid(key, v)),
+    }
+}
+
+fn bytes(o: &Object, key: &str) -> Result<Vec<u8>> {
+    let s = string(o, key)?;
+    decode_base64(&s).ok_or_else(|| invalid(key, &Value::String(s)))
+}
+
+fn timestamp(o: &Object, key: &str) -> Result<Option<SystemTime>> {
+    let s = string(o, key)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    match parse_timestamp(&s) {
+        Some((secs, nanos)) if secs >= 0 => {
+            Ok(Some(UNIX_EPOCH + Duration::new(secs as u64, nanos)))
+        }
+        Some((secs, nanos)) => Ok(Some(
+            UNIX_EPOCH - Duration::from_secs(-secs as u64) + Duration::new(0, nanos),
+        )),
+        None => Err(invalid(key, &Value::String(s))),
+    }
+}
+
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let mut res = Vec::with_capacity(s.len() / 4 * 3);
+    let (mut acc, mut bits) = (0u32, 0);
+    for c in s.bytes().take_while(|c| *c != b'=') {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        acc = acc << 6 | u32::from(v);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            res.push((acc >> bits) as u8);
+        }
+    }
+    Some(res)
+}
+
+fn parse_digits(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Parses a RFC 3339 timestamp into seconds and nanoseconds since Unix epoch.
+fn parse_timestamp(s: &str) -> Option<(i64, u32)> {
+    let (date, time) = s.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(parse_digits);
+    let (y, mo, d) = (date.next()??, date.next()??, date.next()??);
+    let (time, offset) = if let Some(t) = time.strip_suffix('Z') {
+        (t, 0)
+    } else {
+        let pos = time.rfind(['+', '-'])?;
+        let (h, m) = time[pos + 1..].split_once(':')?;
+        let offset = parse_digits(h)? * 3600 + parse_digits(m)? * 60;
+        let offset = if &time[pos..=pos] == "-" {
+            -offset
+        } else {
+            offset
+        };
+        (&time[..pos], offset)
+    };
+    let (time, nanos) = match time.split_once('.') {
+        Some((t, frac)) if !frac.is_empty() && frac.len() <= 9 => {
+            let n = parse_digits(frac)? * 10i64.pow(9 - frac.len() as u32);
+            (t, n as u32)
+        }
+        Some(_) => return None,
+        None => (time, 0),
+    };
+    let mut time = time.splitn(3, ':').map(parse_digits);
+    let (h, mi, sec) = (time.next()??, time.next()??, time.next()??);
+    if !(1..=12).contains(&mo) || !(1..=31).contains(&d) || h > 23 || mi > 59 || sec > 60 {
+        return None;
+    }
+    let secs = days_from_civil(y, mo, d) * 86400 + h * 3600 + mi * 60 + sec - offset;
+    Some((secs, nanos))
+}
+
+fn entity_ref(o: &Object, id_key: &str) -> Result<EntityRef> {
+    Ok(EntityRef {
+        id: id(o, id_key)?,
+        name: string(o, "name")?,
+    })
+}
+
+fn entity_refs(o: &Object, key: &str, id_key: &str) -> Result<Vec<EntityRef>> {
+    objects(o, key)?
+        .into_iter()
+        .map(|r| entity_ref(r, id_key))
+        .collect()
+}
+
+fn call_counters(data: &Object) -> Result<CallCounters> {
+    Ok(CallCounters {
+        calls_started: int(data, "callsStarted")?,
+        calls_succeeded: int(data, "callsSucceeded")?,
+        calls_failed: int(data, "callsFailed")?,
+        last_call_started: timestamp(data, "lastCallStartedTimestamp")?,
+    })
+}
+
+fn connectivity_state(data: &Object) -> Result<Option<ConnectivityState>> {
+    let state = match object(data, "state")? {
+        Some(s) => string(s, "state")?,
+        None => return Ok(None),
+    };
+    Ok(match state.as_str() {
+        "IDLE" => Some(ConnectivityState::GRPC_CHANNEL_IDLE),
+        "CONNECTING" => Some(ConnectivityState::GRPC_CHANNEL_CONNECTING),
+        "READY" => Some(ConnectivityState::GRPC_CHANNEL_READY),
+        "TRANSIENT_FAILURE" => Some(ConnectivityState::GRPC_CHANNEL_TRANSIENT_FAILURE),
+        "SHUTDOWN" => Some(ConnectivityState::GRPC_CHANNEL_SHUTDOWN),
+        _ => None,
+    })
+}
+
+fn trace(data: &Object) -> Result<Option<ChannelTrace>> {
+    let t = match object(data, "trace")? {
+        Some(t) => t,
+        None => return Ok(None),
+    };
+    let mut events = vec![];
+    for e in objects(t, "events")? {
+        let severity = match string(e, "severity")?.as_str() {
+            "CT_INFO" => TraceSeverity::Info,
+            "CT_WARNING" => TraceSeverity::Warning,
+            "CT_ERROR" => TraceSeverity::Error,
+            _ => TraceSeverity::Unknown,
+        };
+        events.push(TraceEvent {
+            description: string(e, "description")?,
+            severity,
+            timestamp: timestamp(e, "timestamp")?,
+            channel_ref: object(e, "channelRef")?
+                .map(|r| entity_ref(r, "channelId"))
+                .transpose()?,
+            subchannel_ref: object(e, "subchannelRef")?
+                .map(|r| entity_ref(r, "subchannelId"))
+                .transpose()?,
+        });
+    }
+    Ok(Some(ChannelTrace {
+        num_events_logged: int(t, "numEventsLogged")?,
+        creation_timestamp: timestamp(t, "creationTimestamp")?,
+        events,
+    }))
+}
+
+impl ChannelInfo {
+    fn parse(o: &Object, id_key: &str) -> Result<ChannelInfo> {
+        let empty = Object::new();
+        let r = entity_ref(object(o, "ref")?.unwrap_or(&empty), id_key)?;
+        let data = object(o, "data")?.unwrap_or(&empty);
+        Ok(ChannelInfo {
+            id: r.id,
+            name: r.name,
+            state: connectivity_state(data)?,
+            target: string(data, "target")?,
+            calls: call_counters(data)?,
+            trace: trace(data)?,
+            channel_refs: entity_refs(o, "channelRef", "channelId")?,
+            subchannel_refs: entity_refs(o, "subchannelRef", "subchannelId")?,
+            socket_refs: entity_refs(o, "socketRef", "socketId")?,
+        })
+    }
+}
+
+impl ServerInfo {
+    fn parse(o: &Object) -> Result<ServerInfo> {
+        let empty = Object::new();
+        let r = entity_ref(object(o, "ref")?.unwrap_or(&empty), "serverId")?;
+        let data = object(o, "data")?.unwrap_or(&empty);
+        Ok(ServerInfo {
+            id: r.id,
+            name: r.name,
+            calls: call_counters(data)?,
+            trace: trace(data)?,
+            listen_sockets: entity_refs(o, "listenSocket", "socketId")?,
+        })
+    }
+}
+
+fn socket_address(o: &Object, key: &str) -> Result<Option<SocketAddress>> {
+    let addr = match object(o, key)? {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    if let Some(tcp) = object(addr, "tcpipAddress")? {
+        let ip = bytes(tcp, "ipAddress")?;
+        let ip = if let Ok(b) = <[u8; 4]>::try_from(ip.as_slice()) {
+            IpAddr::V4(Ipv4Addr::from(b))
+        } else if let Ok(b) = <[u8; 16]>::try_from(ip.as_slice()) {
+            IpAddr::V6(Ipv6Addr::from(b))
+        } else {
+            return Err(invalid("ip address", &tcp["ipAddress"]));
+        };
+        let port = int(tcp, "port")?;
+        return Ok(Some(SocketAddress::Tcp(SocketAddr::new(ip, port as u16))));
+    }
+    if let Some(uds) = object(addr, "udsAddress")? {
+        return Ok(Some(SocketAddress::Uds(string(uds, "filename")?)));
+    }
+    if let Some(other) = object(addr, "otherAddress")? {
+        return Ok(Some(SocketAddress::Other(string(other, "name")?)));
+    }
+    Ok(None)
+}
+
+fn security(o: &Object) -> Result<Option<SecurityInfo>> {
+    let security = match object(o, "security")? {
+        Some(s) => s,
+        None => return Ok(None),
+    };
+    if let Some(tls) = object(security, "tls")? {
+        let mut cipher_suite = string(tls, "standardName")?;
+        if cipher_suite.is_empty() {
+            cipher_suite = string(tls, "otherName")?;
+        }
+        return Ok(Some(SecurityInfo::Tls {
+            cipher_suite,
+            local_certificate: bytes(tls, "localCertificate")?,
+            remote_certificate: bytes(tls, "remoteCertificate")?,
+        }));
+    }
+    if let Some(other) = object(security, "other")? {
+        return Ok(Some(SecurityInfo::Other(string(other, "name")?)));
+    }
+    Ok(None)
+}
+
+impl SocketInfo {
+    fn parse(o: &Object) -> Result<SocketInfo> {
+        let empty = Object::new();
+        let r = entity_ref(object(o, "ref")?.unwrap_or(&empty), "socketId")?;
+        let data = object(o, "data")?.unwrap_or(&empty);
+        Ok(SocketInfo {
+            id: r.id,
+            name: r.name,
+            streams_started: int(data, "streamsStarted")?,
+            streams_succeeded: int(data, "streamsSucceeded")?,
+            streams_failed: int(data, "streamsFailed")?,
+            messages_sent: int(data, "messagesSent")?,
+            messages_received: int(data, "messagesReceived")?,
+            keep_alives_sent: int(data, "keepAlivesSent")?,
+            last_local_stream_created: timestamp(data, "lastLocalStreamCreatedTimestamp")?,
+            last_remote_stream_created: timestamp(data, "lastRemoteStreamCreatedTimestamp")?,
+            last_message_sent: timestamp(data, "lastMessageSentTimestamp")?,
+            last_message_received: timestamp(data, "lastMessageReceivedTimestamp")?,
+            local_flow_control_window: optional_i64(data, "localFlowControlWindow")?,
+            remote_flow_control_window: optional_i64(data, "remoteFlowControlWindow")?,
+            local: socket_address(o, "local")?,
+            remote: socket_address(o, "remote")?,
+            remote_name: string(o, "remoteName")?,
+            security: security(o)?,
+        })
+    }
+}
+
+fn page<T>(json: &str, key: &str, parse: impl Fn(&Object) -> Result<T>) -> Result<Page<T>> {
+    let o = match parse_json(json)? {
+        Some(o) => o,
+        None => {
+            return Ok(Page {
+                items: vec![],
+                end: true,
+            })
+        }
+    };
+    Ok(Page {
+        items: objects(&o, key)?
+            .into_iter()
+            .map(parse)
+            .collect::<Result<_>>()?,
+        end: bool(&o, "end")?,
+    })
+}
+
+fn single<T>(json: &str, key: &str, parse: impl Fn(&Object) -> Result<T>) -> Result<Option<T>> {
+    match parse_json(json)? {
+        Some(o) => object(&o, key)?.map(parse).transpose(),
+        None => Ok(None),
+    }
+}
+
+/// Gets a page of root channels whose ids are not less than `start_channel_id`.
+pub fn top_channels(start_channel_id: u64) -> Result<Page<ChannelInfo>> {
+    super::get_top_channels(start_channel_id, |s| {
+        page(s, "channel", |o| ChannelInfo::parse(o, "channelId"))
+    })
+}
+
+/// Gets a page of servers whose ids are not less than `start_server_id`.
+pub fn servers(start_server_id: u64) -> Result<Page<ServerInfo>> {
+    super::get_servers(start_server_id, |s| page(s, "server", ServerInfo::parse))
+}
+
+/// Gets a single server, returns `None` if it doesn't exist.
+pub fn server(server_id: u64) -> Result<Option<ServerInfo>> {
+    super::get_server(server_id, |s| single(s, "server", ServerInfo::parse))
+}
+
+/// Gets a page of sockets accepted by the server whose ids are not less than
+/// `start_socket_id`. If `max_results` is 0, C core chooses the page size.
+pub fn server_sockets(
+    server_id: u64,
+    start_socket_id: u64,
+    max_results: usize,
+) -> Result<Page<EntityRef>> {
+    super::get_server_sockets(server_id, start_socket_id, max_results, |s| {
+        page(s, "socketRef", |o| entity_ref(o, "socketId"))
+    })
+}
+
+/// Gets a single channel, returns `None` if it doesn't exist.
+pub fn channel(channel_id: u64) -> Result<Option<ChannelInfo>> {
+    super::get_channel(channel_id, |s| {
+        single(s, "channel", |o| ChannelInfo::parse(o, "channelId"))
+    })
+}
+
+/// Gets a single subchannel, returns `None` if it doesn't exist.
+pub fn subchannel(subchannel_id: u64) -> Result<Option<ChannelInfo>> {
+    super::get_subchannel(subchannel_id, |s| {
+        single(s, "subchannel", |o| ChannelInfo::parse(o, "subchannelId"))
+    })
+}
+
+/// Gets a single socket, returns `None` if it doesn't exist.
+pub fn socket(socket_id: u64) -> Result<Option<SocketInfo>> {
+    super::get_socket(socket_id, |s| single(s, "socket", SocketInfo::parse))
+}
+
+/// An iterator that fetches pages lazily until reaching the last one.
+struct Pages<T, F> {
+    fetch: F,
+    id: fn(&T) -> u64,
+    next_start: u64,
+    items: std::vec::IntoIter<T>,
+    end: bool,
+}
+
+impl<T, F: FnMut(u64) -> Result<Page<T>>> Pages<T, F> {
+    fn new(fetch: F, id: fn(&T) -> u64) -> Pages<T, F> {
+        Pages {
+            fetch,
+            id,
+            // 0 is never used as an id.
+            next_start: 0,
+            items: Vec::new().into_iter(),
+            end: false,
+        }
+    }
+}
+
+impl<T, F: FnMut(u64) -> Result<Page<T>>> Iterator for Pages<T, F> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        loop {
+            if let Some(item) = self.items.next() {
+                self.next_start = (self.id)(&item) + 1;
+                return Some(Ok(item));
+            }
+            if self.end {
+                return None;
+            }
+            match (self.fetch)(self.next_start) {
+                Ok(page) => {
+                    // Stop on an empty page to avoid looping forever.
+                    self.end = page.end || page.items.is_empty();
+                    self.items = page.items.into_iter();
+                }
+                Err(e) => {
+                    self.end = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Iterates all root channels, following `start_channel_id` automatically.
+pub fn iter_top_channels() -> impl Iterator<Item = Result<ChannelInfo>> {
+    Pages::new(top_channels, |c| c.id)
+}
+
+/// Iterates all servers, following `start_server_id` automatically.
+pub fn iter_servers() -> impl Iterator<Item = Result<ServerInfo>> {
+    Pages::new(servers, |s| s.id)
+}
+
+/// Iterates all sockets accepted by the server, following `start_socket_id`
+/// automatically.
+pub fn iter_server_sockets(server_id: u64) -> impl Iterator<Item = Result<EntityRef>> {
+    Pages::new(move |start| server_sockets(server_id, start, 0), |r| r.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        let cases = vec![
+            ("1970-01-01T00:00:00Z", Some((0, 0))),
+            (
+                "2021-03-04T05:06:07.123456789Z",
+                Some((1614834367, 123456789)),
+            ),
+            (
+                "2021-03-04T13:06:07.5+08:00",
+                Some((1614834367, 500_000_000)),
+            ),
+            ("1969-12-31T23:59:59Z", Some((-1, 0))),
+            ("2021-03-04T05:06:07", None),
+            ("2021-13-04T05:06:07Z", None),
+        ];
+        for (s, exp) in cases {
+            assert_eq!(parse_timestamp(s), exp, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_socket() {
+        let json = r#"{"socket": {
+            "ref": {"socketId": "5", "name": "s"},
+            "data": {"streamsStarted": "2", "localFlowControlWindow": "65535",
+                     "lastMessageSentTimestamp": "1970-01-01T00:00:01.5Z"},
+            "local": {"tcpipAddress": {"ipAddress": "fwAAAQ==", "port": 50051}},
+            "remote": {"udsAddress": {"filename": "/tmp/s"}},
+            "security": {"tls": {"standardName": "TLS_AES_128_GCM_SHA256",
+                                 "remoteCertificate": "AQID"}}
+        }}"#;
+        let s = single(json, "socket", SocketInfo::parse).unwrap().unwrap();
+        assert_eq!((s.id, s.name.as_str()), (5, "s"));
+        assert_eq!(s.streams_started, 2);
+        assert_eq!(s.local_flow_control_window, Some(65535));
+        assert_eq!(s.remote_flow_control_window, None);
+        assert_eq!(
+            s.last_message_sent,
+            Some(UNIX_EPOCH + Duration::from_millis(1500))
+        );
+        assert_eq!(
+            s.local,
+            Some(SocketAddress::Tcp("127.0.0.1:50051".parse().unwrap()))
+        );
+        assert_eq!(s.remote, Some(SocketAddress::Uds("/tmp/s".to_owned())));
+        assert_eq!(
+            s.security,
+            Some(SecurityInfo::Tls {
+                cipher_suite: "TLS_AES_128_GCM_SHA256".to_owned(),
+                local_certificate: vec![],
+                remote_certificate: vec![1, 2, 3],
+            })
+        );
+
+        assert_eq!(single("", "socket", SocketInfo::parse).unwrap(), None);
+        assert!(single("[]", "socket", SocketInfo::parse).is_err());
+    }
+
+    #[test]
+    fn test_pages() {
+        let mut starts = vec![];
+        let pages = Pages::new(
+            |start| {
+                starts.push(start);
+                let items: Vec<u64> = (start.max(1)..10).take(3).collect();
+                Ok(Page {
+                    end: items.last() == Some(&9),
+                    items,
+                })
+            },
+            |i| *i,
+        );
+        let items: Vec<_> = pages.map(Result::unwrap).collect();
+        assert_eq!(items, (1..10).collect::<Vec<_>>());
+        assert_eq!(starts, vec![0, 4, 7]);
+    }
+}
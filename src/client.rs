@@ -8,7 +8,9 @@ use crate::call::client::{
 };
 use crate::call::{Call, Method};
 use crate::channel::Channel;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::interceptor::{CallInterceptor, ClientInterceptor, ClientInterceptorChain};
+use crate::server::CheckResult;
 use crate::task::Executor;
 use crate::task::Kicker;
 use futures_executor::block_on;
@@ -19,13 +21,51 @@ pub struct Client {
     channel: Channel,
     // Used to kick its completion queue.
     kicker: Kicker,
+    interceptors: Vec<Box<dyn ClientInterceptor>>,
 }
 
 impl Client {
     /// Initialize a new [`Client`].
     pub fn new(channel: Channel) -> Client {
         let kicker = channel.create_kicker().unwrap();
-        Client { channel, kicker }
+        Client {
+            channel,
+            kicker,
+            interceptors: Vec::new(),
+        }
+    }
+
+    /// Add an interceptor to all calls made by the client.
+    ///
+    /// Interceptors registered on the client are invoked after the ones registered
+    /// on the underlying channel.
+    pub fn add_interceptor<I: ClientInterceptor + 'static>(mut self, interceptor: I) -> Client {
+        self.interceptors.push(Box::new(interceptor));
+        self
+    }
+
+    /// Set up the interceptors for a new call.
+    fn start_call(
+        &self,
+        method: &str,
+        opt: &mut CallOption,
+    ) -> Result<Option<Box<dyn CallInterceptor>>> {
+        let channel_interceptors = self.channel.interceptors();
+        if channel_interceptors.is_empty() && self.interceptors.is_empty() {
+            return Ok(None);
+        }
+        let interceptors = channel_interceptors
+            .iter()
+            .chain(&self.interceptors)
+            .cloned()
+            .collect();
+        let mut chain = ClientInterceptorChain::new(interceptors);
+        if let CheckResult::Abort(status) = chain.start(method, opt) {
+            let e = Error::RpcFailure(status);
+            chain.intercept_finish(Err(&e));
+            return Err(e);
+        }
+        Ok(Some(Box::new(chain)))
     }
 
     /// Create a synchronized unary RPC call.
@@ -46,9 +86,10 @@ impl Client {
         &self,
         method: &Method<Req, Resp>,
         req: &Req,
-        opt: CallOption,
+        mut opt: CallOption,
     ) -> Result<ClientUnaryReceiver<Resp>> {
-        Call::unary_async(&self.channel, method, req, opt)
+        let interceptors = self.start_call(method.name, &mut opt)?;
+        Call::unary_async(&self.channel, method, req, opt, interceptors)
     }
 
     /// Create an asynchronized client streaming call.
@@ -57,9 +98,10 @@ impl Client {
     pub fn client_streaming<Req, Resp>(
         &self,
         method: &Method<Req, Resp>,
-        opt: CallOption,
+        mut opt: CallOption,
     ) -> Result<(ClientCStreamSender<Req>, ClientCStreamReceiver<Resp>)> {
-        Call::client_streaming(&self.channel, method, opt)
+        let interceptors = self.start_call(method.name, &mut opt)?;
+        Call::client_streaming(&self.channel, method, opt, interceptors)
     }
 
     /// Create an asynchronized server streaming call.
@@ -69,9 +111,10 @@ impl Client {
         &self,
         method: &Method<Req, Resp>,
         req: &Req,
-        opt: CallOption,
+        mut opt: CallOption,
     ) -> Result<ClientSStreamReceiver<Resp>> {
-        Call::server_streaming(&self.channel, method, req, opt)
+        let interceptors = self.start_call(method.name, &mut opt)?;
+        Call::server_streaming(&self.channel, method, req, opt, interceptors)
     }
 
     /// Create an asynchronized duplex streaming call.
@@ -82,21 +125,27 @@ impl Client {
     pub fn duplex_streaming<Req, Resp>(
         &self,
         method: &Method<Req, Resp>,
-        opt: CallOption,
+        mut opt: CallOption,
     ) -> Result<(ClientDuplexSender<Req>, ClientDuplexReceiver<Resp>)> {
-        Call::duplex_streaming(&self.channel, method, opt)
+        let interceptors = self.start_call(method.name, &mut opt)?;
+        Call::duplex_streaming(&self.channel, method, opt, interceptors)
     }
 
     /// Spawn the future into current gRPC poll thread.
     ///
     /// This can reduce a lot of context switching, but please make
     /// sure there is no heavy work in the future.
+    ///
+    /// If the channel is built with a Tokio runtime handle, the future is spawned
+    /// on Tokio instead, see `ChannelBuilder::spawn_on_tokio`.
     pub fn spawn<F>(&self, f: F)
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        let kicker = self.kicker.clone();
-        Executor::new(self.channel.cq()).spawn(f, kicker)
+        if let Some(f) = self.channel.runtime().try_spawn(f) {
+            let kicker = self.kicker.clone();
+            Executor::new(self.channel.cq()).spawn(f, kicker)
+        }
     }
 
     /// Get the underlying channel.
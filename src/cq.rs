@@ -3,10 +3,12 @@
 use std::cell::UnsafeCell;
 use std::collections::VecDeque;
 use std::ptr;
-use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicIsize, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::thread::{self, ThreadId};
+use std::time::Duration;
 
+use crate::env::CqStats;
 use crate::error::{Error, Result};
 use crate::grpc_sys::{self, gpr_clock_type, grpc_completion_queue};
 use crate::task::UnfinishedWork;
@@ -129,6 +131,54 @@ impl Drop for CompletionQueueRef<'_> {
     }
 }
 
+/// Counters updated by the poll thread of a completion queue.
+#[derive(Default)]
+pub(crate) struct CqMetrics {
+    events: AtomicU64,
+    busy_nanos: AtomicU64,
+    idle_nanos: AtomicU64,
+    spawned_polls: AtomicU64,
+    spawned_poll_nanos: AtomicU64,
+    pending_work: AtomicUsize,
+}
+
+fn as_nanos(d: Duration) -> u64 {
+    d.as_nanos().min(u128::from(u64::MAX)) as u64
+}
+
+impl CqMetrics {
+    /// Records an event that takes `d` to handle.
+    pub fn record_event(&self, d: Duration) {
+        self.events.fetch_add(1, Ordering::Relaxed);
+        self.busy_nanos.fetch_add(as_nanos(d), Ordering::Relaxed);
+    }
+
+    /// Records the time spent on waiting for events.
+    pub fn record_idle(&self, d: Duration) {
+        self.idle_nanos.fetch_add(as_nanos(d), Ordering::Relaxed);
+    }
+
+    /// Records a poll of a spawned future that takes `d`.
+    pub fn record_spawned_poll(&self, d: Duration) {
+        self.spawned_polls.fetch_add(1, Ordering::Relaxed);
+        self.spawned_poll_nanos
+            .fetch_add(as_nanos(d), Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> CqStats {
+        CqStats {
+            events: self.events.load(Ordering::Relaxed),
+            busy_time: Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed)),
+            idle_time: Duration::from_nanos(self.idle_nanos.load(Ordering::Relaxed)),
+            spawned_polls: self.spawned_polls.load(Ordering::Relaxed),
+            spawned_poll_time: Duration::from_nanos(
+                self.spawned_poll_nanos.load(Ordering::Relaxed),
+            ),
+            pending_work: self.pending_work.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// `WorkQueue` stores the unfinished work of a completion queue.
 ///
 /// Every completion queue has a work queue, and every work queue belongs
@@ -143,6 +193,7 @@ impl Drop for CompletionQueueRef<'_> {
 pub struct WorkQueue {
     id: ThreadId,
     pending_work: UnsafeCell<VecDeque<UnfinishedWork>>,
+    pub(crate) metrics: CqMetrics,
 }
 
 unsafe impl Sync for WorkQueue {}
@@ -155,6 +206,7 @@ impl WorkQueue {
         WorkQueue {
             id: std::thread::current().id(),
             pending_work: UnsafeCell::new(VecDeque::with_capacity(QUEUE_CAPACITY)),
+            metrics: CqMetrics::default(),
         }
     }
 
@@ -164,7 +216,11 @@ impl WorkQueue {
     /// the work will returned and no work is pushed.
     pub fn push_work(&self, work: UnfinishedWork) -> Option<UnfinishedWork> {
         if self.id == thread::current().id() {
-            unsafe { &mut *self.pending_work.get() }.push_back(work);
+            let queue = unsafe { &mut *self.pending_work.get() };
+            queue.push_back(work);
+            self.metrics
+                .pending_work
+                .store(queue.len(), Ordering::Relaxed);
             None
         } else {
             Some(work)
@@ -180,7 +236,11 @@ impl WorkQueue {
         if queue.capacity() > QUEUE_CAPACITY && queue.len() < queue.capacity() / 2 {
             queue.shrink_to_fit();
         }
-        { &mut *self.pending_work.get() }.pop_back()
+        let work = queue.pop_back();
+        self.metrics
+            .pending_work
+            .store(queue.len(), Ordering::Relaxed);
+        work
     }
 }
 
@@ -219,4 +279,9 @@ impl CompletionQueue {
     pub fn worker_id(&self) -> ThreadId {
         self.worker.id
     }
+
+    /// Get the statistics of the completion queue and its poll thread.
+    pub fn stats(&self) -> CqStats {
+        self.worker.metrics.stats()
+    }
 }
@@ -4,6 +4,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread::{Builder as ThreadBuilder, JoinHandle};
+use std::time::{Duration, Instant};
 
 use crate::grpc_sys;
 
@@ -16,8 +17,13 @@ fn poll_queue(tx: mpsc::Sender<CompletionQueue>) {
     let worker_info = Arc::new(WorkQueue::new());
     let cq = CompletionQueue::new(cq, worker_info);
     tx.send(cq.clone()).expect("send back completion queue");
+    let metrics = &cq.worker.metrics;
+    let mut idle_since = Instant::now();
     loop {
         let e = cq.next();
+        let busy_since = Instant::now();
+        metrics.record_idle(busy_since - idle_since);
+        idle_since = busy_since;
         match e.type_ {
             EventType::GRPC_QUEUE_SHUTDOWN => break,
             // timeout should not happen in theory.
@@ -31,9 +37,35 @@ fn poll_queue(tx: mpsc::Sender<CompletionQueue>) {
         while let Some(work) = unsafe { cq.worker.pop_work() } {
             work.finish();
         }
+        idle_since = Instant::now();
+        metrics.record_event(idle_since - busy_since);
     }
 }
 
+/// Statistics of a completion queue and the thread polling it.
+///
+/// All the numbers except `pending_work` are accumulated since the queue is
+/// created. Sample them periodically and compare the differences to find out
+/// whether the poll thread is saturated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CqStats {
+    /// The number of events handled by the poll thread.
+    pub events: u64,
+    /// Time spent on handling events, including polling the futures woken by
+    /// the events.
+    pub busy_time: Duration,
+    /// Time spent on waiting for events.
+    pub idle_time: Duration,
+    /// The number of polls of futures spawned into the completion queue, by
+    /// `RpcContext::spawn` or `Client::spawn` for example.
+    pub spawned_polls: u64,
+    /// Time spent on polling the spawned futures.
+    pub spawned_poll_time: Duration,
+    /// The number of woken futures that wait to be polled after the current
+    /// event is handled.
+    pub pending_work: usize,
+}
+
 /// [`Environment`] factory in order to configure the properties.
 pub struct EnvBuilder {
     cq_count: usize,
@@ -152,6 +184,12 @@ impl Environment {
         self.cqs.as_slice()
     }
 
+    /// Get the statistics of all the completion queues, in the same order as
+    /// [`Environment::completion_queues`].
+    pub fn cq_stats(&self) -> Vec<CqStats> {
+        self.cqs.iter().map(CompletionQueue::stats).collect()
+    }
+
     /// Pick an arbitrary completion queue.
     pub fn pick_cq(&self) -> CompletionQueue {
         let idx = self.idx.fetch_add(1, Ordering::Relaxed);
@@ -13,7 +13,11 @@ use protobuf::ProtobufError;
 use protobufv3::Error as ProtobufError;
 
 /// Errors generated from this library.
+///
+/// New variants may be added in minor releases, so matches outside this crate
+/// need a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Codec error.
     Codec(Box<dyn error::Error + Send + Sync>),
@@ -35,6 +39,12 @@ pub enum Error {
     GoogleAuthenticationFailed,
     /// Invalid format of metadata.
     InvalidMetadata(String),
+    /// Invalid service config.
+    InvalidServiceConfig(String),
+    /// Failed to fetch credentials.
+    FetchCredentialsFailed(String),
+    /// Invalid or unsupported TLS options.
+    InvalidTlsOptions(String),
 }
 
 impl fmt::Display for Error {
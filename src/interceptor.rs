@@ -0,0 +1,263 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Interceptors allow inspecting and rewriting the messages of a call without
+//! touching the generated code.
+//!
+//! Interceptors operate on serialized payloads, so they can be shared by all
+//! services regardless of the message types. An interceptor that needs to look
+//! into the content can decode the payload using the codec of the method.
+
+use std::io::Read;
+
+use crate::buf::{GrpcByteBuffer, GrpcByteBufferReader, GrpcSlice};
+use crate::call::client::CallOption;
+use crate::call::server::RpcContext;
+use crate::call::{MessageReader, RpcStatus};
+use crate::error::Error;
+use crate::server::CheckResult;
+
+/// An interceptor that is able to inspect every message of a call on server side.
+///
+/// Different from [`ServerChecker`], which is only invoked before the handler starts,
+/// an interceptor sees every inbound request message, every outbound response message
+/// and the final status of the call for all method types.
+///
+/// An interceptor is cloned for every incoming call, so it's free to keep per-call
+/// states inside. If there are multiple interceptors, request messages are passed
+/// through them in the order added, while response messages and status are passed
+/// in the reverse order.
+///
+/// [`ServerChecker`]: crate::ServerChecker
+pub trait ServerInterceptor: Send {
+    /// Called after all checkers passed and before the handler starts.
+    ///
+    /// Returning `Abort` will abort the call with the given status, and the handler
+    /// will not be invoked.
+    fn on_start(&mut self, _ctx: &RpcContext) -> CheckResult {
+        CheckResult::Continue
+    }
+
+    /// Called with every serialized request message before it's delivered to
+    /// the handler.
+    ///
+    /// The message can be rewritten in place. Returning `Abort` will fail the call
+    /// with the given status.
+    fn on_request(&mut self, _msg: &mut GrpcSlice) -> CheckResult {
+        CheckResult::Continue
+    }
+
+    /// Called with every serialized response message before it's sent to the client.
+    ///
+    /// The message can be rewritten in place. Returning `Abort` will fail the call
+    /// with the given status.
+    fn on_response(&mut self, _msg: &mut GrpcSlice) -> CheckResult {
+        CheckResult::Continue
+    }
+
+    /// Called with the final status before it's sent to the client.
+    fn on_status(&mut self, _status: &mut RpcStatus) {}
+
+    fn box_clone(&self) -> Box<dyn ServerInterceptor>;
+}
+
+impl Clone for Box<dyn ServerInterceptor> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// An interceptor that is able to inspect every call made by a client.
+///
+/// Interceptors can be registered on [`ChannelBuilder`] to apply to all clients
+/// using the channel, or on [`Client`] directly. So generated clients can pick them
+/// up without any change.
+///
+/// An interceptor is cloned for every call, so it's free to keep per-call states
+/// inside. Interceptors registered on the channel are invoked before the ones
+/// registered on the client. Outgoing messages are passed through them in the order
+/// added, while incoming messages and the final result are passed in the reverse order.
+///
+/// [`ChannelBuilder`]: crate::ChannelBuilder
+/// [`Client`]: crate::Client
+pub trait ClientInterceptor: Send + Sync {
+    /// Called before the call is created.
+    ///
+    /// The call option, including headers and timeout, can be changed in place.
+    /// Returning `Abort` will fail the call with the given status without sending
+    /// anything to the server.
+    fn on_start(&mut self, _method: &str, _opt: &mut CallOption) -> CheckResult {
+        CheckResult::Continue
+    }
+
+    /// Called with every serialized request message before it's sent to the server.
+    ///
+    /// The message can be rewritten in place. Returning `Abort` will cancel the call
+    /// with the given status.
+    fn on_send(&mut self, _msg: &mut GrpcSlice) -> CheckResult {
+        CheckResult::Continue
+    }
+
+    /// Called with every serialized response message before it's delivered to
+    /// the caller.
+    ///
+    /// The message can be rewritten in place. Returning `Abort` will cancel the call
+    /// with the given status.
+    fn on_receive(&mut self, _msg: &mut GrpcSlice) -> CheckResult {
+        CheckResult::Continue
+    }
+
+    /// Called once when the result of the call is resolved.
+    ///
+    /// `Ok` means the server finished the call with an OK status.
+    fn on_finish(&mut self, _res: std::result::Result<(), &Error>) {}
+
+    fn box_clone(&self) -> Box<dyn ClientInterceptor>;
+}
+
+impl Clone for Box<dyn ClientInterceptor> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// Copies the message out of the reader so that it can be rewritten.
+fn reader_to_slice(mut reader: MessageReader) -> GrpcSlice {
+    let mut buf = Vec::with_capacity(reader.len());
+    // Reading from a byte buffer never fails.
+    reader.read_to_end(&mut buf).unwrap();
+    GrpcSlice::from(buf)
+}
+
+#[inline]
+fn slice_to_reader(slice: &GrpcSlice) -> MessageReader {
+    GrpcByteBufferReader::new(GrpcByteBuffer::from(slice))
+}
+
+/// Hooks that are invoked on the payloads of a call.
+///
+/// It's implemented by the interceptor chains of both sides, so that the shared
+/// call types don't need to know which side they are on.
+pub(crate) trait CallInterceptor: Send {
+    /// Intercepts a message that is about to be sent.
+    fn intercept_send(&mut self, msg: &mut GrpcSlice) -> CheckResult;
+
+    /// Intercepts a received message.
+    fn intercept_recv(
+        &mut self,
+        reader: MessageReader,
+    ) -> std::result::Result<MessageReader, RpcStatus>;
+
+    /// Intercepts the status that is about to be sent.
+    fn intercept_status(&mut self, _status: &mut RpcStatus) {}
+
+    /// Notifies the received result of the call.
+    fn intercept_finish(&mut self, _res: std::result::Result<(), &Error>) {}
+}
+
+/// The interceptors bound to a single server side call.
+pub(crate) struct ServerInterceptorChain {
+    interceptors: Vec<Box<dyn ServerInterceptor>>,
+}
+
+impl ServerInterceptorChain {
+    pub fn new(interceptors: Vec<Box<dyn ServerInterceptor>>) -> ServerInterceptorChain {
+        ServerInterceptorChain { interceptors }
+    }
+
+    pub fn start(&mut self, ctx: &RpcContext) -> CheckResult {
+        for i in &mut self.interceptors {
+            if let CheckResult::Abort(status) = i.on_start(ctx) {
+                return CheckResult::Abort(status);
+            }
+        }
+        CheckResult::Continue
+    }
+}
+
+impl CallInterceptor for ServerInterceptorChain {
+    fn intercept_send(&mut self, msg: &mut GrpcSlice) -> CheckResult {
+        for i in self.interceptors.iter_mut().rev() {
+            if let CheckResult::Abort(status) = i.on_response(msg) {
+                return CheckResult::Abort(status);
+            }
+        }
+        CheckResult::Continue
+    }
+
+    fn intercept_recv(
+        &mut self,
+        reader: MessageReader,
+    ) -> std::result::Result<MessageReader, RpcStatus> {
+        let mut msg = reader_to_slice(reader);
+        for i in &mut self.interceptors {
+            if let CheckResult::Abort(status) = i.on_request(&mut msg) {
+                return Err(status);
+            }
+        }
+        Ok(slice_to_reader(&msg))
+    }
+
+    fn intercept_status(&mut self, status: &mut RpcStatus) {
+        for i in self.interceptors.iter_mut().rev() {
+            i.on_status(status);
+        }
+    }
+}
+
+/// The interceptors bound to a single client side call.
+pub(crate) struct ClientInterceptorChain {
+    interceptors: Vec<Box<dyn ClientInterceptor>>,
+    finished: bool,
+}
+
+impl ClientInterceptorChain {
+    pub fn new(interceptors: Vec<Box<dyn ClientInterceptor>>) -> ClientInterceptorChain {
+        ClientInterceptorChain {
+            interceptors,
+            finished: false,
+        }
+    }
+
+    pub fn start(&mut self, method: &str, opt: &mut CallOption) -> CheckResult {
+        for i in &mut self.interceptors {
+            if let CheckResult::Abort(status) = i.on_start(method, opt) {
+                return CheckResult::Abort(status);
+            }
+        }
+        CheckResult::Continue
+    }
+}
+
+impl CallInterceptor for ClientInterceptorChain {
+    fn intercept_send(&mut self, msg: &mut GrpcSlice) -> CheckResult {
+        for i in &mut self.interceptors {
+            if let CheckResult::Abort(status) = i.on_send(msg) {
+                return CheckResult::Abort(status);
+            }
+        }
+        CheckResult::Continue
+    }
+
+    fn intercept_recv(
+        &mut self,
+        reader: MessageReader,
+    ) -> std::result::Result<MessageReader, RpcStatus> {
+        let mut msg = reader_to_slice(reader);
+        for i in self.interceptors.iter_mut().rev() {
+            if let CheckResult::Abort(status) = i.on_receive(&mut msg) {
+                return Err(status);
+            }
+        }
+        Ok(slice_to_reader(&msg))
+    }
+
+    fn intercept_finish(&mut self, res: std::result::Result<(), &Error>) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        for i in self.interceptors.iter_mut().rev() {
+            i.on_finish(res);
+        }
+    }
+}
@@ -16,6 +16,9 @@ framework that puts mobile and HTTP/2 first. grpcio is built on [gRPC Core] and
   mechanisms.
 - **`openssl`** - Same as `boringssl`, but base on the system openssl.
 - **`openssl-vendored`** - Same as `openssl`, but build openssl from source.
+- **`tokio`** - Enables spawning the futures of `RpcContext::spawn` and `Client::spawn` on Tokio.
+- **`tracing`** - Creates a [`tracing`](https://docs.rs/tracing) span for every call that
+  propagates trace context.
 
 */
 
@@ -38,17 +41,23 @@ mod codec;
 mod cq;
 mod env;
 mod error;
+mod interceptor;
+mod limit;
 mod log_util;
 mod metadata;
+mod metrics;
 mod quota;
 mod security;
 mod server;
+mod service_config;
 mod task;
+mod trace;
 
 pub use crate::buf::GrpcSlice;
 pub use crate::call::client::{
     CallOption, ClientCStreamReceiver, ClientCStreamSender, ClientDuplexReceiver,
-    ClientDuplexSender, ClientSStreamReceiver, ClientUnaryReceiver, StreamingCallSink,
+    ClientDuplexSender, ClientSStreamReceiver, ClientUnaryReceiver, PropagationMask,
+    StreamingCallSink,
 };
 pub use crate::call::server::{
     ClientStreamingSink, ClientStreamingSinkResult, Deadline, DuplexSink, DuplexSinkFailure,
@@ -68,15 +77,23 @@ pub use crate::codec::pb_codec::{de as pb_de, ser as pb_ser};
 pub use crate::codec::pr_codec::{de as pr_de, ser as pr_ser};
 
 pub use crate::codec::{Marshaller, MAX_MESSAGE_SIZE};
-pub use crate::env::{EnvBuilder, Environment};
+pub use crate::env::{CqStats, EnvBuilder, Environment};
 pub use crate::error::{Error, Result};
+pub use crate::interceptor::{ClientInterceptor, ServerInterceptor};
+pub use crate::limit::ConcurrencyLimit;
 pub use crate::log_util::redirect_log;
 pub use crate::metadata::{Metadata, MetadataBuilder, MetadataIter};
+pub use crate::metrics::{encode_prometheus, Histogram, MethodMetrics, ServerMetrics};
 pub use crate::quota::ResourceQuota;
 pub use crate::security::*;
 pub use crate::server::{
-    CheckResult, Server, ServerBuilder, ServerChecker, Service, ServiceBuilder, ShutdownFuture,
+    AsyncServerChecker, CheckResult, GracefulShutdownFuture, Server, ServerBuilder, ServerChecker,
+    Service, ServiceBuilder, ShutdownFuture, ShutdownStats,
 };
+pub use crate::service_config::{
+    HedgingPolicy, MethodConfig, RetryPolicy, ServiceConfig, ServiceConfigBuilder,
+};
+pub use crate::trace::{TraceContext, TracePropagation, TracePropagator, Traced, W3cPropagator};
 
 /// A shortcut for implementing a service method by returning `UNIMPLEMENTED` status code.
 ///
@@ -0,0 +1,311 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Concurrency limits of a server.
+//!
+//! Limits are registered by [`ServerBuilder::concurrency_limit`] and
+//! [`ServerBuilder::method_concurrency_limit`]. A call takes a permit from every
+//! limit that applies to it before any checker runs, and holds the permits until
+//! it's finished. Permits are taken one at a time, the method limit first, and a
+//! call only tries the next limit after it gets the permit of the previous one.
+//!
+//! [`ServerBuilder::concurrency_limit`]: crate::ServerBuilder::concurrency_limit
+//! [`ServerBuilder::method_concurrency_limit`]: crate::ServerBuilder::method_concurrency_limit
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use crate::call::{RpcStatus, RpcStatusCode};
+
+/// Limits the number of calls that are handled concurrently.
+///
+/// When all permits are taken, new calls wait in a queue of bounded depth. Calls
+/// that don't fit in the queue are rejected with `RESOURCE_EXHAUSTED` immediately.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConcurrencyLimit {
+    max_concurrent: usize,
+    max_queued: usize,
+    retry_after: Option<Duration>,
+}
+
+impl ConcurrencyLimit {
+    /// Allows at most `max_concurrent` calls to be handled at the same time.
+    ///
+    /// No call is queued by default.
+    pub fn new(max_concurrent: usize) -> ConcurrencyLimit {
+        ConcurrencyLimit {
+            max_concurrent,
+            max_queued: 0,
+            retry_after: None,
+        }
+    }
+
+    /// Set the maximum number of calls that wait for a permit.
+    pub fn max_queued(mut self, max_queued: usize) -> ConcurrencyLimit {
+        self.max_queued = max_queued;
+        self
+    }
+
+    /// Attach a `google.rpc.RetryInfo` detail to rejections, which asks clients to
+    /// wait at least `delay` before retrying.
+    ///
+    /// The detail can be extracted by `grpcio_proto::google::rpc::Status`.
+    pub fn retry_after(mut self, delay: Duration) -> ConcurrencyLimit {
+        self.retry_after = Some(delay);
+        self
+    }
+
+    fn reject_status(&self) -> RpcStatus {
+        let message = format!(
+            "concurrency limit {} is reached and {} calls are queued",
+            self.max_concurrent, self.max_queued
+        );
+        let code = RpcStatusCode::RESOURCE_EXHAUSTED;
+        match self.retry_after {
+            Some(delay) => {
+                let details = encode_retry_info(code, &message, delay);
+                RpcStatus::with_details(code, message, details)
+            }
+            None => RpcStatus::with_message(code, message),
+        }
+    }
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+fn put_bytes(buf: &mut Vec<u8>, number: u64, bytes: &[u8]) {
+    // Wire type 2 is length delimited.
+    put_varint(buf, number << 3 | 2);
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn put_varint_field(buf: &mut Vec<u8>, number: u64, v: u64) {
+    if v != 0 {
+        put_varint(buf, number << 3);
+        put_varint(buf, v);
+    }
+}
+
+/// Encodes a `google.rpc.Status` carrying a single `google.rpc.RetryInfo`.
+///
+/// It's encoded by hand as the codec of the crate is chosen by features.
+fn encode_retry_info(code: RpcStatusCode, message: &str, delay: Duration) -> Vec<u8> {
+    // `google.protobuf.Duration` can hold about 10,000 years at most.
+    let delay = cmp::min(delay, Duration::from_secs(315_576_000_000));
+    let mut duration = Vec::new();
+    put_varint_field(&mut duration, 1, delay.as_secs());
+    put_varint_field(&mut duration, 2, u64::from(delay.subsec_nanos()));
+    let mut retry_info = Vec::new();
+    put_bytes(&mut retry_info, 1, &duration);
+    let mut any = Vec::new();
+    put_bytes(
+        &mut any,
+        1,
+        b"type.googleapis.com/google.rpc.RetryInfo".as_ref(),
+    );
+    put_bytes(&mut any, 2, &retry_info);
+    let mut status = Vec::new();
+    put_varint_field(&mut status, 1, i32::from(code) as u64);
+    if !message.is_empty() {
+        put_bytes(&mut status, 2, message.as_bytes());
+    }
+    put_bytes(&mut status, 3, &any);
+    status
+}
+
+enum WaiterState {
+    Waiting(Option<Waker>),
+    Granted,
+    Taken,
+    Cancelled,
+}
+
+type Waiter = Arc<Mutex<WaiterState>>;
+
+struct LimiterState {
+    running: usize,
+    waiters: VecDeque<Waiter>,
+}
+
+/// The runtime state of a [`ConcurrencyLimit`].
+pub(crate) struct Limiter {
+    limit: ConcurrencyLimit,
+    state: Mutex<LimiterState>,
+}
+
+pub(crate) enum Acquire {
+    Acquired(Permit),
+    Queued(QueuedPermit),
+    Rejected(RpcStatus),
+}
+
+impl Limiter {
+    pub fn new(limit: ConcurrencyLimit) -> Limiter {
+        Limiter {
+            limit,
+            state: Mutex::new(LimiterState {
+                running: 0,
+                waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub fn acquire(self: &Arc<Self>) -> Acquire {
+        let mut state = self.state.lock().unwrap();
+        if state.running < self.limit.max_concurrent {
+            state.running += 1;
+            return Acquire::Acquired(Permit {
+                limiter: self.clone(),
+            });
+        }
+        // Cancelled waiters are only removed lazily, drop them before counting.
+        state
+            .waiters
+            .retain(|w| !matches!(*w.lock().unwrap(), WaiterState::Cancelled));
+        if state.waiters.len() >= self.limit.max_queued {
+            return Acquire::Rejected(self.limit.reject_status());
+        }
+        let waiter = Arc::new(Mutex::new(WaiterState::Waiting(None)));
+        state.waiters.push_back(waiter.clone());
+        Acquire::Queued(QueuedPermit {
+            limiter: self.clone(),
+            waiter,
+        })
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        // Hand the permit over to the first waiter that is still waiting.
+        while let Some(waiter) = state.waiters.pop_front() {
+            let mut w = waiter.lock().unwrap();
+            if let WaiterState::Waiting(waker) = &mut *w {
+                let waker = waker.take();
+                *w = WaiterState::Granted;
+                drop(w);
+                drop(state);
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+                return;
+            }
+        }
+        state.running -= 1;
+    }
+}
+
+/// A permit of a [`Limiter`], it's released on drop.
+pub(crate) struct Permit {
+    limiter: Arc<Limiter>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.limiter.release();
+    }
+}
+
+/// A future that resolves when a queued call gets a permit.
+pub(crate) struct QueuedPermit {
+    limiter: Arc<Limiter>,
+    waiter: Waiter,
+}
+
+impl Future for QueuedPermit {
+    type Output = Permit;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Permit> {
+        let mut w = self.waiter.lock().unwrap();
+        match &mut *w {
+            WaiterState::Waiting(waker) => {
+                *waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            WaiterState::Granted => {
+                *w = WaiterState::Taken;
+                Poll::Ready(Permit {
+                    limiter: self.limiter.clone(),
+                })
+            }
+            WaiterState::Taken | WaiterState::Cancelled => panic!("polled after completion"),
+        }
+    }
+}
+
+impl Drop for QueuedPermit {
+    fn drop(&mut self) {
+        let mut w = self.waiter.lock().unwrap();
+        match &*w {
+            WaiterState::Waiting(_) => *w = WaiterState::Cancelled,
+            WaiterState::Granted => {
+                *w = WaiterState::Taken;
+                drop(w);
+                // The permit is granted but never taken, pass it on.
+                self.limiter.release();
+            }
+            WaiterState::Taken | WaiterState::Cancelled => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn test_limiter() {
+        let limiter = Arc::new(Limiter::new(ConcurrencyLimit::new(1).max_queued(2)));
+        let permit = match limiter.acquire() {
+            Acquire::Acquired(p) => p,
+            _ => panic!("should be acquired"),
+        };
+        let mut queued: Vec<_> = (0..2)
+            .map(|_| match limiter.acquire() {
+                Acquire::Queued(q) => q,
+                _ => panic!("should be queued"),
+            })
+            .collect();
+        match limiter.acquire() {
+            Acquire::Rejected(s) => assert_eq!(s.code(), RpcStatusCode::RESOURCE_EXHAUSTED),
+            _ => panic!("should be rejected"),
+        }
+        assert!((&mut queued[0]).now_or_never().is_none());
+        // A cancelled waiter frees its slot in the queue and is skipped.
+        drop(queued.remove(0));
+        let mut q = match limiter.acquire() {
+            Acquire::Queued(q) => q,
+            _ => panic!("should be queued"),
+        };
+        drop(permit);
+        let permit = (&mut queued[0]).now_or_never().unwrap();
+        assert!((&mut q).now_or_never().is_none());
+        drop(permit);
+        let permit = (&mut q).now_or_never().unwrap();
+        drop(permit);
+        assert_eq!(limiter.state.lock().unwrap().running, 0);
+    }
+
+    #[test]
+    fn test_retry_info() {
+        let limit = ConcurrencyLimit::new(0).retry_after(Duration::from_millis(1500));
+        let status = limit.reject_status();
+        assert_eq!(status.code(), RpcStatusCode::RESOURCE_EXHAUSTED);
+        let details = status.details();
+        let type_url = b"type.googleapis.com/google.rpc.RetryInfo";
+        assert!(details.windows(type_url.len()).any(|w| w == type_url));
+        // RetryInfo { retry_delay: Duration { seconds: 1, nanos: 500_000_000 } }
+        let retry_info = [0x0a, 0x08, 0x08, 0x01, 0x10, 0x80, 0xca, 0xb5, 0xee, 0x01];
+        assert!(details.ends_with(&retry_info));
+    }
+}
@@ -6,9 +6,11 @@ use std::fmt;
 use std::mem::ManuallyDrop;
 use std::{mem, slice, str};
 
+use crate::channel::{compression_algorithm_name, CompressionAlgorithms};
 use crate::error::{Error, Result};
 
 const BINARY_ERROR_DETAILS_KEY: &str = "grpc-status-details-bin";
+const COMPRESSION_REQUEST_ALGORITHM_KEY: &str = "grpc-internal-encoding-request";
 
 fn normalize_key(key: &str, binary: bool) -> Result<Cow<'_, str>> {
     if key.is_empty() {
@@ -236,6 +238,15 @@ impl Metadata {
         })
     }
 
+    /// Returns the raw entries, which are still owned by the metadata.
+    #[cfg(feature = "_secure")]
+    pub(crate) fn raw_entries(&self) -> &[grpc_metadata] {
+        if self.0.count == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.0.metadata, self.0.count) }
+    }
+
     /// Search for binary error details.
     pub(crate) fn search_binary_error_details(&self) -> &[u8] {
         for (k, v) in self.iter() {
@@ -245,6 +256,33 @@ impl Metadata {
         }
         &[]
     }
+
+    /// Asks gRPC C core to compress messages of the call with `algo`.
+    ///
+    /// The entry is consumed by C core and never sent to the peer.
+    pub(crate) fn set_compression_algorithm(&mut self, algo: CompressionAlgorithms) {
+        let mut builder = MetadataBuilder::with_capacity(1);
+        builder.add_metadata(
+            COMPRESSION_REQUEST_ALGORITHM_KEY,
+            compression_algorithm_name(algo).as_bytes(),
+        );
+        self.merge(&builder.build());
+    }
+
+    /// Replaces the entries that have the same keys as the ones in `other`, and
+    /// appends the rest of `other`.
+    pub(crate) fn merge(&mut self, other: &Metadata) {
+        let mut builder = MetadataBuilder::with_capacity(self.len() + other.len());
+        for (k, v) in self.iter() {
+            if other.iter().all(|(o, _)| o != k) {
+                builder.add_metadata(k, v);
+            }
+        }
+        for (k, v) in other.iter() {
+            builder.add_metadata(k, v);
+        }
+        *self = builder.build();
+    }
 }
 
 impl fmt::Debug for Metadata {
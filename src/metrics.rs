@@ -0,0 +1,464 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Per-method metrics of a server.
+//!
+//! [`ServerMetrics`] is registered by [`ServerBuilder::metrics`], and records
+//! every call that passes all the checkers. It can be shared by multiple servers,
+//! calls of the same method are aggregated in that case.
+//!
+//! [`ServerBuilder::metrics`]: crate::ServerBuilder::metrics
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::buf::GrpcSlice;
+use crate::call::server::RpcContext;
+use crate::call::{RpcStatus, RpcStatusCode};
+use crate::interceptor::ServerInterceptor;
+use crate::server::CheckResult;
+
+/// Default upper bounds of latency buckets, in milliseconds.
+const DEFAULT_BUCKETS_MS: &[u64] = &[1, 5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+fn as_nanos(d: Duration) -> u64 {
+    d.as_nanos().min(u128::from(u64::MAX)) as u64
+}
+
+/// A snapshot of a latency histogram.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Histogram {
+    /// Upper bounds of the buckets and the number of observations that are
+    /// less than or equal to the bounds. Counts are cumulative like Prometheus.
+    pub buckets: Vec<(Duration, u64)>,
+    /// The number of all observations.
+    pub count: u64,
+    /// The sum of all observations.
+    pub sum: Duration,
+}
+
+/// A snapshot of the metrics of a method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodMetrics {
+    /// The full method name, like `/helloworld.Greeter/SayHello`.
+    pub method: String,
+    /// The number of started calls.
+    pub requests: u64,
+    /// The number of calls that are not finished yet.
+    pub in_flight: i64,
+    /// The number of finished calls by status code, sorted by code.
+    pub status_codes: Vec<(RpcStatusCode, u64)>,
+    /// Latency of finished calls, from the handler starts to the status is sent.
+    pub latency: Histogram,
+    /// The number of received request messages.
+    pub request_messages: u64,
+    /// The total size of received request messages in bytes.
+    pub request_bytes: u64,
+    /// The number of sent response messages.
+    pub response_messages: u64,
+    /// The total size of sent response messages in bytes.
+    pub response_bytes: u64,
+}
+
+struct MethodStats {
+    requests: AtomicU64,
+    in_flight: AtomicI64,
+    status_codes: Mutex<BTreeMap<i32, u64>>,
+    // The last bucket counts observations above all bounds.
+    latency_buckets: Vec<AtomicU64>,
+    latency_nanos: AtomicU64,
+    request_messages: AtomicU64,
+    request_bytes: AtomicU64,
+    response_messages: AtomicU64,
+    response_bytes: AtomicU64,
+}
+
+impl MethodStats {
+    fn new(buckets: usize) -> MethodStats {
+        MethodStats {
+            requests: AtomicU64::new(0),
+            in_flight: AtomicI64::new(0),
+            status_codes: Mutex::default(),
+            latency_buckets: (0..=buckets).map(|_| AtomicU64::new(0)).collect(),
+            latency_nanos: AtomicU64::new(0),
+            request_messages: AtomicU64::new(0),
+            request_bytes: AtomicU64::new(0),
+            response_messages: AtomicU64::new(0),
+            response_bytes: AtomicU64::new(0),
+        }
+    }
+
+    fn snapshot(&self, method: &str, bounds: &[Duration]) -> MethodMetrics {
+        let mut buckets = Vec::with_capacity(bounds.len());
+        let mut count = 0;
+        for (bound, c) in bounds.iter().zip(&self.latency_buckets) {
+            count += c.load(Ordering::Relaxed);
+            buckets.push((*bound, count));
+        }
+        count += self.latency_buckets[bounds.len()].load(Ordering::Relaxed);
+        let status_codes = self
+            .status_codes
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(code, count)| (RpcStatusCode::from(*code), *count))
+            .collect();
+        MethodMetrics {
+            method: method.to_owned(),
+            requests: self.requests.load(Ordering::Relaxed),
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            status_codes,
+            latency: Histogram {
+                buckets,
+                count,
+                sum: Duration::from_nanos(self.latency_nanos.load(Ordering::Relaxed)),
+            },
+            request_messages: self.request_messages.load(Ordering::Relaxed),
+            request_bytes: self.request_bytes.load(Ordering::Relaxed),
+            response_messages: self.response_messages.load(Ordering::Relaxed),
+            response_bytes: self.response_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct Inner {
+    bounds: Vec<Duration>,
+    methods: RwLock<HashMap<String, Arc<MethodStats>>>,
+}
+
+impl Inner {
+    fn method_stats(&self, method: &str) -> Arc<MethodStats> {
+        if let Some(stats) = self.methods.read().unwrap().get(method) {
+            return stats.clone();
+        }
+        self.methods
+            .write()
+            .unwrap()
+            .entry(method.to_owned())
+            .or_insert_with(|| Arc::new(MethodStats::new(self.bounds.len())))
+            .clone()
+    }
+}
+
+/// Collects the metrics of every method served by a server.
+///
+/// Cloning the object is cheap, all the clones share the same metrics.
+#[derive(Clone)]
+pub struct ServerMetrics {
+    inner: Arc<Inner>,
+}
+
+impl ServerMetrics {
+    /// Create a [`ServerMetrics`] with default latency buckets, ranging from
+    /// 1ms to 10s.
+    pub fn new() -> ServerMetrics {
+        let buckets = DEFAULT_BUCKETS_MS
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect();
+        ServerMetrics::with_latency_buckets(buckets)
+    }
+
+    /// Create a [`ServerMetrics`] with the given upper bounds of latency buckets.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the bounds are not strictly increasing.
+    pub fn with_latency_buckets(buckets: Vec<Duration>) -> ServerMetrics {
+        assert!(
+            buckets.windows(2).all(|w| w[0] < w[1]),
+            "latency buckets must be strictly increasing: {:?}",
+            buckets
+        );
+        ServerMetrics {
+            inner: Arc::new(Inner {
+                bounds: buckets,
+                methods: RwLock::default(),
+            }),
+        }
+    }
+
+    /// Take a snapshot of the metrics of all methods, sorted by method name.
+    pub fn snapshot(&self) -> Vec<MethodMetrics> {
+        let methods = self.inner.methods.read().unwrap();
+        let mut res: Vec<_> = methods
+            .iter()
+            .map(|(method, stats)| stats.snapshot(method, &self.inner.bounds))
+            .collect();
+        res.sort_by(|l, r| l.method.cmp(&r.method));
+        res
+    }
+
+    /// Render the metrics in Prometheus text exposition format.
+    ///
+    /// The metric names follow go-grpc-prometheus, and the full method name is
+    /// split into `grpc_service` and `grpc_method` labels.
+    pub fn prometheus_text(&self) -> String {
+        encode_prometheus(&self.snapshot())
+    }
+
+    pub(crate) fn interceptor(&self) -> MetricsInterceptor {
+        MetricsInterceptor {
+            inner: self.inner.clone(),
+            call: None,
+        }
+    }
+}
+
+impl Default for ServerMetrics {
+    fn default() -> ServerMetrics {
+        ServerMetrics::new()
+    }
+}
+
+/// Formats the labels of a method, `extra` is appended as is.
+fn labels(method: &str, extra: &str) -> String {
+    let (service, name) = method
+        .trim_start_matches('/')
+        .split_once('/')
+        .unwrap_or(("", method));
+    let mut s = String::new();
+    write!(s, "{{grpc_service=\"{}\"", escape(service)).unwrap();
+    write!(s, ",grpc_method=\"{}\"", escape(name)).unwrap();
+    s.push_str(extra);
+    s.push('}');
+    s
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn code_name(code: RpcStatusCode) -> String {
+    match code.name() {
+        Some(name) => name.to_owned(),
+        None => i32::from(code).to_string(),
+    }
+}
+
+/// Render the snapshot in Prometheus text exposition format.
+pub fn encode_prometheus(metrics: &[MethodMetrics]) -> String {
+    let mut s = String::new();
+    let mut counter = |name: &str, help: &str, f: &dyn Fn(&MethodMetrics) -> u64| {
+        writeln!(s, "# HELP {name} {help}\n# TYPE {name} counter").unwrap();
+        for m in metrics {
+            writeln!(s, "{name}{} {}", labels(&m.method, ""), f(m)).unwrap();
+        }
+    };
+    counter(
+        "grpc_server_started_total",
+        "Total number of RPCs started on the server.",
+        &|m| m.requests,
+    );
+    counter(
+        "grpc_server_msg_received_total",
+        "Total number of RPC stream messages received on the server.",
+        &|m| m.request_messages,
+    );
+    counter(
+        "grpc_server_msg_received_bytes_total",
+        "Total size of RPC stream messages received on the server.",
+        &|m| m.request_bytes,
+    );
+    counter(
+        "grpc_server_msg_sent_total",
+        "Total number of gRPC stream messages sent by the server.",
+        &|m| m.response_messages,
+    );
+    counter(
+        "grpc_server_msg_sent_bytes_total",
+        "Total size of gRPC stream messages sent by the server.",
+        &|m| m.response_bytes,
+    );
+
+    let name = "grpc_server_handled_total";
+    writeln!(s, "# HELP {name} Total number of RPCs completed on the server, regardless of success or failure.\n# TYPE {name} counter").unwrap();
+    for m in metrics {
+        for (code, count) in &m.status_codes {
+            let extra = format!(",grpc_code=\"{}\"", code_name(*code));
+            writeln!(s, "{name}{} {count}", labels(&m.method, &extra)).unwrap();
+        }
+    }
+
+    let name = "grpc_server_in_flight";
+    writeln!(
+        s,
+        "# HELP {name} Number of RPCs in progress on the server.\n# TYPE {name} gauge"
+    )
+    .unwrap();
+    for m in metrics {
+        writeln!(s, "{name}{} {}", labels(&m.method, ""), m.in_flight).unwrap();
+    }
+
+    let name = "grpc_server_handling_seconds";
+    writeln!(s, "# HELP {name} Histogram of response latency of RPCs handled by the server.\n# TYPE {name} histogram").unwrap();
+    for m in metrics {
+        let h = &m.latency;
+        for (bound, count) in &h.buckets {
+            let extra = format!(",le=\"{}\"", bound.as_secs_f64());
+            writeln!(s, "{name}_bucket{} {count}", labels(&m.method, &extra)).unwrap();
+        }
+        let l = labels(&m.method, ",le=\"+Inf\"");
+        writeln!(s, "{name}_bucket{l} {}", h.count).unwrap();
+        let l = labels(&m.method, "");
+        writeln!(s, "{name}_sum{l} {}", h.sum.as_secs_f64()).unwrap();
+        writeln!(s, "{name}_count{l} {}", h.count).unwrap();
+    }
+    s
+}
+
+struct CallStats {
+    stats: Arc<MethodStats>,
+    start: Instant,
+    finished: bool,
+}
+
+impl CallStats {
+    fn finish(&mut self, code: RpcStatusCode, bounds: &[Duration]) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        let stats = &self.stats;
+        let elapsed = self.start.elapsed();
+        stats.in_flight.fetch_sub(1, Ordering::Relaxed);
+        *stats
+            .status_codes
+            .lock()
+            .unwrap()
+            .entry(code.into())
+            .or_default() += 1;
+        let idx = bounds.partition_point(|b| *b < elapsed);
+        stats.latency_buckets[idx].fetch_add(1, Ordering::Relaxed);
+        stats
+            .latency_nanos
+            .fetch_add(as_nanos(elapsed), Ordering::Relaxed);
+    }
+}
+
+/// The interceptor that records the metrics of a call.
+pub(crate) struct MetricsInterceptor {
+    inner: Arc<Inner>,
+    call: Option<CallStats>,
+}
+
+impl ServerInterceptor for MetricsInterceptor {
+    fn on_start(&mut self, ctx: &RpcContext) -> CheckResult {
+        let stats = self
+            .inner
+            .method_stats(&String::from_utf8_lossy(ctx.method()));
+        stats.requests.fetch_add(1, Ordering::Relaxed);
+        stats.in_flight.fetch_add(1, Ordering::Relaxed);
+        self.call = Some(CallStats {
+            stats,
+            start: Instant::now(),
+            finished: false,
+        });
+        CheckResult::Continue
+    }
+
+    fn on_request(&mut self, msg: &mut GrpcSlice) -> CheckResult {
+        if let Some(call) = &self.call {
+            let stats = &call.stats;
+            stats.request_messages.fetch_add(1, Ordering::Relaxed);
+            stats
+                .request_bytes
+                .fetch_add(msg.len() as u64, Ordering::Relaxed);
+        }
+        CheckResult::Continue
+    }
+
+    fn on_response(&mut self, msg: &mut GrpcSlice) -> CheckResult {
+        if let Some(call) = &self.call {
+            let stats = &call.stats;
+            stats.response_messages.fetch_add(1, Ordering::Relaxed);
+            stats
+                .response_bytes
+                .fetch_add(msg.len() as u64, Ordering::Relaxed);
+        }
+        CheckResult::Continue
+    }
+
+    fn on_status(&mut self, status: &mut RpcStatus) {
+        if let Some(call) = &mut self.call {
+            call.finish(status.code(), &self.inner.bounds);
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn ServerInterceptor> {
+        Box::new(MetricsInterceptor {
+            inner: self.inner.clone(),
+            call: None,
+        })
+    }
+}
+
+impl Drop for MetricsInterceptor {
+    fn drop(&mut self) {
+        // The call is dropped without sending a status, it must be canceled.
+        if let Some(call) = &mut self.call {
+            call.finish(RpcStatusCode::CANCELLED, &self.inner.bounds);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_prometheus() {
+        let metrics = ServerMetrics::with_latency_buckets(vec![
+            Duration::from_millis(10),
+            Duration::from_millis(100),
+        ]);
+        let stats = metrics.inner.method_stats("/helloworld.Greeter/SayHello");
+        stats.requests.fetch_add(3, Ordering::Relaxed);
+        stats.in_flight.fetch_add(3, Ordering::Relaxed);
+        for (ms, code) in [
+            (1, RpcStatusCode::OK),
+            (50, RpcStatusCode::OK),
+            (200, RpcStatusCode::NOT_FOUND),
+        ] {
+            let mut call = CallStats {
+                stats: stats.clone(),
+                start: Instant::now() - Duration::from_millis(ms),
+                finished: false,
+            };
+            call.finish(code, &metrics.inner.bounds);
+            call.finish(code, &metrics.inner.bounds);
+        }
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        let m = &snapshot[0];
+        assert_eq!(m.requests, 3);
+        assert_eq!(m.in_flight, 0);
+        assert_eq!(
+            m.status_codes,
+            vec![(RpcStatusCode::OK, 2), (RpcStatusCode::NOT_FOUND, 1)]
+        );
+        let bounds: Vec<_> = m.latency.buckets.iter().map(|(_, c)| *c).collect();
+        assert_eq!(bounds, vec![1, 2]);
+        assert_eq!(m.latency.count, 3);
+
+        let text = metrics.prometheus_text();
+        let labels = "grpc_service=\"helloworld.Greeter\",grpc_method=\"SayHello\"";
+        for line in [
+            format!("grpc_server_started_total{{{labels}}} 3"),
+            format!("grpc_server_handled_total{{{labels},grpc_code=\"OK\"}} 2"),
+            format!("grpc_server_handled_total{{{labels},grpc_code=\"NOT_FOUND\"}} 1"),
+            format!("grpc_server_in_flight{{{labels}}} 0"),
+            format!("grpc_server_handling_seconds_bucket{{{labels},le=\"0.01\"}} 1"),
+            format!("grpc_server_handling_seconds_bucket{{{labels},le=\"0.1\"}} 2"),
+            format!("grpc_server_handling_seconds_bucket{{{labels},le=\"+Inf\"}} 3"),
+            format!("grpc_server_handling_seconds_count{{{labels}}} 3"),
+        ] {
+            assert!(text.lines().any(|l| l == line), "{} not in {}", line, text);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use crate::call::{RpcStatus, RpcStatusCode};
+use crate::server::{CheckResult, ServerChecker};
+use crate::RpcContext;
+
+/// A [`ServerChecker`] that only allows calls from peers whose certificates
+/// match the allow list.
+///
+/// Patterns are matched against the whole value, where `*` matches any sequence
+/// of characters. For example, `spiffe://example.org/ns/prod/*` allows all the
+/// workloads in the `prod` namespace.
+///
+/// Certificates are only trusted if they are verified, so servers should be
+/// built with [`CertificateRequestType::RequestAndRequireClientCertificateAndVerify`].
+/// Calls from unauthenticated peers are rejected with `UNAUTHENTICATED`, and
+/// calls from peers that don't match are rejected with `PERMISSION_DENIED`.
+///
+/// [`CertificateRequestType::RequestAndRequireClientCertificateAndVerify`]: crate::CertificateRequestType::RequestAndRequireClientCertificateAndVerify
+#[derive(Clone, Debug, Default)]
+pub struct PeerAllowList {
+    spiffe_ids: Vec<String>,
+    sans: Vec<String>,
+}
+
+impl PeerAllowList {
+    /// Creates an allow list that rejects all peers.
+    pub fn new() -> PeerAllowList {
+        PeerAllowList::default()
+    }
+
+    /// Allows peers whose SPIFFE ID matches `pattern`.
+    pub fn allow_spiffe_id(mut self, pattern: &str) -> PeerAllowList {
+        self.spiffe_ids.push(pattern.to_owned());
+        self
+    }
+
+    /// Allows peers that have a DNS name, URI, IP address or email address in
+    /// the subject alternative names matching `pattern`.
+    pub fn allow_san(mut self, pattern: &str) -> PeerAllowList {
+        self.sans.push(pattern.to_owned());
+        self
+    }
+
+    fn matches(patterns: &[String], value: &str) -> bool {
+        patterns
+            .iter()
+            .any(|p| wildcard_match(p.as_bytes(), value.as_bytes()))
+    }
+}
+
+impl ServerChecker for PeerAllowList {
+    fn check(&mut self, ctx: &RpcContext) -> CheckResult {
+        let auth_context = match ctx.auth_context() {
+            Some(auth_context) if auth_context.peer_is_authenticated() => auth_context,
+            _ => {
+                return CheckResult::Abort(RpcStatus::with_message(
+                    RpcStatusCode::UNAUTHENTICATED,
+                    "peer is not authenticated".to_owned(),
+                ))
+            }
+        };
+        if let Some(id) = auth_context.spiffe_id() {
+            if Self::matches(&self.spiffe_ids, id) {
+                return CheckResult::Continue;
+            }
+        }
+        let sans = auth_context
+            .dns_names()
+            .into_iter()
+            .chain(auth_context.uri_names())
+            .chain(auth_context.ip_names())
+            .chain(auth_context.email_names());
+        for san in sans {
+            if Self::matches(&self.sans, san) {
+                return CheckResult::Continue;
+            }
+        }
+        CheckResult::Abort(RpcStatus::with_message(
+            RpcStatusCode::PERMISSION_DENIED,
+            "peer is not in the allow list".to_owned(),
+        ))
+    }
+
+    fn box_clone(&self) -> Box<dyn ServerChecker> {
+        Box::new(self.clone())
+    }
+}
+
+/// Matches `value` against `pattern`, where `*` matches any sequence of bytes.
+fn wildcard_match(pattern: &[u8], value: &[u8]) -> bool {
+    // Where to resume when the last `*` needs to match one more byte.
+    let mut backtrack = None;
+    let (mut p, mut v) = (0, 0);
+    while v < value.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == value[v] {
+            p += 1;
+            v += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|b| *b == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        let cases: &[(&str, &str, bool)] = &[
+            ("", "", true),
+            ("", "a", false),
+            ("*", "", true),
+            ("*", "anything", true),
+            ("foo.example.com", "foo.example.com", true),
+            ("foo.example.com", "bar.example.com", false),
+            ("*.example.com", "foo.example.com", true),
+            ("*.example.com", "example.com", false),
+            ("*.example.com", "foo.example.com.evil", false),
+            (
+                "spiffe://example.org/ns/prod/*",
+                "spiffe://example.org/ns/prod/sa/db",
+                true,
+            ),
+            (
+                "spiffe://example.org/ns/prod/*",
+                "spiffe://example.org/ns/dev/sa/db",
+                false,
+            ),
+            ("a*b*c", "aXXbYYc", true),
+            ("a*b*c", "aXXcYYb", false),
+            ("**", "ab", true),
+        ];
+        for (pattern, value, expected) in cases {
+            assert_eq!(
+                wildcard_match(pattern.as_bytes(), value.as_bytes()),
+                *expected,
+                "{} {}",
+                pattern,
+                value
+            );
+        }
+    }
+}
@@ -9,6 +9,18 @@ use crate::grpc_sys::{
     self, grpc_auth_context, grpc_auth_property, grpc_auth_property_iterator, grpc_call,
 };
 
+/// The security level of the connection of a call.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SecurityLevel {
+    /// Data is neither protected nor verified, for example insecure
+    /// connections.
+    None,
+    /// Data is verified against tampering but not encrypted.
+    IntegrityOnly,
+    /// Data is both encrypted and verified, for example TLS connections.
+    PrivacyAndIntegrity,
+}
+
 /// To perform server-side authentication, gRPC exposes the authentication context
 /// for each call. The context exposes important authentication-related information
 /// about the RPC such as the type of security/authentication type being used and
@@ -68,6 +80,93 @@ impl AuthContext {
     }
 }
 
+/// Typed accessors of the well-known properties.
+impl AuthContext {
+    /// All the properties named `name`, which must end with `\0`.
+    fn find(&self, name: &[u8]) -> AuthPropertyIter<'_> {
+        unsafe {
+            let iter = grpc_sys::grpc_auth_context_find_properties_by_name(
+                self.ctx.as_ref(),
+                name.as_ptr() as _,
+            );
+            AuthPropertyIter {
+                iter,
+                _lifetime: PhantomData,
+            }
+        }
+    }
+
+    fn find_str(&self, name: &[u8]) -> Option<&str> {
+        self.find(name).find_map(|p| p.value_str().ok())
+    }
+
+    fn find_strs(&self, name: &[u8]) -> Vec<&str> {
+        self.find(name).filter_map(|p| p.value_str().ok()).collect()
+    }
+
+    /// The type of the transport security, like `ssl`, `local` or `insecure`.
+    pub fn transport_security_type(&self) -> Option<&str> {
+        self.find_str(grpc_sys::GRPC_TRANSPORT_SECURITY_TYPE_PROPERTY_NAME)
+    }
+
+    /// The security level of the connection.
+    pub fn security_level(&self) -> Option<SecurityLevel> {
+        match self.find_str(grpc_sys::GRPC_TRANSPORT_SECURITY_LEVEL_PROPERTY_NAME)? {
+            "TSI_SECURITY_NONE" => Some(SecurityLevel::None),
+            "TSI_INTEGRITY_ONLY" => Some(SecurityLevel::IntegrityOnly),
+            "TSI_PRIVACY_AND_INTEGRITY" => Some(SecurityLevel::PrivacyAndIntegrity),
+            _ => None,
+        }
+    }
+
+    /// The common name of the peer certificate.
+    pub fn common_name(&self) -> Option<&str> {
+        self.find_str(grpc_sys::GRPC_X509_CN_PROPERTY_NAME)
+    }
+
+    /// The subject of the peer certificate, like `CN=testclient,O=Internet Widgits Pty Ltd`.
+    pub fn subject(&self) -> Option<&str> {
+        self.find_str(grpc_sys::GRPC_X509_SUBJECT_PROPERTY_NAME)
+    }
+
+    /// The SPIFFE ID of the peer. It's only set when exactly one URI in the
+    /// subject alternative names of the peer certificate is a SPIFFE ID.
+    pub fn spiffe_id(&self) -> Option<&str> {
+        self.find_str(grpc_sys::GRPC_PEER_SPIFFE_ID_PROPERTY_NAME)
+    }
+
+    /// The DNS names in the subject alternative names of the peer certificate.
+    pub fn dns_names(&self) -> Vec<&str> {
+        self.find_strs(grpc_sys::GRPC_PEER_DNS_PROPERTY_NAME)
+    }
+
+    /// The URIs in the subject alternative names of the peer certificate.
+    pub fn uri_names(&self) -> Vec<&str> {
+        self.find_strs(grpc_sys::GRPC_PEER_URI_PROPERTY_NAME)
+    }
+
+    /// The IP addresses in the subject alternative names of the peer certificate.
+    pub fn ip_names(&self) -> Vec<&str> {
+        self.find_strs(grpc_sys::GRPC_PEER_IP_PROPERTY_NAME)
+    }
+
+    /// The email addresses in the subject alternative names of the peer
+    /// certificate.
+    pub fn email_names(&self) -> Vec<&str> {
+        self.find_strs(grpc_sys::GRPC_PEER_EMAIL_PROPERTY_NAME)
+    }
+
+    /// The PEM encoded peer certificate.
+    pub fn pem_cert(&self) -> Option<&str> {
+        self.find_str(grpc_sys::GRPC_X509_PEM_CERT_PROPERTY_NAME)
+    }
+
+    /// The PEM encoded certificate chain of the peer.
+    pub fn pem_cert_chain(&self) -> Option<&str> {
+        self.find_str(grpc_sys::GRPC_X509_PEM_CERT_CHAIN_PROPERTY_NAME)
+    }
+}
+
 impl<'a> IntoIterator for &'a AuthContext {
     type Item = AuthProperty<'a>;
     type IntoIter = AuthPropertyIter<'a>;
@@ -96,6 +195,16 @@ pub struct AuthPropertyIter<'a> {
     _lifetime: PhantomData<&'a grpc_auth_property_iterator>,
 }
 
+impl AuthPropertyIter<'_> {
+    /// Iterates over all the properties of `ctx`.
+    pub(crate) unsafe fn all(ctx: *const grpc_auth_context) -> Self {
+        AuthPropertyIter {
+            iter: grpc_sys::grpc_auth_context_property_iterator(ctx),
+            _lifetime: PhantomData,
+        }
+    }
+}
+
 impl<'a> Iterator for AuthPropertyIter<'a> {
     type Item = AuthProperty<'a>;
 
@@ -0,0 +1,187 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::ffi::CString;
+use std::mem::ManuallyDrop;
+use std::os::raw::c_void;
+use std::ptr;
+
+use crate::call::{RpcStatus, RpcStatusCode};
+use crate::grpc_sys::{
+    self, grpc_auth_context, grpc_auth_metadata_processor, grpc_metadata,
+    grpc_process_auth_metadata_done_cb, grpc_status_code,
+};
+use crate::metadata::Metadata;
+use crate::security::auth_context::AuthPropertyIter;
+use crate::security::call_credentials::error_code;
+use crate::ServerCredentials;
+
+/// Authenticates calls by their metadata, it's installed by
+/// [`ServerCredentials::with_auth_metadata_processor`].
+///
+/// A processor usually verifies a token in the metadata and adds the identity
+/// it represents to the auth context, so that handlers can check the verified
+/// identity by [`RpcContext::auth_context`] instead of parsing headers again.
+///
+/// [`RpcContext::auth_context`]: crate::RpcContext::auth_context
+pub trait AuthMetadataProcessor: Send + Sync {
+    /// Processes the metadata of a call.
+    ///
+    /// The method is invoked in gRPC threads and should not block. The request
+    /// can be completed later from any thread.
+    fn process(&self, request: AuthMetadataRequest);
+}
+
+/// The metadata of a call to be processed by [`AuthMetadataProcessor`].
+///
+/// The call is held until the request is accepted or rejected. If it's dropped
+/// without doing either, the call is rejected with `UNAUTHENTICATED`.
+pub struct AuthMetadataRequest {
+    ctx: *mut grpc_auth_context,
+    // Owned by C core, it's valid until `cb` is invoked.
+    metadata: ManuallyDrop<Metadata>,
+    cb: grpc_process_auth_metadata_done_cb,
+    user_data: *mut c_void,
+    done: bool,
+}
+
+unsafe impl Send for AuthMetadataRequest {}
+
+impl AuthMetadataRequest {
+    /// The metadata sent by the client.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// The properties of the auth context, like the properties of the peer
+    /// certificate.
+    pub fn properties(&self) -> AuthPropertyIter<'_> {
+        unsafe { AuthPropertyIter::all(self.ctx) }
+    }
+
+    /// Adds a property to the auth context of the call.
+    pub fn add_property(&mut self, name: &str, value: &[u8]) {
+        let name = CString::new(name).unwrap();
+        unsafe {
+            grpc_sys::grpc_auth_context_add_property(
+                self.ctx,
+                name.as_ptr(),
+                value.as_ptr() as _,
+                value.len(),
+            )
+        }
+    }
+
+    /// Uses the properties named `name` as the peer identity.
+    ///
+    /// Returns false if no such property exists.
+    pub fn set_peer_identity_property_name(&mut self, name: &str) -> bool {
+        let name = CString::new(name).unwrap();
+        unsafe {
+            grpc_sys::grpc_auth_context_set_peer_identity_property_name(self.ctx, name.as_ptr())
+                != 0
+        }
+    }
+
+    /// Accepts the call.
+    ///
+    /// Metadata entries with keys in `consumed` are removed from the call, so
+    /// handlers won't see the credentials.
+    pub fn accept(mut self, consumed: &[&str]) {
+        self.done = true;
+        let consumed_md: Vec<grpc_metadata> = self
+            .metadata
+            .iter()
+            .zip(self.metadata.raw_entries())
+            .filter(|((k, _), _)| consumed.contains(k))
+            .map(|(_, md)| *md)
+            .collect();
+        unsafe {
+            self.cb.unwrap()(
+                self.user_data,
+                consumed_md.as_ptr(),
+                consumed_md.len(),
+                ptr::null(),
+                0,
+                grpc_status_code::GRPC_STATUS_OK,
+                ptr::null(),
+            )
+        }
+    }
+
+    /// Rejects the call with `status`, which is usually `UNAUTHENTICATED` or
+    /// `PERMISSION_DENIED`.
+    pub fn reject(mut self, status: RpcStatus) {
+        self.done = true;
+        self.fail(&status);
+    }
+
+    fn fail(&self, status: &RpcStatus) {
+        let code = error_code(status);
+        let msg = CString::new(status.message()).unwrap_or_default();
+        unsafe {
+            self.cb.unwrap()(
+                self.user_data,
+                ptr::null(),
+                0,
+                ptr::null(),
+                0,
+                code,
+                msg.as_ptr(),
+            )
+        }
+    }
+}
+
+impl Drop for AuthMetadataRequest {
+    fn drop(&mut self) {
+        if !self.done {
+            self.fail(&RpcStatus::with_message(
+                RpcStatusCode::UNAUTHENTICATED,
+                "auth metadata request is dropped".to_owned(),
+            ));
+        }
+    }
+}
+
+impl ServerCredentials {
+    /// Processes the metadata of every call with `processor` before the call is
+    /// dispatched to handlers.
+    pub fn with_auth_metadata_processor<P: AuthMetadataProcessor + 'static>(
+        self,
+        processor: P,
+    ) -> ServerCredentials {
+        let state: Box<Box<dyn AuthMetadataProcessor>> = Box::new(Box::new(processor));
+        let processor = grpc_auth_metadata_processor {
+            process: Some(processor_process),
+            destroy: Some(processor_destroy),
+            state: Box::into_raw(state) as _,
+        };
+        unsafe {
+            grpc_sys::grpc_server_credentials_set_auth_metadata_processor(self.creds, processor)
+        }
+        self
+    }
+}
+
+unsafe extern "C" fn processor_process(
+    state: *mut c_void,
+    context: *mut grpc_auth_context,
+    md: *const grpc_metadata,
+    num_md: usize,
+    cb: grpc_process_auth_metadata_done_cb,
+    user_data: *mut c_void,
+) {
+    let processor = &*(state as *mut Box<dyn AuthMetadataProcessor>);
+    let metadata = Metadata::from_raw_parts(md as *mut _, num_md, num_md);
+    processor.process(AuthMetadataRequest {
+        ctx: context,
+        metadata: ManuallyDrop::new(metadata),
+        cb,
+        user_data,
+        done: false,
+    })
+}
+
+unsafe extern "C" fn processor_destroy(state: *mut c_void) {
+    drop(Box::from_raw(state as *mut Box<dyn AuthMetadataProcessor>));
+}
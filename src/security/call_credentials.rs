@@ -0,0 +1,283 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::{Arc, Mutex};
+use std::{ptr, thread};
+
+use crate::call::{RpcStatus, RpcStatusCode};
+use crate::grpc_sys::{
+    self, grpc_auth_metadata_context, grpc_call_credentials, grpc_credentials_plugin_metadata_cb,
+    grpc_metadata, grpc_metadata_credentials_plugin, grpc_security_level, grpc_status_code,
+};
+use crate::metadata::Metadata;
+use crate::ChannelCredentials;
+
+/// Credentials that are attached to every call, like an OAuth2 access token.
+///
+/// They can be set on a single call by [`CallOption::credentials`], or on all
+/// calls of a channel by [`ChannelCredentials::with_call_credentials`]. Call
+/// credentials are only sent over secure channels.
+///
+/// [`CallOption::credentials`]: crate::CallOption::credentials
+pub struct CallCredentials {
+    creds: *mut grpc_call_credentials,
+}
+
+unsafe impl Send for CallCredentials {}
+unsafe impl Sync for CallCredentials {}
+
+impl CallCredentials {
+    /// Creates credentials that send `token` as a bearer token in the
+    /// `authorization` header.
+    pub fn access_token(token: &str) -> CallCredentials {
+        let token = CString::new(token).unwrap();
+        unsafe {
+            let creds =
+                grpc_sys::grpc_access_token_credentials_create(token.as_ptr(), ptr::null_mut());
+            CallCredentials { creds }
+        }
+    }
+
+    /// Creates credentials that ask `plugin` for the metadata of every call.
+    pub fn from_plugin<P: MetadataCredentialsPlugin + 'static>(plugin: P) -> CallCredentials {
+        let state: Box<Box<dyn MetadataCredentialsPlugin>> = Box::new(Box::new(plugin));
+        let plugin = grpc_metadata_credentials_plugin {
+            get_metadata: Some(plugin_get_metadata),
+            debug_string: None,
+            destroy: Some(plugin_destroy),
+            state: Box::into_raw(state) as _,
+            type_: b"grpcio_plugin\0".as_ptr() as _,
+        };
+        unsafe {
+            let creds = grpc_sys::grpc_metadata_credentials_create_from_plugin(
+                plugin,
+                grpc_security_level::GRPC_PRIVACY_AND_INTEGRITY,
+                ptr::null_mut(),
+            );
+            CallCredentials { creds }
+        }
+    }
+
+    /// Combines two credentials, metadata of both are sent with calls.
+    pub fn compose(self, other: CallCredentials) -> CallCredentials {
+        unsafe {
+            let creds = grpc_sys::grpc_composite_call_credentials_create(
+                self.creds,
+                other.creds,
+                ptr::null_mut(),
+            );
+            CallCredentials { creds }
+        }
+    }
+
+    pub fn as_mut_ptr(&self) -> *mut grpc_call_credentials {
+        self.creds
+    }
+}
+
+impl Drop for CallCredentials {
+    fn drop(&mut self) {
+        unsafe { grpc_sys::grpc_call_credentials_release(self.creds) }
+    }
+}
+
+impl ChannelCredentials {
+    /// Attaches `creds` to all calls made through channels using the credentials.
+    pub fn with_call_credentials(mut self, creds: CallCredentials) -> ChannelCredentials {
+        unsafe {
+            let creds = grpc_sys::grpc_composite_channel_credentials_create(
+                self.creds,
+                creds.creds,
+                ptr::null_mut(),
+            );
+            let mut composite = ChannelCredentials::from_raw(creds);
+            composite.fetcher = self.fetcher.take();
+            composite.key_log = self.key_log.take();
+            composite
+        }
+    }
+}
+
+/// Information of a call that asks for metadata.
+pub struct AuthMetadataContext<'a> {
+    ctx: &'a grpc_auth_metadata_context,
+}
+
+impl AuthMetadataContext<'_> {
+    /// The fully qualified service url, like `https://host/helloworld.Greeter`.
+    pub fn service_url(&self) -> &str {
+        unsafe { to_str(self.ctx.service_url) }
+    }
+
+    /// The method name of the call, not fully qualified, like `SayHello`.
+    pub fn method_name(&self) -> &str {
+        unsafe { to_str(self.ctx.method_name) }
+    }
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> &'a str {
+    if s.is_null() {
+        return "";
+    }
+    CStr::from_ptr(s).to_str().unwrap_or("")
+}
+
+/// Produces auth metadata for calls, it's used by [`CallCredentials::from_plugin`].
+pub trait MetadataCredentialsPlugin: Send + Sync {
+    /// Retrieves the metadata of a call.
+    ///
+    /// The method should not block. The result should be passed to `callback`,
+    /// either before the method returns or later from any thread, for example
+    /// after a refreshed token is fetched. Failing a call with a status fails
+    /// the call with the same status.
+    fn get_metadata(&self, ctx: &AuthMetadataContext<'_>, callback: MetadataCredentialsCallback);
+}
+
+struct CallbackState {
+    // Whether `get_metadata` is still running.
+    inline: bool,
+    result: Option<std::result::Result<Metadata, RpcStatus>>,
+}
+
+/// Passes the result of [`MetadataCredentialsPlugin::get_metadata`] to gRPC.
+///
+/// If it's dropped without being called, the call fails with `INTERNAL`.
+pub struct MetadataCredentialsCallback {
+    cb: grpc_credentials_plugin_metadata_cb,
+    user_data: *mut c_void,
+    state: Arc<Mutex<CallbackState>>,
+    done: bool,
+}
+
+unsafe impl Send for MetadataCredentialsCallback {}
+
+impl MetadataCredentialsCallback {
+    /// Completes the request with `result`.
+    pub fn done(mut self, result: std::result::Result<Metadata, RpcStatus>) {
+        self.done = true;
+        let mut state = self.state.lock().unwrap();
+        if state.inline {
+            // gRPC requires the callback to be invoked from another thread, so
+            // leave it to `plugin_get_metadata`.
+            state.result = Some(result);
+            return;
+        }
+        drop(state);
+        unsafe { invoke_callback(self.cb, self.user_data, result) }
+    }
+}
+
+impl Drop for MetadataCredentialsCallback {
+    fn drop(&mut self) {
+        if !self.done {
+            let status = RpcStatus::with_message(
+                RpcStatusCode::INTERNAL,
+                "metadata credentials callback is dropped".to_owned(),
+            );
+            let cb = MetadataCredentialsCallback {
+                cb: self.cb,
+                user_data: self.user_data,
+                state: self.state.clone(),
+                done: false,
+            };
+            cb.done(Err(status));
+        }
+    }
+}
+
+unsafe fn invoke_callback(
+    cb: grpc_credentials_plugin_metadata_cb,
+    user_data: *mut c_void,
+    result: std::result::Result<Metadata, RpcStatus>,
+) {
+    let cb = cb.unwrap();
+    match result {
+        // C core copies the entries before the callback returns.
+        Ok(md) => {
+            let entries = md.raw_entries();
+            cb(
+                user_data,
+                entries.as_ptr(),
+                entries.len(),
+                grpc_status_code::GRPC_STATUS_OK,
+                ptr::null(),
+            )
+        }
+        Err(status) => {
+            let msg = CString::new(status.message()).unwrap_or_default();
+            cb(user_data, ptr::null(), 0, error_code(&status), msg.as_ptr())
+        }
+    }
+}
+
+pub(crate) fn error_code(status: &RpcStatus) -> grpc_status_code::Type {
+    match status.code() {
+        // OK is not a valid failure.
+        RpcStatusCode::OK => grpc_status_code::GRPC_STATUS_UNKNOWN,
+        code => i32::from(code),
+    }
+}
+
+unsafe extern "C" fn plugin_get_metadata(
+    state: *mut c_void,
+    context: grpc_auth_metadata_context,
+    cb: grpc_credentials_plugin_metadata_cb,
+    user_data: *mut c_void,
+    creds_md: *mut grpc_metadata,
+    num_creds_md: *mut usize,
+    status: *mut grpc_status_code::Type,
+    error_details: *mut *const c_char,
+) -> c_int {
+    let plugin = &*(state as *mut Box<dyn MetadataCredentialsPlugin>);
+    let cb_state = Arc::new(Mutex::new(CallbackState {
+        inline: true,
+        result: None,
+    }));
+    let callback = MetadataCredentialsCallback {
+        cb,
+        user_data,
+        state: cb_state.clone(),
+        done: false,
+    };
+    let ctx = AuthMetadataContext { ctx: &context };
+    plugin.get_metadata(&ctx, callback);
+
+    let mut s = cb_state.lock().unwrap();
+    s.inline = false;
+    match s.result.take() {
+        None => 0,
+        Some(Ok(md)) if md.len() > grpc_sys::GRPC_METADATA_CREDENTIALS_PLUGIN_SYNC_MAX as usize => {
+            // Too many entries to be returned synchronously.
+            let user_data = user_data as usize;
+            thread::spawn(move || unsafe { invoke_callback(cb, user_data as _, Ok(md)) });
+            0
+        }
+        Some(Ok(md)) => {
+            // The caller takes ownership of the returned entries.
+            for (i, entry) in md.raw_entries().iter().enumerate() {
+                let mut e = *entry;
+                e.key = grpc_sys::grpc_slice_ref(entry.key);
+                e.value = grpc_sys::grpc_slice_ref(entry.value);
+                *creds_md.add(i) = e;
+            }
+            *num_creds_md = md.len();
+            *status = grpc_status_code::GRPC_STATUS_OK;
+            *error_details = ptr::null();
+            1
+        }
+        Some(Err(s)) => {
+            let msg = CString::new(s.message()).unwrap_or_default();
+            *num_creds_md = 0;
+            *status = error_code(&s);
+            *error_details = grpc_sys::gpr_strdup(msg.as_ptr());
+            1
+        }
+    }
+}
+
+unsafe extern "C" fn plugin_destroy(state: *mut c_void) {
+    drop(Box::from_raw(
+        state as *mut Box<dyn MetadataCredentialsPlugin>,
+    ));
+}
@@ -2,14 +2,24 @@
 
 use std::error::Error as StdError;
 use std::ffi::CString;
-use std::{mem, ptr};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{Builder as ThreadBuilder, JoinHandle};
+use std::time::Duration;
+use std::{mem, process, ptr};
 
 use crate::error::{Error, Result};
 use crate::grpc_sys::grpc_ssl_certificate_config_reload_status::{self, *};
 use crate::grpc_sys::grpc_ssl_client_certificate_request_type::*;
 use crate::grpc_sys::{
-    self, grpc_ssl_client_certificate_request_type, grpc_ssl_server_certificate_config,
+    self, grpc_local_connect_type, grpc_ssl_client_certificate_request_type,
+    grpc_ssl_server_certificate_config,
 };
+use crate::security::tls::{CertificateProvider, KeyLog, TlsCredentialsBuilder};
 use crate::{ChannelCredentials, ServerCredentials};
 
 #[repr(u32)]
@@ -67,6 +77,25 @@ pub enum CertificateRequestType {
         GRPC_SSL_REQUEST_AND_REQUIRE_CLIENT_CERTIFICATE_AND_VERIFY as u32,
 }
 
+/// The type of local connections that [`ChannelCredentials::local`] and
+/// [`ServerCredentials::local`] accept.
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LocalConnectType {
+    /// Unix domain sockets. Connections are considered private and integral,
+    /// so call credentials can be sent over them.
+    Uds = grpc_local_connect_type::UDS as u32,
+    /// TCP connections to loopback addresses, like `127.0.0.1` or `[::1]`.
+    LocalTcp = grpc_local_connect_type::LOCAL_TCP as u32,
+}
+
+impl LocalConnectType {
+    #[inline]
+    fn to_native(self) -> grpc_local_connect_type {
+        unsafe { mem::transmute(self) }
+    }
+}
+
 /// Traits to retrieve updated SSL server certificates, private keys, and trusted CAs
 /// (for client authentication).
 pub trait ServerCredentialsFetcher {
@@ -76,9 +105,24 @@ pub trait ServerCredentialsFetcher {
     /// connection is about to be accepted. When returning `None` or error, gRPC
     /// will continue to use the previous certificates returned by the method. If no
     /// valid credentials is returned during initialization, the server will fail to start.
+    ///
+    /// Key logging set on the returned builders is ignored.
     fn fetch(&self) -> std::result::Result<Option<ServerCredentialsBuilder>, Box<dyn StdError>>;
 }
 
+/// Traits to retrieve updated SSL client certificates, private keys, and trusted
+/// CAs (for server authentication).
+pub trait ChannelCredentialsFetcher {
+    /// Retrieves updated credentials.
+    ///
+    /// The method will be called when the credentials are created and then
+    /// periodically. Connections established after that use the new credentials.
+    /// When returning `None` or error, gRPC will continue to use the previous
+    /// certificates returned by the method. If no valid credentials is returned
+    /// during initialization, the credentials fail to be created.
+    fn fetch(&self) -> std::result::Result<Option<ChannelCredentialsBuilder>, Box<dyn StdError>>;
+}
+
 impl CertificateRequestType {
     #[inline]
     pub(crate) fn to_native(self) -> grpc_ssl_client_certificate_request_type {
@@ -86,7 +130,7 @@ impl CertificateRequestType {
     }
 }
 
-fn clear_key_securely(key: &mut [u8]) {
+pub(crate) fn clear_key_securely(key: &mut [u8]) {
     unsafe {
         for b in key {
             ptr::write_volatile(b, 0)
@@ -94,6 +138,18 @@ fn clear_key_securely(key: &mut [u8]) {
     }
 }
 
+/// Converts the private key into a `CString` without leaving a copy that is not
+/// cleared.
+pub(crate) fn private_key_to_cstring(mut private_key: Vec<u8>) -> CString {
+    if private_key.capacity() == private_key.len() {
+        let mut nil_key = Vec::with_capacity(private_key.len() + 1);
+        nil_key.extend_from_slice(&private_key);
+        clear_key_securely(&mut private_key);
+        private_key = nil_key;
+    }
+    CString::new(private_key).unwrap()
+}
+
 pub(crate) unsafe extern "C" fn server_cert_fetcher_wrapper(
     user_data: *mut std::os::raw::c_void,
     config: *mut *mut grpc_ssl_server_certificate_config,
@@ -125,6 +181,7 @@ pub struct ServerCredentialsBuilder {
     root: Option<CString>,
     key_cert_pairs: Vec<grpcio_sys::grpc_ssl_pem_key_cert_pair>,
     cer_request_type: CertificateRequestType,
+    key_log: Option<KeyLog>,
 }
 
 impl ServerCredentialsBuilder {
@@ -134,6 +191,7 @@ impl ServerCredentialsBuilder {
             root: None,
             key_cert_pairs: vec![],
             cer_request_type: CertificateRequestType::DontRequestClientCertificate,
+            key_log: None,
         }
     }
 
@@ -150,21 +208,32 @@ impl ServerCredentialsBuilder {
     }
 
     /// Add a PEM encoded server side certificate and key.
-    pub fn add_cert(mut self, cert: Vec<u8>, mut private_key: Vec<u8>) -> ServerCredentialsBuilder {
-        if private_key.capacity() == private_key.len() {
-            let mut nil_key = Vec::with_capacity(private_key.len() + 1);
-            nil_key.extend_from_slice(&private_key);
-            clear_key_securely(&mut private_key);
-            private_key = nil_key;
-        }
+    pub fn add_cert(mut self, cert: Vec<u8>, private_key: Vec<u8>) -> ServerCredentialsBuilder {
         self.key_cert_pairs
             .push(grpcio_sys::grpc_ssl_pem_key_cert_pair {
-                private_key: CString::new(private_key).unwrap().into_raw(),
+                private_key: private_key_to_cstring(private_key).into_raw(),
                 cert_chain: CString::new(cert).unwrap().into_raw(),
             });
         self
     }
 
+    /// Log TLS session keys to the file at `path` in the NSS key log format.
+    /// See [`TlsCredentialsBuilder::tls_session_key_log_file`].
+    pub fn tls_session_key_log_file<P: AsRef<Path>>(mut self, path: P) -> ServerCredentialsBuilder {
+        self.key_log = Some(KeyLog::File(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Pass TLS session keys to `callback` line by line in the NSS key log
+    /// format. See [`TlsCredentialsBuilder::tls_session_key_log_callback`].
+    pub fn tls_session_key_log_callback<F>(mut self, callback: F) -> ServerCredentialsBuilder
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.key_log = Some(KeyLog::Callback(Arc::new(callback)));
+        self
+    }
+
     /// Finalize the [`ServerCredentialsBuilder`] and build the
     /// [`*mut grpcio_sys::bindings::grpc_ssl_server_certificate_config`].
     unsafe fn build_config(mut self) -> *mut grpcio_sys::grpc_ssl_server_certificate_config {
@@ -183,8 +252,32 @@ impl ServerCredentialsBuilder {
         cfg
     }
 
+    /// Builds the credentials on the TLS options, which support key logging.
+    fn build_tls(mut self, key_log: KeyLog) -> ServerCredentials {
+        let root = self.root.take().map(CString::into_bytes);
+        let pairs = self
+            .key_cert_pairs
+            .drain(..)
+            .map(|pair| unsafe {
+                let cert = CString::from_raw(pair.cert_chain as *mut _);
+                let key = CString::from_raw(pair.private_key as *mut _);
+                (cert.into_bytes(), key.into_bytes())
+            })
+            .collect();
+        TlsCredentialsBuilder::new()
+            .certificate_provider(CertificateProvider::static_data(root, pairs))
+            .cert_request_type(self.cer_request_type)
+            .key_log(Some(key_log))
+            .build_server()
+            // TLS versions are not set, so it can't fail.
+            .unwrap()
+    }
+
     /// Finalize the [`ServerCredentialsBuilder`] and build the [`ServerCredentials`].
-    pub fn build(self) -> ServerCredentials {
+    pub fn build(mut self) -> ServerCredentials {
+        if let Some(key_log) = self.key_log.take() {
+            return self.build_tls(key_log);
+        }
         unsafe {
             let opt = grpcio_sys::grpc_ssl_server_credentials_create_options_using_config(
                 self.cer_request_type.to_native(),
@@ -234,10 +327,24 @@ impl ServerCredentials {
     }
 }
 
+impl ServerCredentials {
+    /// Creates credentials that only accept local connections of `connect_type`.
+    ///
+    /// Peers are authenticated by the type of connections without any
+    /// certificate, the transport security type in the auth context is `local`.
+    pub fn local(connect_type: LocalConnectType) -> ServerCredentials {
+        unsafe {
+            let creds = grpc_sys::grpc_local_server_credentials_create(connect_type.to_native());
+            ServerCredentials::from_raw(creds)
+        }
+    }
+}
+
 /// [`ChannelCredentials`] factory in order to configure the properties.
 pub struct ChannelCredentialsBuilder {
     root: Option<CString>,
     cert_key_pair: Option<(CString, CString)>,
+    key_log: Option<KeyLog>,
 }
 
 impl ChannelCredentialsBuilder {
@@ -246,6 +353,7 @@ impl ChannelCredentialsBuilder {
         ChannelCredentialsBuilder {
             root: None,
             cert_key_pair: None,
+            key_log: None,
         }
     }
 
@@ -256,22 +364,56 @@ impl ChannelCredentialsBuilder {
     }
 
     /// Set the PEM encoded client side certificate and key.
-    pub fn cert(mut self, cert: Vec<u8>, mut private_key: Vec<u8>) -> ChannelCredentialsBuilder {
-        if private_key.capacity() == private_key.len() {
-            let mut nil_key = Vec::with_capacity(private_key.len() + 1);
-            nil_key.extend_from_slice(&private_key);
-            clear_key_securely(&mut private_key);
-            private_key = nil_key;
-        }
+    pub fn cert(mut self, cert: Vec<u8>, private_key: Vec<u8>) -> ChannelCredentialsBuilder {
         self.cert_key_pair = Some((
             CString::new(cert).unwrap(),
-            CString::new(private_key).unwrap(),
+            private_key_to_cstring(private_key),
         ));
         self
     }
 
+    /// Log TLS session keys to the file at `path` in the NSS key log format.
+    /// See [`TlsCredentialsBuilder::tls_session_key_log_file`].
+    pub fn tls_session_key_log_file<P: AsRef<Path>>(
+        mut self,
+        path: P,
+    ) -> ChannelCredentialsBuilder {
+        self.key_log = Some(KeyLog::File(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Pass TLS session keys to `callback` line by line in the NSS key log
+    /// format. See [`TlsCredentialsBuilder::tls_session_key_log_callback`].
+    pub fn tls_session_key_log_callback<F>(mut self, callback: F) -> ChannelCredentialsBuilder
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.key_log = Some(KeyLog::Callback(Arc::new(callback)));
+        self
+    }
+
+    /// Builds the credentials on the TLS options, which support key logging.
+    fn build_tls(mut self, key_log: KeyLog) -> ChannelCredentials {
+        let root = self.root.take().map(CString::into_bytes);
+        let pairs = self
+            .cert_key_pair
+            .take()
+            .map(|(cert, key)| (cert.into_bytes(), key.into_bytes()))
+            .into_iter()
+            .collect();
+        TlsCredentialsBuilder::new()
+            .certificate_provider(CertificateProvider::static_data(root, pairs))
+            .key_log(Some(key_log))
+            .build_channel_credentials()
+            // TLS versions are not set, so it can't fail.
+            .unwrap()
+    }
+
     /// Finalize the [`ChannelCredentialsBuilder`] and build the [`ChannelCredentials`].
     pub fn build(mut self) -> ChannelCredentials {
+        if let Some(key_log) = self.key_log.take() {
+            return self.build_tls(key_log);
+        }
         let root_ptr = self
             .root
             .take()
@@ -317,7 +459,148 @@ impl ChannelCredentialsBuilder {
             }
         }
 
-        ChannelCredentials { creds }
+        unsafe { ChannelCredentials::from_raw(creds) }
+    }
+}
+
+const ROOT_CERT_FILE: &str = "ca.pem";
+const CERT_FILE: &str = "cert.pem";
+const KEY_FILE: &str = "key.pem";
+
+const CURRENT_DIR: &str = "current";
+
+fn write_file(dir: &Path, name: &str, data: &[u8]) -> io::Result<()> {
+    let mut opts = OpenOptions::new();
+    opts.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut opts, 0o600);
+    let mut f = opts.open(dir.join(name))?;
+    f.write_all(data)?;
+    f.sync_all()
+}
+
+fn generation_dir(generation: usize) -> String {
+    format!("gen-{generation}")
+}
+
+/// Points `dir/current` to the directory `target` in `dir`.
+#[cfg(unix)]
+fn swap_current(dir: &Path, target: &str) -> io::Result<()> {
+    let tmp = dir.join(format!("{CURRENT_DIR}.tmp"));
+    let _ = fs::remove_file(&tmp);
+    std::os::unix::fs::symlink(target, &tmp)?;
+    // Replacing a symlink by rename is atomic, so readers see either the old
+    // pair or the new pair, never a mix of both.
+    fs::rename(tmp, dir.join(CURRENT_DIR))
+}
+
+/// Copies the files in the directory `target` in `dir` to `dir/current`.
+///
+/// Directories can't be replaced atomically without symlinks, so each file
+/// is replaced on its own. gRPC retries reading the key and certificate when
+/// either of them changes during the read.
+#[cfg(not(unix))]
+fn swap_current(dir: &Path, target: &str) -> io::Result<()> {
+    let current = dir.join(CURRENT_DIR);
+    fs::create_dir_all(&current)?;
+    for name in [ROOT_CERT_FILE, KEY_FILE, CERT_FILE] {
+        let src = dir.join(target).join(name);
+        if src.exists() {
+            let tmp = current.join(format!("{name}.tmp"));
+            fs::copy(&src, &tmp)?;
+            fs::rename(tmp, current.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+impl ChannelCredentialsBuilder {
+    /// Writes the certificates into a new directory in `dir` and makes it
+    /// the one loaded by gRPC from `dir/current`.
+    fn write_files(&self, dir: &Path, generation: usize) -> io::Result<()> {
+        let target = generation_dir(generation);
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        builder.create(dir.join(&target))?;
+        if let Some(root) = &self.root {
+            write_file(&dir.join(&target), ROOT_CERT_FILE, root.as_bytes())?;
+        }
+        if let Some((cert, key)) = &self.cert_key_pair {
+            write_file(&dir.join(&target), KEY_FILE, key.as_bytes())?;
+            write_file(&dir.join(&target), CERT_FILE, cert.as_bytes())?;
+        }
+        swap_current(dir, &target)?;
+        // The previous generation may still be being read by gRPC, so only
+        // the ones before it are removed.
+        if generation >= 2 {
+            let _ = fs::remove_dir_all(dir.join(generation_dir(generation - 2)));
+        }
+        Ok(())
+    }
+}
+
+/// Fetches certificates in background and stores them in a private directory,
+/// which is watched by gRPC.
+///
+/// gRPC C core can't load client certificates from callbacks, it can only
+/// reload them from files.
+pub(crate) struct CertificateRefresher {
+    dir: PathBuf,
+    stop: Option<(mpsc::Sender<()>, JoinHandle<()>)>,
+}
+
+/// Creates a temporary directory that is only accessible by the current user.
+pub(crate) fn create_private_dir(name: &str) -> io::Result<PathBuf> {
+    static SEQ: AtomicUsize = AtomicUsize::new(0);
+    let seq = SEQ.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("grpcio-{name}-{}-{seq}", process::id()));
+    let mut builder = fs::DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(&dir)?;
+    Ok(dir)
+}
+
+impl CertificateRefresher {
+    fn start(
+        &mut self,
+        fetcher: Box<dyn ChannelCredentialsFetcher + Send + Sync>,
+        refresh_interval: Duration,
+    ) -> io::Result<()> {
+        let (tx, rx) = mpsc::channel::<()>();
+        let dir = self.dir.clone();
+        let mut generation = 0;
+        let handle = ThreadBuilder::new()
+            .name("grpc-cert-fetcher".to_owned())
+            .spawn(move || loop {
+                match rx.recv_timeout(refresh_interval) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    _ => return,
+                }
+                match fetcher.fetch() {
+                    Ok(Some(builder)) => {
+                        generation += 1;
+                        if let Err(e) = builder.write_files(&dir, generation) {
+                            warn!("failed to save fetched certificates: {}", e);
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => warn!("cert_fetcher met error: {}", e),
+                }
+            })?;
+        self.stop = Some((tx, handle));
+        Ok(())
+    }
+}
+
+impl Drop for CertificateRefresher {
+    fn drop(&mut self) {
+        if let Some((tx, handle)) = self.stop.take() {
+            drop(tx);
+            let _ = handle.join();
+        }
+        let _ = fs::remove_dir_all(&self.dir);
     }
 }
 
@@ -330,6 +613,75 @@ impl Drop for ChannelCredentialsBuilder {
 }
 
 impl ChannelCredentials {
+    /// Creates credentials that only connect to local servers by connections of
+    /// `connect_type`.
+    pub fn local(connect_type: LocalConnectType) -> ChannelCredentials {
+        unsafe {
+            let creds = grpc_sys::grpc_local_credentials_create(connect_type.to_native());
+            ChannelCredentials::from_raw(creds)
+        }
+    }
+
+    /// Creates the credentials using a certificate fetcher. Use this method to
+    /// reload the client certificates, keys and root certificates of long-lived
+    /// channels. `fetcher` is called every `refresh_interval`, and new
+    /// connections pick up the latest certificates.
+    ///
+    /// gRPC can only reload certificates from files, so the fetched
+    /// certificates **and private keys are written to disk**. They are kept
+    /// in a directory created under [`std::env::temp_dir`], which is only
+    /// accessible by the current user on Unix, and removed when the
+    /// credentials and all the channels built from them are dropped. The
+    /// files are left behind if the process exits without dropping them.
+    ///
+    /// TLS session keys are logged as set on the first fetched builder, key
+    /// logging of the later ones is ignored.
+    ///
+    /// Every fetch is written to a new directory, which then replaces the
+    /// loaded one as a whole, so a key is never paired with the certificate
+    /// of another fetch. The replacement is only atomic on Unix.
+    pub fn with_fetcher(
+        fetcher: Box<dyn ChannelCredentialsFetcher + Send + Sync>,
+        refresh_interval: Duration,
+    ) -> Result<ChannelCredentials> {
+        let fetch_err = |e: &dyn std::fmt::Display| Error::FetchCredentialsFailed(e.to_string());
+        let mut initial = match fetcher.fetch() {
+            Ok(Some(builder)) => builder,
+            Ok(None) => return Err(fetch_err(&"no credentials is fetched")),
+            Err(e) => return Err(fetch_err(&e)),
+        };
+        let mut refresher = CertificateRefresher {
+            dir: create_private_dir("certs").map_err(|e| fetch_err(&e))?,
+            stop: None,
+        };
+        initial
+            .write_files(&refresher.dir, 0)
+            .map_err(|e| fetch_err(&e))?;
+        let dir = refresher.dir.join(CURRENT_DIR);
+        let (root, key, cert) = (
+            dir.join(ROOT_CERT_FILE),
+            dir.join(KEY_FILE),
+            dir.join(CERT_FILE),
+        );
+        let provider = CertificateProvider::file_watcher(
+            initial
+                .cert_key_pair
+                .as_ref()
+                .map(|_| (cert.as_path(), key.as_path())),
+            initial.root.as_ref().map(|_| root.as_path()),
+            refresh_interval,
+        );
+        let mut creds = TlsCredentialsBuilder::new()
+            .certificate_provider(provider)
+            .key_log(initial.key_log.take())
+            .build_channel_credentials()?;
+        refresher
+            .start(fetcher, refresh_interval)
+            .map_err(|e| fetch_err(&e))?;
+        creds.fetcher = Some(refresher);
+        Ok(creds)
+    }
+
     /// Try to build a [`ChannelCredentials`] to authenticate with Google OAuth credentials.
     pub fn google_default_credentials() -> Result<ChannelCredentials> {
         // Initialize the runtime here. Because this is an associated method
@@ -342,7 +694,7 @@ impl ChannelCredentials {
         if creds.is_null() {
             Err(Error::GoogleAuthenticationFailed)
         } else {
-            Ok(ChannelCredentials { creds })
+            Ok(unsafe { ChannelCredentials::from_raw(creds) })
         }
     }
 }
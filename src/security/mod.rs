@@ -1,18 +1,38 @@
 // Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
 
+#[cfg(feature = "_secure")]
+mod allow_list;
 #[cfg(feature = "_secure")]
 mod auth_context;
 #[cfg(feature = "_secure")]
+mod auth_processor;
+#[cfg(feature = "_secure")]
+mod call_credentials;
+#[cfg(feature = "_secure")]
 mod credentials;
+#[cfg(feature = "_secure")]
+mod tls;
 
 use grpcio_sys::{grpc_channel_credentials, grpc_server_credentials};
 
+#[cfg(feature = "_secure")]
+pub use self::allow_list::PeerAllowList;
 #[cfg(feature = "_secure")]
 pub use self::auth_context::*;
 #[cfg(feature = "_secure")]
+pub use self::auth_processor::{AuthMetadataProcessor, AuthMetadataRequest};
+#[cfg(feature = "_secure")]
+pub use self::call_credentials::{
+    AuthMetadataContext, CallCredentials, MetadataCredentialsCallback, MetadataCredentialsPlugin,
+};
+#[cfg(feature = "_secure")]
 pub use self::credentials::{
-    CertificateRequestType, ChannelCredentialsBuilder, ServerCredentialsBuilder,
-    ServerCredentialsFetcher,
+    CertificateRequestType, ChannelCredentialsBuilder, ChannelCredentialsFetcher, LocalConnectType,
+    ServerCredentialsBuilder, ServerCredentialsFetcher,
+};
+#[cfg(feature = "_secure")]
+pub use self::tls::{
+    CertificateProvider, CertificateVerifier, TlsCredentialsBuilder, VerificationRequest,
 };
 
 /// Client-side SSL credentials.
@@ -21,6 +41,13 @@ pub use self::credentials::{
 /// build a [`ChannelCredentials`].
 pub struct ChannelCredentials {
     creds: *mut grpc_channel_credentials,
+    insecure: bool,
+    // Keeps fetching certificates while the credentials are in use.
+    #[cfg(feature = "_secure")]
+    fetcher: Option<self::credentials::CertificateRefresher>,
+    // Forwards logged TLS session keys while the credentials are in use.
+    #[cfg(feature = "_secure")]
+    key_log: Option<self::tls::KeyLogForwarder>,
 }
 
 impl ChannelCredentials {
@@ -32,7 +59,25 @@ impl ChannelCredentials {
     pub fn insecure() -> ChannelCredentials {
         unsafe {
             let creds = grpcio_sys::grpc_insecure_credentials_create();
-            ChannelCredentials { creds }
+            let mut creds = ChannelCredentials::from_raw(creds);
+            creds.insecure = true;
+            creds
+        }
+    }
+
+    /// Whether the credentials are created by [`ChannelCredentials::insecure`].
+    pub(crate) fn is_insecure(&self) -> bool {
+        self.insecure
+    }
+
+    pub(crate) unsafe fn from_raw(creds: *mut grpc_channel_credentials) -> ChannelCredentials {
+        ChannelCredentials {
+            creds,
+            insecure: false,
+            #[cfg(feature = "_secure")]
+            fetcher: None,
+            #[cfg(feature = "_secure")]
+            key_log: None,
         }
     }
 }
@@ -51,6 +96,8 @@ pub struct ServerCredentials {
     // Double allocation to get around C call.
     #[cfg(feature = "_secure")]
     _fetcher: Option<Box<Box<dyn crate::ServerCredentialsFetcher + Send + Sync>>>,
+    #[cfg(feature = "_secure")]
+    _key_log: Option<self::tls::KeyLogForwarder>,
 }
 
 unsafe impl Send for ServerCredentials {}
@@ -69,6 +116,8 @@ impl ServerCredentials {
             creds,
             #[cfg(feature = "_secure")]
             _fetcher: None,
+            #[cfg(feature = "_secure")]
+            _key_log: None,
         }
     }
 
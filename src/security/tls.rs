@@ -0,0 +1,546 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::ffi::{CStr, CString};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{Builder as ThreadBuilder, JoinHandle};
+use std::time::Duration;
+
+use crate::call::RpcStatus;
+use crate::error::{Error, Result};
+use crate::grpc_sys::{
+    self, grpc_status_code, grpc_tls_certificate_provider, grpc_tls_certificate_verifier,
+    grpc_tls_certificate_verifier_external, grpc_tls_credentials_options,
+    grpc_tls_custom_verification_check_request, grpc_tls_on_custom_verification_check_done_cb,
+};
+use crate::security::call_credentials::error_code;
+use crate::security::credentials::{
+    clear_key_securely, create_private_dir, private_key_to_cstring,
+};
+use crate::{CertificateRequestType, ChannelCredentials, ServerCredentials};
+
+/// Provides the root certificates and the identity certificates and keys used
+/// by [`TlsCredentialsBuilder`].
+pub struct CertificateProvider {
+    provider: *mut grpc_tls_certificate_provider,
+    has_root: bool,
+    has_identity: bool,
+}
+
+unsafe impl Send for CertificateProvider {}
+unsafe impl Sync for CertificateProvider {}
+
+impl CertificateProvider {
+    /// Creates a provider that always provides the same certificates.
+    ///
+    /// `root_cert` is the PEM encoded root certificates to verify the peer, and
+    /// `cert_key_pairs` are the PEM encoded certificate chains and private keys.
+    pub fn static_data(
+        root_cert: Option<Vec<u8>>,
+        cert_key_pairs: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> CertificateProvider {
+        let root = root_cert.map(|c| CString::new(c).unwrap());
+        let has_identity = !cert_key_pairs.is_empty();
+        unsafe {
+            grpc_sys::grpc_init();
+            let pairs = if has_identity {
+                let pairs = grpc_sys::grpc_tls_identity_pairs_create();
+                for (cert, key) in cert_key_pairs {
+                    let cert = CString::new(cert).unwrap();
+                    let key = private_key_to_cstring(key);
+                    // The pair is copied by gRPC.
+                    grpc_sys::grpc_tls_identity_pairs_add_pair(pairs, key.as_ptr(), cert.as_ptr());
+                    clear_key_securely(&mut key.into_bytes_with_nul());
+                }
+                pairs
+            } else {
+                ptr::null_mut()
+            };
+            let provider = grpc_sys::grpc_tls_certificate_provider_static_data_create(
+                root.as_ref().map_or_else(ptr::null, |r| r.as_ptr()),
+                pairs,
+            );
+            CertificateProvider {
+                provider,
+                has_root: root.is_some(),
+                has_identity,
+            }
+        }
+    }
+
+    /// Creates a provider that reloads PEM encoded certificates from files every
+    /// `refresh_interval`.
+    ///
+    /// `cert_key_files` are the paths of the certificate chain and the private
+    /// key, and `root_cert_file` is the path of the root certificates. Files are
+    /// read by gRPC, changes are picked up by new connections. The interval is
+    /// rounded down to seconds, and is at least one second.
+    pub fn file_watcher(
+        cert_key_files: Option<(&Path, &Path)>,
+        root_cert_file: Option<&Path>,
+        refresh_interval: Duration,
+    ) -> CertificateProvider {
+        let path = |p: &Path| CString::new(p.to_string_lossy().into_owned()).unwrap();
+        let identity = cert_key_files.map(|(cert, key)| (path(cert), path(key)));
+        let root = root_cert_file.map(path);
+        unsafe {
+            grpc_sys::grpc_init();
+            let (cert_ptr, key_ptr) = identity
+                .as_ref()
+                .map_or((ptr::null(), ptr::null()), |(c, k)| {
+                    (c.as_ptr(), k.as_ptr())
+                });
+            let provider = grpc_sys::grpc_tls_certificate_provider_file_watcher_create(
+                key_ptr,
+                cert_ptr,
+                root.as_ref().map_or_else(ptr::null, |r| r.as_ptr()),
+                refresh_interval.as_secs().clamp(1, u64::from(u32::MAX)) as _,
+            );
+            CertificateProvider {
+                provider,
+                has_root: root.is_some(),
+                has_identity: identity.is_some(),
+            }
+        }
+    }
+}
+
+impl Drop for CertificateProvider {
+    fn drop(&mut self) {
+        unsafe { grpc_sys::grpc_tls_certificate_provider_release(self.provider) }
+    }
+}
+
+/// Verifies the certificate of the peer after the TLS handshake.
+///
+/// On the client side, a custom verifier replaces the default check of the host
+/// name, and it's usually used to authorize servers by their identity.
+pub trait CertificateVerifier: Send + Sync {
+    /// Verifies the peer. Returning an error fails the handshake.
+    ///
+    /// The method is invoked in gRPC threads and should not block.
+    fn verify(&self, request: &VerificationRequest<'_>) -> std::result::Result<(), RpcStatus>;
+}
+
+/// The information of the peer to be verified by [`CertificateVerifier`].
+pub struct VerificationRequest<'a> {
+    req: &'a grpc_tls_custom_verification_check_request,
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> &'a str {
+    if s.is_null() {
+        return "";
+    }
+    CStr::from_ptr(s).to_str().unwrap_or("")
+}
+
+unsafe fn to_strs<'a>(names: *mut *mut c_char, len: usize) -> Vec<&'a str> {
+    (0..len).map(|i| to_str(*names.add(i))).collect()
+}
+
+impl VerificationRequest<'_> {
+    /// The name of the server that the client connects to. It's empty on the
+    /// server side.
+    pub fn target_name(&self) -> &str {
+        unsafe { to_str(self.req.target_name) }
+    }
+
+    /// The common name of the peer certificate.
+    pub fn common_name(&self) -> &str {
+        unsafe { to_str(self.req.peer_info.common_name) }
+    }
+
+    /// The URI names in the subject alternative names of the peer certificate,
+    /// like SPIFFE IDs.
+    pub fn uri_names(&self) -> Vec<&str> {
+        let san = &self.req.peer_info.san_names;
+        unsafe { to_strs(san.uri_names, san.uri_names_size) }
+    }
+
+    /// The DNS names in the subject alternative names of the peer certificate.
+    pub fn dns_names(&self) -> Vec<&str> {
+        let san = &self.req.peer_info.san_names;
+        unsafe { to_strs(san.dns_names, san.dns_names_size) }
+    }
+
+    /// The email addresses in the subject alternative names of the peer
+    /// certificate.
+    pub fn email_names(&self) -> Vec<&str> {
+        let san = &self.req.peer_info.san_names;
+        unsafe { to_strs(san.email_names, san.email_names_size) }
+    }
+
+    /// The IP addresses in the subject alternative names of the peer
+    /// certificate.
+    pub fn ip_names(&self) -> Vec<&str> {
+        let san = &self.req.peer_info.san_names;
+        unsafe { to_strs(san.ip_names, san.ip_names_size) }
+    }
+
+    /// The PEM encoded peer certificate.
+    pub fn peer_cert(&self) -> &str {
+        unsafe { to_str(self.req.peer_info.peer_cert) }
+    }
+
+    /// The PEM encoded certificate chain of the peer.
+    pub fn peer_cert_full_chain(&self) -> &str {
+        unsafe { to_str(self.req.peer_info.peer_cert_full_chain) }
+    }
+
+    /// The subject of the root certificate that verifies the peer certificate.
+    /// It's empty if the chain is not verified.
+    pub fn verified_root_cert_subject(&self) -> &str {
+        unsafe { to_str(self.req.peer_info.verified_root_cert_subject) }
+    }
+}
+
+// `base` must be the first field, so that the pointer passed to gRPC can be
+// freed as the whole struct.
+#[repr(C)]
+struct ExternalVerifier {
+    base: grpc_tls_certificate_verifier_external,
+    verifier: Box<dyn CertificateVerifier>,
+}
+
+unsafe fn create_verifier(
+    verifier: Box<dyn CertificateVerifier>,
+) -> *mut grpc_tls_certificate_verifier {
+    let external = Box::into_raw(Box::new(ExternalVerifier {
+        base: grpc_tls_certificate_verifier_external {
+            user_data: ptr::null_mut(),
+            verify: Some(verifier_verify),
+            cancel: Some(verifier_cancel),
+            destruct: Some(verifier_destruct),
+        },
+        verifier,
+    }));
+    (*external).base.user_data = external as _;
+    grpc_sys::grpc_tls_certificate_verifier_external_create(external as _)
+}
+
+unsafe extern "C" fn verifier_verify(
+    user_data: *mut c_void,
+    request: *mut grpc_tls_custom_verification_check_request,
+    _callback: grpc_tls_on_custom_verification_check_done_cb,
+    _callback_arg: *mut c_void,
+    sync_status: *mut grpc_status_code::Type,
+    sync_error_details: *mut *mut c_char,
+) -> c_int {
+    let external = &*(user_data as *const ExternalVerifier);
+    let req = VerificationRequest { req: &*request };
+    match external.verifier.verify(&req) {
+        Ok(()) => *sync_status = grpc_status_code::GRPC_STATUS_OK,
+        Err(s) => {
+            let msg = CString::new(s.message()).unwrap_or_default();
+            *sync_status = error_code(&s);
+            *sync_error_details = grpc_sys::gpr_strdup(msg.as_ptr());
+        }
+    }
+    // Always verified synchronously.
+    1
+}
+
+unsafe extern "C" fn verifier_cancel(
+    _user_data: *mut c_void,
+    _request: *mut grpc_tls_custom_verification_check_request,
+) {
+}
+
+unsafe extern "C" fn verifier_destruct(user_data: *mut c_void) {
+    drop(Box::from_raw(user_data as *mut ExternalVerifier));
+}
+
+/// Where TLS session keys are logged.
+#[derive(Clone)]
+pub(crate) enum KeyLog {
+    File(PathBuf),
+    Callback(Arc<dyn Fn(&str) + Send + Sync>),
+}
+
+const KEY_LOG_FILE: &str = "keys.log";
+const KEY_LOG_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Passes the TLS session keys logged by gRPC to a callback.
+///
+/// gRPC C core can only log keys to files, so keys are logged to a file in a
+/// private directory, which is tailed by a background thread.
+pub(crate) struct KeyLogForwarder {
+    dir: PathBuf,
+    stop: Option<(mpsc::Sender<()>, JoinHandle<()>)>,
+}
+
+impl KeyLogForwarder {
+    fn start(callback: Arc<dyn Fn(&str) + Send + Sync>) -> io::Result<KeyLogForwarder> {
+        let mut forwarder = KeyLogForwarder {
+            dir: create_private_dir("keylog")?,
+            stop: None,
+        };
+        let path = forwarder.path();
+        let (tx, rx) = mpsc::channel::<()>();
+        let handle = ThreadBuilder::new()
+            .name("grpc-key-logger".to_owned())
+            .spawn(move || {
+                // The file is created by gRPC on the first handshake.
+                let mut reader = None;
+                let mut line = String::new();
+                loop {
+                    let stopped = !matches!(
+                        rx.recv_timeout(KEY_LOG_POLL_INTERVAL),
+                        Err(RecvTimeoutError::Timeout)
+                    );
+                    if reader.is_none() {
+                        reader = File::open(&path).ok().map(BufReader::new);
+                    }
+                    if let Some(r) = &mut reader {
+                        // A partial line is kept until the rest is written.
+                        while let Ok(n) = r.read_line(&mut line) {
+                            if n == 0 || !line.ends_with('\n') {
+                                break;
+                            }
+                            callback(line.trim_end());
+                            line.clear();
+                        }
+                    }
+                    if stopped {
+                        return;
+                    }
+                }
+            })?;
+        forwarder.stop = Some((tx, handle));
+        Ok(forwarder)
+    }
+
+    fn path(&self) -> PathBuf {
+        self.dir.join(KEY_LOG_FILE)
+    }
+}
+
+impl Drop for KeyLogForwarder {
+    fn drop(&mut self) {
+        if let Some((tx, handle)) = self.stop.take() {
+            drop(tx);
+            let _ = handle.join();
+        }
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// [`ChannelCredentials`] and [`ServerCredentials`] factory based on the TLS
+/// options of gRPC, it supports more options than [`ChannelCredentialsBuilder`]
+/// and [`ServerCredentialsBuilder`].
+///
+/// The TLS version can't be pinned. gRPC always negotiates TLS 1.2 or TLS 1.3,
+/// and its public API has no option to narrow the range, so TLS 1.3-only
+/// connections are not supported.
+///
+/// [`ChannelCredentialsBuilder`]: crate::ChannelCredentialsBuilder
+/// [`ServerCredentialsBuilder`]: crate::ServerCredentialsBuilder
+pub struct TlsCredentialsBuilder {
+    provider: Option<CertificateProvider>,
+    crl_directory: Option<CString>,
+    cert_request_type: CertificateRequestType,
+    verify_server_cert: bool,
+    check_call_host: bool,
+    verifier: Option<Box<dyn CertificateVerifier>>,
+    key_log: Option<KeyLog>,
+}
+
+impl TlsCredentialsBuilder {
+    /// Initialize a new [`TlsCredentialsBuilder`].
+    pub fn new() -> TlsCredentialsBuilder {
+        TlsCredentialsBuilder {
+            provider: None,
+            crl_directory: None,
+            cert_request_type: CertificateRequestType::DontRequestClientCertificate,
+            verify_server_cert: true,
+            check_call_host: true,
+            verifier: None,
+            key_log: None,
+        }
+    }
+
+    /// Set the provider of certificates and keys.
+    ///
+    /// Clients without root certificates verify servers by the root certificates
+    /// of the system. Servers must be provided with identity certificates.
+    pub fn certificate_provider(mut self, provider: CertificateProvider) -> TlsCredentialsBuilder {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Reject peers whose certificates are revoked by the hashed X.509 CRL files
+    /// in `dir`.
+    pub fn crl_directory<P: AsRef<Path>>(mut self, dir: P) -> TlsCredentialsBuilder {
+        let dir = dir.as_ref().to_string_lossy().into_owned();
+        self.crl_directory = Some(CString::new(dir).unwrap());
+        self
+    }
+
+    /// Set whether to request and verify client certificates. It only applies
+    /// to servers.
+    pub fn cert_request_type(
+        mut self,
+        request_type: CertificateRequestType,
+    ) -> TlsCredentialsBuilder {
+        self.cert_request_type = request_type;
+        self
+    }
+
+    /// Set whether to verify the certificate chain of servers, it's enabled by
+    /// default. It only applies to clients.
+    ///
+    /// Disabling it without a custom verifier makes connections vulnerable to
+    /// man-in-the-middle attacks.
+    pub fn verify_server_cert(mut self, verify: bool) -> TlsCredentialsBuilder {
+        self.verify_server_cert = verify;
+        self
+    }
+
+    /// Set whether to check that the host of every call is covered by the
+    /// server certificate, it's enabled by default. It only applies to clients.
+    pub fn check_call_host(mut self, check: bool) -> TlsCredentialsBuilder {
+        self.check_call_host = check;
+        self
+    }
+
+    /// Verify peers with `verifier` after the certificate chain is verified.
+    pub fn certificate_verifier<V: CertificateVerifier + 'static>(
+        mut self,
+        verifier: V,
+    ) -> TlsCredentialsBuilder {
+        self.verifier = Some(Box::new(verifier));
+        self
+    }
+
+    /// Log TLS session keys to the file at `path` in the NSS key log format, so
+    /// that captured traffic can be decrypted by tools like Wireshark.
+    ///
+    /// It's for debugging only, anyone who can read the keys can decrypt the
+    /// traffic.
+    pub fn tls_session_key_log_file<P: AsRef<Path>>(mut self, path: P) -> TlsCredentialsBuilder {
+        self.key_log = Some(KeyLog::File(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Pass TLS session keys to `callback` line by line in the NSS key log
+    /// format.
+    ///
+    /// Keys are passed from a background thread shortly after handshakes. It's
+    /// for debugging only, anyone who can read the keys can decrypt the traffic.
+    pub fn tls_session_key_log_callback<F>(mut self, callback: F) -> TlsCredentialsBuilder
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.key_log = Some(KeyLog::Callback(Arc::new(callback)));
+        self
+    }
+
+    pub(crate) fn key_log(mut self, key_log: Option<KeyLog>) -> TlsCredentialsBuilder {
+        self.key_log = key_log;
+        self
+    }
+
+    /// Creates the options that are shared by both sides. The options are owned
+    /// by the returned credentials.
+    unsafe fn build_options(self) -> (*mut grpc_tls_credentials_options, Option<KeyLogForwarder>) {
+        grpc_sys::grpc_init();
+        let opts = grpc_sys::grpc_tls_credentials_options_create();
+        if let Some(provider) = &self.provider {
+            grpc_sys::grpc_tls_credentials_options_set_certificate_provider(
+                opts,
+                provider.provider,
+            );
+            if provider.has_root {
+                grpc_sys::grpc_tls_credentials_options_watch_root_certs(opts);
+            }
+            if provider.has_identity {
+                grpc_sys::grpc_tls_credentials_options_watch_identity_key_cert_pairs(opts);
+            }
+        }
+        if let Some(dir) = &self.crl_directory {
+            grpc_sys::grpc_tls_credentials_options_set_crl_directory(opts, dir.as_ptr());
+        }
+        if let Some(verifier) = self.verifier {
+            let verifier = create_verifier(verifier);
+            grpc_sys::grpc_tls_credentials_options_set_certificate_verifier(opts, verifier);
+            grpc_sys::grpc_tls_certificate_verifier_release(verifier);
+        }
+        let mut forwarder = None;
+        let key_log_path = match self.key_log {
+            Some(KeyLog::File(path)) => Some(path),
+            Some(KeyLog::Callback(callback)) => match KeyLogForwarder::start(callback) {
+                Ok(f) => {
+                    let path = f.path();
+                    forwarder = Some(f);
+                    Some(path)
+                }
+                // Key logging is only for debugging, so don't fail the credentials.
+                Err(e) => {
+                    warn!("failed to start key log forwarder: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+        if let Some(path) = key_log_path {
+            let path = CString::new(path.to_string_lossy().into_owned()).unwrap();
+            grpc_sys::grpc_tls_credentials_options_set_tls_session_key_log_file_path(
+                opts,
+                path.as_ptr(),
+            );
+        }
+        (opts, forwarder)
+    }
+
+    /// Finalize the [`TlsCredentialsBuilder`] and build the [`ChannelCredentials`].
+    pub fn build_channel_credentials(self) -> Result<ChannelCredentials> {
+        let verify_server_cert = self.verify_server_cert;
+        let check_call_host = self.check_call_host;
+        unsafe {
+            let (opts, key_log) = self.build_options();
+            grpc_sys::grpc_tls_credentials_options_set_verify_server_cert(
+                opts,
+                verify_server_cert as c_int,
+            );
+            grpc_sys::grpc_tls_credentials_options_set_check_call_host(
+                opts,
+                check_call_host as c_int,
+            );
+            // Options can only be released by the credentials that own them.
+            let mut creds =
+                ChannelCredentials::from_raw(grpc_sys::grpc_tls_credentials_create(opts));
+            creds.key_log = key_log;
+            Ok(creds)
+        }
+    }
+
+    /// Finalize the [`TlsCredentialsBuilder`] and build the [`ServerCredentials`].
+    pub fn build_server_credentials(self) -> Result<ServerCredentials> {
+        if !self.provider.as_ref().is_some_and(|p| p.has_identity) {
+            return Err(Error::InvalidTlsOptions(
+                "servers must be provided with identity certificates".to_owned(),
+            ));
+        }
+        self.build_server()
+    }
+
+    pub(crate) fn build_server(self) -> Result<ServerCredentials> {
+        let cert_request_type = self.cert_request_type;
+        unsafe {
+            let (opts, key_log) = self.build_options();
+            grpc_sys::grpc_tls_credentials_options_set_cert_request_type(
+                opts,
+                cert_request_type.to_native(),
+            );
+            let mut creds =
+                ServerCredentials::from_raw(grpc_sys::grpc_tls_server_credentials_create(opts));
+            creds._key_log = key_log;
+            Ok(creds)
+        }
+    }
+}
@@ -6,21 +6,28 @@ use std::ffi::CString;
 use std::fmt::{self, Debug, Formatter};
 use std::future::Future;
 use std::pin::Pin;
-use std::ptr;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use std::task::{Context, Poll};
+use std::thread::{Builder as ThreadBuilder, JoinHandle};
+use std::time::Duration;
+use std::{mem, ptr};
 
 use crate::grpc_sys::{self, grpc_call_error, grpc_server};
+use futures_util::future::BoxFuture;
 use futures_util::ready;
 
 use crate::call::server::*;
 use crate::call::{MessageReader, Method, MethodType};
-use crate::channel::ChannelArgs;
+use crate::channel::{Channel, ChannelArgs};
 use crate::cq::CompletionQueue;
 use crate::env::Environment;
 use crate::error::{Error, Result};
-use crate::task::{CallTag, CqFuture};
+use crate::interceptor::ServerInterceptor;
+use crate::limit::{ConcurrencyLimit, Limiter, Permit};
+use crate::metrics::ServerMetrics;
+use crate::task::{CallTag, CqFuture, Runtime};
+use crate::trace::TracePropagator;
 use crate::RpcStatus;
 use crate::{RpcContext, ServerCredentials};
 
@@ -189,6 +196,29 @@ impl Clone for Box<dyn ServerChecker> {
     }
 }
 
+/// A checker whose result is resolved asynchronously.
+///
+/// It's useful when the check needs to consult a token cache, a database or
+/// a remote policy engine, which should not block the poll thread. The returned
+/// future is spawned to the completion queue of the call, and the handler is only
+/// dispatched after it resolves to `Continue`.
+///
+/// The future is polled by the gRPC poll thread, so it should not block either.
+/// Heavy work should be done in other threads and awaited by the future.
+pub trait AsyncServerChecker: Send {
+    /// Start checking the call.
+    ///
+    /// Anything needed from `ctx` should be copied out before returning.
+    fn check(&mut self, ctx: &RpcContext) -> BoxFuture<'static, CheckResult>;
+    fn box_clone(&self) -> Box<dyn AsyncServerChecker>;
+}
+
+impl Clone for Box<dyn AsyncServerChecker> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
 /// A gRPC service.
 ///
 /// Use [`ServiceBuilder`] to build a [`Service`].
@@ -203,6 +233,13 @@ pub struct ServerBuilder {
     slots_per_cq: usize,
     handlers: HashMap<&'static [u8], BoxHandler>,
     checkers: Vec<Box<dyn ServerChecker>>,
+    async_checkers: Vec<Box<dyn AsyncServerChecker>>,
+    interceptors: Vec<Box<dyn ServerInterceptor>>,
+    runtime: Runtime,
+    trace_propagator: Option<Arc<dyn TracePropagator>>,
+    before_shutdown: Vec<Box<dyn FnOnce() + Send>>,
+    concurrency_limit: Option<ConcurrencyLimit>,
+    method_concurrency_limits: HashMap<Vec<u8>, ConcurrencyLimit>,
 }
 
 impl ServerBuilder {
@@ -214,6 +251,13 @@ impl ServerBuilder {
             slots_per_cq: DEFAULT_REQUEST_SLOTS_PER_CQ,
             handlers: HashMap::new(),
             checkers: Vec::new(),
+            async_checkers: Vec::new(),
+            interceptors: Vec::new(),
+            runtime: Runtime::default(),
+            trace_propagator: None,
+            before_shutdown: Vec::new(),
+            concurrency_limit: None,
+            method_concurrency_limits: HashMap::new(),
         }
     }
 
@@ -239,12 +283,115 @@ impl ServerBuilder {
     /// This allows users to operate grpc call based on the context. Users can add
     /// multiple checkers and they will be executed in the order added.
     ///
-    /// TODO: Extend this interface to intercepte each payload like grpc-c++.
+    /// Use [`add_interceptor`](ServerBuilder::add_interceptor) to intercept each payload.
     pub fn add_checker<C: ServerChecker + 'static>(mut self, checker: C) -> ServerBuilder {
         self.checkers.push(Box::new(checker));
         self
     }
 
+    /// Add a custom checker that resolves its result asynchronously.
+    ///
+    /// Async checkers are executed in the order added after all checkers added by
+    /// [`add_checker`](ServerBuilder::add_checker) pass. `check` is invoked on all
+    /// of them before any returned future is polled. If one of the futures resolves
+    /// to `Abort`, the remaining futures are dropped without being polled.
+    pub fn add_async_checker<C: AsyncServerChecker + 'static>(
+        mut self,
+        checker: C,
+    ) -> ServerBuilder {
+        self.async_checkers.push(Box::new(checker));
+        self
+    }
+
+    /// Add a custom interceptor to inspect every message and the final status of
+    /// each call. Interceptors are started after all checkers pass. Users can add
+    /// multiple interceptors, see [`ServerInterceptor`] for the order they are invoked.
+    pub fn add_interceptor<I: ServerInterceptor + 'static>(
+        mut self,
+        interceptor: I,
+    ) -> ServerBuilder {
+        self.interceptors.push(Box::new(interceptor));
+        self
+    }
+
+    /// Record the metrics of every call into `metrics`.
+    ///
+    /// The metrics are recorded by an interceptor that always runs before all
+    /// the interceptors added by [`ServerBuilder::add_interceptor`], so the sizes
+    /// and status it sees are the same as on the wire. Calls rejected by checkers
+    /// are not recorded.
+    pub fn metrics(mut self, metrics: ServerMetrics) -> ServerBuilder {
+        self.interceptors.insert(0, Box::new(metrics.interceptor()));
+        self
+    }
+
+    /// Extract the trace context of every call from request headers by `propagator`.
+    ///
+    /// The context is available to handlers by [`RpcContext::trace_context`], and is
+    /// set as [`TraceContext::current`] when handlers and the futures spawned by
+    /// [`RpcContext::spawn`] run, so that [`TracePropagation`] can pass it to the
+    /// calls made by handlers. A new trace is started if the request doesn't carry
+    /// one. If the `tracing` feature is enabled, a span is created for every call too.
+    ///
+    /// [`TraceContext::current`]: crate::TraceContext::current
+    /// [`TracePropagation`]: crate::TracePropagation
+    pub fn trace_propagator<P: TracePropagator + 'static>(
+        mut self,
+        propagator: P,
+    ) -> ServerBuilder {
+        self.trace_propagator = Some(Arc::new(propagator));
+        self
+    }
+
+    /// Spawn the futures passed to [`RpcContext::spawn`](crate::RpcContext::spawn)
+    /// on Tokio instead of the gRPC poll threads.
+    ///
+    /// Only the spawned futures are moved: handlers themselves are still invoked
+    /// in the poll threads, so they should return quickly and leave the work to
+    /// the futures. The futures can use Tokio timers and call
+    /// `tokio::task::spawn_blocking` to offload heavy work. Sinks and request
+    /// streams are woken by the poll threads directly when events arrive.
+    #[cfg(feature = "tokio")]
+    pub fn spawn_on_tokio(mut self, handle: tokio::runtime::Handle) -> ServerBuilder {
+        self.runtime = Runtime::tokio(handle);
+        self
+    }
+
+    /// Execute function `f` once the server starts to shut down, before it stops
+    /// accepting new calls.
+    ///
+    /// It's the place to mark the server unhealthy, for example by calling
+    /// `HealthService::shutdown` of grpcio-health, so that clients can move away
+    /// while in-flight calls are drained by [`Server::graceful_shutdown`].
+    pub fn before_shutdown<F: FnOnce() + Send + 'static>(mut self, f: F) -> ServerBuilder {
+        self.before_shutdown.push(Box::new(f));
+        self
+    }
+
+    /// Limit the number of calls that are handled concurrently by the server.
+    ///
+    /// Calls exceeding the limit are queued or rejected with `RESOURCE_EXHAUSTED`
+    /// before any checker runs. See [`ConcurrencyLimit`] for details.
+    pub fn concurrency_limit(mut self, limit: ConcurrencyLimit) -> ServerBuilder {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
+    /// Limit the number of calls of `method` that are handled concurrently.
+    ///
+    /// `method` is the full method name, like `/helloworld.Greeter/SayHello`. A
+    /// call has to satisfy both the method limit and the limit set by
+    /// [`ServerBuilder::concurrency_limit`].
+    pub fn method_concurrency_limit(
+        mut self,
+        method: &str,
+        limit: ConcurrencyLimit,
+    ) -> ServerBuilder {
+        self.method_concurrency_limits
+            .insert(method.as_bytes().to_vec(), limit);
+        self
+    }
+
     /// Finalize the [`ServerBuilder`] and build the [`Server`].
     pub fn build(self) -> Result<Server> {
         let args = self
@@ -268,10 +415,22 @@ impl ServerBuilder {
                     server,
                     creds: Mutex::new(Vec::new()),
                     shutdown: AtomicBool::new(false),
+                    calls: Mutex::new(CallStats::default()),
                     slots_per_cq: self.slots_per_cq,
+                    runtime: self.runtime,
+                    trace_propagator: self.trace_propagator,
+                    limiter: self.concurrency_limit.map(|l| Arc::new(Limiter::new(l))),
+                    method_limiters: self
+                        .method_concurrency_limits
+                        .into_iter()
+                        .map(|(m, l)| (m, Arc::new(Limiter::new(l))))
+                        .collect(),
                 }),
                 handlers: self.handlers,
                 checkers: self.checkers,
+                async_checkers: self.async_checkers,
+                interceptors: self.interceptors,
+                before_shutdown: self.before_shutdown,
             })
         }
     }
@@ -282,6 +441,11 @@ struct ServerCore {
     creds: Mutex<Vec<ServerCredentials>>,
     slots_per_cq: usize,
     shutdown: AtomicBool,
+    calls: Mutex<CallStats>,
+    runtime: Runtime,
+    trace_propagator: Option<Arc<dyn TracePropagator>>,
+    limiter: Option<Arc<Limiter>>,
+    method_limiters: HashMap<Vec<u8>, Arc<Limiter>>,
 }
 
 impl Drop for ServerCore {
@@ -293,6 +457,39 @@ impl Drop for ServerCore {
 unsafe impl Send for ServerCore {}
 unsafe impl Sync for ServerCore {}
 
+/// Counts of calls handled by a server, used to report the result of
+/// [`Server::graceful_shutdown`].
+///
+/// They are updated under the same lock, so every call that is in flight when
+/// draining starts is counted as either drained or cancelled exactly once.
+#[derive(Default)]
+struct CallStats {
+    in_flight: usize,
+    /// Whether graceful shutdown has started.
+    draining: bool,
+    /// Whether the drain timeout has expired.
+    timed_out: bool,
+    drained: usize,
+    cancelled: usize,
+}
+
+/// Counts a call as in flight until it's dropped.
+pub(crate) struct CallGuard {
+    server: Arc<ServerCore>,
+    /// Permits of concurrency limits, released together with the call.
+    pub permits: Vec<Permit>,
+}
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        let mut calls = self.server.calls.lock().unwrap();
+        calls.in_flight -= 1;
+        if calls.draining && !calls.timed_out {
+            calls.drained += 1;
+        }
+    }
+}
+
 pub type BoxHandler = Box<dyn CloneableHandler>;
 
 #[derive(Clone)]
@@ -300,6 +497,8 @@ pub struct RequestCallContext {
     server: Arc<ServerCore>,
     registry: Arc<UnsafeCell<HashMap<&'static [u8], BoxHandler>>>,
     checkers: Vec<Box<dyn ServerChecker>>,
+    async_checkers: Vec<Box<dyn AsyncServerChecker>>,
+    interceptors: Vec<Box<dyn ServerInterceptor>>,
 }
 
 impl RequestCallContext {
@@ -311,9 +510,46 @@ impl RequestCallContext {
         registry.get_mut(path)
     }
 
+    pub(crate) fn runtime(&self) -> &Runtime {
+        &self.server.runtime
+    }
+
+    /// Start tracking a call, it's in flight until the guard is dropped.
+    pub(crate) fn track_call(&self) -> CallGuard {
+        self.server.calls.lock().unwrap().in_flight += 1;
+        CallGuard {
+            server: self.server.clone(),
+            permits: Vec::new(),
+        }
+    }
+
+    /// Concurrency limiters that apply to `method`, the method one goes first.
+    pub(crate) fn limiters(&self, method: &[u8]) -> Vec<Arc<Limiter>> {
+        let server = &self.server;
+        server
+            .method_limiters
+            .get(method)
+            .into_iter()
+            .chain(server.limiter.as_ref())
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn trace_propagator(&self) -> Option<&dyn TracePropagator> {
+        self.server.trace_propagator.as_deref()
+    }
+
     pub(crate) fn get_checker(&self) -> Vec<Box<dyn ServerChecker>> {
         self.checkers.clone()
     }
+
+    pub(crate) fn get_async_checkers(&self) -> Vec<Box<dyn AsyncServerChecker>> {
+        self.async_checkers.clone()
+    }
+
+    pub(crate) fn get_interceptors(&self) -> Vec<Box<dyn ServerInterceptor>> {
+        self.interceptors.clone()
+    }
 }
 
 // Apparently, its life time is guaranteed by the ref count, hence is safe to be sent
@@ -368,6 +604,41 @@ impl Future for ShutdownFuture {
     }
 }
 
+/// The result of [`Server::graceful_shutdown`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShutdownStats {
+    /// The number of in-flight calls that finished before the timeout.
+    pub drained: usize,
+    /// The number of calls that were canceled when the timeout expired.
+    pub cancelled: usize,
+}
+
+/// A `Future` that will resolve when a graceful shutdown completes.
+pub struct GracefulShutdownFuture {
+    shutdown: ShutdownFuture,
+    core: Arc<ServerCore>,
+    // Dropping the sender stops the timer.
+    timer: Option<(mpsc::Sender<()>, JoinHandle<()>)>,
+}
+
+impl Future for GracefulShutdownFuture {
+    type Output = Result<ShutdownStats>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let res = ready!(Pin::new(&mut self.shutdown).poll(cx));
+        if let Some((tx, handle)) = self.timer.take() {
+            drop(tx);
+            // The timer exits as soon as the sender is dropped.
+            let _ = handle.join();
+        }
+        let calls = self.core.calls.lock().unwrap();
+        Poll::Ready(res.map(|()| ShutdownStats {
+            drained: calls.drained,
+            cancelled: calls.cancelled,
+        }))
+    }
+}
+
 /// A gRPC server.
 ///
 /// A single server can serve arbitrary number of services and can listen on more than one port.
@@ -378,11 +649,20 @@ pub struct Server {
     core: Arc<ServerCore>,
     handlers: HashMap<&'static [u8], BoxHandler>,
     checkers: Vec<Box<dyn ServerChecker>>,
+    async_checkers: Vec<Box<dyn AsyncServerChecker>>,
+    interceptors: Vec<Box<dyn ServerInterceptor>>,
+    before_shutdown: Vec<Box<dyn FnOnce() + Send>>,
 }
 
 impl Server {
     /// Shutdown the server asynchronously.
+    ///
+    /// New calls are rejected, while in-flight calls are allowed to finish. The
+    /// future resolves after all the calls are finished.
     pub fn shutdown(&mut self) -> ShutdownFuture {
+        for f in mem::take(&mut self.before_shutdown) {
+            f();
+        }
         let (cq_f, prom) = CallTag::action_pair();
         let prom_box = Box::new(prom);
         let tag = Box::into_raw(prom_box);
@@ -399,6 +679,38 @@ impl Server {
         ShutdownFuture { cq_f }
     }
 
+    /// Shutdown the server gracefully.
+    ///
+    /// Functions registered by [`ServerBuilder::before_shutdown`] are executed
+    /// first, then the server stops accepting new calls and waits for in-flight
+    /// calls to finish. Calls that are still running when `timeout` expires are
+    /// canceled. The future resolves to the numbers of drained and canceled calls
+    /// after all the calls are finished.
+    pub fn graceful_shutdown(&mut self, timeout: Duration) -> GracefulShutdownFuture {
+        self.core.calls.lock().unwrap().draining = true;
+        let shutdown = self.shutdown();
+        let (tx, rx) = mpsc::channel();
+        let core = self.core.clone();
+        let timer = ThreadBuilder::new()
+            .name("grpc-shutdown".to_owned())
+            .spawn(move || {
+                if let Err(mpsc::RecvTimeoutError::Timeout) = rx.recv_timeout(timeout) {
+                    {
+                        let mut calls = core.calls.lock().unwrap();
+                        calls.timed_out = true;
+                        calls.cancelled = calls.in_flight;
+                    }
+                    unsafe { grpc_sys::grpc_server_cancel_all_calls(core.server) }
+                }
+            })
+            .unwrap();
+        GracefulShutdownFuture {
+            shutdown,
+            core: self.core.clone(),
+            timer: Some((tx, timer)),
+        }
+    }
+
     /// Cancel all in-progress calls.
     ///
     /// Only usable after shutdown.
@@ -423,6 +735,8 @@ impl Server {
                     server: self.core.clone(),
                     registry: Arc::new(UnsafeCell::new(registry)),
                     checkers: self.checkers.clone(),
+                    async_checkers: self.async_checkers.clone(),
+                    interceptors: self.interceptors.clone(),
                 };
                 for _ in 0..self.core.slots_per_cq {
                     request_call(rc.clone(), cq);
@@ -461,6 +775,30 @@ impl Server {
         }
     }
 
+    /// Create a [`Channel`] that calls the server in the same process without
+    /// going through sockets.
+    ///
+    /// The server should be started first. Calls on the channel fail after the
+    /// server is shutdown. Credentials and interceptors of `ChannelBuilder`
+    /// don't apply, so only use `ChannelBuilder::build_args` to build `args`.
+    ///
+    /// # Panics
+    ///
+    /// The in-process transport is not part of the public API of gRPC and is
+    /// not exported by its shared libraries, so it's only available when gRPC
+    /// is built from source. Panics if gRPC is linked by pkg-config
+    /// (`GRPCIO_SYS_USE_PKG_CONFIG=1`).
+    pub fn in_process_channel(&self, args: ChannelArgs) -> Channel {
+        unsafe {
+            let channel = grpc_sys::grpcwrap_inproc_channel_create(self.core.server, args.as_ptr());
+            assert!(
+                !channel.is_null(),
+                "in-process transport requires gRPC built from source"
+            );
+            Channel::new(self.env.pick_cq(), self.env.clone(), channel)
+        }
+    }
+
     /// Add an rpc channel for an established connection represented as a file
     /// descriptor. Takes ownership of the file descriptor, closing it when
     /// channel is closed.
@@ -488,6 +826,47 @@ impl Server {
         // won't be accessed by other code.
         unsafe { self.add_channel_from_fd(fd.into_raw_fd()) }
     }
+
+    /// Create a connected socket pair, serve RPCs on one end of it and return
+    /// the other end.
+    ///
+    /// The returned end can be passed to `ChannelBuilder::connect_from_ownedfd`,
+    /// for example by a child process that inherits it, so no port needs to be
+    /// bound. gRPC only supports insecure transport on file descriptors, so
+    /// connections are not encrypted, but the server can still listen on ports
+    /// with any credentials at the same time.
+    #[cfg(unix)]
+    pub fn add_socketpair(&mut self) -> ::std::io::Result<::std::os::fd::OwnedFd> {
+        use std::os::unix::net::UnixStream;
+
+        let (server_end, client_end) = UnixStream::pair()?;
+        self.add_channel_from_ownedfd(server_end.into());
+        Ok(client_end.into())
+    }
+
+    /// Create a [`Channel`] that calls the server over a connected socket pair.
+    ///
+    /// Options, interceptors and runtime of `builder` apply to the channel.
+    /// As only insecure transport is supported on file descriptors, an error
+    /// of kind `InvalidInput` is returned if credentials other than
+    /// [`ChannelCredentials::insecure`] are set on `builder`. See
+    /// [`Server::add_socketpair`] for more details.
+    ///
+    /// [`ChannelCredentials::insecure`]: crate::ChannelCredentials::insecure
+    #[cfg(unix)]
+    pub fn socketpair_channel(
+        &mut self,
+        builder: crate::ChannelBuilder,
+    ) -> ::std::io::Result<Channel> {
+        if builder.has_secure_credentials() {
+            return Err(::std::io::Error::new(
+                ::std::io::ErrorKind::InvalidInput,
+                "only insecure credentials are supported on socket pairs",
+            ));
+        }
+        let fd = self.add_socketpair()?;
+        Ok(builder.connect_from_ownedfd("socketpair", fd))
+    }
 }
 
 impl Drop for Server {
@@ -508,9 +887,11 @@ impl Debug for Server {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Server {{ handlers: {}, checkers: {} }}",
+            "Server {{ handlers: {}, checkers: {}, async_checkers: {}, interceptors: {} }}",
             self.handlers.len(),
-            self.checkers.len()
+            self.checkers.len(),
+            self.async_checkers.len(),
+            self.interceptors.len()
         )
     }
 }
@@ -0,0 +1,516 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Typed builders for the [service config].
+//!
+//! [service config]: https://github.com/grpc/grpc/blob/master/doc/service_config.md
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use crate::channel::LbPolicy;
+use crate::error::{Error, Result};
+use crate::RpcStatusCode;
+
+fn invalid(msg: String) -> Error {
+    Error::InvalidServiceConfig(msg)
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c < ' ' => write!(res, "\\u{:04x}", c as u32).unwrap(),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+fn json_object(fields: Vec<(&str, String)>) -> String {
+    let fields: Vec<_> = fields
+        .into_iter()
+        .map(|(k, v)| format!("{}:{}", json_string(k), v))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn json_array(items: Vec<String>) -> String {
+    format!("[{}]", items.join(","))
+}
+
+/// Durations are encoded as strings like `1.5s`, following the proto3 JSON mapping.
+fn json_duration(d: Duration) -> String {
+    if d.subsec_nanos() == 0 {
+        format!("\"{}s\"", d.as_secs())
+    } else {
+        let s = format!("{}.{:09}", d.as_secs(), d.subsec_nanos());
+        format!("\"{}s\"", s.trim_end_matches('0'))
+    }
+}
+
+fn json_codes(field: &str, codes: &[RpcStatusCode]) -> Result<String> {
+    let mut res = Vec::with_capacity(codes.len());
+    for code in codes {
+        match code.name() {
+            Some(name) if *code != RpcStatusCode::OK => res.push(json_string(name)),
+            _ => return Err(invalid(format!("{field} contains invalid code {code}"))),
+        }
+    }
+    Ok(json_array(res))
+}
+
+/// Policy for retrying failed calls, see [gRFC A6] for details.
+///
+/// [gRFC A6]: https://github.com/grpc/proposal/blob/master/A6-client-retries.md
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the original one. Must be
+    /// greater than 1, values greater than 5 are treated as 5 by gRPC.
+    pub max_attempts: u32,
+    /// The backoff before the first retry, must be greater than 0.
+    pub initial_backoff: Duration,
+    /// The upper bound of backoff, must be greater than 0.
+    pub max_backoff: Duration,
+    /// Backoff is multiplied by this value after each retry, must be greater than 0.
+    pub backoff_multiplier: f64,
+    /// Calls failed with these codes will be retried, must not be empty.
+    pub retryable_status_codes: Vec<RpcStatusCode>,
+}
+
+impl RetryPolicy {
+    fn to_json(&self) -> Result<String> {
+        if self.max_attempts < 2 {
+            return Err(invalid(format!(
+                "retryPolicy.maxAttempts must be greater than 1, got {}",
+                self.max_attempts
+            )));
+        }
+        if self.initial_backoff.is_zero() || self.max_backoff.is_zero() {
+            return Err(invalid(
+                "retryPolicy.initialBackoff and retryPolicy.maxBackoff must be greater than 0"
+                    .to_owned(),
+            ));
+        }
+        if !(self.backoff_multiplier > 0.0 && self.backoff_multiplier.is_finite()) {
+            return Err(invalid(format!(
+                "retryPolicy.backoffMultiplier must be greater than 0, got {}",
+                self.backoff_multiplier
+            )));
+        }
+        if self.retryable_status_codes.is_empty() {
+            return Err(invalid(
+                "retryPolicy.retryableStatusCodes must not be empty".to_owned(),
+            ));
+        }
+        let codes = json_codes(
+            "retryPolicy.retryableStatusCodes",
+            &self.retryable_status_codes,
+        )?;
+        Ok(json_object(vec![
+            ("maxAttempts", self.max_attempts.to_string()),
+            ("initialBackoff", json_duration(self.initial_backoff)),
+            ("maxBackoff", json_duration(self.max_backoff)),
+            ("backoffMultiplier", self.backoff_multiplier.to_string()),
+            ("retryableStatusCodes", codes),
+        ]))
+    }
+}
+
+/// Policy for sending hedged requests, see [gRFC A6] for details.
+///
+/// NOTE: Hedging is not implemented by gRPC C core yet, the policy is only
+/// carried by the service config.
+///
+/// [gRFC A6]: https://github.com/grpc/proposal/blob/master/A6-client-retries.md
+#[derive(Clone, Debug)]
+pub struct HedgingPolicy {
+    /// The maximum number of requests to send, including the original one.
+    /// Must be greater than 1.
+    pub max_attempts: u32,
+    /// The delay between sending hedged requests.
+    pub hedging_delay: Duration,
+    /// Hedged requests keep going if a request fails with these codes.
+    pub non_fatal_status_codes: Vec<RpcStatusCode>,
+}
+
+impl HedgingPolicy {
+    fn to_json(&self) -> Result<String> {
+        if self.max_attempts < 2 {
+            return Err(invalid(format!(
+                "hedgingPolicy.maxAttempts must be greater than 1, got {}",
+                self.max_attempts
+            )));
+        }
+        let mut fields = vec![
+            ("maxAttempts", self.max_attempts.to_string()),
+            ("hedgingDelay", json_duration(self.hedging_delay)),
+        ];
+        if !self.non_fatal_status_codes.is_empty() {
+            let codes = json_codes(
+                "hedgingPolicy.nonFatalStatusCodes",
+                &self.non_fatal_status_codes,
+            )?;
+            fields.push(("nonFatalStatusCodes", codes));
+        }
+        Ok(json_object(fields))
+    }
+}
+
+/// Configuration for a set of methods.
+#[derive(Clone, Debug, Default)]
+pub struct MethodConfig {
+    names: Vec<(String, String)>,
+    wait_for_ready: Option<bool>,
+    timeout: Option<Duration>,
+    max_request_message_bytes: Option<u32>,
+    max_response_message_bytes: Option<u32>,
+    retry_policy: Option<RetryPolicy>,
+    hedging_policy: Option<HedgingPolicy>,
+}
+
+impl MethodConfig {
+    pub fn new() -> MethodConfig {
+        MethodConfig::default()
+    }
+
+    /// Applies the config to `method` of `service`.
+    ///
+    /// `service` should be the fully qualified name like `helloworld.Greeter`.
+    /// If `method` is empty, the config applies to all methods of the service.
+    /// If both are empty, the config applies to all methods that don't match
+    /// any other configs.
+    pub fn add_name(mut self, service: &str, method: &str) -> MethodConfig {
+        self.names.push((service.to_owned(), method.to_owned()));
+        self
+    }
+
+    /// Whether calls should wait for the channel to be ready instead of
+    /// failing fast.
+    pub fn wait_for_ready(mut self, wait: bool) -> MethodConfig {
+        self.wait_for_ready = Some(wait);
+        self
+    }
+
+    /// The default timeout of calls. The smaller one is used if a deadline is
+    /// also set by [`CallOption`](crate::CallOption).
+    pub fn timeout(mut self, timeout: Duration) -> MethodConfig {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The maximum size of a request message.
+    pub fn max_request_message_bytes(mut self, bytes: u32) -> MethodConfig {
+        self.max_request_message_bytes = Some(bytes);
+        self
+    }
+
+    /// The maximum size of a response message.
+    pub fn max_response_message_bytes(mut self, bytes: u32) -> MethodConfig {
+        self.max_response_message_bytes = Some(bytes);
+        self
+    }
+
+    /// Retries failed calls with the policy. It can't be used together with
+    /// [`hedging_policy`](MethodConfig::hedging_policy).
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> MethodConfig {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Sends hedged requests with the policy. It can't be used together with
+    /// [`retry_policy`](MethodConfig::retry_policy).
+    pub fn hedging_policy(mut self, policy: HedgingPolicy) -> MethodConfig {
+        self.hedging_policy = Some(policy);
+        self
+    }
+
+    fn to_json(&self, names: &mut HashSet<(String, String)>) -> Result<String> {
+        if self.names.is_empty() {
+            return Err(invalid("methodConfig.name must not be empty".to_owned()));
+        }
+        let mut json_names = Vec::with_capacity(self.names.len());
+        for (service, method) in &self.names {
+            if service.is_empty() && !method.is_empty() {
+                return Err(invalid(format!(
+                    "methodConfig.name: method {method:?} is set without service"
+                )));
+            }
+            if !names.insert((service.clone(), method.clone())) {
+                return Err(invalid(format!(
+                    "methodConfig.name: duplicated name {service:?} {method:?}"
+                )));
+            }
+            let mut name = vec![];
+            if !service.is_empty() {
+                name.push(("service", json_string(service)));
+            }
+            if !method.is_empty() {
+                name.push(("method", json_string(method)));
+            }
+            json_names.push(json_object(name));
+        }
+        let mut fields = vec![("name", json_array(json_names))];
+        if let Some(wait) = self.wait_for_ready {
+            fields.push(("waitForReady", wait.to_string()));
+        }
+        if let Some(timeout) = self.timeout {
+            fields.push(("timeout", json_duration(timeout)));
+        }
+        if let Some(bytes) = self.max_request_message_bytes {
+            fields.push(("maxRequestMessageBytes", bytes.to_string()));
+        }
+        if let Some(bytes) = self.max_response_message_bytes {
+            fields.push(("maxResponseMessageBytes", bytes.to_string()));
+        }
+        match (&self.retry_policy, &self.hedging_policy) {
+            (Some(_), Some(_)) => {
+                return Err(invalid(
+                    "methodConfig: retryPolicy and hedgingPolicy are mutually exclusive".to_owned(),
+                ))
+            }
+            (Some(p), None) => fields.push(("retryPolicy", p.to_json()?)),
+            (None, Some(p)) => fields.push(("hedgingPolicy", p.to_json()?)),
+            (None, None) => (),
+        }
+        Ok(json_object(fields))
+    }
+}
+
+/// A validated service config that can be set by
+/// [`ChannelBuilder::service_config`](crate::ChannelBuilder::service_config).
+#[derive(Clone, Debug)]
+pub struct ServiceConfig {
+    json: String,
+}
+
+impl ServiceConfig {
+    /// Gets the JSON representation of the config.
+    pub fn as_json(&self) -> &str {
+        &self.json
+    }
+}
+
+/// [`ServiceConfig`] factory in order to configure the properties.
+#[derive(Default)]
+pub struct ServiceConfigBuilder {
+    method_configs: Vec<MethodConfig>,
+    lb_policies: Vec<LbPolicy>,
+    retry_throttling: Option<(u32, f64)>,
+    health_check_service_name: Option<String>,
+}
+
+impl ServiceConfigBuilder {
+    pub fn new() -> ServiceConfigBuilder {
+        ServiceConfigBuilder::default()
+    }
+
+    /// Add a config for methods. A method can only be matched by one config.
+    pub fn add_method_config(mut self, config: MethodConfig) -> ServiceConfigBuilder {
+        self.method_configs.push(config);
+        self
+    }
+
+    /// Add a load balancing policy. The first policy that is supported will be used.
+    pub fn add_load_balancing_policy(mut self, policy: LbPolicy) -> ServiceConfigBuilder {
+        self.lb_policies.push(policy);
+        self
+    }
+
+    /// Throttle retries when too many calls fail.
+    ///
+    /// Each failed call consumes 1 token and each successful call adds
+    /// `token_ratio` tokens, retries are disabled when tokens are less than
+    /// half of `max_tokens`. `max_tokens` must be in (0, 1000] and `token_ratio`
+    /// must be greater than 0.
+    pub fn retry_throttling(mut self, max_tokens: u32, token_ratio: f64) -> ServiceConfigBuilder {
+        self.retry_throttling = Some((max_tokens, token_ratio));
+        self
+    }
+
+    /// Check the health of backends with the health checking service, see
+    /// [gRFC A17] for details.
+    ///
+    /// [gRFC A17]: https://github.com/grpc/proposal/blob/master/A17-client-side-health-checking.md
+    pub fn health_check_service_name<S: Into<String>>(mut self, name: S) -> ServiceConfigBuilder {
+        self.health_check_service_name = Some(name.into());
+        self
+    }
+
+    /// Validate the configuration and build a [`ServiceConfig`].
+    pub fn build(self) -> Result<ServiceConfig> {
+        let mut fields = vec![];
+        if !self.lb_policies.is_empty() {
+            let policies = self
+                .lb_policies
+                .iter()
+                .map(|p| {
+                    let name = match p {
+                        LbPolicy::PickFirst => "pick_first",
+                        LbPolicy::RoundRobin => "round_robin",
+                    };
+                    json_object(vec![(name, "{}".to_owned())])
+                })
+                .collect();
+            fields.push(("loadBalancingConfig", json_array(policies)));
+        }
+        if !self.method_configs.is_empty() {
+            let mut names = HashSet::new();
+            let configs = self
+                .method_configs
+                .iter()
+                .map(|c| c.to_json(&mut names))
+                .collect::<Result<_>>()?;
+            fields.push(("methodConfig", json_array(configs)));
+        }
+        if let Some((max_tokens, token_ratio)) = self.retry_throttling {
+            if max_tokens == 0 || max_tokens > 1000 {
+                return Err(invalid(format!(
+                    "retryThrottling.maxTokens must be in (0, 1000], got {max_tokens}"
+                )));
+            }
+            if !(token_ratio > 0.0 && token_ratio.is_finite()) {
+                return Err(invalid(format!(
+                    "retryThrottling.tokenRatio must be greater than 0, got {token_ratio}"
+                )));
+            }
+            fields.push((
+                "retryThrottling",
+                json_object(vec![
+                    ("maxTokens", max_tokens.to_string()),
+                    ("tokenRatio", token_ratio.to_string()),
+                ]),
+            ));
+        }
+        if let Some(name) = &self.health_check_service_name {
+            fields.push((
+                "healthCheckConfig",
+                json_object(vec![("serviceName", json_string(name))]),
+            ));
+        }
+        Ok(ServiceConfig {
+            json: json_object(fields),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            backoff_multiplier: 1.5,
+            retryable_status_codes: vec![RpcStatusCode::UNAVAILABLE],
+        }
+    }
+
+    #[test]
+    fn test_service_config() {
+        let cfg = ServiceConfigBuilder::new().build().unwrap();
+        assert_eq!(cfg.as_json(), "{}");
+
+        let cfg =
+            ServiceConfigBuilder::new()
+                .add_load_balancing_policy(LbPolicy::RoundRobin)
+                .add_method_config(
+                    MethodConfig::new()
+                        .add_name("helloworld.Greeter", "SayHello")
+                        .add_name("a\"b", "")
+                        .wait_for_ready(true)
+                        .timeout(Duration::from_millis(1500))
+                        .max_request_message_bytes(1024)
+                        .retry_policy(retry_policy()),
+                )
+                .add_method_config(MethodConfig::new().add_name("", "").hedging_policy(
+                    HedgingPolicy {
+                        max_attempts: 2,
+                        hedging_delay: Duration::from_secs(2),
+                        non_fatal_status_codes: vec![],
+                    },
+                ))
+                .retry_throttling(10, 0.1)
+                .health_check_service_name("svc")
+                .build()
+                .unwrap();
+        assert_eq!(
+            cfg.as_json(),
+            concat!(
+                r#"{"loadBalancingConfig":[{"round_robin":{}}],"#,
+                r#""methodConfig":[{"name":[{"service":"helloworld.Greeter","method":"SayHello"},{"service":"a\"b"}],"#,
+                r#""waitForReady":true,"timeout":"1.5s","maxRequestMessageBytes":1024,"#,
+                r#""retryPolicy":{"maxAttempts":3,"initialBackoff":"0.1s","maxBackoff":"1s","#,
+                r#""backoffMultiplier":1.5,"retryableStatusCodes":["UNAVAILABLE"]}},"#,
+                r#"{"name":[{}],"hedgingPolicy":{"maxAttempts":2,"hedgingDelay":"2s"}}],"#,
+                r#""retryThrottling":{"maxTokens":10,"tokenRatio":0.1},"#,
+                r#""healthCheckConfig":{"serviceName":"svc"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_service_config() {
+        let build = |cfg: MethodConfig| {
+            ServiceConfigBuilder::new()
+                .add_method_config(cfg)
+                .build()
+                .map(|_| ())
+        };
+        let name = || MethodConfig::new().add_name("s", "m");
+        let mut policies = vec![];
+        for f in [
+            (|p: &mut RetryPolicy| p.max_attempts = 1) as fn(&mut RetryPolicy),
+            |p| p.initial_backoff = Duration::ZERO,
+            |p| p.backoff_multiplier = 0.0,
+            |p| p.backoff_multiplier = f64::NAN,
+            |p| p.retryable_status_codes.clear(),
+            |p| p.retryable_status_codes.push(RpcStatusCode::OK),
+            |p| p.retryable_status_codes.push(100.into()),
+        ] {
+            let mut p = retry_policy();
+            f(&mut p);
+            policies.push(p);
+        }
+        for p in policies {
+            let res = build(name().retry_policy(p.clone()));
+            assert!(
+                matches!(res, Err(Error::InvalidServiceConfig(_))),
+                "{:?} {:?}",
+                p,
+                res
+            );
+        }
+
+        let hedging = HedgingPolicy {
+            max_attempts: 2,
+            hedging_delay: Duration::ZERO,
+            non_fatal_status_codes: vec![],
+        };
+        build(name().hedging_policy(hedging.clone())).unwrap();
+        build(name().retry_policy(retry_policy()).hedging_policy(hedging)).unwrap_err();
+        build(MethodConfig::new()).unwrap_err();
+        build(MethodConfig::new().add_name("", "m")).unwrap_err();
+        build(name().add_name("s", "m")).unwrap_err();
+        ServiceConfigBuilder::new()
+            .add_method_config(name())
+            .add_method_config(name())
+            .build()
+            .unwrap_err();
+        for (tokens, ratio) in [(0, 0.1), (1001, 0.1), (10, 0.0), (10, f64::INFINITY)] {
+            ServiceConfigBuilder::new()
+                .retry_throttling(tokens, ratio)
+                .build()
+                .unwrap_err();
+        }
+    }
+}
@@ -13,6 +13,7 @@ use std::pin::Pin;
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Instant;
 
 use futures_util::task::{waker_ref, ArcWake};
 
@@ -199,12 +200,14 @@ fn poll(task: Arc<SpawnTask>, woken: bool) {
         let mut cx = Context::from_waker(&waker);
 
         // L208 "lock"s state, hence it's safe to get a mutable reference.
-        match unsafe { &mut *task.handle.get() }
+        let start = Instant::now();
+        let res = unsafe { &mut *task.handle.get() }
             .as_mut()
             .unwrap()
             .as_mut()
-            .poll(&mut cx)
-        {
+            .poll(&mut cx);
+        task.queue.metrics.record_spawned_poll(start.elapsed());
+        match res {
             Poll::Ready(()) => {
                 task.state.store(COMPLETED, Ordering::Release);
                 unsafe { &mut *task.handle.get() }.take();
@@ -255,3 +258,36 @@ impl Executor<'_> {
         poll(notify, false)
     }
 }
+
+/// A runtime that takes over the futures spawned by `RpcContext` and `Client`
+/// from the gRPC poll threads.
+#[derive(Clone, Default)]
+pub(crate) struct Runtime {
+    #[cfg(feature = "tokio")]
+    tokio: Option<tokio::runtime::Handle>,
+}
+
+impl Runtime {
+    #[cfg(feature = "tokio")]
+    pub fn tokio(handle: tokio::runtime::Handle) -> Runtime {
+        Runtime {
+            tokio: Some(handle),
+        }
+    }
+
+    /// Spawn the future into the runtime.
+    ///
+    /// The future is given back if no runtime is configured, in which case it
+    /// should be spawned into the poll thread by `Executor` instead.
+    pub fn try_spawn<F>(&self, f: F) -> Option<F>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        #[cfg(feature = "tokio")]
+        if let Some(handle) = &self.tokio {
+            handle.spawn(f);
+            return None;
+        }
+        Some(f)
+    }
+}
@@ -21,7 +21,7 @@ use crate::cq::CompletionQueue;
 use crate::error::{Error, Result};
 use crate::server::RequestCallContext;
 
-pub(crate) use self::executor::{Executor, Kicker, UnfinishedWork};
+pub(crate) use self::executor::{Executor, Kicker, Runtime, UnfinishedWork};
 pub(crate) use self::promise::BatchResult;
 pub use self::promise::BatchType;
 
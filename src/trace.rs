@@ -0,0 +1,444 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Trace context propagation.
+//!
+//! A [`TraceContext`] identifies the span of work that a call belongs to. On
+//! server side, it's extracted from the request headers if a propagator is set
+//! by [`ServerBuilder::trace_propagator`], and is available to handlers by
+//! [`RpcContext::trace_context`] and [`TraceContext::current`]. On client side,
+//! [`TracePropagation`] injects the current context into the headers of every
+//! call, so that a trace can cross multiple servers without any change to the
+//! services.
+//!
+//! When the `tracing` feature is enabled, each call also gets a span carrying
+//! the method, peer, status and timing.
+//!
+//! [`ServerBuilder::trace_propagator`]: crate::ServerBuilder::trace_propagator
+//! [`RpcContext::trace_context`]: crate::RpcContext::trace_context
+
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::pin::Pin;
+use std::str;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::call::client::CallOption;
+use crate::error::Error;
+use crate::interceptor::ClientInterceptor;
+use crate::metadata::{Metadata, MetadataBuilder};
+use crate::server::CheckResult;
+
+const TRACEPARENT_KEY: &str = "traceparent";
+const TRACESTATE_KEY: &str = "tracestate";
+
+/// The flag indicates that the caller may have recorded the trace.
+const FLAG_SAMPLED: u8 = 0x01;
+
+thread_local! {
+    static CURRENT: RefCell<Option<TraceContext>> = const { RefCell::new(None) };
+}
+
+/// Generates a random non-zero id.
+fn random_id() -> u64 {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    loop {
+        // `RandomState` is seeded randomly, mixing in a sequence makes ids
+        // unique even if the seeds are reused.
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(SEQ.fetch_add(1, Ordering::Relaxed));
+        let id = hasher.finish();
+        if id != 0 {
+            return id;
+        }
+    }
+}
+
+fn parse_hex(s: &str, len: usize) -> Option<u128> {
+    if s.len() != len || !s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        return None;
+    }
+    u128::from_str_radix(s, 16).ok()
+}
+
+/// The trace context of a call, as defined by [W3C Trace Context].
+///
+/// [W3C Trace Context]: https://www.w3.org/TR/trace-context/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceContext {
+    trace_id: u128,
+    span_id: u64,
+    flags: u8,
+    trace_state: String,
+}
+
+impl TraceContext {
+    /// Create a trace context with the given ids and flags.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if any id is 0, which is invalid.
+    pub fn new(trace_id: u128, span_id: u64, flags: u8) -> TraceContext {
+        assert!(
+            trace_id != 0 && span_id != 0,
+            "trace id and span id should not be 0"
+        );
+        TraceContext {
+            trace_id,
+            span_id,
+            flags,
+            trace_state: String::new(),
+        }
+    }
+
+    /// Start a new sampled trace with random ids.
+    pub fn new_root() -> TraceContext {
+        let trace_id = (u128::from(random_id()) << 64) | u128::from(random_id());
+        TraceContext::new(trace_id, random_id(), FLAG_SAMPLED)
+    }
+
+    /// Create a context for a new span in the same trace, which is a child of
+    /// current span.
+    pub fn child(&self) -> TraceContext {
+        TraceContext {
+            span_id: random_id(),
+            ..self.clone()
+        }
+    }
+
+    /// Set the vendor specific `tracestate` value.
+    pub fn with_trace_state(mut self, trace_state: impl Into<String>) -> TraceContext {
+        self.trace_state = trace_state.into();
+        self
+    }
+
+    pub fn trace_id(&self) -> u128 {
+        self.trace_id
+    }
+
+    pub fn span_id(&self) -> u64 {
+        self.span_id
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Check if the caller may have recorded the trace.
+    pub fn is_sampled(&self) -> bool {
+        self.flags & FLAG_SAMPLED != 0
+    }
+
+    /// Get the vendor specific `tracestate` value, empty if not set.
+    pub fn trace_state(&self) -> &str {
+        &self.trace_state
+    }
+
+    /// Parse the value of a `traceparent` header.
+    ///
+    /// `None` is returned if the value is invalid.
+    pub fn from_traceparent(value: &str) -> Option<TraceContext> {
+        let mut parts = value.trim().splitn(5, '-');
+        let version = parse_hex(parts.next()?, 2)?;
+        let trace_id = parse_hex(parts.next()?, 32)?;
+        let span_id = parse_hex(parts.next()?, 16)? as u64;
+        let flags = parse_hex(parts.next()?, 2)? as u8;
+        // Later versions are allowed to append fields.
+        if version == 0xff || (version == 0 && parts.next().is_some()) {
+            return None;
+        }
+        if trace_id == 0 || span_id == 0 {
+            return None;
+        }
+        Some(TraceContext::new(trace_id, span_id, flags))
+    }
+
+    /// Format the context as the value of a `traceparent` header.
+    pub fn to_traceparent(&self) -> String {
+        format!(
+            "00-{:032x}-{:016x}-{:02x}",
+            self.trace_id, self.span_id, self.flags
+        )
+    }
+
+    /// Get the context of the call that is being handled by current thread.
+    ///
+    /// It's available in handlers and the futures they spawn by `RpcContext::spawn`
+    /// when a propagator is set on the server, or in the scope of
+    /// [`TraceContext::in_scope`] and [`TraceContext::instrument`].
+    pub fn current() -> Option<TraceContext> {
+        CURRENT.with(|c| c.borrow().clone())
+    }
+
+    /// Run `f` with the context set as current.
+    pub fn in_scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Reset(Option<TraceContext>);
+
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT.with(|c| *c.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _reset = Reset(CURRENT.with(|c| c.borrow_mut().replace(self.clone())));
+        f()
+    }
+
+    /// Wrap the future so that the context is set as current whenever it's polled.
+    pub fn instrument<F: Future>(self, f: F) -> Traced<F> {
+        Traced { ctx: self, f }
+    }
+}
+
+impl fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_traceparent())
+    }
+}
+
+/// A future that sets its trace context as current when being polled.
+///
+/// It's created by [`TraceContext::instrument`].
+pub struct Traced<F> {
+    ctx: TraceContext,
+    f: F,
+}
+
+impl<F: Future> Future for Traced<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        // `f` is never moved out of the pinned struct.
+        let this = unsafe { self.get_unchecked_mut() };
+        let f = unsafe { Pin::new_unchecked(&mut this.f) };
+        this.ctx.in_scope(|| f.poll(cx))
+    }
+}
+
+/// Carries trace contexts in the headers of calls.
+pub trait TracePropagator: Send + Sync {
+    /// Extract the context of the caller from request headers.
+    ///
+    /// `None` should be returned if there is no valid context.
+    fn extract(&self, headers: &Metadata) -> Option<TraceContext>;
+
+    /// Add the entries describing `ctx` to request headers.
+    fn inject(&self, ctx: &TraceContext, headers: &mut MetadataBuilder);
+}
+
+/// A propagator that uses the `traceparent` and `tracestate` headers defined by
+/// W3C Trace Context.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct W3cPropagator;
+
+impl TracePropagator for W3cPropagator {
+    fn extract(&self, headers: &Metadata) -> Option<TraceContext> {
+        let mut parent = None;
+        let mut state = String::new();
+        for (k, v) in headers.iter() {
+            match k {
+                TRACEPARENT_KEY => parent = Some(v),
+                // Multiple `tracestate` headers should be combined.
+                TRACESTATE_KEY => {
+                    if let Ok(v) = str::from_utf8(v) {
+                        if !state.is_empty() {
+                            state.push(',');
+                        }
+                        state.push_str(v.trim());
+                    }
+                }
+                _ => {}
+            }
+        }
+        let ctx = TraceContext::from_traceparent(str::from_utf8(parent?).ok()?)?;
+        Some(ctx.with_trace_state(state))
+    }
+
+    fn inject(&self, ctx: &TraceContext, headers: &mut MetadataBuilder) {
+        headers
+            .add_str(TRACEPARENT_KEY, &ctx.to_traceparent())
+            .unwrap();
+        if !ctx.trace_state.is_empty() {
+            // An invalid state is dropped instead of failing the call.
+            let _ = headers.add_str(TRACESTATE_KEY, &ctx.trace_state);
+        }
+    }
+}
+
+/// A client interceptor that injects the current trace context into the headers
+/// of every call.
+///
+/// A child span of [`TraceContext::current`] is created for each call. If there
+/// is no current context, the call starts a new trace.
+#[derive(Clone)]
+pub struct TracePropagation {
+    propagator: Arc<dyn TracePropagator>,
+    #[cfg(feature = "tracing")]
+    span: Option<(tracing::Span, std::time::Instant)>,
+}
+
+impl TracePropagation {
+    pub fn new<P: TracePropagator + 'static>(propagator: P) -> TracePropagation {
+        TracePropagation {
+            propagator: Arc::new(propagator),
+            #[cfg(feature = "tracing")]
+            span: None,
+        }
+    }
+}
+
+impl Default for TracePropagation {
+    fn default() -> TracePropagation {
+        TracePropagation::new(W3cPropagator)
+    }
+}
+
+impl ClientInterceptor for TracePropagation {
+    fn on_start(&mut self, _method: &str, opt: &mut CallOption) -> CheckResult {
+        let ctx = match TraceContext::current() {
+            Some(parent) => parent.child(),
+            None => TraceContext::new_root(),
+        };
+        let mut builder = MetadataBuilder::new();
+        self.propagator.inject(&ctx, &mut builder);
+        opt.merge_headers(&builder.build());
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "grpc.client",
+                rpc.method = _method,
+                trace_id = %format_args!("{:032x}", ctx.trace_id),
+                span_id = %format_args!("{:016x}", ctx.span_id),
+                grpc.status_code = tracing::field::Empty,
+                elapsed_ms = tracing::field::Empty,
+            );
+            self.span = Some((span, std::time::Instant::now()));
+        }
+        CheckResult::Continue
+    }
+
+    fn on_finish(&mut self, _res: std::result::Result<(), &Error>) {
+        #[cfg(feature = "tracing")]
+        if let Some((span, start)) = &self.span {
+            record_finish(span, *start, status_code(_res));
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn ClientInterceptor> {
+        Box::new(TracePropagation {
+            propagator: self.propagator.clone(),
+            #[cfg(feature = "tracing")]
+            span: None,
+        })
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn status_code(res: std::result::Result<(), &Error>) -> crate::RpcStatusCode {
+    match res {
+        Ok(()) => crate::RpcStatusCode::OK,
+        Err(Error::RpcFailure(s)) => s.code(),
+        Err(_) => crate::RpcStatusCode::UNKNOWN,
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn record_finish(span: &tracing::Span, start: std::time::Instant, code: crate::RpcStatusCode) {
+    span.record("grpc.status_code", i32::from(code));
+    span.record("elapsed_ms", start.elapsed().as_secs_f64() * 1000.0);
+}
+
+/// Creates the span of a server side call, and an interceptor that records
+/// the status and timing into the span.
+#[cfg(feature = "tracing")]
+pub(crate) fn server_span(
+    ctx: &crate::RpcContext,
+    trace: &TraceContext,
+) -> (tracing::Span, Box<dyn crate::ServerInterceptor>) {
+    let span = tracing::info_span!(
+        "grpc.server",
+        rpc.method = %String::from_utf8_lossy(ctx.method()),
+        net.peer = %ctx.peer(),
+        trace_id = %format_args!("{:032x}", trace.trace_id),
+        span_id = %format_args!("{:016x}", trace.span_id),
+        grpc.status_code = tracing::field::Empty,
+        elapsed_ms = tracing::field::Empty,
+    );
+    let interceptor = ServerSpan {
+        span: span.clone(),
+        start: std::time::Instant::now(),
+    };
+    (span, Box::new(interceptor))
+}
+
+#[cfg(feature = "tracing")]
+#[derive(Clone)]
+struct ServerSpan {
+    span: tracing::Span,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl crate::ServerInterceptor for ServerSpan {
+    fn on_status(&mut self, status: &mut crate::RpcStatus) {
+        record_finish(&self.span, self.start, status.code());
+    }
+
+    fn box_clone(&self) -> Box<dyn crate::ServerInterceptor> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_traceparent() {
+        let s = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let ctx = TraceContext::from_traceparent(s).unwrap();
+        assert_eq!(ctx.trace_id(), 0x4bf92f3577b34da6a3ce929d0e0e4736);
+        assert_eq!(ctx.span_id(), 0x00f067aa0ba902b7);
+        assert!(ctx.is_sampled());
+        assert_eq!(ctx.to_traceparent(), s);
+
+        // Future versions may append fields.
+        let s = "cc-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00-what";
+        let ctx = TraceContext::from_traceparent(s).unwrap();
+        assert!(!ctx.is_sampled());
+
+        for s in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01",
+        ] {
+            assert_eq!(TraceContext::from_traceparent(s), None, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_current() {
+        assert_eq!(TraceContext::current(), None);
+        let root = TraceContext::new_root();
+        let child = root.child();
+        assert_eq!(child.trace_id(), root.trace_id());
+        assert_ne!(child.span_id(), root.span_id());
+        root.in_scope(|| {
+            assert_eq!(TraceContext::current().as_ref(), Some(&root));
+            child.in_scope(|| assert_eq!(TraceContext::current().as_ref(), Some(&child)));
+            assert_eq!(TraceContext::current().as_ref(), Some(&root));
+        });
+        assert_eq!(TraceContext::current(), None);
+
+        let f = child.clone().instrument(async { TraceContext::current() });
+        assert_eq!(futures_executor::block_on(f), Some(child));
+    }
+}
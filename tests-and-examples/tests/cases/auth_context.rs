@@ -5,8 +5,11 @@ use grpcio::*;
 use grpcio_proto::example::helloworld::*;
 
 use std::collections::HashMap;
+use std::ffi::CString;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Sender};
 use std::sync::*;
+use std::thread;
 use std::time::*;
 
 use tests_and_examples::util::{read_cert_pair, read_single_crt};
@@ -123,3 +126,291 @@ fn test_no_crash_on_insecure() {
     assert_eq!(ctx_map.get("transport_security_type").unwrap(), "insecure");
     assert_eq!(ctx_map.get("security_level").unwrap(), "TSI_SECURITY_NONE");
 }
+
+/// Maps bearer tokens to user names.
+struct TokenProcessor;
+
+impl AuthMetadataProcessor for TokenProcessor {
+    fn process(&self, mut request: AuthMetadataRequest) {
+        let token = request
+            .metadata()
+            .iter()
+            .find(|(k, _)| *k == "authorization")
+            .map(|(_, v)| v.to_vec());
+        if token.as_deref() != Some(b"Bearer alice-token".as_ref()) {
+            let status =
+                RpcStatus::with_message(RpcStatusCode::UNAUTHENTICATED, "invalid token".to_owned());
+            return request.reject(status);
+        }
+        request.add_property("username", b"alice");
+        assert!(request.set_peer_identity_property_name("username"));
+        assert!(request
+            .properties()
+            .any(|p| p.name() == "transport_security_type"));
+        request.accept(&["authorization"]);
+    }
+}
+
+#[test]
+fn test_auth_metadata_processor() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (tx, rx) = mpsc::channel();
+    let service = create_greeter(GreeterService { tx });
+    let (server_crt, server_key) = read_cert_pair("server1").unwrap();
+    let server_credentials = grpcio::ServerCredentialsBuilder::new()
+        .add_cert(server_crt.into(), server_key.into())
+        .build()
+        .with_auth_metadata_processor(TokenProcessor);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_credentials)
+        .unwrap();
+    server.start();
+
+    let connect = |token: &str| {
+        let client_credentials = ChannelCredentialsBuilder::new()
+            .root_cert(read_single_crt("ca").unwrap().into())
+            .build()
+            .with_call_credentials(CallCredentials::access_token(token));
+        let ch = ChannelBuilder::new(env.clone())
+            .override_ssl_target("rust.test.google.fr")
+            .set_credentials(client_credentials)
+            .connect(&format!("127.0.0.1:{port}"));
+        GreeterClient::new(ch)
+    };
+
+    let mut req = HelloRequest::default();
+    req.name = "world".to_owned();
+    let resp = connect("alice-token").say_hello(&req).unwrap();
+    assert_eq!(resp.message, "hello world");
+    let ctx_map = rx.recv_timeout(Duration::from_secs(1)).unwrap().unwrap();
+    assert_eq!(ctx_map.get("username").unwrap(), "alice");
+
+    match connect("bob-token").say_hello(&req).unwrap_err() {
+        Error::RpcFailure(s) => {
+            assert_eq!(s.code(), RpcStatusCode::UNAUTHENTICATED);
+            assert!(s.message().contains("invalid token"), "{:?}", s);
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+    // The handler is not invoked for rejected calls.
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_local_credentials() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (tx, rx) = mpsc::channel();
+    let service = create_greeter(GreeterService { tx });
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port(
+            "127.0.0.1:0",
+            ServerCredentials::local(LocalConnectType::LocalTcp),
+        )
+        .unwrap();
+    #[cfg(unix)]
+    let uds_path = std::env::temp_dir().join(format!("grpcio-local-{}", std::process::id()));
+    #[cfg(unix)]
+    server
+        .add_listening_port(
+            format!("unix:{}", uds_path.display()),
+            ServerCredentials::local(LocalConnectType::Uds),
+        )
+        .unwrap();
+    server.start();
+
+    let mut req = HelloRequest::default();
+    req.name = "world".to_owned();
+    let check = |addr: &str, connect_type| {
+        let ch = ChannelBuilder::new(env.clone())
+            .set_credentials(ChannelCredentials::local(connect_type))
+            .connect(addr);
+        let resp = GreeterClient::new(ch).say_hello(&req).unwrap();
+        assert_eq!(resp.message, "hello world");
+        let ctx_map = rx.recv_timeout(Duration::from_secs(1)).unwrap().unwrap();
+        assert_eq!(ctx_map.get("transport_security_type").unwrap(), "local");
+        ctx_map
+    };
+    check(&format!("127.0.0.1:{port}"), LocalConnectType::LocalTcp);
+    #[cfg(unix)]
+    {
+        let ctx_map = check(
+            &format!("unix:{}", uds_path.display()),
+            LocalConnectType::Uds,
+        );
+        assert_eq!(
+            ctx_map.get("security_level").unwrap(),
+            "TSI_PRIVACY_AND_INTEGRITY"
+        );
+        let _ = std::fs::remove_file(&uds_path);
+    }
+}
+
+/// Switches to another client certificate when `switch` is set.
+struct ClientCertFetcher {
+    switch: Arc<AtomicBool>,
+}
+
+impl ChannelCredentialsFetcher for ClientCertFetcher {
+    fn fetch(
+        &self,
+    ) -> std::result::Result<Option<ChannelCredentialsBuilder>, Box<dyn std::error::Error>> {
+        let name = if self.switch.load(Ordering::SeqCst) {
+            "client"
+        } else {
+            "client1"
+        };
+        let (crt, key) = read_cert_pair(name)?;
+        let builder = ChannelCredentialsBuilder::new()
+            .root_cert(read_single_crt("ca")?.into())
+            .cert(crt.into(), key.into());
+        Ok(Some(builder))
+    }
+}
+
+#[test]
+fn test_channel_credentials_fetcher() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (tx, rx) = mpsc::channel();
+    let service = create_greeter(GreeterService { tx });
+    let (server_crt, server_key) = read_cert_pair("server1").unwrap();
+    let server_credentials = grpcio::ServerCredentialsBuilder::new()
+        .root_cert(
+            read_single_crt("ca").unwrap(),
+            CertificateRequestType::RequestAndRequireClientCertificateAndVerify,
+        )
+        .add_cert(server_crt.into(), server_key.into())
+        .build();
+    // Close connections frequently so that the client reconnects.
+    let args = ChannelBuilder::new(env.clone())
+        .raw_cfg_int(CString::new("grpc.max_connection_age_ms").unwrap(), 200)
+        .raw_cfg_int(
+            CString::new("grpc.max_connection_age_grace_ms").unwrap(),
+            200,
+        )
+        .build_args();
+    let mut server = ServerBuilder::new(env.clone())
+        .channel_args(args)
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_credentials)
+        .unwrap();
+    server.start();
+
+    let switch = Arc::new(AtomicBool::new(false));
+    let fetcher = ClientCertFetcher {
+        switch: switch.clone(),
+    };
+    let creds =
+        ChannelCredentials::with_fetcher(Box::new(fetcher), Duration::from_secs(1)).unwrap();
+    let ch = ChannelBuilder::new(env)
+        .override_ssl_target("rust.test.google.fr")
+        .set_credentials(creds)
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let req = HelloRequest::default();
+    let common_name = || {
+        client.say_hello(&req).unwrap();
+        let ctx_map = rx.recv_timeout(Duration::from_secs(1)).unwrap().unwrap();
+        ctx_map.get("x509_common_name").unwrap().clone()
+    };
+    assert_eq!(common_name(), "testclient1");
+
+    // The renewed certificate is picked up by new connections.
+    switch.store(true, Ordering::SeqCst);
+    let timer = Instant::now();
+    while common_name() != "testclient" {
+        assert!(timer.elapsed() < Duration::from_secs(10));
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Replies with the typed identity of the peer.
+#[derive(Clone)]
+struct IdentityService;
+
+impl Greeter for IdentityService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, _: HelloRequest, sink: UnarySink<HelloReply>) {
+        let auth_context = ctx.auth_context().unwrap();
+        let mut resp = HelloReply::default();
+        resp.message = format!(
+            "{:?} {:?} {:?} {:?} {}",
+            auth_context.transport_security_type(),
+            auth_context.security_level(),
+            auth_context.common_name(),
+            auth_context.dns_names(),
+            auth_context.pem_cert().is_some(),
+        );
+        ctx.spawn(
+            sink.success(resp)
+                .map_err(|e| panic!("failed to reply {:?}", e))
+                .map(|_| ()),
+        );
+    }
+}
+
+#[test]
+fn test_peer_allow_list() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (server_crt, server_key) = read_cert_pair("server1").unwrap();
+    let server_credentials = grpcio::ServerCredentialsBuilder::new()
+        .root_cert(
+            read_single_crt("ca").unwrap(),
+            CertificateRequestType::RequestAndRequireClientCertificateAndVerify,
+        )
+        .add_cert(server_crt.into(), server_key.into())
+        .build();
+    let allow_list = PeerAllowList::new()
+        .allow_spiffe_id("spiffe://example.org/ns/prod/*")
+        .allow_san("*.test.google.fr");
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(IdentityService))
+        .add_checker(allow_list)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_credentials)
+        .unwrap();
+    server.start();
+
+    let connect = |name: &str| {
+        let (crt, key) = read_cert_pair(name).unwrap();
+        let client_credentials = ChannelCredentialsBuilder::new()
+            .root_cert(read_single_crt("ca").unwrap().into())
+            .cert(crt.into(), key.into())
+            .build();
+        let ch = ChannelBuilder::new(env.clone())
+            .override_ssl_target("rust.test.google.fr")
+            .set_credentials(client_credentials)
+            .connect(&format!("127.0.0.1:{port}"));
+        GreeterClient::new(ch)
+    };
+
+    // server1 has the DNS name "*.test.google.fr".
+    let req = HelloRequest::default();
+    let resp = connect("server1").say_hello(&req).unwrap();
+    assert!(
+        resp.message.starts_with(
+            "Some(\"ssl\") Some(PrivacyAndIntegrity) Some(\"*.test.google.com.au\") [\"*.test.google.fr\""
+        ),
+        "{}",
+        resp.message
+    );
+    assert!(resp.message.ends_with("true"), "{}", resp.message);
+
+    // client1 has no subject alternative names.
+    match connect("client1").say_hello(&req).unwrap_err() {
+        Error::RpcFailure(s) => assert_eq!(s.code(), RpcStatusCode::PERMISSION_DENIED),
+        e => panic!("unexpected error {:?}", e),
+    }
+}
@@ -3,14 +3,18 @@
 use futures_util::future::{FutureExt as _, TryFutureExt as _};
 
 use grpcio::{
-    CertificateRequestType, ChannelBuilder, ChannelCredentialsBuilder, EnvBuilder, RpcContext,
-    ServerBuilder, ServerCredentials, ServerCredentialsBuilder, ServerCredentialsFetcher,
-    UnarySink,
+    AuthMetadataContext, CallCredentials, CallOption, CertificateProvider, CertificateRequestType,
+    CertificateVerifier, ChannelBuilder, ChannelCredentials, ChannelCredentialsBuilder,
+    ChannelCredentialsFetcher, EnvBuilder, Error, MetadataBuilder, MetadataCredentialsCallback,
+    MetadataCredentialsPlugin, RpcContext, RpcStatus, RpcStatusCode, ServerBuilder,
+    ServerCredentials, ServerCredentialsBuilder, ServerCredentialsFetcher, TlsCredentialsBuilder,
+    UnarySink, VerificationRequest,
 };
 use grpcio_proto::example::helloworld::*;
 
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use tests_and_examples::util::{read_cert_pair, read_single_crt};
 
 #[derive(Clone)]
@@ -167,3 +171,379 @@ fn test_reload_fail() {
         assert_eq!(reply.message, "Hello world");
     }
 }
+
+/// Replies the auth headers sent by the client.
+#[derive(Clone)]
+struct AuthEchoService;
+
+impl Greeter for AuthEchoService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, _: HelloRequest, sink: UnarySink<HelloReply>) {
+        let mut headers: Vec<_> = ctx
+            .request_headers()
+            .iter()
+            .filter(|(k, _)| *k == "authorization" || *k == "x-plugin")
+            .map(|(k, v)| format!("{}={}", k, String::from_utf8_lossy(v)))
+            .collect();
+        headers.sort();
+        let mut resp = HelloReply::default();
+        resp.message = headers.join(",");
+        ctx.spawn(sink.success(resp).map(|_| ()))
+    }
+}
+
+/// Produces the name of the method, asynchronously if `spawn` is true.
+struct MethodPlugin {
+    spawn: bool,
+}
+
+impl MetadataCredentialsPlugin for MethodPlugin {
+    fn get_metadata(&self, ctx: &AuthMetadataContext<'_>, callback: MetadataCredentialsCallback) {
+        let method = ctx.method_name().to_owned();
+        let f = move || {
+            if method != "SayHello" {
+                let status = RpcStatus::with_message(RpcStatusCode::UNAUTHENTICATED, method);
+                return callback.done(Err(status));
+            }
+            let mut builder = MetadataBuilder::new();
+            builder.add_str("x-plugin", &method).unwrap();
+            callback.done(Ok(builder.build()))
+        };
+        if self.spawn {
+            thread::spawn(f);
+        } else {
+            f();
+        }
+    }
+}
+
+struct FailPlugin;
+
+impl MetadataCredentialsPlugin for FailPlugin {
+    fn get_metadata(&self, _: &AuthMetadataContext<'_>, callback: MetadataCredentialsCallback) {
+        let status = RpcStatus::with_message(RpcStatusCode::UNAUTHENTICATED, "expired".to_owned());
+        callback.done(Err(status))
+    }
+}
+
+#[test]
+fn test_call_credentials() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (crt, key) = read_cert_pair("server1").unwrap();
+    let server_creds = ServerCredentialsBuilder::new()
+        .add_cert(crt.into(), key.into())
+        .build();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(AuthEchoService))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_creds)
+        .unwrap();
+    server.start();
+
+    let connect = |creds: CallCredentials| {
+        let creds = ChannelCredentialsBuilder::new()
+            .root_cert(read_single_crt("ca").unwrap().into())
+            .build()
+            .with_call_credentials(creds);
+        let ch = ChannelBuilder::new(env.clone())
+            .override_ssl_target("rust.test.google.fr")
+            .set_credentials(creds)
+            .connect(&format!("127.0.0.1:{port}"));
+        GreeterClient::new(ch)
+    };
+    let req = HelloRequest::default();
+
+    // Static token on the channel, plugin on the call.
+    let client = connect(CallCredentials::access_token("secret"));
+    let reply = client.say_hello(&req).unwrap();
+    assert_eq!(reply.message, "authorization=Bearer secret");
+    for spawn in [false, true] {
+        let opt =
+            CallOption::default().credentials(CallCredentials::from_plugin(MethodPlugin { spawn }));
+        let reply = client.say_hello_opt(&req, opt).unwrap();
+        assert_eq!(
+            reply.message,
+            "authorization=Bearer secret,x-plugin=SayHello"
+        );
+    }
+
+    // Composed credentials on the channel.
+    let creds = CallCredentials::access_token("secret")
+        .compose(CallCredentials::from_plugin(MethodPlugin { spawn: true }));
+    let client = connect(creds);
+    let reply = client.say_hello(&req).unwrap();
+    assert_eq!(
+        reply.message,
+        "authorization=Bearer secret,x-plugin=SayHello"
+    );
+
+    // Failures of plugins fail the calls.
+    let client = connect(CallCredentials::from_plugin(FailPlugin));
+    match client.say_hello(&req).unwrap_err() {
+        Error::RpcFailure(s) => {
+            assert_eq!(s.code(), RpcStatusCode::UNAUTHENTICATED);
+            assert!(s.message().contains("expired"), "{:?}", s);
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_socketpair_channel_with_secure_port() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (crt, key) = read_cert_pair("server1").unwrap();
+    let server_creds = ServerCredentialsBuilder::new()
+        .add_cert(crt.into(), key.into())
+        .build();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(AuthEchoService))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_creds)
+        .unwrap();
+    server.start();
+    let socketpair_ch = server
+        .socketpair_channel(ChannelBuilder::new(env.clone()))
+        .unwrap();
+    let insecure_ch = server
+        .socketpair_channel(
+            ChannelBuilder::new(env.clone()).set_credentials(ChannelCredentials::insecure()),
+        )
+        .unwrap();
+
+    // Only insecure transport is supported on socket pairs.
+    let creds = ChannelCredentialsBuilder::new()
+        .root_cert(read_single_crt("ca").unwrap().into())
+        .build();
+    match server.socketpair_channel(ChannelBuilder::new(env.clone()).set_credentials(creds)) {
+        Err(e) => assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput),
+        Ok(_) => panic!("secure credentials should be rejected"),
+    }
+
+    let creds = ChannelCredentialsBuilder::new()
+        .root_cert(read_single_crt("ca").unwrap().into())
+        .build();
+    let secure_ch = ChannelBuilder::new(env)
+        .override_ssl_target("rust.test.google.fr")
+        .set_credentials(creds)
+        .connect(&format!("127.0.0.1:{port}"));
+
+    let req = HelloRequest::default();
+    for ch in [socketpair_ch, insecure_ch, secure_ch] {
+        let client = GreeterClient::new(ch);
+        let reply = client.say_hello(&req).unwrap();
+        assert_eq!(reply.message, "");
+    }
+}
+
+// Common names and DNS names of verified peers.
+type Peers = Arc<Mutex<Vec<(String, Vec<String>)>>>;
+
+struct RecordVerifier {
+    peers: Peers,
+    accept: bool,
+}
+
+impl CertificateVerifier for RecordVerifier {
+    fn verify(&self, req: &VerificationRequest<'_>) -> Result<(), RpcStatus> {
+        let dns_names = req.dns_names().into_iter().map(str::to_owned).collect();
+        self.peers
+            .lock()
+            .unwrap()
+            .push((req.common_name().to_owned(), dns_names));
+        if self.accept {
+            Ok(())
+        } else {
+            Err(RpcStatus::with_message(
+                RpcStatusCode::PERMISSION_DENIED,
+                "untrusted server".to_owned(),
+            ))
+        }
+    }
+}
+
+#[test]
+fn test_tls_credentials() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (crt, key) = read_cert_pair("server1").unwrap();
+    let provider = CertificateProvider::static_data(None, vec![(crt.into(), key.into())]);
+    let server_creds = TlsCredentialsBuilder::new()
+        .certificate_provider(provider)
+        .build_server_credentials()
+        .unwrap();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(GreeterService))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_creds)
+        .unwrap();
+    server.start();
+
+    let connect = |builder: TlsCredentialsBuilder| {
+        let provider =
+            CertificateProvider::static_data(Some(read_single_crt("ca").unwrap().into()), vec![]);
+        let creds = builder
+            .certificate_provider(provider)
+            .build_channel_credentials()
+            .unwrap();
+        let ch = ChannelBuilder::new(env.clone())
+            .override_ssl_target("rust.test.google.fr")
+            .set_credentials(creds)
+            .connect(&format!("127.0.0.1:{port}"));
+        GreeterClient::new(ch)
+    };
+    let mut req = HelloRequest::default();
+    req.name = "world".to_owned();
+
+    let client = connect(TlsCredentialsBuilder::new());
+    let reply = client.say_hello(&req).unwrap();
+    assert_eq!(reply.message, "Hello world");
+
+    // Custom verifiers authorize servers.
+    let peers = Arc::new(Mutex::new(vec![]));
+    for accept in [true, false] {
+        let verifier = RecordVerifier {
+            peers: peers.clone(),
+            accept,
+        };
+        let client = connect(TlsCredentialsBuilder::new().certificate_verifier(verifier));
+        let res = client.say_hello(&req);
+        if accept {
+            assert_eq!(res.unwrap().message, "Hello world");
+        } else {
+            match res.unwrap_err() {
+                Error::RpcFailure(s) => assert_eq!(s.code(), RpcStatusCode::UNAVAILABLE),
+                e => panic!("unexpected error {:?}", e),
+            }
+        }
+        let (common_name, dns_names) = peers.lock().unwrap().pop().unwrap();
+        assert_eq!(common_name, "*.test.google.com.au");
+        assert!(
+            dns_names.iter().any(|n| n == "*.test.google.fr"),
+            "{:?}",
+            dns_names
+        );
+    }
+
+    // Servers must be provided with identity certificates.
+    let res = TlsCredentialsBuilder::new().build_server_credentials();
+    assert!(matches!(res, Err(Error::InvalidTlsOptions(_))));
+}
+
+#[test]
+fn test_tls_session_key_log() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let server_keys = Arc::new(Mutex::new(vec![]));
+    let keys = server_keys.clone();
+    let (crt, key) = read_cert_pair("server1").unwrap();
+    let server_creds = ServerCredentialsBuilder::new()
+        .add_cert(crt.into(), key.into())
+        .tls_session_key_log_callback(move |line| keys.lock().unwrap().push(line.to_owned()))
+        .build();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(GreeterService))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_creds)
+        .unwrap();
+    server.start();
+
+    let log_path =
+        std::env::temp_dir().join(format!("grpcio-test-keys-{}.log", std::process::id()));
+    let creds = ChannelCredentialsBuilder::new()
+        .root_cert(read_single_crt("ca").unwrap().into())
+        .tls_session_key_log_file(&log_path)
+        .build();
+    let ch = ChannelBuilder::new(env)
+        .override_ssl_target("rust.test.google.fr")
+        .set_credentials(creds)
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    let mut req = HelloRequest::default();
+    req.name = "world".to_owned();
+    let reply = client.say_hello(&req).unwrap();
+    assert_eq!(reply.message, "Hello world");
+
+    // Every line is `<label> <client random> <secret>`.
+    let check = |lines: &[String]| {
+        !lines.is_empty()
+            && lines
+                .iter()
+                .all(|l| l.split(' ').filter(|s| !s.is_empty()).count() == 3)
+    };
+    let client_keys: Vec<String> = std::fs::read_to_string(&log_path)
+        .unwrap()
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    std::fs::remove_file(&log_path).unwrap();
+    assert!(check(&client_keys), "{:?}", client_keys);
+
+    // Keys of the server are forwarded asynchronously.
+    let timer = std::time::Instant::now();
+    while server_keys.lock().unwrap().len() < client_keys.len() {
+        assert!(timer.elapsed() < std::time::Duration::from_secs(5));
+        thread::sleep(std::time::Duration::from_millis(50));
+    }
+    let server_keys = server_keys.lock().unwrap();
+    assert!(check(&server_keys), "{:?}", server_keys);
+    // Both sides log the same secrets.
+    for key in &client_keys {
+        assert!(server_keys.contains(key), "{} {:?}", key, server_keys);
+    }
+}
+
+struct KeyLogFetcher {
+    keys: Arc<Mutex<Vec<String>>>,
+}
+
+impl ChannelCredentialsFetcher for KeyLogFetcher {
+    fn fetch(&self) -> Result<Option<ChannelCredentialsBuilder>, Box<dyn std::error::Error>> {
+        let keys = self.keys.clone();
+        let builder = ChannelCredentialsBuilder::new()
+            .root_cert(read_single_crt("ca").unwrap().into())
+            .tls_session_key_log_callback(move |line| keys.lock().unwrap().push(line.to_owned()));
+        Ok(Some(builder))
+    }
+}
+
+#[test]
+fn test_tls_session_key_log_with_fetcher() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let (crt, key) = read_cert_pair("server1").unwrap();
+    let server_creds = ServerCredentialsBuilder::new()
+        .add_cert(crt.into(), key.into())
+        .build();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(GreeterService))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", server_creds)
+        .unwrap();
+    server.start();
+
+    let keys = Arc::new(Mutex::new(vec![]));
+    let fetcher = KeyLogFetcher { keys: keys.clone() };
+    let creds =
+        ChannelCredentials::with_fetcher(Box::new(fetcher), std::time::Duration::from_secs(60))
+            .unwrap();
+    let ch = ChannelBuilder::new(env)
+        .override_ssl_target("rust.test.google.fr")
+        .set_credentials(creds)
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    client.say_hello(&HelloRequest::default()).unwrap();
+
+    // Keys set on the fetched builder are logged.
+    let timer = std::time::Instant::now();
+    while keys.lock().unwrap().is_empty() {
+        assert!(timer.elapsed() < std::time::Duration::from_secs(5));
+        thread::sleep(std::time::Duration::from_millis(50));
+    }
+}
@@ -6,11 +6,17 @@ use futures_util::{SinkExt, TryStreamExt};
 use grpcio::*;
 use grpcio_proto::example::helloworld::*;
 
+#[cfg(any(feature = "protobufv3-codec", feature = "prost-codec"))]
+use grpcio_proto::error_details::bad_request::FieldViolation;
+#[cfg(feature = "protobuf-codec")]
+use grpcio_proto::error_details::BadRequest_FieldViolation as FieldViolation;
+use grpcio_proto::error_details::*;
 use grpcio_proto::example::route_guide::{Feature, Rectangle};
 use grpcio_proto::example::route_guide_grpc::{create_route_guide, RouteGuide, RouteGuideClient};
 use grpcio_proto::google::rpc::Status;
 use std::convert::TryInto;
 use std::sync::*;
+use std::time::Duration;
 
 #[derive(Clone)]
 struct GreeterService;
@@ -44,6 +50,29 @@ impl Greeter for GreeterService {
             );
             return;
         }
+        if req.name.is_empty() {
+            let mut status = Status {
+                code: RpcStatusCode::INVALID_ARGUMENT.into(),
+                message: "name is required".to_owned(),
+                ..Default::default()
+            };
+            let violation = FieldViolation {
+                field: "name".to_owned(),
+                description: "name can't be empty".to_owned(),
+                ..Default::default()
+            };
+            let mut bad_request = BadRequest::default();
+            bad_request.field_violations.push(violation);
+            status.add_detail(&bad_request).unwrap();
+            let retry_info = RetryInfo::with_delay(Duration::from_millis(1500));
+            status.add_detail(&retry_info).unwrap();
+            ctx.spawn(
+                sink.fail(status.try_into().unwrap())
+                    .map_err(|e| panic!("failed to report error: {:?}", e))
+                    .map(|_| ()),
+            );
+            return;
+        }
 
         let mut resp = HelloReply::default();
         resp.message = format!("hello {}", req.name);
@@ -148,3 +177,211 @@ fn test_rich_error() {
     let details: Option<HelloRequest> = s.details[0].unpack().unwrap();
     assert_eq!(Some(req), details);
 }
+
+/// Tests standard error details can be sent and extracted.
+#[test]
+fn test_error_details() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_greeter(GreeterService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let err = client.say_hello(&HelloRequest::default()).unwrap_err();
+    let s = Status::from_error(&err).unwrap();
+    assert_eq!(s.code, i32::from(RpcStatusCode::INVALID_ARGUMENT));
+    assert_eq!(s.message, "name is required");
+    let bad_request: BadRequest = s.get_detail().unwrap().unwrap();
+    assert_eq!(bad_request.field_violations.len(), 1);
+    assert_eq!(bad_request.field_violations[0].field, "name");
+    let retry_info: RetryInfo = s.get_detail().unwrap().unwrap();
+    assert_eq!(
+        retry_info.delay().unwrap(),
+        Some(Duration::from_millis(1500))
+    );
+    assert_eq!(s.get_detail::<ErrorInfo>().unwrap(), None);
+
+    // Status without details can also be extracted.
+    let err = Error::RpcFailure(RpcStatus::with_message(
+        RpcStatusCode::NOT_FOUND,
+        "not found".to_owned(),
+    ));
+    let s = Status::from_error(&err).unwrap();
+    assert_eq!(s.code, i32::from(RpcStatusCode::NOT_FOUND));
+    assert!(s.details.is_empty());
+    assert!(Status::from_error(&Error::RemoteStopped).is_none());
+}
+
+#[derive(Clone)]
+struct HeaderInterceptor;
+
+impl ClientInterceptor for HeaderInterceptor {
+    fn on_start(&mut self, method: &str, opt: &mut CallOption) -> CheckResult {
+        assert_eq!(method, "/helloworld.Greeter/SayHello");
+        let mut builder = MetadataBuilder::new();
+        builder.add_str("k2", "v2").unwrap();
+        *opt = std::mem::take(opt).headers(builder.build());
+        CheckResult::Continue
+    }
+
+    fn box_clone(&self) -> Box<dyn ClientInterceptor> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Clone)]
+struct RecordInterceptor {
+    abort: bool,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ClientInterceptor for RecordInterceptor {
+    fn on_start(&mut self, _: &str, _: &mut CallOption) -> CheckResult {
+        self.events.lock().unwrap().push("start".to_owned());
+        if self.abort {
+            CheckResult::Abort(RpcStatus::with_message(
+                RpcStatusCode::PERMISSION_DENIED,
+                "denied by interceptor".to_owned(),
+            ))
+        } else {
+            CheckResult::Continue
+        }
+    }
+
+    fn on_send(&mut self, _: &mut GrpcSlice) -> CheckResult {
+        self.events.lock().unwrap().push("send".to_owned());
+        CheckResult::Continue
+    }
+
+    fn on_receive(&mut self, _: &mut GrpcSlice) -> CheckResult {
+        self.events.lock().unwrap().push("receive".to_owned());
+        CheckResult::Continue
+    }
+
+    fn on_finish(&mut self, res: std::result::Result<(), &Error>) {
+        let event = match res {
+            Ok(()) => "finish ok".to_owned(),
+            Err(e) => format!("finish {e}"),
+        };
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn box_clone(&self) -> Box<dyn ClientInterceptor> {
+        Box::new(self.clone())
+    }
+}
+
+/// Tests client interceptors can change headers and observe the whole call.
+#[test]
+fn test_client_interceptor() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_greeter(GreeterService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env)
+        .add_interceptor(HeaderInterceptor)
+        .connect(&format!("127.0.0.1:{port}"));
+    let events = Arc::new(Mutex::new(vec![]));
+    let client = GreeterClient {
+        client: Client::new(ch.clone()).add_interceptor(RecordInterceptor {
+            abort: false,
+            events: events.clone(),
+        }),
+    };
+
+    let req = HelloRequest {
+        name: "world".to_owned(),
+        ..Default::default()
+    };
+    let mut resp = client.say_hello_async(&req).unwrap();
+    let headers = block_on(resp.headers()).unwrap();
+    let v: Vec<_> = headers.iter().collect();
+    assert_eq!(v, [("k2", b"v2" as &[u8])]);
+    assert_eq!(block_on(resp.message()).unwrap().message, "hello world");
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["start", "send", "receive", "finish ok"]
+    );
+
+    events.lock().unwrap().clear();
+    let client = GreeterClient {
+        client: Client::new(ch).add_interceptor(RecordInterceptor {
+            abort: true,
+            events: events.clone(),
+        }),
+    };
+    let err = client.say_hello(&req).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "RpcFailure: 7-PERMISSION_DENIED denied by interceptor"
+    );
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "start",
+            "finish RpcFailure: 7-PERMISSION_DENIED denied by interceptor"
+        ]
+    );
+}
+
+#[derive(Clone)]
+struct TraceService;
+
+impl Greeter for TraceService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, _: HelloRequest, sink: UnarySink<HelloReply>) {
+        let trace = ctx.trace_context().unwrap().clone();
+        assert_eq!(TraceContext::current().as_ref(), Some(&trace));
+        ctx.spawn(async move {
+            // The context is also available in spawned futures.
+            let current = TraceContext::current().unwrap();
+            let mut resp = HelloReply::default();
+            resp.message = current.to_traceparent();
+            sink.success(resp).await.unwrap();
+        });
+    }
+}
+
+#[test]
+fn test_trace_context() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_greeter(TraceService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .trace_propagator(W3cPropagator)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env)
+        .add_interceptor(TracePropagation::default())
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let root = TraceContext::new_root().with_trace_state("vendor=1");
+    let resp = root
+        .in_scope(|| client.say_hello(&HelloRequest::default()))
+        .unwrap();
+    let server_ctx = TraceContext::from_traceparent(&resp.message).unwrap();
+    assert_eq!(server_ctx.trace_id(), root.trace_id());
+    assert_ne!(server_ctx.span_id(), root.span_id());
+
+    // A new trace is started if there is no current context.
+    let resp = client.say_hello(&HelloRequest::default()).unwrap();
+    let server_ctx = TraceContext::from_traceparent(&resp.message).unwrap();
+    assert_ne!(server_ctx.trace_id(), root.trace_id());
+}
@@ -6,6 +6,8 @@ use futures_util::future::{self, FutureExt as _, TryFutureExt as _};
 use grpcio::*;
 use grpcio_proto::example::helloworld::*;
 
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
 use std::sync::atomic::*;
 use std::sync::*;
 use std::thread::{self, JoinHandle};
@@ -27,6 +29,53 @@ impl Greeter for PeerService {
     }
 }
 
+#[test]
+fn test_in_process_channel() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(PeerService))
+        .build()
+        .unwrap();
+    server.start();
+    let args = ChannelBuilder::new(env).build_args();
+    let client = GreeterClient::new(server.in_process_channel(args));
+
+    let req = HelloRequest::default();
+    let resp = client.say_hello(&req).unwrap();
+    assert!(resp.message.contains("inproc"), "{:?}", resp);
+
+    block_on(server.shutdown()).unwrap();
+    assert!(client.say_hello(&req).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_socketpair_channel() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(PeerService))
+        .build()
+        .unwrap();
+    server.start();
+    let req = HelloRequest::default();
+
+    let ch = server
+        .socketpair_channel(ChannelBuilder::new(env.clone()))
+        .unwrap();
+    let client = GreeterClient::new(ch);
+    client.say_hello(&req).unwrap();
+
+    // The returned end can be handed over, e.g. to a child process.
+    let fd = server.add_socketpair().unwrap();
+    let ch = ChannelBuilder::new(env).connect_from_ownedfd("socketpair", fd);
+    let client2 = GreeterClient::new(ch);
+    client2.say_hello(&req).unwrap();
+
+    block_on(server.shutdown()).unwrap();
+    assert!(client.say_hello(&req).is_err());
+    assert!(client2.say_hello(&req).is_err());
+}
+
 #[derive(Clone)]
 struct SleepService(bool);
 
@@ -289,6 +338,146 @@ impl ServerChecker for FlagChecker {
     }
 }
 
+#[test]
+fn test_custom_async_checker_server_side() {
+    let flag = Arc::new(atomic::AtomicBool::new(false));
+    let checker = AsyncFlagChecker { flag: flag.clone() };
+
+    let env = Arc::new(Environment::new(2));
+    let service = create_greeter(PeerService);
+    let mut server = ServerBuilder::new(env.clone())
+        .add_async_checker(checker)
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    let req = HelloRequest::default();
+
+    let resp = client.say_hello(&req).unwrap();
+    assert!(!resp.message.is_empty());
+
+    flag.store(true, Ordering::SeqCst);
+    assert_eq!(
+        client.say_hello(&req).unwrap_err().to_string(),
+        "RpcFailure: 7-PERMISSION_DENIED".to_owned()
+    );
+}
+
+#[derive(Clone)]
+struct AsyncFlagChecker {
+    flag: Arc<atomic::AtomicBool>,
+}
+
+impl AsyncServerChecker for AsyncFlagChecker {
+    fn check(&mut self, ctx: &RpcContext) -> future::BoxFuture<'static, CheckResult> {
+        assert_eq!(ctx.method(), b"/helloworld.Greeter/SayHello");
+        let flag = self.flag.clone();
+        Box::pin(async move {
+            // Simulate a remote lookup.
+            Delay::new(Duration::from_millis(10)).await;
+            if flag.load(Ordering::SeqCst) {
+                CheckResult::Abort(RpcStatus::new(RpcStatusCode::PERMISSION_DENIED))
+            } else {
+                CheckResult::Continue
+            }
+        })
+    }
+
+    fn box_clone(&self) -> Box<dyn AsyncServerChecker> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_custom_interceptor_server_side() {
+    let flag = Arc::new(atomic::AtomicBool::new(false));
+    let events = Arc::new(Mutex::new(vec![]));
+    let interceptor = RecordInterceptor {
+        flag: flag.clone(),
+        events: events.clone(),
+    };
+
+    let env = Arc::new(Environment::new(2));
+    let service = create_greeter(PeerService);
+    let mut server = ServerBuilder::new(env.clone())
+        .add_interceptor(interceptor)
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    let req = HelloRequest::default();
+
+    let resp = client.say_hello(&req).unwrap();
+    assert!(resp.message.contains("127.0.0.1"), "{:?}", resp);
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["start", "request", "response", "status 0-OK"]
+    );
+
+    events.lock().unwrap().clear();
+    flag.store(true, Ordering::SeqCst);
+    assert_eq!(
+        client.say_hello(&req).unwrap_err().to_string(),
+        "RpcFailure: 7-PERMISSION_DENIED denied by interceptor".to_owned()
+    );
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["start", "request", "status 7-PERMISSION_DENIED"]
+    );
+}
+
+#[derive(Clone)]
+struct RecordInterceptor {
+    flag: Arc<atomic::AtomicBool>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServerInterceptor for RecordInterceptor {
+    fn on_start(&mut self, ctx: &RpcContext) -> CheckResult {
+        assert_eq!(ctx.method(), b"/helloworld.Greeter/SayHello");
+        self.events.lock().unwrap().push("start".to_owned());
+        CheckResult::Continue
+    }
+
+    fn on_request(&mut self, _: &mut GrpcSlice) -> CheckResult {
+        self.events.lock().unwrap().push("request".to_owned());
+        if self.flag.load(Ordering::SeqCst) {
+            CheckResult::Abort(RpcStatus::with_message(
+                RpcStatusCode::PERMISSION_DENIED,
+                "denied by interceptor".to_owned(),
+            ))
+        } else {
+            CheckResult::Continue
+        }
+    }
+
+    fn on_response(&mut self, _: &mut GrpcSlice) -> CheckResult {
+        self.events.lock().unwrap().push("response".to_owned());
+        CheckResult::Continue
+    }
+
+    fn on_status(&mut self, status: &mut RpcStatus) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("status {}", status.code()));
+    }
+
+    fn box_clone(&self) -> Box<dyn ServerInterceptor> {
+        Box::new(self.clone())
+    }
+}
+
 /// Tests connectivity related API works as expected.
 #[test]
 fn test_connectivity() {
@@ -421,3 +610,570 @@ fn test_channelz() {
         res
     );
 }
+
+/// Tests typed channelz APIs.
+#[test]
+fn test_channelz_types() {
+    let env = Arc::new(Environment::new(2));
+    let service = create_greeter(PeerService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let target = format!("127.0.0.1:{port}");
+    let ch = ChannelBuilder::new(env).connect(&target);
+    let client = GreeterClient::new(ch);
+    client.say_hello(&HelloRequest::default()).unwrap();
+
+    let channel = channelz::iter_top_channels()
+        .map(Result::unwrap)
+        .find(|c| c.target.ends_with(&target))
+        .unwrap();
+    assert_eq!(channel.state, Some(ConnectivityState::GRPC_CHANNEL_READY));
+    assert_eq!(channel.calls.calls_started, 1);
+    assert_eq!(channel.calls.calls_succeeded, 1);
+    assert!(channel.calls.last_call_started.is_some());
+    let c = channelz::channel(channel.id).unwrap().unwrap();
+    assert_eq!((c.id, c.target), (channel.id, channel.target));
+    assert_eq!(channelz::channel(0).unwrap(), None);
+
+    // Servers created by other tests can show up, find the one by address.
+    let mut found = None;
+    for s in channelz::iter_servers() {
+        let s = s.unwrap();
+        for r in channelz::iter_server_sockets(s.id) {
+            let socket = channelz::socket(r.unwrap().id).unwrap().unwrap();
+            let local = socket.local.clone();
+            if local == Some(channelz::SocketAddress::Tcp(target.parse().unwrap())) {
+                found = Some((s.clone(), socket));
+            }
+        }
+    }
+    let (s, socket) = found.unwrap();
+    assert_eq!(s.calls.calls_started, 1);
+    assert_eq!(channelz::server(s.id).unwrap().map(|s| s.id), Some(s.id));
+    assert_eq!(socket.streams_started, 1);
+    assert!(socket.messages_received >= 1, "{:?}", socket);
+    assert!(
+        matches!(socket.remote, Some(channelz::SocketAddress::Tcp(_))),
+        "{:?}",
+        socket
+    );
+}
+
+#[derive(Clone)]
+struct FlakyService(Arc<AtomicUsize>);
+
+impl Greeter for FlakyService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, _: HelloRequest, sink: UnarySink<HelloReply>) {
+        let f = if self.0.fetch_add(1, Ordering::SeqCst) < 2 {
+            sink.fail(RpcStatus::new(RpcStatusCode::UNAVAILABLE))
+        } else {
+            sink.success(HelloReply::default())
+        };
+        ctx.spawn(f.map_err(|e| panic!("failed to reply {:?}", e)).map(|_| ()));
+    }
+}
+
+/// Tests retries configured by typed service config.
+#[test]
+fn test_service_config_retry() {
+    let env = Arc::new(Environment::new(2));
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let service = create_greeter(FlakyService(attempts.clone()));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+
+    let retry_policy = RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(100),
+        backoff_multiplier: 2.0,
+        retryable_status_codes: vec![RpcStatusCode::UNAVAILABLE],
+    };
+    let config = ServiceConfigBuilder::new()
+        .add_method_config(
+            MethodConfig::new()
+                .add_name("helloworld.Greeter", "")
+                .retry_policy(retry_policy),
+        )
+        .build()
+        .unwrap();
+    let ch = ChannelBuilder::new(env)
+        .service_config(config)
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    client.say_hello(&HelloRequest::default()).unwrap();
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    // Retries are exhausted.
+    attempts.store(0, Ordering::SeqCst);
+    let config = ServiceConfigBuilder::new()
+        .add_method_config(
+            MethodConfig::new()
+                .add_name("", "")
+                .retry_policy(RetryPolicy {
+                    max_attempts: 2,
+                    initial_backoff: Duration::from_millis(10),
+                    max_backoff: Duration::from_millis(10),
+                    backoff_multiplier: 1.0,
+                    retryable_status_codes: vec![RpcStatusCode::UNAVAILABLE],
+                }),
+        )
+        .build()
+        .unwrap();
+    let ch = ChannelBuilder::new(Arc::new(Environment::new(1)))
+        .service_config(config)
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    match client.say_hello(&HelloRequest::default()) {
+        Err(Error::RpcFailure(s)) => assert_eq!(s.code(), RpcStatusCode::UNAVAILABLE),
+        r => panic!("unexpected result {:?}", r),
+    }
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+}
+
+#[derive(Clone)]
+struct CompressService;
+
+impl Greeter for CompressService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, req: HelloRequest, sink: UnarySink<HelloReply>) {
+        if req.name.len() > 1024 {
+            ctx.set_compression_algorithm(CompressionAlgorithms::GRPC_COMPRESS_GZIP);
+        }
+        let mut resp = HelloReply::default();
+        resp.message = req.name;
+        ctx.spawn(
+            sink.success(resp)
+                .map_err(|e| panic!("failed to reply {:?}", e))
+                .map(|_| ()),
+        );
+    }
+}
+
+/// Copies bytes from `from` to `to` and counts them in `counter`.
+fn forward(mut from: TcpStream, mut to: TcpStream, counter: Arc<AtomicUsize>) {
+    thread::spawn(move || {
+        let mut buf = [0; 4096];
+        loop {
+            let n = match from.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            counter.fetch_add(n, Ordering::SeqCst);
+            if to.write_all(&buf[..n]).is_err() {
+                break;
+            }
+        }
+        let _ = to.shutdown(Shutdown::Write);
+    });
+}
+
+/// Starts a TCP proxy to `port`, returns its port and the bytes sent to the
+/// server and to the client.
+fn counting_proxy(port: u16) -> (u16, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let proxy_port = listener.local_addr().unwrap().port();
+    let to_server = Arc::new(AtomicUsize::new(0));
+    let to_client = Arc::new(AtomicUsize::new(0));
+    let (ts, tc) = (to_server.clone(), to_client.clone());
+    thread::spawn(move || {
+        for client in listener.incoming() {
+            let client = match client {
+                Ok(c) => c,
+                Err(_) => return,
+            };
+            let server = TcpStream::connect(("127.0.0.1", port)).unwrap();
+            forward(
+                client.try_clone().unwrap(),
+                server.try_clone().unwrap(),
+                ts.clone(),
+            );
+            forward(server, client, tc.clone());
+        }
+    });
+    (proxy_port, to_server, to_client)
+}
+
+/// Tests compression can be chosen per call.
+#[test]
+fn test_call_compression() {
+    let env = Arc::new(Environment::new(2));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(CompressService))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    // Messages are checked on the wire, as C core hides `grpc-encoding` from
+    // applications.
+    let (proxy_port, to_server, to_client) = counting_proxy(port);
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{proxy_port}"));
+    let client = GreeterClient::new(ch);
+
+    let opts = vec![
+        (CallOption::default(), false),
+        (
+            CallOption::default().compression_algorithm(CompressionAlgorithms::GRPC_COMPRESS_GZIP),
+            true,
+        ),
+        (
+            CallOption::default()
+                .compression_algorithm(CompressionAlgorithms::GRPC_COMPRESS_DEFLATE),
+            true,
+        ),
+        (
+            CallOption::default().compression_level(CompressionLevel::GRPC_COMPRESS_LEVEL_HIGH),
+            true,
+        ),
+        (
+            CallOption::default().compression_level(CompressionLevel::GRPC_COMPRESS_LEVEL_NONE),
+            false,
+        ),
+    ];
+    for (opt, compressed) in opts {
+        for len in [10, 4096] {
+            let mut req = HelloRequest::default();
+            req.name = "a".repeat(len);
+            let sent = to_server.load(Ordering::SeqCst);
+            let received = to_client.load(Ordering::SeqCst);
+            let resp = client.say_hello_opt(&req, opt.clone()).unwrap();
+            assert_eq!(resp.message, req.name);
+            if len < 1024 {
+                continue;
+            }
+            let sent = to_server.load(Ordering::SeqCst) - sent;
+            assert_eq!(sent < 1024, compressed, "{} bytes sent", sent);
+            // The server always compresses large responses.
+            let received = to_client.load(Ordering::SeqCst) - received;
+            assert!(received < 1024, "{} bytes received", received);
+        }
+    }
+    let opt = CallOption::default().compression_level(CompressionLevel::GRPC_COMPRESS_LEVEL_NONE);
+    assert_eq!(
+        opt.get_compression_algorithm(),
+        Some(CompressionAlgorithms::GRPC_COMPRESS_NONE)
+    );
+}
+
+#[test]
+fn test_cq_stats() {
+    let env = Arc::new(EnvBuilder::new().cq_count(1).build());
+    let service = create_greeter(PeerService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env.clone()).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    client.say_hello(&HelloRequest::default()).unwrap();
+
+    let stats = env.cq_stats();
+    assert_eq!(stats.len(), 1);
+    let stats = stats[0];
+    assert!(stats.events > 0, "{:?}", stats);
+    assert!(stats.spawned_polls > 0, "{:?}", stats);
+    assert!(stats.busy_time >= stats.spawned_poll_time, "{:?}", stats);
+    assert!(stats.idle_time > Duration::ZERO, "{:?}", stats);
+}
+
+#[test]
+fn test_server_metrics() {
+    let env = Arc::new(Environment::new(1));
+    let metrics = ServerMetrics::new();
+    let service = create_greeter(PeerService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .metrics(metrics.clone())
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+    for _ in 0..2 {
+        client.say_hello(&HelloRequest::default()).unwrap();
+    }
+
+    // The status is recorded before it's sent, so the client always sees it.
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.len(), 1, "{:?}", snapshot);
+    let m = &snapshot[0];
+    assert_eq!(m.method, "/helloworld.Greeter/SayHello");
+    assert_eq!(m.requests, 2);
+    assert_eq!(m.in_flight, 0);
+    assert_eq!(m.status_codes, vec![(RpcStatusCode::OK, 2)]);
+    assert_eq!(m.latency.count, 2);
+    assert_eq!(m.request_messages, 2);
+    assert_eq!(m.response_messages, 2);
+    assert!(m.response_bytes > 0);
+    let text = metrics.prometheus_text();
+    assert!(text.contains(
+        "grpc_server_started_total{grpc_service=\"helloworld.Greeter\",grpc_method=\"SayHello\"} 2"
+    ));
+}
+
+#[test]
+fn test_deadline() {
+    let deadline = Deadline::after(Duration::from_secs(10));
+    assert!(!deadline.exceeded());
+    let remaining = deadline.remaining();
+    assert!(remaining <= Duration::from_secs(10), "{:?}", remaining);
+    assert!(remaining > Duration::from_secs(9), "{:?}", remaining);
+
+    let deadline = Deadline::at(SystemTime::now() - Duration::from_secs(1));
+    assert!(deadline.exceeded());
+    assert_eq!(deadline.remaining(), Duration::ZERO);
+
+    assert!(Deadline::infinite().is_infinite());
+    assert!(!Deadline::infinite().exceeded());
+    assert!(!deadline.is_infinite());
+}
+
+#[derive(Clone)]
+struct ProxyService {
+    client: GreeterClient,
+    tx: mpsc::SyncSender<(Duration, RpcStatus)>,
+}
+
+impl Greeter for ProxyService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, req: HelloRequest, sink: UnarySink<HelloReply>) {
+        let start = Instant::now();
+        let opt = CallOption::default().propagate_from(&ctx, PropagationMask::DEADLINE);
+        let f = self.client.say_hello_async_opt(&req, opt).unwrap();
+        let tx = self.tx.clone();
+        ctx.spawn(async move {
+            let status = match f.await {
+                Ok(_) => RpcStatus::ok(),
+                Err(Error::RpcFailure(s)) => s,
+                Err(e) => panic!("unexpected error {:?}", e),
+            };
+            tx.send((start.elapsed(), status.clone())).unwrap();
+            let _ = sink.fail(status).await;
+        });
+    }
+}
+
+#[test]
+fn test_deadline_propagation() {
+    let env = Arc::new(EnvBuilder::new().build());
+    // The backend takes 3s to reply.
+    let mut backend = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(SleepService(true)))
+        .build()
+        .unwrap();
+    let port = backend
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    backend.start();
+    let ch = ChannelBuilder::new(env.clone()).connect(&format!("127.0.0.1:{port}"));
+    let (tx, rx) = mpsc::sync_channel(1);
+    let service = ProxyService {
+        client: GreeterClient::new(ch),
+        tx,
+    };
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(service))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let opt = CallOption::default().deadline(Duration::from_millis(500));
+    let err = client
+        .say_hello_opt(&HelloRequest::default(), opt)
+        .unwrap_err();
+    match err {
+        Error::RpcFailure(s) => assert_eq!(s.code(), RpcStatusCode::DEADLINE_EXCEEDED),
+        e => panic!("unexpected error {:?}", e),
+    }
+    // The call to the backend should inherit the deadline instead of waiting for 3s.
+    let (elapsed, status) = rx.recv_timeout(Duration::from_secs(2)).unwrap();
+    assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+    assert_eq!(status.code(), RpcStatusCode::DEADLINE_EXCEEDED);
+}
+
+#[test]
+fn test_graceful_shutdown() {
+    let env = Arc::new(Environment::new(2));
+    let hooked = Arc::new(AtomicBool::new(false));
+    let h = hooked.clone();
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(SleepService(true)))
+        .before_shutdown(move || h.store(true, Ordering::SeqCst))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let send_task = client.say_hello_async(&HelloRequest::default()).unwrap();
+    // Wait for the call to reach the server.
+    thread::sleep(Duration::from_millis(500));
+    let timer = Instant::now();
+    let stats = block_on(server.graceful_shutdown(Duration::from_millis(200))).unwrap();
+    assert!(hooked.load(Ordering::SeqCst));
+    assert!(
+        timer.elapsed() < Duration::from_secs(2),
+        "{:?}",
+        timer.elapsed()
+    );
+    assert_eq!(
+        stats,
+        ShutdownStats {
+            drained: 0,
+            cancelled: 1
+        }
+    );
+    assert!(block_on(send_task).is_err());
+}
+
+#[derive(Clone)]
+struct DelayService(Duration);
+
+impl Greeter for DelayService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, _: HelloRequest, sink: UnarySink<HelloReply>) {
+        let delay = Delay::new(self.0);
+        ctx.spawn(async move {
+            delay.await;
+            let _ = sink.success(HelloReply::default()).await;
+        });
+    }
+}
+
+/// Tests calls that finish before the timeout are counted as drained.
+#[test]
+fn test_graceful_shutdown_drained() {
+    let env = Arc::new(Environment::new(2));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(DelayService(Duration::from_millis(500))))
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let send_task = client.say_hello_async(&HelloRequest::default()).unwrap();
+    // Wait for the call to reach the server.
+    thread::sleep(Duration::from_millis(200));
+    let stats = block_on(server.graceful_shutdown(Duration::from_secs(10))).unwrap();
+    assert_eq!(
+        stats,
+        ShutdownStats {
+            drained: 1,
+            cancelled: 0
+        }
+    );
+    block_on(send_task).unwrap();
+}
+
+#[test]
+fn test_concurrency_limit() {
+    let env = Arc::new(Environment::new(2));
+    let limit = ConcurrencyLimit::new(1)
+        .max_queued(1)
+        .retry_after(Duration::from_millis(1500));
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(DelayService(Duration::from_secs(1))))
+        .method_concurrency_limit("/helloworld.Greeter/SayHello", limit)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let req = HelloRequest::default();
+    let timer = Instant::now();
+    let running = client.say_hello_async(&req).unwrap();
+    let queued = client.say_hello_async(&req).unwrap();
+    thread::sleep(Duration::from_millis(200));
+    // Neither a permit nor a place in the queue is left.
+    let err = client.say_hello(&req).unwrap_err();
+    let status = grpcio_proto::google::rpc::Status::from_error(&err).unwrap();
+    assert_eq!(status.code, i32::from(RpcStatusCode::RESOURCE_EXHAUSTED));
+    let retry_info: grpcio_proto::error_details::RetryInfo = status.get_detail().unwrap().unwrap();
+    assert_eq!(
+        retry_info.delay().unwrap(),
+        Some(Duration::from_millis(1500))
+    );
+
+    block_on(running).unwrap();
+    block_on(queued).unwrap();
+    // Queued call is handled after the running one finishes.
+    assert!(
+        timer.elapsed() >= Duration::from_secs(2),
+        "{:?}",
+        timer.elapsed()
+    );
+    // Permits are released after calls finish.
+    block_on(client.say_hello_async(&req).unwrap()).unwrap();
+}
+
+/// Tests calls queue on both the method limit and the global limit without
+/// deadlocks.
+#[test]
+fn test_method_and_global_concurrency_limit() {
+    let env = Arc::new(Environment::new(2));
+    let limit = ConcurrencyLimit::new(1).max_queued(2);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(DelayService(Duration::from_millis(500))))
+        .concurrency_limit(limit.clone())
+        .method_concurrency_limit("/helloworld.Greeter/SayHello", limit)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    let req = HelloRequest::default();
+    let opt = CallOption::default().timeout(Duration::from_secs(10));
+    let timer = Instant::now();
+    let calls: Vec<_> = (0..3)
+        .map(|_| client.say_hello_async_opt(&req, opt.clone()).unwrap())
+        .collect();
+    for call in calls {
+        block_on(call).unwrap();
+    }
+    // Calls are handled one by one.
+    assert!(
+        timer.elapsed() >= Duration::from_millis(1500),
+        "{:?}",
+        timer.elapsed()
+    );
+    // Permits of both limits are released after calls finish.
+    block_on(client.say_hello_async(&req).unwrap()).unwrap();
+}
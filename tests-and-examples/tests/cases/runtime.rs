@@ -0,0 +1,82 @@
+// Copyright 2026 TiKV Project Authors. Licensed under Apache-2.0.
+
+use futures_channel::oneshot;
+use futures_util::future::{FutureExt as _, TryFutureExt as _};
+use grpcio::*;
+use grpcio_proto::example::helloworld::*;
+use std::sync::*;
+use std::thread;
+use std::time::*;
+use tokio::runtime::{Builder, Runtime};
+
+#[derive(Clone)]
+struct GreeterService;
+
+impl Greeter for GreeterService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, req: HelloRequest, sink: UnarySink<HelloReply>) {
+        let f = async move {
+            // Timers and blocking pools are only available inside a Tokio runtime.
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            let name = tokio::task::spawn_blocking(move || {
+                assert!(thread::current().name().unwrap().starts_with("tokio-test"));
+                req.name
+            })
+            .await?;
+            let mut resp = HelloReply::default();
+            resp.message = format!("hello {name}");
+            sink.success(resp).await?;
+            Ok(())
+        }
+        .map_err(|e: Box<dyn std::error::Error>| panic!("failed to handle request: {:?}", e))
+        .map(|_| ());
+        ctx.spawn(f)
+    }
+}
+
+fn new_runtime() -> Runtime {
+    Builder::new_multi_thread()
+        .worker_threads(2)
+        .thread_name("tokio-test")
+        .enable_time()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_spawn_on_tokio() {
+    let rt = new_runtime();
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_greeter(GreeterService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .spawn_on_tokio(rt.handle().clone())
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env)
+        .spawn_on_tokio(rt.handle().clone())
+        .connect(&format!("127.0.0.1:{port}"));
+    let client = GreeterClient::new(ch);
+
+    // Receivers can be awaited by Tokio directly.
+    let mut req = HelloRequest::default();
+    req.name = "world".to_owned();
+    let resp = rt.block_on(client.say_hello_async(&req).unwrap()).unwrap();
+    assert_eq!(resp.message, "hello world");
+
+    // Futures spawned by the client are polled by the runtime.
+    let (tx, rx) = oneshot::channel();
+    let c = client.clone();
+    client.spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let mut req = HelloRequest::default();
+        req.name = "tokio".to_owned();
+        let resp = c.say_hello_async(&req).unwrap().await;
+        tx.send(resp.map(|r| r.message)).unwrap();
+    });
+    let resp = rt.block_on(rx).unwrap().unwrap();
+    assert_eq!(resp, "hello tokio");
+}
@@ -115,6 +115,9 @@ const PROTOS: &[(&str, &[&str], &str, &str)] = &[
     ("proto/proto", &["grpc/testing"], "proto/src/proto", "testing"),
     ("proto/proto", &["grpc/example"], "proto/src/proto", "example"),
     ("proto/proto", &["google/rpc"], "proto/src/proto", "google/rpc"),
+    ("reflection/proto", &["grpc/reflection/v1"], "reflection/src/proto", "v1"),
+    ("reflection/proto", &["grpc/reflection/v1alpha"], "reflection/src/proto", "v1alpha"),
+    ("channelz/proto", &["grpc/channelz"], "channelz/src/proto", ""),
 ];
 
 const NAMING_PATCH: &[(&str, &[(&str, &str)])] = &[